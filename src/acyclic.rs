@@ -0,0 +1,369 @@
+//! A wrapper around graph types that keeps them acyclic.
+//!
+//! [`Acyclic`](struct.Acyclic.html) rejects every edge that would close a cycle, and maintains a
+//! topological order of its nodes incrementally while edges are added.
+
+use std::collections::{HashMap, HashSet};
+
+use crate::algo::topological::toposort_by;
+use crate::algo::Cycle;
+use crate::data::{Build, DataMapMut};
+use crate::visit::{
+    GraphBase, GraphProp, IntoNeighborsDirected, IntoNodeIdentifiers, NodeIndexable,
+};
+use crate::Directed;
+use crate::Direction::{Incoming, Outgoing};
+
+/// An error returned by [`Acyclic::try_add_edge`](struct.Acyclic.html#method.try_add_edge): the
+/// edge would have closed a cycle.
+///
+/// Holds the nodes of the would-be cycle in edge order, starting with the source of the rejected
+/// edge, which is followed by its target. The last node has an edge back to the first one in the
+/// graph.
+#[derive(Clone, Debug, PartialEq)]
+pub struct WouldCycle<N>(Vec<N>);
+
+impl<N> WouldCycle<N> {
+    /// Return the nodes of the cycle that the edge would have closed.
+    pub fn cycle(&self) -> &[N] {
+        &self.0
+    }
+
+    /// Return the nodes of the cycle that the edge would have closed.
+    pub fn into_cycle(self) -> Vec<N> {
+        self.0
+    }
+}
+
+/// A directed graph that is guaranteed to stay acyclic.
+///
+/// Edges can only be added through [`try_add_edge`](#method.try_add_edge), which rejects edges that
+/// would create a cycle. A topological order of the nodes is kept up to date using the dynamic
+/// topological sort of [Pearce and Kelly][1]: adding an edge only visits the nodes whose positions
+/// lie between the endpoints of the edge, instead of checking the whole graph for cycles.
+///
+/// The wrapped graph can be borrowed with [`inner`](#method.inner) and passed to any of the
+/// algorithms or traversals.
+///
+/// [1]: https://doi.org/10.1145/1187436.1210590
+///
+/// # Example
+/// ```rust
+/// use petgraph::acyclic::Acyclic;
+/// use petgraph::prelude::*;
+///
+/// let mut dag = Acyclic::<DiGraph<&str, ()>>::new();
+/// let a = dag.add_node("a");
+/// let b = dag.add_node("b");
+/// let c = dag.add_node("c");
+///
+/// assert!(dag.try_add_edge(c, b, ()).is_ok());
+/// assert!(dag.try_add_edge(b, a, ()).is_ok());
+/// assert_eq!(dag.topological_order(), &[c, b, a]);
+///
+/// let cycle = dag.try_add_edge(a, c, ()).unwrap_err();
+/// assert_eq!(cycle.cycle(), &[a, c, b]);
+/// assert_eq!(dag.inner().edge_count(), 2);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Acyclic<G>
+where
+    G: GraphBase,
+{
+    graph: G,
+    /// The nodes in topological order.
+    order: Vec<G::NodeId>,
+    /// The position of every node in `order`, indexed by `to_index`.
+    position: Vec<usize>,
+}
+
+impl<G> Default for Acyclic<G>
+where
+    G: GraphBase + Default,
+{
+    fn default() -> Self {
+        Acyclic {
+            graph: G::default(),
+            order: Vec::new(),
+            position: Vec::new(),
+        }
+    }
+}
+
+impl<G> Acyclic<G>
+where
+    G: GraphBase,
+{
+    /// Create a new, empty acyclic graph.
+    pub fn new() -> Self
+    where
+        G: Default,
+    {
+        Self::default()
+    }
+
+    /// Return a reference to the wrapped graph.
+    pub fn inner(&self) -> &G {
+        &self.graph
+    }
+
+    /// Return the wrapped graph.
+    pub fn into_inner(self) -> G {
+        self.graph
+    }
+
+    /// Return the nodes of the graph in topological order: every node is placed before all of its
+    /// successors.
+    ///
+    /// This is maintained incrementally and does not traverse the graph.
+    pub fn topological_order(&self) -> &[G::NodeId] {
+        &self.order
+    }
+}
+
+impl<G> Acyclic<G>
+where
+    G: Build + NodeIndexable + GraphProp<EdgeType = Directed>,
+    for<'a> &'a G: IntoNeighborsDirected + IntoNodeIdentifiers + GraphBase<NodeId = G::NodeId>,
+{
+    /// Wrap an existing graph, computing a topological order of its nodes.
+    ///
+    /// Return a `Cycle` error if the graph is not acyclic.
+    pub fn try_from_graph(graph: G) -> Result<Self, Cycle<G::NodeId>> {
+        let order = toposort_by(&graph, |&a, &b| graph.to_index(a).cmp(&graph.to_index(b)))?;
+        let mut position = vec![0; graph.node_bound()];
+        for (pos, &n) in order.iter().enumerate() {
+            position[graph.to_index(n)] = pos;
+        }
+        Ok(Acyclic {
+            graph,
+            order,
+            position,
+        })
+    }
+
+    /// Return the position of `n` in the topological order.
+    ///
+    /// **Panics** if `n` is not a node of the graph.
+    pub fn position(&self, n: G::NodeId) -> usize {
+        let pos = self.position.get(self.graph.to_index(n)).cloned();
+        match pos {
+            Some(pos) if self.order.get(pos) == Some(&n) => pos,
+            _ => panic!("Acyclic::position: node not found"),
+        }
+    }
+
+    /// Add a node with associated data `weight` to the graph, placing it last in the topological
+    /// order.
+    pub fn add_node(&mut self, weight: G::NodeWeight) -> G::NodeId {
+        let n = self.graph.add_node(weight);
+        let ix = self.graph.to_index(n);
+        if self.position.len() <= ix {
+            self.position.resize(ix + 1, 0);
+        }
+        self.position[ix] = self.order.len();
+        self.order.push(n);
+        n
+    }
+
+    /// Add an edge from `a` to `b` if it does not create a cycle.
+    ///
+    /// Return `Ok` with the result of `Build::add_edge`, which is `None` if the graph does not
+    /// allow parallel edges and the edge already exists. Otherwise, return the cycle that the edge
+    /// would have closed and leave the graph unchanged. Self loops are also cycles.
+    ///
+    /// The topological order is updated to place `a` before `b`.
+    ///
+    /// **Panics** if any of the nodes don't exist.
+    pub fn try_add_edge(
+        &mut self,
+        a: G::NodeId,
+        b: G::NodeId,
+        weight: G::EdgeWeight,
+    ) -> Result<Option<G::EdgeId>, WouldCycle<G::NodeId>> {
+        self.reorder(a, b)?;
+        Ok(self.graph.add_edge(a, b, weight))
+    }
+
+    /// Add or update an edge from `a` to `b` if it does not create a cycle.
+    ///
+    /// Return the id of the affected edge, or the cycle that the edge would have closed, leaving
+    /// the graph unchanged.
+    ///
+    /// **Panics** if any of the nodes don't exist.
+    pub fn try_update_edge(
+        &mut self,
+        a: G::NodeId,
+        b: G::NodeId,
+        weight: G::EdgeWeight,
+    ) -> Result<G::EdgeId, WouldCycle<G::NodeId>> {
+        self.reorder(a, b)?;
+        Ok(self.graph.update_edge(a, b, weight))
+    }
+
+    /// Return `true` if an edge from `a` to `b` can be added without creating a cycle.
+    ///
+    /// **Panics** if any of the nodes don't exist.
+    pub fn is_valid_edge(&self, a: G::NodeId, b: G::NodeId) -> bool {
+        if a == b {
+            return false;
+        }
+        let upper = self.position(a);
+        if self.position(b) > upper {
+            return true;
+        }
+        self.search_forward(b, upper).is_ok()
+    }
+
+    /// Search the successors of `start` whose position is less than `upper`.
+    ///
+    /// Return the visited nodes, or a path from `start` to the node at position `upper` if it is
+    /// reachable.
+    fn search_forward(
+        &self,
+        start: G::NodeId,
+        upper: usize,
+    ) -> Result<Vec<G::NodeId>, Vec<G::NodeId>> {
+        let g = &self.graph;
+        let mut parent = HashMap::new();
+        let mut visited = vec![start];
+        let mut stack = vec![start];
+        parent.insert(g.to_index(start), start);
+        while let Some(n) = stack.pop() {
+            for succ in g.neighbors_directed(n, Outgoing) {
+                let pos = self.position[g.to_index(succ)];
+                if pos == upper {
+                    let mut path = vec![n];
+                    let mut cur = n;
+                    while cur != start {
+                        cur = parent[&g.to_index(cur)];
+                        path.push(cur);
+                    }
+                    path.reverse();
+                    return Err(path);
+                }
+                if pos < upper && !parent.contains_key(&g.to_index(succ)) {
+                    parent.insert(g.to_index(succ), n);
+                    visited.push(succ);
+                    stack.push(succ);
+                }
+            }
+        }
+        Ok(visited)
+    }
+
+    /// Update the topological order so that `a` is placed before `b`, or return the cycle that an
+    /// edge from `a` to `b` would close.
+    fn reorder(&mut self, a: G::NodeId, b: G::NodeId) -> Result<(), WouldCycle<G::NodeId>> {
+        if a == b {
+            return Err(WouldCycle(vec![a]));
+        }
+        let lower = self.position(b);
+        let upper = self.position(a);
+        if lower > upper {
+            return Ok(());
+        }
+
+        // The affected region is the positions from `lower` to `upper`. Find the
+        // nodes reachable from `b` and the nodes reaching `a` within it.
+        let mut delta_forward = match self.search_forward(b, upper) {
+            Ok(visited) => visited,
+            Err(path) => {
+                let mut cycle = vec![a];
+                cycle.extend(path);
+                return Err(WouldCycle(cycle));
+            }
+        };
+
+        let g = &self.graph;
+        let mut delta_backward = vec![a];
+        let mut stack = vec![a];
+        let mut visited = HashSet::new();
+        visited.insert(g.to_index(a));
+        while let Some(n) = stack.pop() {
+            for pred in g.neighbors_directed(n, Incoming) {
+                let ix = g.to_index(pred);
+                if self.position[ix] > lower && visited.insert(ix) {
+                    delta_backward.push(pred);
+                    stack.push(pred);
+                }
+            }
+        }
+
+        // Place all of `delta_backward` before `delta_forward`, reusing the
+        // positions they occupied and keeping the relative order within each set.
+        let position = &self.position;
+        delta_backward.sort_by_key(|&n| position[g.to_index(n)]);
+        delta_forward.sort_by_key(|&n| position[g.to_index(n)]);
+        let mut positions: Vec<usize> = delta_backward
+            .iter()
+            .chain(&delta_forward)
+            .map(|&n| position[g.to_index(n)])
+            .collect();
+        positions.sort_unstable();
+
+        for (&n, pos) in delta_backward.iter().chain(&delta_forward).zip(positions) {
+            self.order[pos] = n;
+            self.position[g.to_index(n)] = pos;
+        }
+        Ok(())
+    }
+}
+
+impl<G> Acyclic<G>
+where
+    G: DataMapMut,
+{
+    /// Access the weight for node `n`, mutably.
+    ///
+    /// Weights don't affect the structure of the graph, so they can be changed freely.
+    pub fn node_weight_mut(&mut self, n: G::NodeId) -> Option<&mut G::NodeWeight> {
+        self.graph.node_weight_mut(n)
+    }
+
+    /// Access the weight for edge `e`, mutably.
+    ///
+    /// Weights don't affect the structure of the graph, so they can be changed freely.
+    pub fn edge_weight_mut(&mut self, e: G::EdgeId) -> Option<&mut G::EdgeWeight> {
+        self.graph.edge_weight_mut(e)
+    }
+}
+
+impl<N, E, Ix> Acyclic<crate::Graph<N, E, Directed, Ix>>
+where
+    Ix: crate::graph::IndexType,
+{
+    /// Remove an edge and return its edge weight, or `None` if it didn't exist.
+    ///
+    /// Removing an edge never invalidates the topological order.
+    pub fn remove_edge(&mut self, e: crate::graph::EdgeIndex<Ix>) -> Option<E> {
+        self.graph.remove_edge(e)
+    }
+}
+
+#[cfg(feature = "stable_graph")]
+impl<N, E, Ix> Acyclic<crate::stable_graph::StableGraph<N, E, Directed, Ix>>
+where
+    Ix: crate::graph::IndexType,
+{
+    /// Remove an edge and return its edge weight, or `None` if it didn't exist.
+    ///
+    /// Removing an edge never invalidates the topological order.
+    pub fn remove_edge(&mut self, e: crate::graph::EdgeIndex<Ix>) -> Option<E> {
+        self.graph.remove_edge(e)
+    }
+
+    /// Remove `n` from the graph along with its edges, and return its weight, or `None` if it
+    /// didn't exist.
+    ///
+    /// Computes in **O(|V|)** time, to close the gap in the topological order.
+    pub fn remove_node(&mut self, n: crate::graph::NodeIndex<Ix>) -> Option<N> {
+        let weight = self.graph.remove_node(n)?;
+        let pos = self.position[n.index()];
+        self.order.remove(pos);
+        for &m in &self.order[pos..] {
+            self.position[m.index()] -= 1;
+        }
+        Some(weight)
+    }
+}
@@ -0,0 +1,214 @@
+//! Every shortest path between two nodes, and the number of shortest paths to each node.
+//!
+//! Both functions run Dijkstra's algorithm once, keeping every predecessor that lies on a shortest
+//! path rather than just one. The predecessors form the *shortest-path DAG*, whose paths from
+//! `start` are exactly the shortest paths: [`all_shortest_paths`] walks it back from `goal`, and
+//! [`shortest_path_counts`] adds up the counts of the predecessors of each node in the order the
+//! nodes were settled.
+//!
+//! [`all_shortest_paths`]: fn.all_shortest_paths.html
+//! [`shortest_path_counts`]: fn.shortest_path_counts.html
+
+use std::collections::hash_map::Entry::{Occupied, Vacant};
+use std::collections::{BinaryHeap, HashMap};
+use std::hash::Hash;
+use std::iter::from_fn;
+
+use crate::algo::Measure;
+use crate::scored::MinScored;
+use crate::visit::{EdgeRef, IntoEdges, VisitMap, Visitable};
+
+/// Dijkstra's algorithm, recording every predecessor that lies on a shortest path.
+///
+/// Return the nodes in the order they were settled and, for each of them, the distinct predecessors
+/// in the shortest-path DAG.
+#[allow(clippy::type_complexity)]
+fn shortest_path_dag<G, F, K>(
+    graph: G,
+    start: G::NodeId,
+    goal: Option<G::NodeId>,
+    mut edge_cost: F,
+) -> (Vec<G::NodeId>, HashMap<G::NodeId, Vec<G::NodeId>>)
+where
+    G: IntoEdges + Visitable,
+    G::NodeId: Eq + Hash,
+    F: FnMut(G::EdgeRef) -> K,
+    K: Measure + Copy,
+{
+    let mut visited = graph.visit_map();
+    let mut scores = HashMap::new();
+    let mut predecessors: HashMap<G::NodeId, Vec<G::NodeId>> = HashMap::new();
+    let mut settled = Vec::new();
+    let mut visit_next = BinaryHeap::new();
+    scores.insert(start, K::default());
+    visit_next.push(MinScored(K::default(), start));
+    while let Some(MinScored(node_score, node)) = visit_next.pop() {
+        if !visited.visit(node) {
+            continue;
+        }
+        settled.push(node);
+        if goal.as_ref() == Some(&node) {
+            break;
+        }
+        for edge in graph.edges(node) {
+            let next = edge.target();
+            if visited.is_visited(&next) {
+                continue;
+            }
+            let next_score = node_score + edge_cost(edge);
+            match scores.entry(next) {
+                Occupied(ent) => {
+                    if next_score < *ent.get() {
+                        *ent.into_mut() = next_score;
+                        visit_next.push(MinScored(next_score, next));
+                        predecessors.insert(next, vec![node]);
+                    } else if next_score == *ent.get() {
+                        let preds = predecessors.entry(next).or_insert_with(Vec::new);
+                        if !preds.contains(&node) {
+                            preds.push(node);
+                        }
+                    }
+                }
+                Vacant(ent) => {
+                    ent.insert(next_score);
+                    visit_next.push(MinScored(next_score, next));
+                    predecessors.insert(next, vec![node]);
+                }
+            }
+        }
+    }
+    (settled, predecessors)
+}
+
+/// \[Generic\] Return an iterator over every shortest path from `start` to `goal`.
+///
+/// Unlike [`dijkstra`](../dijkstra/fn.dijkstra.html) and [`astar`](../astar/fn.astar.html), which
+/// find a single shortest path, this produces every path of minimum total cost, as the sequence of
+/// its nodes from `start` to `goal`. Paths that only differ by parallel edges are produced once.
+///
+/// The graph should be `Visitable` and implement `IntoEdges`. The function `edge_cost` should
+/// return the cost for a particular edge, which is used to compute path costs. Edge costs must be
+/// positive.
+///
+/// If `goal` is not reachable from `start`, the iterator is empty.
+///
+/// The shortest-path DAG is computed up front in **O(|E| + |V| log |V|)** time, and each path is
+/// then produced in time proportional to its length.
+///
+/// # Example
+/// ```rust
+/// use petgraph::prelude::*;
+/// use petgraph::algo::all_shortest_paths;
+///
+/// let mut graph = UnGraph::<(), u32>::new_undirected();
+/// let a = graph.add_node(());
+/// let b = graph.add_node(());
+/// let c = graph.add_node(());
+/// let d = graph.add_node(());
+/// graph.extend_with_edges(&[(a, b, 1), (b, d, 2), (a, c, 2), (c, d, 1), (a, d, 4)]);
+///
+/// let mut paths: Vec<_> = all_shortest_paths(&graph, a, d, |e| *e.weight()).collect();
+/// paths.sort();
+/// assert_eq!(paths, vec![vec![a, b, d], vec![a, c, d]]);
+/// ```
+pub fn all_shortest_paths<G, F, K>(
+    graph: G,
+    start: G::NodeId,
+    goal: G::NodeId,
+    edge_cost: F,
+) -> impl Iterator<Item = Vec<G::NodeId>>
+where
+    G: IntoEdges + Visitable,
+    G::NodeId: Eq + Hash,
+    F: FnMut(G::EdgeRef) -> K,
+    K: Measure + Copy,
+{
+    let (settled, predecessors) = shortest_path_dag(graph, start, Some(goal), edge_cost);
+
+    // Walk the shortest-path DAG backwards from `goal`. Each stack entry is a
+    // node on the current path and the index of the next predecessor to try.
+    let mut stack = Vec::new();
+    if settled.last() == Some(&goal) {
+        stack.push((goal, 0));
+    }
+    from_fn(move || {
+        while let Some(&mut (node, ref mut next)) = stack.last_mut() {
+            if node == start {
+                let path = stack.iter().rev().map(|&(n, _)| n).collect();
+                stack.pop();
+                return Some(path);
+            }
+            match predecessors.get(&node).and_then(|preds| preds.get(*next)) {
+                Some(&pred) => {
+                    *next += 1;
+                    stack.push((pred, 0));
+                }
+                None => {
+                    stack.pop();
+                }
+            }
+        }
+        None
+    })
+}
+
+/// \[Generic\] Count the shortest paths from `start` to every reachable node.
+///
+/// The counts are accumulated over the shortest-path DAG: the number of shortest paths to a node is
+/// the sum over its predecessors on shortest paths. Paths that only differ by parallel edges are
+/// counted once, and counts saturate at `usize::MAX`.
+///
+/// The graph should be `Visitable` and implement `IntoEdges`. The function `edge_cost` should
+/// return the cost for a particular edge, which is used to compute path costs. Edge costs must be
+/// positive.
+///
+/// Computes in **O(|E| + |V| log |V|)** time.
+///
+/// Returns a `HashMap` that maps `NodeId` to the number of shortest paths; `start` has a single,
+/// empty, path.
+///
+/// # Example
+/// ```rust
+/// use petgraph::prelude::*;
+/// use petgraph::algo::shortest_path_counts;
+///
+/// // A 3x3 grid: there are 6 shortest paths between opposite corners.
+/// let mut graph = UnGraph::<(), ()>::new_undirected();
+/// let n: Vec<_> = (0..9).map(|_| graph.add_node(())).collect();
+/// for i in 0..3 {
+///     for j in 0..2 {
+///         graph.add_edge(n[3 * i + j], n[3 * i + j + 1], ());
+///         graph.add_edge(n[3 * j + i], n[3 * (j + 1) + i], ());
+///     }
+/// }
+///
+/// let counts = shortest_path_counts(&graph, n[0], |_| 1);
+/// assert_eq!(counts[&n[8]], 6);
+/// assert_eq!(counts[&n[4]], 2);
+/// assert_eq!(counts[&n[0]], 1);
+/// ```
+pub fn shortest_path_counts<G, F, K>(
+    graph: G,
+    start: G::NodeId,
+    edge_cost: F,
+) -> HashMap<G::NodeId, usize>
+where
+    G: IntoEdges + Visitable,
+    G::NodeId: Eq + Hash,
+    F: FnMut(G::EdgeRef) -> K,
+    K: Measure + Copy,
+{
+    let (settled, predecessors) = shortest_path_dag(graph, start, None, edge_cost);
+    let mut counts = HashMap::with_capacity(settled.len());
+    // Predecessors are settled before their successors, since costs are positive.
+    for node in settled {
+        let count = match predecessors.get(&node) {
+            Some(preds) => preds
+                .iter()
+                .fold(0usize, |acc, p| acc.saturating_add(counts[p])),
+            None => 1,
+        };
+        counts.insert(node, count);
+    }
+    counts
+}
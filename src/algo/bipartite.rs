@@ -0,0 +1,136 @@
+//! Bipartite graphs, whose nodes split into two sets with every edge joining the two.
+//!
+//! A graph is bipartite if and only if it has no cycle with an odd number of nodes, so
+//! [`bipartition`] proves its answer either way: it returns the two sets, or such a cycle. A
+//! breadth-first search colors the nodes of each component by the parity of their depth, and an
+//! edge between two nodes of the same color closes an odd cycle through their nearest common
+//! ancestor in the search tree.
+//!
+//! [`bipartition`]: fn.bipartition.html
+
+use std::collections::VecDeque;
+
+use crate::visit::{IntoNeighbors, IntoNodeIdentifiers, NodeIndexable};
+
+/// A cycle of odd length, proving that a graph is not bipartite.
+///
+/// Returned by [`bipartition`](fn.bipartition.html).
+#[derive(Clone, Debug, PartialEq)]
+pub struct OddCycle<N>(Vec<N>);
+
+impl<N> OddCycle<N> {
+    /// Return the nodes of the cycle, in order.
+    pub fn cycle(&self) -> &[N] {
+        &self.0
+    }
+
+    /// Return the nodes of the cycle, in order.
+    pub fn into_cycle(self) -> Vec<N> {
+        self.0
+    }
+}
+
+/// \[Generic\] Split the nodes of a graph into two sets, such that every edge joins nodes in
+/// different sets.
+///
+/// Unlike [`is_bipartite_undirected`](fn.is_bipartite_undirected.html), this covers every
+/// connected component, and proves the answer either way: it returns the two sets if the graph
+/// is bipartite, and otherwise a cycle with an odd number of nodes. A loop is a cycle of one
+/// node.
+///
+/// Uses breadth-first search from the first node of each component, which is put in the first
+/// set. Computes in **O(|V| + |E|)** time.
+///
+/// Always treats the input graph as if undirected.
+///
+/// # Example
+///
+/// ```
+/// use petgraph::algo::bipartition;
+/// use petgraph::prelude::*;
+///
+/// let square = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 3), (3, 0), (4, 5)]);
+/// let (left, right) = bipartition(&square).unwrap();
+/// assert_eq!(left, [0.into(), 2.into(), 4.into()]);
+/// assert_eq!(right, [1.into(), 3.into(), 5.into()]);
+///
+/// let pentagon = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 3), (3, 4), (4, 0)]);
+/// let cycle = bipartition(&pentagon).unwrap_err();
+/// assert_eq!(cycle.cycle().len(), 5);
+/// ```
+#[allow(clippy::type_complexity)]
+pub fn bipartition<G>(graph: G) -> Result<(Vec<G::NodeId>, Vec<G::NodeId>), OddCycle<G::NodeId>>
+where
+    G: IntoNeighbors + IntoNodeIdentifiers + NodeIndexable,
+{
+    let mut adjacency = vec![Vec::new(); graph.node_bound()];
+    for n in graph.node_identifiers() {
+        let a = graph.to_index(n);
+        for m in graph.neighbors(n) {
+            let b = graph.to_index(m);
+            adjacency[a].push(b);
+            if a != b {
+                adjacency[b].push(a);
+            }
+        }
+    }
+
+    // The side and the breadth-first search parent of each node.
+    let mut side: Vec<Option<bool>> = vec![None; graph.node_bound()];
+    let mut parent = vec![std::usize::MAX; graph.node_bound()];
+    let mut queue = VecDeque::new();
+    for start in graph.node_identifiers() {
+        let start = graph.to_index(start);
+        if side[start].is_some() {
+            continue;
+        }
+        side[start] = Some(false);
+        queue.push_back(start);
+        while let Some(a) = queue.pop_front() {
+            let a_side = side[a].unwrap();
+            for &b in &adjacency[a] {
+                match side[b] {
+                    None => {
+                        side[b] = Some(!a_side);
+                        parent[b] = a;
+                        queue.push_back(b);
+                    }
+                    Some(b_side) if b_side == a_side => {
+                        let cycle = odd_cycle(&parent, a, b);
+                        return Err(OddCycle(
+                            cycle.into_iter().map(|v| graph.from_index(v)).collect(),
+                        ));
+                    }
+                    Some(_) => {}
+                }
+            }
+        }
+    }
+
+    let mut sets = (Vec::new(), Vec::new());
+    for n in graph.node_identifiers() {
+        if side[graph.to_index(n)] == Some(false) {
+            sets.0.push(n);
+        } else {
+            sets.1.push(n);
+        }
+    }
+    Ok(sets)
+}
+
+/// The cycle closed by an edge between `a` and `b`, which are on the same side: the tree paths
+/// from both up to their nearest common ancestor. Being on the same side of a breadth-first
+/// search tree, they are at the same depth, so the cycle has an odd number of nodes.
+fn odd_cycle(parent: &[usize], a: usize, b: usize) -> Vec<usize> {
+    let (mut x, mut y) = (a, b);
+    let mut from_a = vec![a];
+    let mut from_b = Vec::new();
+    while x != y {
+        x = parent[x];
+        from_a.push(x);
+        from_b.push(y);
+        y = parent[y];
+    }
+    from_a.extend(from_b.into_iter().rev());
+    from_a
+}
@@ -0,0 +1,427 @@
+//! Isomorphism-invariant hashing and canonical labeling.
+//!
+//! Both functions take closures that compute a label for each node and edge, so that only
+//! isomorphisms preserving the labels are considered. Pass `|_| ()` to compare graph structure
+//! only.
+
+use std::collections::hash_map::DefaultHasher;
+use std::collections::BTreeMap;
+use std::hash::{Hash, Hasher};
+
+use crate::visit::{
+    EdgeRef, GraphProp, IntoEdgeReferences, IntoNodeReferences, NodeIndexable, NodeRef,
+};
+
+fn hash_one<T: Hash>(value: T) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// The nodes of `graph` and, for each of them, its incident edges as
+/// `(incoming, edge label, neighbor)` triples between node positions.
+///
+/// Edges of an undirected graph are never marked as incoming, and self loops appear twice in the
+/// list of their node.
+#[allow(clippy::type_complexity)]
+fn labeled_adjacency<G, FN, FE, NL, EL>(
+    graph: G,
+    mut node_label: FN,
+    mut edge_label: FE,
+) -> (Vec<G::NodeId>, Vec<NL>, Vec<Vec<(bool, EL, usize)>>)
+where
+    G: IntoEdgeReferences + IntoNodeReferences + NodeIndexable + GraphProp,
+    FN: FnMut(G::NodeRef) -> NL,
+    FE: FnMut(G::EdgeRef) -> EL,
+    EL: Clone,
+{
+    let mut nodes = Vec::new();
+    let mut labels = Vec::new();
+    let mut position = vec![std::usize::MAX; graph.node_bound()];
+    for node in graph.node_references() {
+        position[graph.to_index(node.id())] = nodes.len();
+        nodes.push(node.id());
+        labels.push(node_label(node));
+    }
+    let mut adjacency = vec![Vec::new(); nodes.len()];
+    for edge in graph.edge_references() {
+        let a = position[graph.to_index(edge.source())];
+        let b = position[graph.to_index(edge.target())];
+        let label = edge_label(edge);
+        adjacency[a].push((false, label.clone(), b));
+        adjacency[b].push((graph.is_directed(), label, a));
+    }
+    (nodes, labels, adjacency)
+}
+
+/// \[Generic\] Compute the Weisfeiler–Lehman hash of a graph.
+///
+/// Every node starts with the hash of its label, and is then relabeled `iterations` times with the
+/// hash of its own label and the multiset of the labels of its incident edges and neighbors,
+/// distinguishing incoming and outgoing edges in directed graphs. The hash of the graph combines
+/// the multisets of node labels after every iteration.
+///
+/// Isomorphic graphs, with matching node and edge labels, have the same hash. The converse does not
+/// hold: for example, all regular graphs with the same number of nodes and degree have the same
+/// hash, such as a cycle of six nodes and two disjoint triangles. Use the hash to bucket graphs
+/// before testing the graphs in each bucket for isomorphism, or use a
+/// [`canonical_labeling`](fn.canonical_labeling.html) instead.
+///
+/// The hash is computed with the standard library's default hasher, and may change between versions
+/// of Rust or of this crate.
+///
+/// Computes in **O(iterations × (|V| + |E| log |E|))** time.
+///
+/// # Example
+/// ```rust
+/// use petgraph::prelude::*;
+/// use petgraph::algo::weisfeiler_lehman_hash;
+///
+/// let path = UnGraph::<&str, ()>::from_edges(&[(0, 1), (1, 2)]);
+/// let same_path = UnGraph::<&str, ()>::from_edges(&[(2, 0), (0, 1)]);
+/// let star = UnGraph::<&str, ()>::from_edges(&[(0, 1), (0, 2), (0, 3)]);
+///
+/// let hash = |g: &UnGraph<&str, ()>| weisfeiler_lehman_hash(g, 3, |_| (), |_| ());
+/// assert_eq!(hash(&path), hash(&same_path));
+/// assert_ne!(hash(&path), hash(&star));
+/// ```
+pub fn weisfeiler_lehman_hash<G, FN, FE, NL, EL>(
+    graph: G,
+    iterations: usize,
+    node_label: FN,
+    mut edge_label: FE,
+) -> u64
+where
+    G: IntoEdgeReferences + IntoNodeReferences + NodeIndexable + GraphProp,
+    FN: FnMut(G::NodeRef) -> NL,
+    FE: FnMut(G::EdgeRef) -> EL,
+    NL: Hash,
+    EL: Hash,
+{
+    let (_, labels, adjacency) = labeled_adjacency(graph, node_label, |e| hash_one(edge_label(e)));
+    let mut labels: Vec<u64> = labels.iter().map(hash_one).collect();
+    let mut graph_hasher = DefaultHasher::new();
+    let mut histogram = labels.clone();
+    histogram.sort_unstable();
+    histogram.hash(&mut graph_hasher);
+
+    let mut neighborhood = Vec::new();
+    for _ in 0..iterations {
+        labels = adjacency
+            .iter()
+            .zip(&labels)
+            .map(|(edges, &label)| {
+                neighborhood.clear();
+                neighborhood.extend(
+                    edges
+                        .iter()
+                        .map(|&(incoming, e, b)| (incoming, e, labels[b])),
+                );
+                neighborhood.sort_unstable();
+                hash_one((label, &neighborhood))
+            })
+            .collect();
+        histogram.clone_from(&labels);
+        histogram.sort_unstable();
+        histogram.hash(&mut graph_hasher);
+    }
+    graph_hasher.finish()
+}
+
+/// Replace each label by its rank among the distinct labels.
+fn ranks<L: Ord>(labels: impl IntoIterator<Item = L>) -> (Vec<usize>, usize) {
+    let labels: Vec<L> = labels.into_iter().collect();
+    let mut rank: BTreeMap<&L, usize> = labels.iter().map(|l| (l, 0)).collect();
+    let distinct = rank.len();
+    for (i, r) in rank.values_mut().enumerate() {
+        *r = i;
+    }
+    (labels.iter().map(|l| rank[l]).collect(), distinct)
+}
+
+/// An ordered partition of the node positions.
+type Partition = Vec<Vec<usize>>;
+
+/// The search for the canonical labeling: each node of the search tree is an equitable ordered
+/// partition, and its children individualize each node of its first non-singleton cell in turn. The
+/// leaves are discrete partitions, that is, orderings of the nodes.
+struct CanonicalSearch<'a> {
+    node_labels: &'a [usize],
+    /// The incident edges of each node as `(incoming, edge label, neighbor)`.
+    adjacency: &'a [Vec<(bool, usize, usize)>],
+    directed: bool,
+    /// The first leaf found.
+    first: Option<Leaf>,
+    /// The leaf with the smallest certificate so far.
+    best: Option<Leaf>,
+    /// The automorphisms found so far, as maps between positions.
+    automorphisms: Vec<Vec<usize>>,
+}
+
+/// The node labels and sorted edges of a graph whose nodes are ordered.
+type Certificate = (Vec<usize>, Vec<(usize, usize, usize)>);
+
+/// A leaf of the search tree.
+#[derive(Clone)]
+struct Leaf {
+    /// The nodes, ordered by the discrete partition.
+    order: Vec<usize>,
+    /// The nodes individualized to reach the leaf.
+    path: Vec<usize>,
+    certificate: Certificate,
+}
+
+impl<'a> CanonicalSearch<'a> {
+    /// Refine `partition` until it is equitable: nodes in the same cell have the same number of
+    /// edges with each label to each cell.
+    fn refine(&self, mut partition: Partition) -> Partition {
+        let n = self.adjacency.len();
+        let mut cell = vec![0; n];
+        let mut signature: Vec<Vec<(bool, usize, usize)>> = vec![Vec::new(); n];
+        loop {
+            for (i, c) in partition.iter().enumerate() {
+                for &v in c {
+                    cell[v] = i;
+                }
+            }
+            for (v, edges) in self.adjacency.iter().enumerate() {
+                signature[v].clear();
+                signature[v].extend(edges.iter().map(|&(incoming, e, b)| (incoming, e, cell[b])));
+                signature[v].sort_unstable();
+            }
+            let cells = partition.len();
+            partition = partition
+                .into_iter()
+                .flat_map(|mut c| {
+                    c.sort_by(|&a, &b| signature[a].cmp(&signature[b]));
+                    let mut split: Vec<Vec<usize>> = Vec::new();
+                    for v in c {
+                        match split.last_mut() {
+                            Some(last) if signature[last[0]] == signature[v] => last.push(v),
+                            _ => split.push(vec![v]),
+                        }
+                    }
+                    split
+                })
+                .collect();
+            if partition.len() == cells {
+                return partition;
+            }
+        }
+    }
+
+    fn certificate(&self, order: &[usize]) -> Certificate {
+        let mut position = vec![0; order.len()];
+        for (i, &v) in order.iter().enumerate() {
+            position[v] = i;
+        }
+        let labels = order.iter().map(|&v| self.node_labels[v]).collect();
+        let mut edges = Vec::new();
+        for (v, incident) in self.adjacency.iter().enumerate() {
+            for &(incoming, e, b) in incident {
+                let (a, b) = (position[v], position[b]);
+                if !incoming && (self.directed || a <= b) {
+                    edges.push((a, b, e));
+                }
+            }
+        }
+        edges.sort_unstable();
+        (labels, edges)
+    }
+
+    /// Explore the subtree of `partition`, reached by individualizing the nodes of `path`. Return
+    /// the depth to backtrack to if the rest of the subtree is known to be equivalent to an
+    /// explored one.
+    fn search(&mut self, partition: Partition, path: &mut Vec<usize>) -> Option<usize> {
+        let depth = path.len();
+        let target = match partition.iter().position(|c| c.len() > 1) {
+            Some(target) => target,
+            None => {
+                let order: Vec<usize> = partition.into_iter().map(|c| c[0]).collect();
+                return self.leaf(order, path);
+            }
+        };
+        let mut tried: Vec<usize> = Vec::new();
+        for &v in &partition[target] {
+            if self.in_explored_orbit(v, &tried, path) {
+                continue;
+            }
+            tried.push(v);
+            let mut child = partition.clone();
+            let rest: Vec<usize> = child[target].iter().cloned().filter(|&w| w != v).collect();
+            child.splice(target..=target, vec![vec![v], rest]);
+            path.push(v);
+            let child = self.refine(child);
+            let backtrack = self.search(child, path);
+            path.pop();
+            match backtrack {
+                Some(d) if d < depth => return Some(d),
+                _ => {}
+            }
+        }
+        None
+    }
+
+    /// Whether an automorphism found so far that fixes every node of `path` maps one of the `tried`
+    /// nodes to `v`.
+    fn in_explored_orbit(&self, v: usize, tried: &[usize], path: &[usize]) -> bool {
+        if tried.is_empty() {
+            return false;
+        }
+        let mut orbits = crate::unionfind::UnionFind::new(self.adjacency.len());
+        for gamma in &self.automorphisms {
+            if path.iter().all(|&p| gamma[p] == p) {
+                for (a, &b) in gamma.iter().enumerate() {
+                    orbits.union(a, b);
+                }
+            }
+        }
+        tried.iter().any(|&t| orbits.equiv(t, v))
+    }
+
+    fn leaf(&mut self, order: Vec<usize>, path: &[usize]) -> Option<usize> {
+        let certificate = self.certificate(&order);
+        let (first, best) = match (&self.first, &self.best) {
+            (Some(first), Some(best)) => (first, best),
+            _ => {
+                let leaf = Leaf {
+                    order,
+                    path: path.to_vec(),
+                    certificate,
+                };
+                self.first = Some(leaf.clone());
+                self.best = Some(leaf);
+                return None;
+            }
+        };
+        let reference = if certificate == first.certificate {
+            first
+        } else if certificate == best.certificate {
+            best
+        } else {
+            if certificate < best.certificate {
+                self.best = Some(Leaf {
+                    order,
+                    path: path.to_vec(),
+                    certificate,
+                });
+            }
+            return None;
+        };
+
+        // Equal certificates give an automorphism. It maps the subtree where
+        // the path of the reference leaf leaves the current path, which has
+        // been explored, to the one being explored: the rest of it can be
+        // skipped.
+        let mut gamma = vec![0; order.len()];
+        for (&a, &b) in reference.order.iter().zip(&order) {
+            gamma[a] = b;
+        }
+        let common = reference
+            .path
+            .iter()
+            .zip(path)
+            .take_while(|&(a, b)| a == b)
+            .count();
+        self.automorphisms.push(gamma);
+        Some(common)
+    }
+}
+
+/// \[Generic\] Compute a canonical labeling of a graph.
+///
+/// Return the nodes of the graph in a canonical order: two graphs are isomorphic, with matching
+/// node and edge labels, if and only if renumbering the nodes of each graph by their position in
+/// the canonical order makes the graphs identical. Canonical orders can therefore be used to
+/// compute exact isomorphism-invariant hashes, or to store graphs in a canonical form.
+///
+/// The labels must be totally ordered, and the canonical order depends on that ordering. The
+/// canonical order is the one that gives the smallest sorted list of edges among the leaves of a
+/// search tree of individualized nodes and refined partitions, as in McKay's *nauty* [1]. Subtrees
+/// that are equivalent under automorphisms found during the search are skipped.
+///
+/// The worst-case running time is exponential, but small and sparse graphs, as well as highly
+/// symmetric ones, are handled quickly.
+///
+/// [1]: https://doi.org/10.1016/j.jsc.2013.09.003
+///
+/// # Example
+/// ```rust
+/// use petgraph::prelude::*;
+/// use petgraph::algo::canonical_labeling;
+///
+/// /// The edges of the graph, renumbered in canonical order.
+/// fn canonical_edges(g: &UnGraph<(), ()>) -> Vec<(usize, usize)> {
+///     let order = canonical_labeling(g, |_| (), |_| ());
+///     let mut position = vec![0; order.len()];
+///     for (i, n) in order.into_iter().enumerate() {
+///         position[n.index()] = i;
+///     }
+///     let mut edges: Vec<_> = g
+///         .edge_references()
+///         .map(|e| {
+///             let (a, b) = (position[e.source().index()], position[e.target().index()]);
+///             (a.min(b), a.max(b))
+///         })
+///         .collect();
+///     edges.sort();
+///     edges
+/// }
+///
+/// let path = UnGraph::from_edges(&[(0, 1), (1, 2), (2, 3)]);
+/// let same_path = UnGraph::from_edges(&[(3, 1), (0, 2), (1, 0)]);
+/// let star = UnGraph::from_edges(&[(0, 1), (0, 2), (0, 3)]);
+///
+/// assert_eq!(canonical_edges(&path), canonical_edges(&same_path));
+/// assert_ne!(canonical_edges(&path), canonical_edges(&star));
+/// ```
+pub fn canonical_labeling<G, FN, FE, NL, EL>(
+    graph: G,
+    node_label: FN,
+    edge_label: FE,
+) -> Vec<G::NodeId>
+where
+    G: IntoEdgeReferences + IntoNodeReferences + NodeIndexable + GraphProp,
+    FN: FnMut(G::NodeRef) -> NL,
+    FE: FnMut(G::EdgeRef) -> EL,
+    NL: Ord,
+    EL: Ord + Clone,
+{
+    let (nodes, node_labels, adjacency) = labeled_adjacency(graph, node_label, edge_label);
+    if nodes.is_empty() {
+        return nodes;
+    }
+    let (node_labels, label_count) = ranks(node_labels);
+    let (edge_labels, _) = ranks(
+        adjacency
+            .iter()
+            .flat_map(|edges| edges.iter().map(|(_, e, _)| e)),
+    );
+    let mut edge_labels = edge_labels.into_iter();
+    let adjacency: Vec<Vec<(bool, usize, usize)>> = adjacency
+        .iter()
+        .map(|edges| {
+            edges
+                .iter()
+                .map(|&(incoming, _, b)| (incoming, edge_labels.next().unwrap(), b))
+                .collect()
+        })
+        .collect();
+
+    let mut search = CanonicalSearch {
+        node_labels: &node_labels,
+        adjacency: &adjacency,
+        directed: graph.is_directed(),
+        first: None,
+        best: None,
+        automorphisms: Vec::new(),
+    };
+    let mut partition = vec![Vec::new(); label_count];
+    for (v, &label) in node_labels.iter().enumerate() {
+        partition[label].push(v);
+    }
+    let partition = search.refine(partition);
+    search.search(partition, &mut Vec::new());
+    let best = search.best.unwrap();
+    best.order.into_iter().map(|v| nodes[v]).collect()
+}
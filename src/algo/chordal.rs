@@ -0,0 +1,335 @@
+//! Chordal graphs: recognition, perfect elimination orderings and chordal completion.
+//!
+//! A graph is *chordal* if every cycle of four or more nodes has a chord, an edge between two nodes
+//! that are not consecutive on the cycle. Equivalently, its nodes can be ordered so that the later
+//! neighbors of every node form a clique: such an order is a *perfect elimination ordering*, and
+//! eliminating the nodes of a sparse symmetric matrix in that order creates no fill-in.
+//!
+//! All functions in this module treat the input graph as if undirected.
+
+use std::cmp::Reverse;
+use std::collections::{BTreeSet, BinaryHeap, VecDeque};
+
+use crate::visit::{IntoNeighbors, IntoNodeIdentifiers, MaxCardinalitySearch, NodeIndexable};
+
+/// A chordless cycle of four or more nodes, proving that a graph is not chordal.
+///
+/// Returned by [`is_chordal`](fn.is_chordal.html).
+#[derive(Clone, Debug, PartialEq)]
+pub struct ChordlessCycle<N>(Vec<N>);
+
+impl<N> ChordlessCycle<N> {
+    /// Return the nodes of the cycle, in order.
+    pub fn cycle(&self) -> &[N] {
+        &self.0
+    }
+
+    /// Return the nodes of the cycle, in order.
+    pub fn into_cycle(self) -> Vec<N> {
+        self.0
+    }
+}
+
+/// The nodes of `graph` and its sorted, deduplicated and symmetric adjacency lists between their
+/// positions, without self loops.
+pub(crate) fn compact_adjacency<G>(graph: G) -> (Vec<G::NodeId>, Vec<Vec<usize>>)
+where
+    G: IntoNeighbors + IntoNodeIdentifiers + NodeIndexable,
+{
+    let nodes: Vec<_> = graph.node_identifiers().collect();
+    let mut position = vec![std::usize::MAX; graph.node_bound()];
+    for (i, &n) in nodes.iter().enumerate() {
+        position[graph.to_index(n)] = i;
+    }
+    let mut adjacency = vec![Vec::new(); nodes.len()];
+    for (a, &n) in nodes.iter().enumerate() {
+        for m in graph.neighbors(n) {
+            let b = position[graph.to_index(m)];
+            if a != b {
+                adjacency[a].push(b);
+                adjacency[b].push(a);
+            }
+        }
+    }
+    for neighbors in &mut adjacency {
+        neighbors.sort_unstable();
+        neighbors.dedup();
+    }
+    (nodes, adjacency)
+}
+
+/// \[Generic\] Test whether a graph is chordal.
+///
+/// Return a perfect elimination ordering of the nodes if the graph is chordal: for every node, its
+/// neighbors that come later in the ordering are all adjacent to each other. Otherwise, return a
+/// chordless cycle of four or more nodes.
+///
+/// The ordering is the reverse of a [`MaxCardinalitySearch`], checked with the algorithm of Tarjan
+/// and Yannakakis [1].
+///
+/// The graph is treated as undirected; self loops and parallel edges are ignored.
+///
+/// Computes in **O(|V| + |E| log |V|)** time.
+///
+/// [`MaxCardinalitySearch`]: ../../visit/struct.MaxCardinalitySearch.html
+/// [1]: https://doi.org/10.1137/0213035
+///
+/// # Example
+/// ```rust
+/// use petgraph::prelude::*;
+/// use petgraph::algo::is_chordal;
+///
+/// // A square with one diagonal is chordal.
+/// let mut graph = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 3), (3, 0), (0, 2)]);
+/// let order = is_chordal(&graph).unwrap();
+/// assert_eq!(order.len(), 4);
+///
+/// // Without the diagonal, the square is a chordless cycle.
+/// let diagonal = graph.find_edge(0.into(), 2.into()).unwrap();
+/// graph.remove_edge(diagonal);
+/// let cycle = is_chordal(&graph).unwrap_err();
+/// assert_eq!(cycle.cycle().len(), 4);
+/// ```
+pub fn is_chordal<G>(graph: G) -> Result<Vec<G::NodeId>, ChordlessCycle<G::NodeId>>
+where
+    G: IntoNeighbors + IntoNodeIdentifiers + NodeIndexable,
+{
+    let (nodes, adjacency) = compact_adjacency(graph);
+    let mut position = vec![std::usize::MAX; graph.node_bound()];
+    for (i, &n) in nodes.iter().enumerate() {
+        position[graph.to_index(n)] = i;
+    }
+
+    // The perfect elimination ordering candidate and each node's place in it.
+    let mut order = Vec::with_capacity(nodes.len());
+    let mut mcs = MaxCardinalitySearch::new(graph);
+    while let Some(n) = mcs.next(graph) {
+        order.push(position[graph.to_index(n)]);
+    }
+    order.reverse();
+    let mut rank = vec![0; nodes.len()];
+    for (i, &v) in order.iter().enumerate() {
+        rank[v] = i;
+    }
+
+    // The later neighbors of `v`, other than the first one `u`, must all be
+    // adjacent to `u`: the later neighbors of `u` are then checked in turn.
+    for &v in &order {
+        let later = adjacency[v].iter().filter(|&&x| rank[x] > rank[v]);
+        let u = match later.clone().min_by_key(|&&x| rank[x]) {
+            Some(&u) => u,
+            None => continue,
+        };
+        for &w in later {
+            if w != u && adjacency[u].binary_search(&w).is_err() {
+                let cycle = chordless_cycle(&adjacency, v, u, w);
+                return Err(ChordlessCycle(
+                    cycle.into_iter().map(|i| nodes[i]).collect(),
+                ));
+            }
+        }
+    }
+    Ok(order.into_iter().map(|i| nodes[i]).collect())
+}
+
+/// Close the path `u - v - w`, where `u` and `w` are not adjacent, into a chordless cycle through
+/// nodes that are not adjacent to `v`.
+fn chordless_cycle(adjacency: &[Vec<usize>], v: usize, u: usize, w: usize) -> Vec<usize> {
+    // A shortest path from `u` to `w` avoiding the other neighbors of `v` has
+    // no chords, and the failed elimination check guarantees that one exists.
+    let mut blocked = vec![false; adjacency.len()];
+    blocked[v] = true;
+    for &x in &adjacency[v] {
+        blocked[x] = x != u && x != w;
+    }
+    let mut predecessor = vec![std::usize::MAX; adjacency.len()];
+    predecessor[u] = u;
+    let mut queue = VecDeque::new();
+    queue.push_back(u);
+    while let Some(x) = queue.pop_front() {
+        if x == w {
+            break;
+        }
+        for &y in &adjacency[x] {
+            if !blocked[y] && predecessor[y] == std::usize::MAX {
+                predecessor[y] = x;
+                queue.push_back(y);
+            }
+        }
+    }
+    let mut cycle = vec![w];
+    let mut x = w;
+    while x != u {
+        x = predecessor[x];
+        cycle.push(x);
+    }
+    cycle.push(v);
+    cycle.reverse();
+    cycle
+}
+
+/// The rule used to choose the next node to eliminate when building an elimination ordering.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum EliminationHeuristic {
+    /// Eliminate a node of minimum degree.
+    MinDegree,
+    /// Eliminate a node whose elimination adds the fewest fill edges.
+    MinFill,
+}
+
+/// The elimination game: nodes are removed one at a time, and the remaining neighbors of each
+/// removed node are made into a clique.
+pub(crate) struct EliminationGame {
+    adjacency: Vec<BTreeSet<usize>>,
+    heuristic: EliminationHeuristic,
+    eliminated: Vec<bool>,
+    score: Vec<usize>,
+    /// Candidates by increasing score and position. Entries whose score is outdated are skipped.
+    queue: BinaryHeap<Reverse<(usize, usize)>>,
+}
+
+impl EliminationGame {
+    pub(crate) fn new(adjacency: &[Vec<usize>], heuristic: EliminationHeuristic) -> Self {
+        let mut game = EliminationGame {
+            adjacency: adjacency
+                .iter()
+                .map(|neighbors| neighbors.iter().cloned().collect())
+                .collect(),
+            heuristic,
+            eliminated: vec![false; adjacency.len()],
+            score: vec![0; adjacency.len()],
+            queue: BinaryHeap::new(),
+        };
+        for v in 0..adjacency.len() {
+            let score = game.score_of(v);
+            game.score[v] = score;
+            game.queue.push(Reverse((score, v)));
+        }
+        game
+    }
+
+    fn fill_count(&self, v: usize) -> usize {
+        let neighbors = &self.adjacency[v];
+        neighbors
+            .iter()
+            .enumerate()
+            .map(|(i, a)| {
+                neighbors
+                    .iter()
+                    .skip(i + 1)
+                    .filter(|b| !self.adjacency[*a].contains(b))
+                    .count()
+            })
+            .sum()
+    }
+
+    fn score_of(&self, v: usize) -> usize {
+        match self.heuristic {
+            EliminationHeuristic::MinDegree => self.adjacency[v].len(),
+            EliminationHeuristic::MinFill => self.fill_count(v),
+        }
+    }
+
+    fn update(&mut self, v: usize) {
+        let score = self.score_of(v);
+        if score != self.score[v] {
+            self.score[v] = score;
+            self.queue.push(Reverse((score, v)));
+        }
+    }
+
+    /// Eliminate the next node, pushing the added fill edges to `fill`. Return the node and its
+    /// neighbors at the time of its elimination.
+    pub(crate) fn eliminate_next(
+        &mut self,
+        fill: &mut Vec<(usize, usize)>,
+    ) -> Option<(usize, Vec<usize>)> {
+        let v = loop {
+            let Reverse((score, v)) = self.queue.pop()?;
+            if !self.eliminated[v] && score == self.score[v] {
+                break v;
+            }
+        };
+        self.eliminated[v] = true;
+        let neighbors: Vec<usize> = std::mem::take(&mut self.adjacency[v]).into_iter().collect();
+        for (i, &a) in neighbors.iter().enumerate() {
+            self.adjacency[a].remove(&v);
+            for &b in &neighbors[i + 1..] {
+                if self.adjacency[a].insert(b) {
+                    self.adjacency[b].insert(a);
+                    fill.push((a, b));
+                }
+            }
+        }
+
+        // Only the degrees of the neighbors change, but the fill counts of
+        // their own neighbors change too.
+        let mut affected: BTreeSet<usize> = neighbors.iter().cloned().collect();
+        if self.heuristic == EliminationHeuristic::MinFill {
+            for &a in &neighbors {
+                affected.extend(self.adjacency[a].iter().cloned());
+            }
+        }
+        for x in affected {
+            self.update(x);
+        }
+        Some((v, neighbors))
+    }
+}
+
+/// \[Generic\] Compute a chordal completion of a graph.
+///
+/// Build an elimination ordering of the nodes greedily with the elimination game: the next node is
+/// chosen by `heuristic`, and its remaining neighbors are then made adjacent to each other. The
+/// edges added this way are the *fill*, and adding them to the graph makes it chordal, with the
+/// elimination ordering as a perfect elimination ordering.
+///
+/// Finding a completion with the fewest fill edges is NP-hard, and both heuristics are only
+/// approximations. On a graph that is already chordal, [`EliminationHeuristic::MinFill`] adds no
+/// fill edges.
+///
+/// Return the elimination ordering and the fill edges, in the order they were added. The graph is
+/// treated as undirected; self loops and parallel edges are ignored.
+///
+/// Computes in **O(|V| Δ⁴ log |V|)** time for `MinFill` and **O(|V| Δ² log |V|)** time for
+/// `MinDegree`, where **Δ** is the maximum degree of the completed graph.
+///
+/// [`EliminationHeuristic::MinFill`]: enum.EliminationHeuristic.html#variant.MinFill
+///
+/// # Example
+/// ```rust
+/// use petgraph::prelude::*;
+/// use petgraph::algo::{chordal_completion, is_chordal, EliminationHeuristic};
+///
+/// // A chordless cycle of five nodes needs two fill edges.
+/// let mut graph = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 3), (3, 4), (4, 0)]);
+/// let (order, fill) = chordal_completion(&graph, EliminationHeuristic::MinFill);
+/// assert_eq!(order.len(), 5);
+/// assert_eq!(fill.len(), 2);
+///
+/// for (a, b) in fill {
+///     graph.add_edge(a, b, ());
+/// }
+/// assert!(is_chordal(&graph).is_ok());
+/// ```
+#[allow(clippy::type_complexity)]
+pub fn chordal_completion<G>(
+    graph: G,
+    heuristic: EliminationHeuristic,
+) -> (Vec<G::NodeId>, Vec<(G::NodeId, G::NodeId)>)
+where
+    G: IntoNeighbors + IntoNodeIdentifiers + NodeIndexable,
+{
+    let (nodes, adjacency) = compact_adjacency(graph);
+    let mut game = EliminationGame::new(&adjacency, heuristic);
+    let mut order = Vec::with_capacity(nodes.len());
+    let mut fill = Vec::new();
+    while let Some((v, _)) = game.eliminate_next(&mut fill) {
+        order.push(nodes[v]);
+    }
+    let fill = fill
+        .into_iter()
+        .map(|(a, b)| (nodes[a], nodes[b]))
+        .collect();
+    (order, fill)
+}
@@ -0,0 +1,432 @@
+//! Community detection: edge betweenness and Girvan–Newman clustering.
+
+use std::collections::VecDeque;
+
+use crate::graph::{EdgeIndex, NodeIndex};
+use crate::stable_graph::StableUnGraph;
+use crate::unionfind::UnionFind;
+use crate::visit::{
+    EdgeIndexable, EdgeRef, GraphProp, IntoEdgeReferences, IntoEdges, IntoNodeIdentifiers,
+    NodeIndexable,
+};
+
+/// Reusable buffers for the shortest path searches of Brandes' algorithm.
+#[derive(Clone, Debug)]
+struct Brandes {
+    distance: Vec<usize>,
+    /// The number of shortest paths from the source to each node.
+    paths: Vec<f64>,
+    /// The dependency of the source on each node.
+    dependency: Vec<f64>,
+    /// The nodes and edges preceding each node on its shortest paths.
+    predecessors: Vec<Vec<(usize, usize)>>,
+    order: Vec<usize>,
+    queue: VecDeque<usize>,
+}
+
+impl Brandes {
+    fn new(node_bound: usize) -> Self {
+        Brandes {
+            distance: vec![std::usize::MAX; node_bound],
+            paths: vec![0.; node_bound],
+            dependency: vec![0.; node_bound],
+            predecessors: vec![Vec::new(); node_bound],
+            order: Vec::new(),
+            queue: VecDeque::new(),
+        }
+    }
+
+    /// Add `scale` times the number of shortest paths from `source` that pass through each edge,
+    /// divided by the number of shortest paths between the same endpoints, to `betweenness`.
+    fn accumulate<G>(&mut self, graph: G, source: G::NodeId, scale: f64, betweenness: &mut [f64])
+    where
+        G: IntoEdges + NodeIndexable + EdgeIndexable,
+    {
+        let s = NodeIndexable::to_index(&graph, source);
+        self.distance[s] = 0;
+        self.paths[s] = 1.;
+        self.queue.push_back(s);
+        while let Some(v) = self.queue.pop_front() {
+            self.order.push(v);
+            let node = NodeIndexable::from_index(&graph, v);
+            for edge in graph.edges(node) {
+                let other = if edge.source() == node {
+                    edge.target()
+                } else {
+                    edge.source()
+                };
+                let w = NodeIndexable::to_index(&graph, other);
+                if self.distance[w] == std::usize::MAX {
+                    self.distance[w] = self.distance[v] + 1;
+                    self.queue.push_back(w);
+                }
+                if self.distance[w] == self.distance[v] + 1 {
+                    self.paths[w] += self.paths[v];
+                    self.predecessors[w].push((v, EdgeIndexable::to_index(&graph, edge.id())));
+                }
+            }
+        }
+
+        for &w in self.order.iter().rev() {
+            let factor = (1. + self.dependency[w]) / self.paths[w];
+            for &(v, e) in &self.predecessors[w] {
+                let share = self.paths[v] * factor;
+                betweenness[e] += scale * share;
+                self.dependency[v] += share;
+            }
+        }
+        for v in self.order.drain(..) {
+            self.distance[v] = std::usize::MAX;
+            self.paths[v] = 0.;
+            self.dependency[v] = 0.;
+            self.predecessors[v].clear();
+        }
+    }
+}
+
+/// \[Generic\] Compute the *edge betweenness centrality* of every edge, using [Brandes'
+/// algorithm][1].
+///
+/// The betweenness of an edge is the sum, over all pairs of nodes, of the fraction of the shortest
+/// paths between them that pass through the edge. Paths are counted by their number of edges,
+/// ignoring edge weights. Directed graphs count paths from each node to each other node along the
+/// direction of the edges, and undirected graphs count each pair of nodes once. Parallel edges are
+/// distinct paths and share the betweenness, and self loops have none.
+///
+/// Return a vector indexed by [`EdgeIndexable::to_index`](../visit/trait.EdgeIndexable.html), with
+/// NaN for indices that are not in use by an edge.
+///
+/// Computes in **O(|V||E|)** time.
+///
+/// **Reference**
+///
+/// * Ulrik Brandes; *A faster algorithm for betweenness centrality*
+///
+/// [1]: https://en.wikipedia.org/wiki/Betweenness_centrality
+///
+/// # Example
+/// ```rust
+/// use petgraph::prelude::*;
+/// use petgraph::algo::edge_betweenness;
+///
+/// // Two triangles joined by a bridge.
+/// let graph = UnGraph::<(), ()>::from_edges(&[
+///     (0, 1), (1, 2), (2, 0),
+///     (2, 3),
+///     (3, 4), (4, 5), (5, 3),
+/// ]);
+/// let betweenness = edge_betweenness(&graph);
+/// // All 9 paths between the triangles cross the bridge.
+/// assert_eq!(betweenness[3], 9.);
+/// assert_eq!(betweenness[0], 1.);
+/// assert_eq!(betweenness[1], 4.);
+/// ```
+pub fn edge_betweenness<G>(graph: G) -> Vec<f64>
+where
+    G: IntoEdges + IntoNodeIdentifiers + NodeIndexable + EdgeIndexable + GraphProp,
+{
+    let mut betweenness = vec![std::f64::NAN; graph.edge_bound()];
+    for edge in graph.edge_references() {
+        betweenness[EdgeIndexable::to_index(&graph, edge.id())] = 0.;
+    }
+    // Every pair of an undirected graph is found from both of its ends.
+    let scale = if graph.is_directed() { 1. } else { 0.5 };
+    let mut brandes = Brandes::new(graph.node_bound());
+    for source in graph.node_identifiers() {
+        brandes.accumulate(graph, source, scale, &mut betweenness);
+    }
+    betweenness
+}
+
+/// \[Generic\] Divide a graph into communities with the [Girvan–Newman algorithm][1].
+///
+/// The algorithm repeatedly removes the edge of highest betweenness (see
+/// [`edge_betweenness`](fn.edge_betweenness.html)), on the grounds that the few edges between
+/// communities carry most shortest paths. The betweenness is recomputed after every removal, in the
+/// component that lost the edge; ties are broken in favor of the edge found first in
+/// `edge_references` order.
+///
+/// The graph is treated as undirected, edge weights are ignored and self loops are skipped. It is
+/// copied into a [`StableGraph`](../stable_graph/struct.StableGraph.html) whose edges are then
+/// removed, so `graph` itself is left as it is.
+///
+/// Return an iterator over the successive partitions: each time a removal splits a connected
+/// component in two, the iterator produces the connected components of what is left. Every
+/// partition has one community more than the one before, from one more than the number of connected
+/// components of the graph up to a community for each node. Communities are listed in order of
+/// their first node, and their nodes in `node_identifiers` order.
+///
+/// The whole hierarchy is available as a [`Dendrogram`](struct.Dendrogram.html) from
+/// [`GirvanNewman::dendrogram`](struct.GirvanNewman.html#method.dendrogram).
+///
+/// Every removal takes **O(|V||E|)** time, so running to the end takes **O(|V||E|²)** time.
+///
+/// **Reference**
+///
+/// * M. Girvan, M. E. J. Newman; *Community structure in social and biological networks*
+///
+/// [1]: https://en.wikipedia.org/wiki/Girvan%E2%80%93Newman_algorithm
+///
+/// # Example
+/// ```rust
+/// use petgraph::prelude::*;
+/// use petgraph::algo::girvan_newman;
+///
+/// // Two triangles joined by a bridge.
+/// let graph = UnGraph::<(), ()>::from_edges(&[
+///     (0, 1), (1, 2), (2, 0),
+///     (2, 3),
+///     (3, 4), (4, 5), (5, 3),
+/// ]);
+/// let n: Vec<_> = graph.node_indices().collect();
+///
+/// let mut partitions = girvan_newman(&graph);
+/// assert_eq!(
+///     partitions.next(),
+///     Some(vec![vec![n[0], n[1], n[2]], vec![n[3], n[4], n[5]]])
+/// );
+///
+/// let dendrogram = partitions.dendrogram();
+/// assert_eq!(dendrogram.merges().len(), 5);
+/// assert_eq!(dendrogram.cut(2), vec![vec![n[0], n[1], n[2]], vec![n[3], n[4], n[5]]]);
+/// ```
+pub fn girvan_newman<G>(graph: G) -> GirvanNewman<G::NodeId>
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable,
+{
+    let nodes: Vec<_> = graph.node_identifiers().collect();
+    let mut position = vec![std::usize::MAX; graph.node_bound()];
+    let mut copy = StableUnGraph::default();
+    for (i, &n) in nodes.iter().enumerate() {
+        position[graph.to_index(n)] = i;
+        copy.add_node(());
+    }
+    for edge in graph.edge_references() {
+        let a = position[graph.to_index(edge.source())];
+        let b = position[graph.to_index(edge.target())];
+        if a != b {
+            copy.add_edge(NodeIndex::new(a), NodeIndex::new(b), ());
+        }
+    }
+
+    let mut component = vec![std::usize::MAX; nodes.len()];
+    let mut components = 0;
+    let mut betweenness = vec![0.; copy.edge_bound()];
+    let mut brandes = Brandes::new(nodes.len());
+    for v in 0..nodes.len() {
+        if component[v] == std::usize::MAX {
+            let members = reachable(&copy, v);
+            for &u in &members {
+                component[u] = components;
+            }
+            components += 1;
+            for &u in &members {
+                brandes.accumulate(&copy, NodeIndex::new(u), 0.5, &mut betweenness);
+            }
+        }
+    }
+
+    GirvanNewman {
+        nodes,
+        graph: copy,
+        betweenness,
+        brandes,
+        component,
+        components,
+        splits: Vec::new(),
+    }
+}
+
+/// The nodes in the same connected component as `start`.
+fn reachable(graph: &StableUnGraph<(), ()>, start: usize) -> Vec<usize> {
+    let mut seen = vec![false; graph.node_bound()];
+    seen[start] = true;
+    let mut members = vec![start];
+    let mut i = 0;
+    while let Some(&v) = members.get(i) {
+        i += 1;
+        for w in graph.neighbors(NodeIndex::new(v)) {
+            if !seen[w.index()] {
+                seen[w.index()] = true;
+                members.push(w.index());
+            }
+        }
+    }
+    members
+}
+
+/// An iterator over the partitions of the Girvan–Newman algorithm.
+///
+/// Created with [`girvan_newman`](fn.girvan_newman.html).
+#[derive(Clone, Debug)]
+pub struct GirvanNewman<N> {
+    nodes: Vec<N>,
+    /// The graph with the edges removed so far; node `i` is `nodes[i]`.
+    graph: StableUnGraph<(), ()>,
+    /// The edge betweenness in `graph`, by edge index.
+    betweenness: Vec<f64>,
+    brandes: Brandes,
+    /// The connected component of each node in `graph`.
+    component: Vec<usize>,
+    components: usize,
+    /// The endpoints of the edges whose removal split a component, in order.
+    splits: Vec<(usize, usize)>,
+}
+
+impl<N> GirvanNewman<N>
+where
+    N: Copy,
+{
+    /// Remove the edge of highest betweenness, and return whether that split a component. Return
+    /// `None` if there are no edges left.
+    fn remove_edge(&mut self) -> Option<bool> {
+        // Values that differ only by rounding count as ties.
+        let mut best: Option<EdgeIndex> = None;
+        for e in self.graph.edge_indices() {
+            let value = self.betweenness[e.index()];
+            if best.map_or(true, |b| value > self.betweenness[b.index()] * (1. + 1e-9)) {
+                best = Some(e);
+            }
+        }
+        let best = best?;
+        let (a, b) = self.graph.edge_endpoints(best).unwrap();
+        self.graph.remove_edge(best);
+
+        // Only the component that lost the edge has new shortest paths.
+        let members = reachable(&self.graph, a.index());
+        let split = !members.contains(&b.index());
+        let mut sources = members;
+        if split {
+            let other = reachable(&self.graph, b.index());
+            for &v in &other {
+                self.component[v] = self.components;
+            }
+            self.components += 1;
+            self.splits.push((a.index(), b.index()));
+            sources.extend(other);
+        }
+        for &v in &sources {
+            for e in self.graph.edges(NodeIndex::new(v)) {
+                self.betweenness[e.id().index()] = 0.;
+            }
+        }
+        for &v in &sources {
+            self.brandes
+                .accumulate(&self.graph, NodeIndex::new(v), 0.5, &mut self.betweenness);
+        }
+        Some(split)
+    }
+
+    /// Run the algorithm to the end, and return the hierarchy of all the partitions, including
+    /// those already produced by the iterator.
+    pub fn dendrogram(mut self) -> Dendrogram<N> {
+        while self.remove_edge().is_some() {}
+        let n = self.nodes.len();
+        let mut sets = UnionFind::new(n);
+        let mut cluster: Vec<usize> = (0..n).collect();
+        let mut merges = Vec::with_capacity(self.splits.len());
+        for &(a, b) in self.splits.iter().rev() {
+            merges.push((cluster[sets.find(a)], cluster[sets.find(b)]));
+            sets.union(a, b);
+            cluster[sets.find(a)] = n + merges.len() - 1;
+        }
+        Dendrogram {
+            leaves: self.nodes,
+            merges,
+        }
+    }
+}
+
+impl<N> Iterator for GirvanNewman<N>
+where
+    N: Copy,
+{
+    type Item = Vec<Vec<N>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.remove_edge()? {}
+        Some(communities(&self.nodes, &self.component, self.components))
+    }
+}
+
+/// Group `nodes` by their `label`, in order of their first node.
+fn communities<N: Copy>(nodes: &[N], label: &[usize], labels: usize) -> Vec<Vec<N>> {
+    let mut position = vec![std::usize::MAX; labels];
+    let mut communities: Vec<Vec<N>> = Vec::new();
+    for (&node, &l) in nodes.iter().zip(label) {
+        if position[l] == std::usize::MAX {
+            position[l] = communities.len();
+            communities.push(Vec::new());
+        }
+        communities[position[l]].push(node);
+    }
+    communities
+}
+
+/// The hierarchy of communities found by the Girvan–Newman algorithm.
+///
+/// The leaves of the dendrogram are the nodes of the graph, and every merge joins two clusters into
+/// a new one, undoing a split in reverse order: the last merge joins the two halves of the first
+/// split. Clusters are numbered as in hierarchical clustering libraries: `0` to `n - 1` are the
+/// leaves, and merge `i` creates cluster `n + i`. A disconnected graph has one root cluster for
+/// each connected component.
+///
+/// Created with [`GirvanNewman::dendrogram`](struct.GirvanNewman.html#method.dendrogram).
+#[derive(Clone, Debug)]
+pub struct Dendrogram<N> {
+    leaves: Vec<N>,
+    merges: Vec<(usize, usize)>,
+}
+
+impl<N> Dendrogram<N>
+where
+    N: Copy,
+{
+    /// Return the nodes of the graph, in `node_identifiers` order; node `i` is cluster `i`.
+    pub fn leaves(&self) -> &[N] {
+        &self.leaves
+    }
+
+    /// Return the pairs of clusters merged by each merge, from the last split to the first.
+    pub fn merges(&self) -> &[(usize, usize)] {
+        &self.merges
+    }
+
+    /// Return the nodes in `cluster`, in `node_identifiers` order.
+    ///
+    /// **Panics** if `cluster` is not a cluster of the dendrogram.
+    pub fn members(&self, cluster: usize) -> Vec<N> {
+        let n = self.leaves.len();
+        assert!(cluster < n + self.merges.len());
+        let mut inside = vec![false; cluster + 1];
+        inside[cluster] = true;
+        for i in (n..=cluster).rev() {
+            if inside[i] {
+                let (a, b) = self.merges[i - n];
+                inside[a] = true;
+                inside[b] = true;
+            }
+        }
+        (0..n.min(cluster + 1))
+            .filter(|&v| inside[v])
+            .map(|v| self.leaves[v])
+            .collect()
+    }
+
+    /// Return the partition of the graph into `k` communities, as the iterator produced it.
+    ///
+    /// If `k` is larger than the number of nodes, every node is a community of its own; if it is
+    /// smaller than the number of connected components, the communities are the connected
+    /// components.
+    pub fn cut(&self, k: usize) -> Vec<Vec<N>> {
+        let n = self.leaves.len();
+        let applied = n.saturating_sub(k).min(self.merges.len());
+        let mut sets = UnionFind::new(n + applied);
+        for (i, &(a, b)) in self.merges[..applied].iter().enumerate() {
+            sets.union(a, n + i);
+            sets.union(b, n + i);
+        }
+        let label: Vec<usize> = (0..n).map(|v| sets.find(v)).collect();
+        communities(&self.leaves, &label, n + applied)
+    }
+}
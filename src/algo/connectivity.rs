@@ -0,0 +1,364 @@
+//! Edge and node connectivity, and the decomposition of graphs into highly connected parts.
+//!
+//! A graph is *k-edge-connected* if it stays connected whenever fewer than **k** edges are removed,
+//! and *k-vertex-connected* if it has more than **k** nodes and stays connected whenever fewer than
+//! **k** nodes are removed.
+//!
+//! All functions in this module treat the input graph as if undirected.
+
+use super::flow::FlowNetwork;
+use super::min_cut::stoer_wagner_min_cut;
+use crate::graph::{NodeIndex, UnGraph};
+use crate::visit::{EdgeRef, IntoEdgeReferences, IntoNodeIdentifiers, NodeIndexable};
+
+/// The nodes of `graph` and its edges between their positions, without self loops.
+fn compact_edges<G>(graph: G) -> (Vec<G::NodeId>, Vec<(usize, usize)>)
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable,
+{
+    let nodes: Vec<_> = graph.node_identifiers().collect();
+    let mut position = vec![std::usize::MAX; graph.node_bound()];
+    for (i, &n) in nodes.iter().enumerate() {
+        position[graph.to_index(n)] = i;
+    }
+    let edges = graph
+        .edge_references()
+        .map(|e| {
+            (
+                position[graph.to_index(e.source())],
+                position[graph.to_index(e.target())],
+            )
+        })
+        .filter(|&(a, b)| a != b)
+        .collect();
+    (nodes, edges)
+}
+
+/// Sorted, deduplicated adjacency lists of the subgraph induced by `set`, numbered by position in
+/// `set`.
+fn induced_adjacency(
+    set: &[usize],
+    edges: &[(usize, usize)],
+    position: &mut [usize],
+) -> Vec<Vec<usize>> {
+    for (i, &v) in set.iter().enumerate() {
+        position[v] = i;
+    }
+    let mut adjacency = vec![Vec::new(); set.len()];
+    for &(a, b) in edges {
+        let (a, b) = (position[a], position[b]);
+        if a != std::usize::MAX && b != std::usize::MAX {
+            adjacency[a].push(b);
+            adjacency[b].push(a);
+        }
+    }
+    for neighbors in &mut adjacency {
+        neighbors.sort_unstable();
+        neighbors.dedup();
+    }
+    for &v in set {
+        position[v] = std::usize::MAX;
+    }
+    adjacency
+}
+
+/// A minimum set of nodes separating the non-adjacent nodes `s` and `t`.
+fn local_vertex_cut(adjacency: &[Vec<usize>], s: usize, t: usize) -> Vec<usize> {
+    // Split every node `x` into `2x -> 2x + 1` with unit capacity, so that a
+    // minimum edge cut between `2s + 1` and `2t` only cuts such arcs.
+    let n = adjacency.len();
+    let mut network = FlowNetwork::new(2 * n);
+    for (x, neighbors) in adjacency.iter().enumerate() {
+        network.add_edge(2 * x, 2 * x + 1, 1, 0);
+        for &y in neighbors {
+            network.add_edge(2 * x + 1, 2 * y, n, 0);
+        }
+    }
+    network.max_flow(2 * s + 1, 2 * t);
+    let reachable = network.source_side(2 * s + 1);
+    (0..n)
+        .filter(|&x| reachable[2 * x] && !reachable[2 * x + 1])
+        .collect()
+}
+
+/// A minimum set of nodes whose removal disconnects the graph, or `None` for a complete graph.
+///
+/// Uses the algorithm of Esfahanian and Hakimi: a node `v` of minimum degree is either outside some
+/// minimum cut, which then separates it from a non-neighbor, or inside every one, which then
+/// separates two of its neighbors.
+fn min_vertex_cut(adjacency: &[Vec<usize>]) -> Option<Vec<usize>> {
+    let n = adjacency.len();
+    let v = (0..n).min_by_key(|&x| adjacency[x].len())?;
+    let adjacent = |x: usize, y: usize| adjacency[x].binary_search(&y).is_ok();
+
+    let mut best: Option<Vec<usize>> = None;
+    let mut consider = |cut: Vec<usize>| {
+        if best.as_ref().map_or(true, |b| cut.len() < b.len()) {
+            best = Some(cut);
+        }
+    };
+    for w in 0..n {
+        if w != v && !adjacent(v, w) {
+            consider(local_vertex_cut(adjacency, v, w));
+        }
+    }
+    let neighbors = &adjacency[v];
+    for (i, &x) in neighbors.iter().enumerate() {
+        for &y in &neighbors[i + 1..] {
+            if !adjacent(x, y) {
+                consider(local_vertex_cut(adjacency, x, y));
+            }
+        }
+    }
+    best
+}
+
+/// \[Generic\] Return the *edge connectivity* of the graph: the least number of edges whose removal
+/// disconnects it.
+///
+/// Parallel edges count separately, and self loops are ignored. A graph with fewer than two nodes
+/// has edge connectivity zero.
+///
+/// Computes a global minimum cut with unit weights, in **O(|V| (|E| + |V|) log |V|)** time.
+///
+/// # Example
+/// ```rust
+/// use petgraph::prelude::*;
+/// use petgraph::algo::edge_connectivity;
+///
+/// // A cycle of four nodes, with a chord.
+/// let mut graph = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 3), (3, 0), (0, 2)]);
+/// assert_eq!(edge_connectivity(&graph), 2);
+///
+/// graph.add_node(());
+/// assert_eq!(edge_connectivity(&graph), 0);
+/// ```
+pub fn edge_connectivity<G>(graph: G) -> usize
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable,
+{
+    stoer_wagner_min_cut(graph, |_| 1usize).map_or(0, |(value, _)| value)
+}
+
+/// \[Generic\] Return the *node connectivity* of the graph: the least number of nodes whose removal
+/// disconnects it, or leaves a single node.
+///
+/// A complete graph with **n** nodes has node connectivity **n - 1**, and a disconnected graph has
+/// node connectivity zero.
+///
+/// Uses maximum flows in the split graph, where each node is replaced by an arc of unit capacity,
+/// following Esfahanian and Hakimi to limit the number of pairs of nodes to check to
+/// **O(|V| + δ²)**, where **δ** is the minimum degree.
+///
+/// # Example
+/// ```rust
+/// use petgraph::prelude::*;
+/// use petgraph::algo::node_connectivity;
+///
+/// // Two triangles sharing node 2.
+/// let graph = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (4, 2)]);
+/// assert_eq!(node_connectivity(&graph), 1);
+///
+/// let complete = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 0), (0, 3), (1, 3), (2, 3)]);
+/// assert_eq!(node_connectivity(&complete), 3);
+/// ```
+pub fn node_connectivity<G>(graph: G) -> usize
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable,
+{
+    let (nodes, edges) = compact_edges(graph);
+    let n = nodes.len();
+    if n < 2 {
+        return 0;
+    }
+    let all: Vec<usize> = (0..n).collect();
+    let adjacency = induced_adjacency(&all, &edges, &mut vec![std::usize::MAX; n]);
+    min_vertex_cut(&adjacency).map_or(n - 1, |cut| cut.len())
+}
+
+/// \[Generic\] Decompose the graph into its maximal *k-edge-connected subgraphs*.
+///
+/// Return a vector where each element is the set of nodes of a maximal subgraph that is
+/// k-edge-connected by itself, using only the edges between its nodes. Every node belongs to
+/// exactly one set, and nodes that are not part of any larger k-edge-connected subgraph form sets
+/// of their own. The order of the sets and of the nodes within them is arbitrary.
+///
+/// Parallel edges count separately, and self loops are ignored. For **k = 1** the sets are the
+/// connected components, and for **k = 2** the 2-edge-connected components that remain after
+/// removing every bridge.
+///
+/// Repeatedly splits the nodes along global minimum cuts that are lighter than **k**, with at most
+/// **|V|** minimum cut computations.
+///
+/// # Example
+/// ```rust
+/// use petgraph::prelude::*;
+/// use petgraph::algo::k_edge_connected_subgraphs;
+///
+/// // Two triangles joined by a bridge from 2 to 3.
+/// let graph = UnGraph::<(), ()>::from_edges(&[
+///     (0, 1), (1, 2), (2, 0),
+///     (2, 3),
+///     (3, 4), (4, 5), (5, 3),
+/// ]);
+///
+/// let mut subgraphs = k_edge_connected_subgraphs(&graph, 2);
+/// subgraphs.iter_mut().for_each(|s| s.sort());
+/// subgraphs.sort();
+/// assert_eq!(subgraphs, vec![
+///     vec![0, 1, 2].into_iter().map(NodeIndex::new).collect::<Vec<_>>(),
+///     vec![3, 4, 5].into_iter().map(NodeIndex::new).collect(),
+/// ]);
+/// ```
+pub fn k_edge_connected_subgraphs<G>(graph: G, k: usize) -> Vec<Vec<G::NodeId>>
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable,
+{
+    let (nodes, edges) = compact_edges(graph);
+    let mut position = vec![std::usize::MAX; nodes.len()];
+    let mut subgraphs = Vec::new();
+    let mut work = vec![(0..nodes.len()).collect::<Vec<_>>()];
+    while let Some(set) = work.pop() {
+        if set.len() < 2 {
+            subgraphs.extend(Some(set).filter(|s| !s.is_empty()));
+            continue;
+        }
+        for (i, &v) in set.iter().enumerate() {
+            position[v] = i;
+        }
+        let mut induced = UnGraph::<(), ()>::with_capacity(set.len(), 0);
+        for _ in 0..set.len() {
+            induced.add_node(());
+        }
+        for &(a, b) in &edges {
+            if position[a] != std::usize::MAX && position[b] != std::usize::MAX {
+                induced.add_edge(NodeIndex::new(position[a]), NodeIndex::new(position[b]), ());
+            }
+        }
+        for &v in &set {
+            position[v] = std::usize::MAX;
+        }
+
+        match stoer_wagner_min_cut(&induced, |_| 1usize) {
+            Some((value, side)) if value < k => {
+                let mut in_side = vec![false; set.len()];
+                for v in side {
+                    in_side[v.index()] = true;
+                }
+                let (one, other) = set.iter().enumerate().partition(|&(i, _)| in_side[i]);
+                let unzip =
+                    |part: Vec<(usize, &usize)>| part.into_iter().map(|(_, &v)| v).collect();
+                work.push(unzip(one));
+                work.push(unzip(other));
+            }
+            _ => subgraphs.push(set),
+        }
+    }
+    subgraphs
+        .into_iter()
+        .map(|set| set.into_iter().map(|v| nodes[v]).collect())
+        .collect()
+}
+
+/// \[Generic\] Decompose the graph into its *k-vertex-connected components*: its maximal
+/// k-vertex-connected subgraphs.
+///
+/// Return a vector where each element is the set of nodes of a maximal subgraph that is
+/// k-vertex-connected by itself, using only the edges between its nodes. Unlike for edge
+/// connectivity, components may overlap in fewer than **k** nodes, and nodes that are part of no
+/// such subgraph are omitted. The order of the components and of the nodes within them is
+/// arbitrary.
+///
+/// For **k = 1** the components are the connected components with at least two nodes, and for
+/// **k = 2** the biconnected components with at least three nodes.
+///
+/// Repeatedly splits the nodes along minimum vertex cuts smaller than **k**, keeping the cut nodes
+/// on every side. This is exact, but may need many maximum flow computations on graphs with many
+/// small cuts.
+///
+/// # Example
+/// ```rust
+/// use petgraph::prelude::*;
+/// use petgraph::algo::k_vertex_connected_components;
+///
+/// // Two 4-cycles sharing node 0.
+/// let graph = UnGraph::<(), ()>::from_edges(&[
+///     (0, 1), (1, 2), (2, 3), (3, 0),
+///     (0, 4), (4, 5), (5, 6), (6, 0),
+/// ]);
+///
+/// let mut components = k_vertex_connected_components(&graph, 2);
+/// components.iter_mut().for_each(|c| c.sort());
+/// components.sort();
+/// assert_eq!(components, vec![
+///     vec![0, 1, 2, 3].into_iter().map(NodeIndex::new).collect::<Vec<_>>(),
+///     vec![0, 4, 5, 6].into_iter().map(NodeIndex::new).collect(),
+/// ]);
+/// ```
+pub fn k_vertex_connected_components<G>(graph: G, k: usize) -> Vec<Vec<G::NodeId>>
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable,
+{
+    let (nodes, edges) = compact_edges(graph);
+    let mut position = vec![std::usize::MAX; nodes.len()];
+    let mut components: Vec<Vec<usize>> = Vec::new();
+    let mut work = vec![(0..nodes.len()).collect::<Vec<_>>()];
+    while let Some(set) = work.pop() {
+        if set.len() <= k {
+            continue;
+        }
+        let adjacency = induced_adjacency(&set, &edges, &mut position);
+        let cut = match min_vertex_cut(&adjacency) {
+            Some(cut) if cut.len() < k => cut,
+            _ => {
+                components.push(set);
+                continue;
+            }
+        };
+
+        // Split the remaining nodes into connected parts, each keeping the cut.
+        let mut part = vec![std::usize::MAX; set.len()];
+        for &c in &cut {
+            part[c] = 0;
+        }
+        let mut parts = 0;
+        for start in 0..set.len() {
+            if part[start] != std::usize::MAX {
+                continue;
+            }
+            parts += 1;
+            let mut members: Vec<usize> = cut.iter().map(|&c| set[c]).collect();
+            part[start] = parts;
+            let mut stack = vec![start];
+            while let Some(x) = stack.pop() {
+                members.push(set[x]);
+                for &y in &adjacency[x] {
+                    if part[y] == std::usize::MAX {
+                        part[y] = parts;
+                        stack.push(y);
+                    }
+                }
+            }
+            work.push(members);
+        }
+    }
+
+    // Splitting may find the same component, or parts of it, more than once.
+    for component in &mut components {
+        component.sort_unstable();
+    }
+    components.sort_by_key(|c| std::cmp::Reverse(c.len()));
+    let mut maximal: Vec<Vec<usize>> = Vec::new();
+    for component in components {
+        let contained = maximal
+            .iter()
+            .any(|m| component.iter().all(|v| m.binary_search(v).is_ok()));
+        if !contained {
+            maximal.push(component);
+        }
+    }
+    maximal
+        .into_iter()
+        .map(|set| set.into_iter().map(|v| nodes[v]).collect())
+        .collect()
+}
@@ -0,0 +1,474 @@
+//! Independent sets, vertex covers and dominating sets.
+//!
+//! An *independent set* is a set of nodes no two of which are adjacent, and a *vertex cover* is a
+//! set of nodes that contains an endpoint of every edge: the nodes outside an independent set
+//! form a vertex cover, and the other way around. A *dominating set* contains, or has an edge to,
+//! every node of the graph.
+//!
+//! Finding the largest independent set, or the smallest vertex cover or dominating set, is
+//! NP-hard in general. This module has an exact [`maximum_independent_set`] for small graphs, an
+//! exact [`bipartite_vertex_cover`] for bipartite graphs, and fast heuristics for the rest.
+//!
+//! [`maximum_independent_set`]: fn.maximum_independent_set.html
+//! [`bipartite_vertex_cover`]: fn.bipartite_vertex_cover.html
+
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, VecDeque};
+use std::hash::Hash;
+
+use fixedbitset::FixedBitSet;
+
+use super::{bipartition, greedy_matching, maximum_matching, OddCycle};
+use crate::graph::{NodeIndex, UnGraph};
+use crate::visit::{IntoEdges, IntoNeighbors, IntoNodeIdentifiers, NodeIndexable, Visitable};
+
+/// \[Generic\] Find a maximum independent set: a largest set of nodes no two of which are
+/// adjacent.
+///
+/// Uses branch and reduce: nodes of degree zero or one are always taken, and otherwise the search
+/// branches on a node of maximum degree, taking it or not, and prunes branches that cannot beat
+/// the best set found so far, bounding each by a greedy partition of the remaining nodes into
+/// cliques. This takes exponential time in the worst case, which is practical for up to about a
+/// hundred nodes, depending on the structure of the graph.
+///
+/// The input graph is treated as if undirected. Nodes with loops are never in the set.
+///
+/// Returns the nodes of the set, in the order of `node_identifiers`.
+///
+/// # Example
+///
+/// ```
+/// use petgraph::algo::maximum_independent_set;
+/// use petgraph::prelude::*;
+///
+/// // A cycle of five nodes, with a pendant node on each.
+/// let g = UnGraph::<(), ()>::from_edges(&[
+///     (0, 1), (1, 2), (2, 3), (3, 4), (4, 0),
+///     (0, 5), (1, 6), (2, 7), (3, 8), (4, 9),
+/// ]);
+/// let set = maximum_independent_set(&g);
+/// assert_eq!(set.len(), 5);
+/// for edge in g.edge_references() {
+///     assert!(!set.contains(&edge.source()) || !set.contains(&edge.target()));
+/// }
+/// ```
+pub fn maximum_independent_set<G>(graph: G) -> Vec<G::NodeId>
+where
+    G: IntoNeighbors + IntoNodeIdentifiers + NodeIndexable,
+{
+    let (nodes, adjacency) = Adjacency::new(graph, true);
+    let n = nodes.len();
+    let mut neighbors = Vec::with_capacity(n);
+    let mut alive = FixedBitSet::with_capacity(n);
+    for v in 0..n {
+        let mut set = FixedBitSet::with_capacity(n);
+        set.extend(adjacency.neighbors[v].iter().cloned());
+        neighbors.push(set);
+        if !adjacency.has_loop[v] {
+            alive.insert(v);
+        }
+    }
+    let best = adjacency.greedy_independent_set();
+    let mut search = IndependentSetSearch {
+        neighbors,
+        chosen: Vec::new(),
+        best,
+    };
+    search.branch(alive);
+    let mut set = search.best;
+    set.sort_unstable();
+    set.into_iter().map(|v| nodes[v]).collect()
+}
+
+/// \[Generic\] Find a maximal independent set with a greedy heuristic: repeatedly take a node of
+/// minimum degree, and remove it and its neighbors from the graph.
+///
+/// The set is maximal, in that no node can be added to it, but not necessarily maximum; see
+/// [`maximum_independent_set`](fn.maximum_independent_set.html).
+///
+/// The input graph is treated as if undirected. Nodes with loops are never in the set.
+///
+/// Returns the nodes of the set, in the order of `node_identifiers`. Computes in
+/// **O((|V| + |E|) log |V|)** time.
+///
+/// # Example
+///
+/// ```
+/// use petgraph::algo::greedy_independent_set;
+/// use petgraph::prelude::*;
+///
+/// // A star takes its leaves rather than its center.
+/// let star = UnGraph::<(), ()>::from_edges(&[(0, 1), (0, 2), (0, 3)]);
+/// assert_eq!(greedy_independent_set(&star), [1.into(), 2.into(), 3.into()]);
+/// ```
+pub fn greedy_independent_set<G>(graph: G) -> Vec<G::NodeId>
+where
+    G: IntoNeighbors + IntoNodeIdentifiers + NodeIndexable,
+{
+    let (nodes, adjacency) = Adjacency::new(graph, true);
+    let mut set = adjacency.greedy_independent_set();
+    set.sort_unstable();
+    set.into_iter().map(|v| nodes[v]).collect()
+}
+
+/// \[Generic\] Find a vertex cover of at most twice the minimum size: both endpoints of the edges
+/// of a maximal matching.
+///
+/// Extends a [`greedy_matching`](fn.greedy_matching.html) until no edge has two unmatched
+/// endpoints. Any vertex cover contains an endpoint of each matched edge, so it has at least half
+/// as many nodes.
+///
+/// The input graph is treated as if undirected. Nodes with loops are always in the cover.
+///
+/// Returns the nodes of the cover, in the order of `node_identifiers`. Computes in
+/// **O(|V| + |E|)** time.
+///
+/// # Example
+///
+/// ```
+/// use petgraph::algo::approximate_vertex_cover;
+/// use petgraph::prelude::*;
+///
+/// let path = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 3)]);
+/// let cover = approximate_vertex_cover(&path);
+/// assert!(cover.len() <= 4);
+/// for edge in path.edge_references() {
+///     assert!(cover.contains(&edge.source()) || cover.contains(&edge.target()));
+/// }
+/// ```
+pub fn approximate_vertex_cover<G>(graph: G) -> Vec<G::NodeId>
+where
+    G: Visitable + IntoNeighbors + IntoNodeIdentifiers + NodeIndexable,
+    G::NodeId: Eq + Hash,
+    G::EdgeId: Eq + Hash,
+{
+    let matching = greedy_matching(graph);
+    let mut covered = vec![false; graph.node_bound()];
+    for v in matching.nodes() {
+        covered[graph.to_index(v)] = true;
+    }
+    for v in graph.node_identifiers() {
+        let a = graph.to_index(v);
+        for w in graph.neighbors(v) {
+            let b = graph.to_index(w);
+            if !covered[a] && !covered[b] {
+                covered[a] = true;
+                covered[b] = true;
+            }
+        }
+    }
+    graph
+        .node_identifiers()
+        .filter(|&v| covered[graph.to_index(v)])
+        .collect()
+}
+
+/// \[Generic\] Find a minimum vertex cover of a bipartite graph, from a maximum matching by
+/// König's theorem.
+///
+/// In a bipartite graph, the smallest vertex cover has as many nodes as the largest matching.
+/// Starting from the unmatched nodes of the first side of a
+/// [`bipartition`](fn.bipartition.html), the cover takes the nodes of the second side that are
+/// reachable along paths that alternate between unmatched and matched edges, and the nodes of
+/// the first side that are not.
+///
+/// The input graph is treated as if undirected. Returns the nodes of the cover, in the order of
+/// `node_identifiers`, or a cycle of odd length if the graph is not bipartite.
+///
+/// Computes in **O(|V|³)** time, dominated by [`maximum_matching`](fn.maximum_matching.html).
+///
+/// **Reference**
+///
+/// * Dénes Kőnig; *Gráfok és mátrixok*
+///
+/// # Example
+///
+/// ```
+/// use petgraph::algo::bipartite_vertex_cover;
+/// use petgraph::prelude::*;
+///
+/// // Two stars, centered on 2 and 3, joined by an edge.
+/// let g = UnGraph::<(), ()>::from_edges(&[(0, 3), (1, 3), (2, 3), (2, 4), (2, 5)]);
+/// let cover = bipartite_vertex_cover(&g).unwrap();
+/// assert_eq!(cover, [2.into(), 3.into()]);
+///
+/// let triangle = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 0)]);
+/// assert!(bipartite_vertex_cover(&triangle).is_err());
+/// ```
+pub fn bipartite_vertex_cover<G>(graph: G) -> Result<Vec<G::NodeId>, OddCycle<G::NodeId>>
+where
+    G: Visitable + IntoEdges + IntoNodeIdentifiers + NodeIndexable,
+{
+    let (left, _) = bipartition(graph)?;
+    let (_, adjacency) = Adjacency::new(graph, true);
+    let mut position = vec![0; graph.node_bound()];
+    for (i, v) in graph.node_identifiers().enumerate() {
+        position[graph.to_index(v)] = i;
+    }
+    let n = adjacency.neighbors.len();
+    let mut is_left = vec![false; n];
+    for &v in &left {
+        is_left[position[graph.to_index(v)]] = true;
+    }
+    // `maximum_matching` only follows the outgoing edges of a directed graph, so match on an
+    // undirected copy.
+    let mut undirected = UnGraph::<(), ()>::with_capacity(n, 0);
+    for _ in 0..n {
+        undirected.add_node(());
+    }
+    for (a, list) in adjacency.neighbors.iter().enumerate() {
+        for &b in list.iter().filter(|&&b| a < b) {
+            undirected.add_edge(NodeIndex::new(a), NodeIndex::new(b), ());
+        }
+    }
+    let matching = maximum_matching(&undirected);
+    let mate: Vec<Option<usize>> = undirected
+        .node_indices()
+        .map(|v| matching.mate(v).map(|w| w.index()))
+        .collect();
+
+    // The nodes reachable from the unmatched nodes on the left by alternating paths.
+    let mut reached = vec![false; n];
+    let mut queue: VecDeque<usize> = (0..n)
+        .filter(|&v| is_left[v] && mate[v].is_none())
+        .collect();
+    for &v in &queue {
+        reached[v] = true;
+    }
+    while let Some(v) = queue.pop_front() {
+        for &w in &adjacency.neighbors[v] {
+            if reached[w] || mate[v] == Some(w) {
+                continue;
+            }
+            reached[w] = true;
+            if let Some(u) = mate[w] {
+                if !reached[u] {
+                    reached[u] = true;
+                    queue.push_back(u);
+                }
+            }
+        }
+    }
+    Ok(graph
+        .node_identifiers()
+        .enumerate()
+        .filter(|&(i, _)| is_left[i] != reached[i])
+        .map(|(_, v)| v)
+        .collect())
+}
+
+/// \[Generic\] Find a dominating set with a greedy heuristic: repeatedly take the node that
+/// dominates the most nodes that are not dominated yet.
+///
+/// A node dominates itself and its neighbors; in a directed graph, its successors. The set has at
+/// most **1 + ln |V|** times as many nodes as the minimum.
+///
+/// Returns the nodes of the set, in the order they were taken. Computes in
+/// **O((|V| + |E|) Δ log |V|)** time, where **Δ** is the maximum degree, and usually much faster.
+///
+/// **Reference**
+///
+/// * David S. Johnson; *Approximation algorithms for combinatorial problems*
+///
+/// # Example
+///
+/// ```
+/// use petgraph::algo::greedy_dominating_set;
+/// use petgraph::prelude::*;
+///
+/// // Two stars, joined at a leaf.
+/// let g = UnGraph::<(), ()>::from_edges(&[
+///     (0, 1), (0, 2), (0, 3),
+///     (4, 3), (4, 5), (4, 6),
+/// ]);
+/// assert_eq!(greedy_dominating_set(&g), [0.into(), 4.into()]);
+/// ```
+pub fn greedy_dominating_set<G>(graph: G) -> Vec<G::NodeId>
+where
+    G: IntoNeighbors + IntoNodeIdentifiers + NodeIndexable,
+{
+    let (nodes, adjacency) = Adjacency::new(graph, false);
+    let n = nodes.len();
+    let mut dominated = vec![false; n];
+    let gain = |dominated: &[bool], v: usize| {
+        !dominated[v] as usize
+            + adjacency.neighbors[v]
+                .iter()
+                .filter(|&&w| !dominated[w])
+                .count()
+    };
+    // Gains only decrease, so an entry whose gain is still current is the largest.
+    let mut heap: BinaryHeap<(usize, Reverse<usize>)> =
+        (0..n).map(|v| (gain(&dominated, v), Reverse(v))).collect();
+    let mut set = Vec::new();
+    let mut remaining = n;
+    while remaining > 0 {
+        let (old, Reverse(v)) = heap.pop().unwrap();
+        let new = gain(&dominated, v);
+        if new < old {
+            heap.push((new, Reverse(v)));
+            continue;
+        }
+        set.push(nodes[v]);
+        for w in Some(v)
+            .into_iter()
+            .chain(adjacency.neighbors[v].iter().cloned())
+        {
+            if !dominated[w] {
+                dominated[w] = true;
+                remaining -= 1;
+            }
+        }
+    }
+    set
+}
+
+/// The neighbors of each node, by position in the list of nodes, without loops or parallel
+/// edges.
+struct Adjacency {
+    neighbors: Vec<Vec<usize>>,
+    has_loop: Vec<bool>,
+}
+
+impl Adjacency {
+    /// With `symmetric`, add the reverse of every edge, treating the graph as undirected.
+    fn new<G>(graph: G, symmetric: bool) -> (Vec<G::NodeId>, Self)
+    where
+        G: IntoNeighbors + IntoNodeIdentifiers + NodeIndexable,
+    {
+        let nodes: Vec<G::NodeId> = graph.node_identifiers().collect();
+        let mut position = vec![0; graph.node_bound()];
+        for (i, &v) in nodes.iter().enumerate() {
+            position[graph.to_index(v)] = i;
+        }
+        let mut neighbors = vec![Vec::new(); nodes.len()];
+        let mut has_loop = vec![false; nodes.len()];
+        for (a, &v) in nodes.iter().enumerate() {
+            for w in graph.neighbors(v) {
+                let b = position[graph.to_index(w)];
+                if a == b {
+                    has_loop[a] = true;
+                    continue;
+                }
+                neighbors[a].push(b);
+                if symmetric {
+                    neighbors[b].push(a);
+                }
+            }
+        }
+        for list in &mut neighbors {
+            list.sort_unstable();
+            list.dedup();
+        }
+        (
+            nodes,
+            Adjacency {
+                neighbors,
+                has_loop,
+            },
+        )
+    }
+
+    /// A maximal independent set, taking nodes of minimum remaining degree first.
+    fn greedy_independent_set(&self) -> Vec<usize> {
+        let n = self.neighbors.len();
+        let mut removed = self.has_loop.clone();
+        let mut degree: Vec<usize> = (0..n)
+            .map(|v| self.neighbors[v].iter().filter(|&&w| !removed[w]).count())
+            .collect();
+        let mut heap: BinaryHeap<Reverse<(usize, usize)>> = (0..n)
+            .filter(|&v| !removed[v])
+            .map(|v| Reverse((degree[v], v)))
+            .collect();
+        let mut set = Vec::new();
+        while let Some(Reverse((d, v))) = heap.pop() {
+            if removed[v] || d != degree[v] {
+                continue;
+            }
+            set.push(v);
+            removed[v] = true;
+            for &w in &self.neighbors[v] {
+                if removed[w] {
+                    continue;
+                }
+                removed[w] = true;
+                for &u in &self.neighbors[w] {
+                    if !removed[u] {
+                        degree[u] -= 1;
+                        heap.push(Reverse((degree[u], u)));
+                    }
+                }
+            }
+        }
+        set
+    }
+}
+
+/// The state of the branch and reduce search for a maximum independent set.
+struct IndependentSetSearch {
+    neighbors: Vec<FixedBitSet>,
+    chosen: Vec<usize>,
+    best: Vec<usize>,
+}
+
+impl IndependentSetSearch {
+    fn degree(&self, v: usize, alive: &FixedBitSet) -> usize {
+        self.neighbors[v].intersection(alive).count()
+    }
+
+    /// Take `v` into the set, removing it and its neighbors.
+    fn take(&mut self, v: usize, alive: &mut FixedBitSet) {
+        self.chosen.push(v);
+        alive.set(v, false);
+        alive.difference_with(&self.neighbors[v]);
+    }
+
+    /// An upper bound on the size of an independent set of `alive`: the number of cliques in a
+    /// greedy partition of it, since an independent set has at most one node of each.
+    fn clique_cover_bound(&self, alive: &FixedBitSet) -> usize {
+        let mut remaining = alive.clone();
+        let mut cliques = 0;
+        while let Some(v) = remaining.ones().next() {
+            remaining.set(v, false);
+            let mut candidates = remaining.clone();
+            candidates.intersect_with(&self.neighbors[v]);
+            while let Some(u) = candidates.ones().next() {
+                remaining.set(u, false);
+                candidates.set(u, false);
+                candidates.intersect_with(&self.neighbors[u]);
+            }
+            cliques += 1;
+        }
+        cliques
+    }
+
+    fn branch(&mut self, mut alive: FixedBitSet) {
+        let depth = self.chosen.len();
+        // Nodes of degree zero or one are in some maximum independent set.
+        loop {
+            let low = alive.ones().find(|&v| self.degree(v, &alive) <= 1);
+            match low {
+                Some(v) => self.take(v, &mut alive),
+                None => break,
+            }
+        }
+
+        if alive.count_ones(..) == 0 {
+            if self.chosen.len() > self.best.len() {
+                self.best = self.chosen.clone();
+            }
+        } else if self.chosen.len() + self.clique_cover_bound(&alive) > self.best.len() {
+            let v = alive
+                .ones()
+                .max_by_key(|&v| self.degree(v, &alive))
+                .unwrap();
+            let mut with = alive.clone();
+            self.take(v, &mut with);
+            self.branch(with);
+            self.chosen.pop();
+            alive.set(v, false);
+            self.branch(alive);
+        }
+        self.chosen.truncate(depth);
+    }
+}
@@ -0,0 +1,555 @@
+//! Measures of the distances in a graph: the eccentricity of its nodes, its diameter and radius,
+//! and its center and periphery.
+//!
+//! The eccentricity of a node is the greatest distance from it to any other node. All measures
+//! are defined for connected undirected graphs and strongly connected directed graphs only; the
+//! functions return `None`, or an empty list of nodes, for any other graph.
+//!
+//! Each measure comes in two variants: one that counts the edges on a path, using breadth-first
+//! search, and a `weighted_` one that adds up the edge costs given by a closure, using Dijkstra's
+//! algorithm.
+//!
+//! Except for [`eccentricity`](fn.eccentricity.html), the measures are computed by bounding the
+//! eccentricities of all nodes, using the technique of Takes and Kosters, which generalizes the
+//! double sweep lower bound of the diameter. Every search from a node `w` gives its exact
+//! eccentricity, and bounds the eccentricity of every other node `v` by
+//! `max(d(v, w), ecc(w) - d(w, v))` from below and `d(v, w) + ecc(w)` from above. Searches
+//! continue from nodes whose bounds can still change the result, alternating between the largest
+//! upper and the smallest lower bound. This takes **|V|** searches in the worst case, but usually
+//! a few dozen at most, even for large graphs.
+//!
+//! **Reference**
+//!
+//! * Frank W. Takes, Walter A. Kosters; *Computing the eccentricity distribution of large graphs*
+
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+use std::ops::Sub;
+
+use super::Measure;
+use crate::scored::MinScored;
+use crate::visit::{
+    EdgeRef, GraphProp, IntoEdgesDirected, IntoNeighborsDirected, IntoNodeIdentifiers,
+    NodeIndexable,
+};
+use crate::Direction::{self, Incoming, Outgoing};
+
+/// \[Generic\] Returns the eccentricity of `node`: the greatest number of edges on a shortest
+/// path from it to any other node.
+///
+/// Returns `None` if some node is not reachable from `node`.
+///
+/// Computes a breadth-first search, with time complexity **O(|V| + |E|)**.
+///
+/// # Example
+///
+/// ```
+/// use petgraph::algo::eccentricity;
+/// use petgraph::prelude::*;
+///
+/// let path = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 3)]);
+/// assert_eq!(eccentricity(&path, 0.into()), Some(3));
+/// assert_eq!(eccentricity(&path, 1.into()), Some(2));
+///
+/// let directed = DiGraph::<(), ()>::from_edges(&[(0, 1), (1, 2)]);
+/// assert_eq!(eccentricity(&directed, 0.into()), Some(2));
+/// assert_eq!(eccentricity(&directed, 1.into()), None);
+/// ```
+pub fn eccentricity<G>(graph: G, node: G::NodeId) -> Option<usize>
+where
+    G: IntoNeighborsDirected + IntoNodeIdentifiers + NodeIndexable,
+{
+    let distance = breadth_first_distances(graph, graph.to_index(node), Outgoing);
+    farthest(graph, &distance)
+}
+
+/// \[Generic\] Returns the eccentricity of every node: the greatest number of edges on a shortest
+/// path from it to any other node.
+///
+/// Returns `None` if the graph is empty, or not (strongly) connected.
+///
+/// # Example
+///
+/// ```
+/// use petgraph::algo::eccentricities;
+/// use petgraph::prelude::*;
+///
+/// let star = UnGraph::<(), ()>::from_edges(&[(0, 1), (0, 2), (0, 3)]);
+/// let eccentricities = eccentricities(&star).unwrap();
+/// assert_eq!(eccentricities[&NodeIndex::new(0)], 1);
+/// assert_eq!(eccentricities[&NodeIndex::new(3)], 2);
+/// ```
+pub fn eccentricities<G>(graph: G) -> Option<HashMap<G::NodeId, usize>>
+where
+    G: IntoNeighborsDirected + IntoNodeIdentifiers + NodeIndexable + GraphProp,
+    G::NodeId: Eq + Hash,
+{
+    let bounds = bound_unweighted(graph, Target::Eccentricities)?;
+    Some(bounds.eccentricities(graph))
+}
+
+/// \[Generic\] Returns the diameter of the graph: the greatest eccentricity of its nodes, in
+/// number of edges.
+///
+/// Returns `None` if the graph is empty, or not (strongly) connected.
+///
+/// # Example
+///
+/// ```
+/// use petgraph::algo::diameter;
+/// use petgraph::prelude::*;
+///
+/// let cycle = DiGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 3), (3, 0)]);
+/// assert_eq!(diameter(&cycle), Some(3));
+/// assert_eq!(diameter(&cycle.into_edge_type::<Undirected>()), Some(2));
+/// ```
+pub fn diameter<G>(graph: G) -> Option<usize>
+where
+    G: IntoNeighborsDirected + IntoNodeIdentifiers + NodeIndexable + GraphProp,
+{
+    Some(bound_unweighted(graph, Target::Diameter)?.diameter())
+}
+
+/// \[Generic\] Returns the radius of the graph: the least eccentricity of its nodes, in number
+/// of edges.
+///
+/// Returns `None` if the graph is empty, or not (strongly) connected.
+///
+/// # Example
+///
+/// ```
+/// use petgraph::algo::radius;
+/// use petgraph::prelude::*;
+///
+/// let path = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 3), (3, 4)]);
+/// assert_eq!(radius(&path), Some(2));
+/// ```
+pub fn radius<G>(graph: G) -> Option<usize>
+where
+    G: IntoNeighborsDirected + IntoNodeIdentifiers + NodeIndexable + GraphProp,
+{
+    Some(bound_unweighted(graph, Target::Radius)?.radius())
+}
+
+/// \[Generic\] Returns the center of the graph: the nodes whose eccentricity is equal to the
+/// radius, counting edges.
+///
+/// Returns an empty list if the graph is not (strongly) connected.
+///
+/// # Example
+///
+/// ```
+/// use petgraph::algo::center;
+/// use petgraph::prelude::*;
+///
+/// let path = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 3)]);
+/// assert_eq!(center(&path), [NodeIndex::new(1), NodeIndex::new(2)]);
+/// ```
+pub fn center<G>(graph: G) -> Vec<G::NodeId>
+where
+    G: IntoNeighborsDirected + IntoNodeIdentifiers + NodeIndexable + GraphProp,
+{
+    bound_unweighted(graph, Target::Center).map_or_else(Vec::new, |bounds| bounds.center(graph))
+}
+
+/// \[Generic\] Returns the periphery of the graph: the nodes whose eccentricity is equal to the
+/// diameter, counting edges.
+///
+/// Returns an empty list if the graph is not (strongly) connected.
+///
+/// # Example
+///
+/// ```
+/// use petgraph::algo::periphery;
+/// use petgraph::prelude::*;
+///
+/// let path = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 3)]);
+/// assert_eq!(periphery(&path), [NodeIndex::new(0), NodeIndex::new(3)]);
+/// ```
+pub fn periphery<G>(graph: G) -> Vec<G::NodeId>
+where
+    G: IntoNeighborsDirected + IntoNodeIdentifiers + NodeIndexable + GraphProp,
+{
+    bound_unweighted(graph, Target::Periphery)
+        .map_or_else(Vec::new, |bounds| bounds.periphery(graph))
+}
+
+/// \[Generic\] Returns the eccentricity of `node`: the greatest length of a shortest path from
+/// it to any other node.
+///
+/// The function `edge_cost` should return the cost for a particular edge; costs must be
+/// non-negative.
+///
+/// Returns `None` if some node is not reachable from `node`.
+///
+/// Computes a single run of Dijkstra's algorithm, with time complexity
+/// **O((|V| + |E|) log |V|)**.
+///
+/// # Example
+///
+/// ```
+/// use petgraph::algo::weighted_eccentricity;
+/// use petgraph::prelude::*;
+///
+/// let g = UnGraph::<(), f64>::from_edges(&[(0, 1, 1.5), (1, 2, 1.), (0, 2, 3.)]);
+/// assert_eq!(weighted_eccentricity(&g, 0.into(), |e| *e.weight()), Some(2.5));
+/// ```
+pub fn weighted_eccentricity<G, F, K>(graph: G, node: G::NodeId, mut edge_cost: F) -> Option<K>
+where
+    G: IntoEdgesDirected + IntoNodeIdentifiers + NodeIndexable,
+    F: FnMut(G::EdgeRef) -> K,
+    K: Measure + Copy,
+{
+    let distance = dijkstra_distances(graph, graph.to_index(node), Outgoing, &mut edge_cost);
+    farthest(graph, &distance)
+}
+
+/// \[Generic\] Returns the eccentricity of every node: the greatest length of a shortest path
+/// from it to any other node.
+///
+/// The function `edge_cost` should return the cost for a particular edge; costs must be
+/// non-negative.
+///
+/// Returns `None` if the graph is empty, or not (strongly) connected.
+pub fn weighted_eccentricities<G, F, K>(graph: G, edge_cost: F) -> Option<HashMap<G::NodeId, K>>
+where
+    G: IntoEdgesDirected + IntoNodeIdentifiers + NodeIndexable + GraphProp,
+    G::NodeId: Eq + Hash,
+    F: FnMut(G::EdgeRef) -> K,
+    K: Measure + Copy + Sub<Output = K>,
+{
+    let bounds = bound_weighted(graph, Target::Eccentricities, edge_cost)?;
+    Some(bounds.eccentricities(graph))
+}
+
+/// \[Generic\] Returns the diameter of the graph: the greatest eccentricity of its nodes.
+///
+/// The function `edge_cost` should return the cost for a particular edge; costs must be
+/// non-negative.
+///
+/// Returns `None` if the graph is empty, or not (strongly) connected.
+///
+/// # Example
+///
+/// ```
+/// use petgraph::algo::weighted_diameter;
+/// use petgraph::prelude::*;
+///
+/// let g = UnGraph::<(), u32>::from_edges(&[(0, 1, 2), (1, 2, 2), (2, 3, 7), (3, 0, 1)]);
+/// assert_eq!(weighted_diameter(&g, |e| *e.weight()), Some(5));
+/// ```
+pub fn weighted_diameter<G, F, K>(graph: G, edge_cost: F) -> Option<K>
+where
+    G: IntoEdgesDirected + IntoNodeIdentifiers + NodeIndexable + GraphProp,
+    F: FnMut(G::EdgeRef) -> K,
+    K: Measure + Copy + Sub<Output = K>,
+{
+    Some(bound_weighted(graph, Target::Diameter, edge_cost)?.diameter())
+}
+
+/// \[Generic\] Returns the radius of the graph: the least eccentricity of its nodes.
+///
+/// The function `edge_cost` should return the cost for a particular edge; costs must be
+/// non-negative.
+///
+/// Returns `None` if the graph is empty, or not (strongly) connected.
+pub fn weighted_radius<G, F, K>(graph: G, edge_cost: F) -> Option<K>
+where
+    G: IntoEdgesDirected + IntoNodeIdentifiers + NodeIndexable + GraphProp,
+    F: FnMut(G::EdgeRef) -> K,
+    K: Measure + Copy + Sub<Output = K>,
+{
+    Some(bound_weighted(graph, Target::Radius, edge_cost)?.radius())
+}
+
+/// \[Generic\] Returns the center of the graph: the nodes whose eccentricity is equal to the
+/// radius.
+///
+/// The function `edge_cost` should return the cost for a particular edge; costs must be
+/// non-negative.
+///
+/// Returns an empty list if the graph is not (strongly) connected.
+pub fn weighted_center<G, F, K>(graph: G, edge_cost: F) -> Vec<G::NodeId>
+where
+    G: IntoEdgesDirected + IntoNodeIdentifiers + NodeIndexable + GraphProp,
+    F: FnMut(G::EdgeRef) -> K,
+    K: Measure + Copy + Sub<Output = K>,
+{
+    bound_weighted(graph, Target::Center, edge_cost).map_or_else(Vec::new, |b| b.center(graph))
+}
+
+/// \[Generic\] Returns the periphery of the graph: the nodes whose eccentricity is equal to the
+/// diameter.
+///
+/// The function `edge_cost` should return the cost for a particular edge; costs must be
+/// non-negative.
+///
+/// Returns an empty list if the graph is not (strongly) connected.
+pub fn weighted_periphery<G, F, K>(graph: G, edge_cost: F) -> Vec<G::NodeId>
+where
+    G: IntoEdgesDirected + IntoNodeIdentifiers + NodeIndexable + GraphProp,
+    F: FnMut(G::EdgeRef) -> K,
+    K: Measure + Copy + Sub<Output = K>,
+{
+    bound_weighted(graph, Target::Periphery, edge_cost)
+        .map_or_else(Vec::new, |b| b.periphery(graph))
+}
+
+/// The greatest distance, if all nodes are reachable.
+fn farthest<G, K>(graph: G, distance: &[Option<K>]) -> Option<K>
+where
+    G: IntoNodeIdentifiers + NodeIndexable,
+    K: Measure + Copy,
+{
+    let mut farthest = None;
+    for n in graph.node_identifiers() {
+        let d = distance[graph.to_index(n)]?;
+        if farthest.map_or(true, |f| f < d) {
+            farthest = Some(d);
+        }
+    }
+    farthest
+}
+
+fn breadth_first_distances<G>(graph: G, source: usize, dir: Direction) -> Vec<Option<usize>>
+where
+    G: IntoNeighborsDirected + NodeIndexable,
+{
+    let mut distance = vec![None; graph.node_bound()];
+    distance[source] = Some(0);
+    let mut queue = VecDeque::new();
+    queue.push_back(source);
+    while let Some(v) = queue.pop_front() {
+        let next = distance[v].map(|d| d + 1);
+        for w in graph.neighbors_directed(graph.from_index(v), dir) {
+            let w = graph.to_index(w);
+            if distance[w].is_none() {
+                distance[w] = next;
+                queue.push_back(w);
+            }
+        }
+    }
+    distance
+}
+
+fn dijkstra_distances<G, F, K>(
+    graph: G,
+    source: usize,
+    dir: Direction,
+    edge_cost: &mut F,
+) -> Vec<Option<K>>
+where
+    G: IntoEdgesDirected + NodeIndexable,
+    F: FnMut(G::EdgeRef) -> K,
+    K: Measure + Copy,
+{
+    let mut distance = vec![None; graph.node_bound()];
+    let mut visited = vec![false; graph.node_bound()];
+    let mut visit_next = BinaryHeap::new();
+    distance[source] = Some(K::default());
+    visit_next.push(MinScored(K::default(), source));
+    while let Some(MinScored(d, v)) = visit_next.pop() {
+        if visited[v] {
+            continue;
+        }
+        visited[v] = true;
+        let node = graph.from_index(v);
+        for edge in graph.edges_directed(node, dir) {
+            let other = if edge.source() == node {
+                edge.target()
+            } else {
+                edge.source()
+            };
+            let w = graph.to_index(other);
+            if visited[w] {
+                continue;
+            }
+            let next = d + edge_cost(edge);
+            if distance[w].map_or(true, |old| next < old) {
+                distance[w] = Some(next);
+                visit_next.push(MinScored(next, w));
+            }
+        }
+    }
+    distance
+}
+
+fn bound_unweighted<G>(graph: G, target: Target) -> Option<Bounds<usize>>
+where
+    G: IntoNeighborsDirected + IntoNodeIdentifiers + NodeIndexable + GraphProp,
+{
+    let degree = |n: G::NodeId| graph.neighbors_directed(n, Outgoing).count();
+    let search = |s, dir| breadth_first_distances(graph, s, dir);
+    Bounds::compute(graph, degree, target, search)
+}
+
+fn bound_weighted<G, F, K>(graph: G, target: Target, mut edge_cost: F) -> Option<Bounds<K>>
+where
+    G: IntoEdgesDirected + IntoNodeIdentifiers + NodeIndexable + GraphProp,
+    F: FnMut(G::EdgeRef) -> K,
+    K: Measure + Copy + Sub<Output = K>,
+{
+    let degree = |n: G::NodeId| graph.edges_directed(n, Outgoing).count();
+    let search = |s, dir| dijkstra_distances(graph, s, dir, &mut edge_cost);
+    Bounds::compute(graph, degree, target, search)
+}
+
+/// The measure that the eccentricity bounds are refined for.
+#[derive(Clone, Copy, PartialEq)]
+enum Target {
+    Eccentricities,
+    Diameter,
+    Radius,
+    Center,
+    Periphery,
+}
+
+/// Lower and upper bounds of the eccentricity of each node.
+struct Bounds<K> {
+    nodes: Vec<usize>,
+    lower: Vec<K>,
+    upper: Vec<K>,
+}
+
+impl<K> Bounds<K>
+where
+    K: Measure + Copy + Sub<Output = K>,
+{
+    /// Refine the bounds until they determine `target`, with `search` computing the distances
+    /// from or to a node. Returns `None` if the graph is empty or not strongly connected.
+    fn compute<G, D, S>(graph: G, mut degree: D, target: Target, mut search: S) -> Option<Self>
+    where
+        G: IntoNodeIdentifiers + NodeIndexable + GraphProp,
+        D: FnMut(G::NodeId) -> usize,
+        S: FnMut(usize, Direction) -> Vec<Option<K>>,
+    {
+        let nodes: Vec<usize> = graph
+            .node_identifiers()
+            .map(|n| graph.to_index(n))
+            .collect();
+        let mut degrees = vec![0; graph.node_bound()];
+        for n in graph.node_identifiers() {
+            degrees[graph.to_index(n)] = degree(n);
+        }
+        // Start from the node of highest degree, which tends to be central.
+        let mut next = *nodes.iter().max_by_key(|&&v| degrees[v])?;
+        let mut bounds = Bounds {
+            lower: vec![K::default(); graph.node_bound()],
+            upper: Vec::new(),
+            nodes,
+        };
+        let mut candidates = bounds.nodes.clone();
+        let mut pick_upper = true;
+        loop {
+            let from = search(next, Outgoing);
+            let to_search;
+            let to = if graph.is_directed() {
+                to_search = search(next, Incoming);
+                &to_search
+            } else {
+                &from
+            };
+            let mut ecc = K::default();
+            for &v in &bounds.nodes {
+                to[v]?;
+                if ecc < from[v]? {
+                    ecc = from[v].unwrap();
+                }
+            }
+            if bounds.upper.is_empty() {
+                bounds.upper = vec![K::default(); graph.node_bound()];
+                for &v in &bounds.nodes {
+                    bounds.upper[v] = to[v].unwrap() + ecc;
+                }
+            }
+            for &v in &bounds.nodes {
+                let (from, to) = (from[v].unwrap(), to[v].unwrap());
+                let lower = if to < ecc - from { ecc - from } else { to };
+                if bounds.lower[v] < lower {
+                    bounds.lower[v] = lower;
+                }
+                if to + ecc < bounds.upper[v] {
+                    bounds.upper[v] = to + ecc;
+                }
+            }
+            bounds.lower[next] = ecc;
+            bounds.upper[next] = ecc;
+
+            let (diameter, radius) = (bounds.diameter(), bounds.radius());
+            let (lower, upper) = (&bounds.lower, &bounds.upper);
+            candidates.retain(|&v| {
+                lower[v] != upper[v]
+                    && match target {
+                        Target::Eccentricities => true,
+                        Target::Diameter => upper[v] > diameter,
+                        Target::Periphery => upper[v] >= diameter,
+                        Target::Radius => lower[v] < radius,
+                        Target::Center => lower[v] <= radius,
+                    }
+            });
+
+            // Alternate between the largest upper and the smallest lower bound, which are
+            // likely to be on the periphery and in the center.
+            let by_upper = |&&v: &&usize| (upper[v], degrees[v]);
+            let by_lower = |&&v: &&usize| (lower[v], std::cmp::Reverse(degrees[v]));
+            let chosen = if pick_upper {
+                candidates
+                    .iter()
+                    .max_by(|a, b| by_upper(a).partial_cmp(&by_upper(b)).unwrap())
+            } else {
+                candidates
+                    .iter()
+                    .min_by(|a, b| by_lower(a).partial_cmp(&by_lower(b)).unwrap())
+            };
+            next = match chosen {
+                Some(&v) => v,
+                None => return Some(bounds),
+            };
+            pick_upper = !pick_upper;
+        }
+    }
+
+    /// The greatest lower bound, which is the diameter once the bounds are refined for it.
+    fn diameter(&self) -> K {
+        self.nodes
+            .iter()
+            .map(|&v| self.lower[v])
+            .fold(K::default(), |max, l| if max < l { l } else { max })
+    }
+
+    /// The least upper bound, which is the radius once the bounds are refined for it.
+    fn radius(&self) -> K {
+        let mut upper = self.nodes.iter().map(|&v| self.upper[v]);
+        let first = upper.next().unwrap();
+        upper.fold(first, |min, u| if u < min { u } else { min })
+    }
+
+    fn eccentricities<G>(&self, graph: G) -> HashMap<G::NodeId, K>
+    where
+        G: NodeIndexable,
+        G::NodeId: Eq + Hash,
+    {
+        self.nodes
+            .iter()
+            .map(|&v| (graph.from_index(v), self.lower[v]))
+            .collect()
+    }
+
+    fn center<G: NodeIndexable>(&self, graph: G) -> Vec<G::NodeId> {
+        let radius = self.radius();
+        self.nodes
+            .iter()
+            .filter(|&&v| self.upper[v] == radius)
+            .map(|&v| graph.from_index(v))
+            .collect()
+    }
+
+    fn periphery<G: NodeIndexable>(&self, graph: G) -> Vec<G::NodeId> {
+        let diameter = self.diameter();
+        self.nodes
+            .iter()
+            .filter(|&&v| self.lower[v] == diameter)
+            .map(|&v| graph.from_index(v))
+            .collect()
+    }
+}
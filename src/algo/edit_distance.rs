@@ -0,0 +1,900 @@
+//! Graph edit distance.
+//!
+//! The *edit distance* between two graphs is the smallest total cost of a sequence of edit
+//! operations — substituting, deleting and inserting nodes and edges — that turns the first graph
+//! into one isomorphic to the second.
+//!
+//! The costs are given by a [`NodeCost`] and an [`EdgeCost`], the counterparts of the
+//! [`NodeMatcher`] and [`EdgeMatcher`] of the isomorphism functions. Closures over the weights
+//! implement them, and [`UnitCost`] turns a matcher into costs that count the edit operations.
+//!
+//! [`NodeCost`]: trait.NodeCost.html
+//! [`EdgeCost`]: trait.EdgeCost.html
+//! [`UnitCost`]: struct.UnitCost.html
+//! [`NodeMatcher`]: ../isomorphism/trait.NodeMatcher.html
+//! [`EdgeMatcher`]: ../isomorphism/trait.EdgeMatcher.html
+
+use std::collections::BinaryHeap;
+use std::ops::Sub;
+
+use fixedbitset::FixedBitSet;
+
+use crate::algo::isomorphism::{EdgeMatcher, NodeMatcher};
+use crate::algo::Measure;
+use crate::data::DataMap;
+use crate::scored::MinScored;
+use crate::visit::{EdgeRef, GraphBase, GraphProp, IntoEdgeReferences, NodeCompactIndexable};
+
+/// A node assignment of an edit path: a substituted node `(Some(a), Some(b))`, a deleted node
+/// `(Some(a), None)` or an inserted node `(None, Some(b))`.
+pub type NodeEdit<N0, N1> = (Option<N0>, Option<N1>);
+
+/// An edit path, given by the assignments of the nodes.
+pub type EditPath<N0, N1> = Vec<NodeEdit<N0, N1>>;
+
+/// The cost of an edit operation on the nodes of `G0` and `G1`.
+///
+/// Implemented by [`UnitCost`](struct.UnitCost.html) and by closures over node weights,
+/// `FnMut(Option<&G0::NodeWeight>, Option<&G1::NodeWeight>) -> K`.
+pub trait NodeCost<G0: GraphBase, G1: GraphBase, K> {
+    /// Return the cost of substituting `n0` by `n1`, of deleting `n0` if `n1` is `None` or of
+    /// inserting `n1` if `n0` is `None`.
+    fn cost(&mut self, g0: &G0, g1: &G1, n0: Option<G0::NodeId>, n1: Option<G1::NodeId>) -> K;
+}
+
+impl<G0, G1, F, K> NodeCost<G0, G1, K> for F
+where
+    G0: GraphBase + DataMap,
+    G1: GraphBase + DataMap,
+    F: FnMut(Option<&G0::NodeWeight>, Option<&G1::NodeWeight>) -> K,
+{
+    #[inline]
+    fn cost(&mut self, g0: &G0, g1: &G1, n0: Option<G0::NodeId>, n1: Option<G1::NodeId>) -> K {
+        self(
+            n0.and_then(|n| g0.node_weight(n)),
+            n1.and_then(|n| g1.node_weight(n)),
+        )
+    }
+}
+
+/// The cost of an edit operation on the edges of `G0` and `G1`.
+///
+/// Implemented by [`UnitCost`](struct.UnitCost.html) and by closures over edge weights,
+/// `FnMut(Option<&G0::EdgeWeight>, Option<&G1::EdgeWeight>) -> K`.
+pub trait EdgeCost<G0: IntoEdgeReferences, G1: IntoEdgeReferences, K> {
+    /// Return the cost of substituting `e0` by `e1`, of deleting `e0` if `e1` is `None` or of
+    /// inserting `e1` if `e0` is `None`.
+    fn cost(&mut self, g0: &G0, g1: &G1, e0: Option<G0::EdgeRef>, e1: Option<G1::EdgeRef>) -> K;
+}
+
+impl<G0, G1, F, K> EdgeCost<G0, G1, K> for F
+where
+    G0: IntoEdgeReferences,
+    G1: IntoEdgeReferences,
+    F: FnMut(Option<&G0::EdgeWeight>, Option<&G1::EdgeWeight>) -> K,
+{
+    #[inline]
+    fn cost(&mut self, _: &G0, _: &G1, e0: Option<G0::EdgeRef>, e1: Option<G1::EdgeRef>) -> K {
+        self(
+            e0.as_ref().map(|e| e.weight()),
+            e1.as_ref().map(|e| e.weight()),
+        )
+    }
+}
+
+/// Unit costs from a [`NodeMatcher`](../isomorphism/trait.NodeMatcher.html) or an
+/// [`EdgeMatcher`](../isomorphism/trait.EdgeMatcher.html): every insertion and deletion costs one,
+/// and a substitution costs one unless the matcher accepts the pair.
+///
+/// With unit costs the edit distance counts the edit operations, and it is zero if and only if the
+/// graphs are isomorphic under the matchers.
+#[derive(Clone, Copy, Debug)]
+pub struct UnitCost<M>(pub M);
+
+impl<G0, G1, M> NodeCost<G0, G1, usize> for UnitCost<M>
+where
+    G0: GraphBase,
+    G1: GraphBase,
+    M: NodeMatcher<G0, G1>,
+{
+    fn cost(&mut self, g0: &G0, g1: &G1, n0: Option<G0::NodeId>, n1: Option<G1::NodeId>) -> usize {
+        match (n0, n1) {
+            (Some(a), Some(b)) => (M::enabled() && !self.0.eq(g0, g1, a, b)) as usize,
+            _ => 1,
+        }
+    }
+}
+
+impl<G0, G1, M> EdgeCost<G0, G1, usize> for UnitCost<M>
+where
+    G0: IntoEdgeReferences,
+    G1: IntoEdgeReferences,
+    M: EdgeMatcher<G0, G1>,
+{
+    fn cost(
+        &mut self,
+        g0: &G0,
+        g1: &G1,
+        e0: Option<G0::EdgeRef>,
+        e1: Option<G1::EdgeRef>,
+    ) -> usize {
+        match (e0, e1) {
+            (Some(e0), Some(e1)) => {
+                let e0 = (e0.source(), e0.target());
+                let e1 = (e1.source(), e1.target());
+                (M::enabled() && !self.0.eq(g0, g1, e0, e1)) as usize
+            }
+            _ => 1,
+        }
+    }
+}
+
+/// \[Generic\] Compute the exact graph edit distance between `g0` and `g1`, using A* search over
+/// partial node mappings.
+///
+/// `node_cost` and `edge_cost` give the cost of each edit operation, see [`NodeCost`] and
+/// [`EdgeCost`]. An edge is substituted when its endpoints are substituted by the endpoints of an
+/// edge of `g1`, and deleted or inserted otherwise. Costs must be non-negative, and not NaN: the
+/// result is unspecified otherwise, but the search still terminates. With costs of zero for
+/// matching weights and positive costs otherwise, such as the [`UnitCost`] of the matchers of
+/// [`is_isomorphic_matching`](fn.is_isomorphic_matching.html), the distance is zero if and only if
+/// the graphs are isomorphic.
+///
+/// Return the distance and an optimal edit path, as the assignment of every node of `g0` (in index
+/// order) followed by the inserted nodes of `g1`. The graphs should not be multigraphs.
+///
+/// The search space grows exponentially with the number of nodes, so this is only practical for
+/// small graphs; use [`graph_edit_distance_within_budget`] to bound the search, or
+/// [`approximate_graph_edit_distance`] for large graphs. Partial mappings are bounded by an optimal
+/// assignment of the remaining node costs, and pruned against the approximate distance.
+///
+/// **Reference**
+///
+/// * Kaspar Riesen; *Structural Pattern Recognition with Graph Edit Distance*, chapter 3
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::edit_distance::UnitCost;
+/// use petgraph::algo::graph_edit_distance;
+/// use petgraph::prelude::*;
+///
+/// let path = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2)]);
+/// let mut triangle = path.clone();
+/// triangle.add_edge(2.into(), 0.into(), ());
+///
+/// // Unit costs for insertions and deletions.
+/// let unit = |a: Option<&_>, b: Option<&_>| if a.is_some() && b.is_some() { 0 } else { 1 };
+/// let (distance, path) = graph_edit_distance(&path, &triangle, unit, unit);
+/// assert_eq!(distance, 1);
+/// assert_eq!(path.len(), 3);
+///
+/// // Unit costs that also count the substitutions of nodes with different weights.
+/// let mut g0 = DiGraph::new();
+/// let (a, b, c) = (g0.add_node("a"), g0.add_node("b"), g0.add_node("c"));
+/// g0.extend_with_edges(&[(a, b), (b, c)]);
+/// let mut g1 = g0.clone();
+/// g1[c] = "d";
+/// let edges = |_: Option<&()>, _: Option<&()>| 0;
+/// let (distance, _) = graph_edit_distance(&g0, &g1, UnitCost(|x: &&str, y: &&str| x == y), edges);
+/// assert_eq!(distance, 1);
+/// ```
+///
+/// [`NodeCost`]: edit_distance/trait.NodeCost.html
+/// [`EdgeCost`]: edit_distance/trait.EdgeCost.html
+/// [`UnitCost`]: edit_distance/struct.UnitCost.html
+/// [`graph_edit_distance_within_budget`]: fn.graph_edit_distance_within_budget.html
+/// [`approximate_graph_edit_distance`]: fn.approximate_graph_edit_distance.html
+pub fn graph_edit_distance<G0, G1, NC, EC, K>(
+    g0: G0,
+    g1: G1,
+    mut node_cost: NC,
+    mut edge_cost: EC,
+) -> (K, EditPath<G0::NodeId, G1::NodeId>)
+where
+    G0: NodeCompactIndexable + IntoEdgeReferences + GraphProp,
+    G1: NodeCompactIndexable + IntoEdgeReferences + GraphProp<EdgeType = G0::EdgeType>,
+    NC: NodeCost<G0, G1, K>,
+    EC: EdgeCost<G0, G1, K>,
+    K: Measure + Copy + Sub<Output = K>,
+{
+    let instance = EditInstance::new(g0, g1, &mut node_cost);
+    let (upper_bound, best) = instance.approximate(&mut edge_cost);
+    let n0 = instance.n0;
+    let order = instance.order();
+
+    // The A* states: partial mappings of `order[..depth]`, as a tree of assignments.
+    struct State<K> {
+        parent: usize,
+        target: Option<usize>,
+        depth: usize,
+        cost: K,
+    }
+    let mut states = vec![State {
+        parent: std::usize::MAX,
+        target: None,
+        depth: 0,
+        cost: K::default(),
+    }];
+    let mut heap = BinaryHeap::new();
+    heap.push(MinScored(K::default(), 0));
+    let mut assigned = vec![None; n0];
+    let mut used = FixedBitSet::with_capacity(instance.n1);
+    while let Some(MinScored(_, s)) = heap.pop() {
+        let depth = states[s].depth;
+        // Rebuild the partial mapping of this state; the final insertion step does not assign a
+        // node.
+        for a in &mut assigned {
+            *a = None;
+        }
+        used.clear();
+        let mut t = if depth == n0 + 1 { states[s].parent } else { s };
+        while t != 0 {
+            let node = order[states[t].depth - 1];
+            assigned[node] = states[t].target;
+            if let Some(b) = states[t].target {
+                used.insert(b);
+            }
+            t = states[t].parent;
+        }
+        if depth == n0 + 1 {
+            return (states[s].cost, instance.edit_path(&assigned));
+        }
+        if depth == n0 {
+            // Only the insertions of the unused nodes of `g1` remain.
+            let cost = states[s].cost + instance.insertion_cost(&used, &mut edge_cost);
+            if cost < upper_bound {
+                states.push(State {
+                    parent: s,
+                    target: None,
+                    depth: n0 + 1,
+                    cost,
+                });
+                heap.push(MinScored(cost, states.len() - 1));
+            }
+            continue;
+        }
+
+        let cost = states[s].cost;
+        for (target, cost, estimate) in
+            instance.extensions(&order, depth, cost, &assigned, &used, &mut edge_cost)
+        {
+            if estimate < upper_bound {
+                states.push(State {
+                    parent: s,
+                    target,
+                    depth: depth + 1,
+                    cost,
+                });
+                heap.push(MinScored(estimate, states.len() - 1));
+            }
+        }
+    }
+    // Nothing is cheaper than the approximation, so it is optimal.
+    (upper_bound, best)
+}
+
+/// \[Generic\] Compute the graph edit distance between `g0` and `g1` as well as possible within
+/// `max_steps` steps of the search.
+///
+/// This is an anytime version of [`graph_edit_distance`](fn.graph_edit_distance.html): starting
+/// from the edit path of
+/// [`approximate_graph_edit_distance`](fn.approximate_graph_edit_distance.html), a depth-first
+/// branch and bound search over partial node mappings improves the best edit path found so far,
+/// until it is shown optimal or the budget runs out. A step extends one partial mapping.
+///
+/// The costs and the edit path are as in [`graph_edit_distance`](fn.graph_edit_distance.html).
+/// Return the best distance found, its edit path, and `true` if the search completed, so the
+/// distance is exact. Otherwise the distance is an upper bound of the graph edit distance.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::graph_edit_distance_within_budget;
+/// use petgraph::prelude::*;
+///
+/// let g0 = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 3), (3, 4), (4, 0)]);
+/// let g1 = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 0), (2, 3), (3, 4)]);
+///
+/// let unit = |a: Option<&_>, b: Option<&_>| if a.is_some() && b.is_some() { 0 } else { 1 };
+/// let (bound, _, _) = graph_edit_distance_within_budget(&g0, &g1, unit, unit, 0);
+/// let (distance, path, exact) = graph_edit_distance_within_budget(&g0, &g1, unit, unit, 1000);
+/// assert!(exact);
+/// assert_eq!(distance, 2);
+/// assert!(bound >= distance);
+/// assert_eq!(path.len(), 5);
+/// ```
+pub fn graph_edit_distance_within_budget<G0, G1, NC, EC, K>(
+    g0: G0,
+    g1: G1,
+    mut node_cost: NC,
+    mut edge_cost: EC,
+    max_steps: usize,
+) -> (K, EditPath<G0::NodeId, G1::NodeId>, bool)
+where
+    G0: NodeCompactIndexable + IntoEdgeReferences + GraphProp,
+    G1: NodeCompactIndexable + IntoEdgeReferences + GraphProp<EdgeType = G0::EdgeType>,
+    NC: NodeCost<G0, G1, K>,
+    EC: EdgeCost<G0, G1, K>,
+    K: Measure + Copy + Sub<Output = K>,
+{
+    let instance = EditInstance::new(g0, g1, &mut node_cost);
+    let (upper_bound, approximation) = instance.approximate(&mut edge_cost);
+    let mut search = BranchAndBound {
+        order: instance.order(),
+        assigned: vec![None; instance.n0],
+        used: FixedBitSet::with_capacity(instance.n1),
+        best: (upper_bound, None),
+        steps: max_steps,
+    };
+    let complete = search.extend(&instance, 0, K::default(), &mut edge_cost);
+    let path = match search.best.1 {
+        Some(assigned) => instance.edit_path(&assigned),
+        None => approximation,
+    };
+    (search.best.0, path, complete)
+}
+
+/// \[Generic\] Compute an upper bound of the graph edit distance between `g0` and `g1` by bipartite
+/// assignment.
+///
+/// Each node of `g0` is substituted by a node of `g1` or deleted, and each node of `g1` substituted
+/// or inserted, following an optimal assignment for costs that combine the node costs with an
+/// optimal assignment of the edges around each pair of nodes. The returned cost is the exact cost
+/// of the resulting edit path, so it is never below the graph edit distance.
+///
+/// The costs and the return value are as in [`graph_edit_distance`](fn.graph_edit_distance.html).
+///
+/// Computes in **O((|V0| + |V1|)³ + |V0| |V1| Δ³)** time, where Δ is the largest degree.
+///
+/// **Reference**
+///
+/// * Kaspar Riesen, Horst Bunke; *Approximate graph edit distance computation by means of bipartite
+///   graph matching*
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::{approximate_graph_edit_distance, graph_edit_distance};
+/// use petgraph::prelude::*;
+///
+/// let g0 = DiGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 3)]);
+/// let g1 = DiGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 0), (3, 0)]);
+///
+/// let unit = |a: Option<&_>, b: Option<&_>| if a.is_some() && b.is_some() { 0 } else { 1 };
+/// let (approximate, _) = approximate_graph_edit_distance(&g0, &g1, unit, unit);
+/// let (exact, _) = graph_edit_distance(&g0, &g1, unit, unit);
+/// assert!(exact <= approximate);
+/// ```
+pub fn approximate_graph_edit_distance<G0, G1, NC, EC, K>(
+    g0: G0,
+    g1: G1,
+    mut node_cost: NC,
+    mut edge_cost: EC,
+) -> (K, EditPath<G0::NodeId, G1::NodeId>)
+where
+    G0: NodeCompactIndexable + IntoEdgeReferences + GraphProp,
+    G1: NodeCompactIndexable + IntoEdgeReferences + GraphProp<EdgeType = G0::EdgeType>,
+    NC: NodeCost<G0, G1, K>,
+    EC: EdgeCost<G0, G1, K>,
+    K: Measure + Copy + Sub<Output = K>,
+{
+    EditInstance::new(g0, g1, &mut node_cost).approximate(&mut edge_cost)
+}
+
+/// The state of the depth-first branch and bound search: the partial mapping of `order[..depth]`
+/// and the best complete mapping found so far, if better than the approximation.
+struct BranchAndBound<K> {
+    order: Vec<usize>,
+    assigned: Vec<Option<usize>>,
+    used: FixedBitSet,
+    best: (K, Option<Vec<Option<usize>>>),
+    steps: usize,
+}
+
+impl<K> BranchAndBound<K>
+where
+    K: Measure + Copy + Sub<Output = K>,
+{
+    /// Search the extensions of the partial mapping of `order[..depth]` of the given `cost`. Return
+    /// `false` if the budget ran out.
+    fn extend<G0, G1, EC>(
+        &mut self,
+        instance: &EditInstance<G0, G1, K>,
+        depth: usize,
+        cost: K,
+        edge_cost: &mut EC,
+    ) -> bool
+    where
+        G0: NodeCompactIndexable + IntoEdgeReferences + GraphProp,
+        G1: NodeCompactIndexable + IntoEdgeReferences + GraphProp,
+        EC: EdgeCost<G0, G1, K>,
+    {
+        if self.steps == 0 {
+            return false;
+        }
+        self.steps -= 1;
+        if depth == instance.n0 {
+            let cost = cost + instance.insertion_cost(&self.used, edge_cost);
+            if cost < self.best.0 {
+                self.best = (cost, Some(self.assigned.clone()));
+            }
+            return true;
+        }
+        // Try the most promising extensions first, to improve the bound early.
+        let mut extensions = instance.extensions(
+            &self.order,
+            depth,
+            cost,
+            &self.assigned,
+            &self.used,
+            edge_cost,
+        );
+        // Estimates are only NaN for NaN costs: sort them last, keeping the order total.
+        let is_nan = |k: &K| k.partial_cmp(k).is_none();
+        extensions.sort_by(|x, y| {
+            x.2.partial_cmp(&y.2)
+                .unwrap_or_else(|| is_nan(&x.2).cmp(&is_nan(&y.2)))
+        });
+        let a = self.order[depth];
+        for (target, cost, estimate) in extensions {
+            if estimate >= self.best.0 {
+                break;
+            }
+            self.assigned[a] = target;
+            if let Some(b) = target {
+                self.used.insert(b);
+            }
+            let complete = self.extend(instance, depth + 1, cost, edge_cost);
+            self.assigned[a] = None;
+            if let Some(b) = target {
+                self.used.set(b, false);
+            }
+            if !complete {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+/// Sorted outgoing adjacency lists, by node index; undirected edges are listed at both ends.
+fn adjacency<G>(g: G) -> Vec<Vec<(usize, G::EdgeRef)>>
+where
+    G: NodeCompactIndexable + IntoEdgeReferences + GraphProp,
+{
+    let mut adjacency = vec![Vec::new(); g.node_count()];
+    for edge in g.edge_references() {
+        let (a, b) = (g.to_index(edge.source()), g.to_index(edge.target()));
+        adjacency[a].push((b, edge));
+        if !g.is_directed() && a != b {
+            adjacency[b].push((a, edge));
+        }
+    }
+    for list in &mut adjacency {
+        list.sort_by_key(|&(b, _)| b);
+    }
+    adjacency
+}
+
+/// The incoming adjacency lists for outgoing adjacency lists.
+fn reversed<E: Copy>(adjacency: &[Vec<(usize, E)>]) -> Vec<Vec<(usize, E)>> {
+    let mut incoming = vec![Vec::new(); adjacency.len()];
+    for (a, list) in adjacency.iter().enumerate() {
+        for &(b, e) in list {
+            incoming[b].push((a, e));
+        }
+    }
+    incoming
+}
+
+fn find_edge<E: Copy>(adjacency: &[Vec<(usize, E)>], a: usize, b: usize) -> Option<E> {
+    adjacency[a]
+        .binary_search_by_key(&b, |&(t, _)| t)
+        .ok()
+        .map(|i| adjacency[a][i].1)
+}
+
+/// The graphs and node costs of an edit distance problem, by node index.
+struct EditInstance<G0: IntoEdgeReferences, G1: IntoEdgeReferences, K> {
+    g0: G0,
+    g1: G1,
+    n0: usize,
+    n1: usize,
+    adjacency0: Vec<Vec<(usize, G0::EdgeRef)>>,
+    adjacency1: Vec<Vec<(usize, G1::EdgeRef)>>,
+    /// The incoming adjacency lists of directed graphs.
+    incoming0: Vec<Vec<(usize, G0::EdgeRef)>>,
+    incoming1: Vec<Vec<(usize, G1::EdgeRef)>>,
+    substitute: Vec<Vec<K>>,
+    delete: Vec<K>,
+    insert: Vec<K>,
+}
+
+impl<G0, G1, K> EditInstance<G0, G1, K>
+where
+    G0: NodeCompactIndexable + IntoEdgeReferences + GraphProp,
+    G1: NodeCompactIndexable + IntoEdgeReferences + GraphProp,
+    K: Measure + Copy + Sub<Output = K>,
+{
+    fn new<NC>(g0: G0, g1: G1, node_cost: &mut NC) -> Self
+    where
+        NC: NodeCost<G0, G1, K>,
+    {
+        let (n0, n1) = (g0.node_count(), g1.node_count());
+        let node0 = |a| Some(g0.from_index(a));
+        let node1 = |b| Some(g1.from_index(b));
+        let substitute = (0..n0)
+            .map(|a| {
+                (0..n1)
+                    .map(|b| node_cost.cost(&g0, &g1, node0(a), node1(b)))
+                    .collect()
+            })
+            .collect();
+        let delete = (0..n0)
+            .map(|a| node_cost.cost(&g0, &g1, node0(a), None))
+            .collect();
+        let insert = (0..n1)
+            .map(|b| node_cost.cost(&g0, &g1, None, node1(b)))
+            .collect();
+        let adjacency0 = adjacency(g0);
+        let adjacency1 = adjacency(g1);
+        let (incoming0, incoming1) = if g0.is_directed() {
+            (reversed(&adjacency0), reversed(&adjacency1))
+        } else {
+            (Vec::new(), Vec::new())
+        };
+        EditInstance {
+            g0,
+            g1,
+            n0,
+            n1,
+            adjacency0,
+            adjacency1,
+            incoming0,
+            incoming1,
+            substitute,
+            delete,
+            insert,
+        }
+    }
+
+    /// The order to map the nodes of `g0` in: nodes of high degree first, as they constrain the
+    /// cost the most.
+    fn order(&self) -> Vec<usize> {
+        let mut order: Vec<usize> = (0..self.n0).collect();
+        order.sort_by_key(|&a| std::cmp::Reverse(self.adjacency0[a].len()));
+        order
+    }
+
+    /// The extensions of the partial mapping `assigned` of `order[..depth]`, of the given `cost`,
+    /// by the node `order[depth]`: each possible target with the cost of the extended mapping and a
+    /// lower bound of the cost of its completions.
+    fn extensions<EC>(
+        &self,
+        order: &[usize],
+        depth: usize,
+        cost: K,
+        assigned: &[Option<usize>],
+        used: &FixedBitSet,
+        edge_cost: &mut EC,
+    ) -> Vec<(Option<usize>, K, K)>
+    where
+        EC: EdgeCost<G0, G1, K>,
+    {
+        let a = order[depth];
+        let mapped = &order[..depth];
+        let remaining0 = &order[depth + 1..];
+        let mut targets: Vec<Option<usize>> =
+            (0..self.n1).filter(|&b| !used[b]).map(Some).collect();
+        targets.push(None);
+        let mut extensions = Vec::with_capacity(targets.len());
+        for &target in &targets {
+            let cost = cost + self.assignment_cost(a, target, mapped, assigned, edge_cost);
+            let remaining1: Vec<usize> = targets
+                .iter()
+                .filter_map(|&b| b)
+                .filter(|&b| Some(b) != target)
+                .collect();
+            let estimate = cost + self.node_cost_bound(remaining0, &remaining1);
+            extensions.push((target, cost, estimate));
+        }
+        extensions
+    }
+
+    /// The cost of edge `a0 → b0` of `g0` (if any) against the edge between the images `a1 → b1` in
+    /// `g1` (if any).
+    fn edge_pair_cost<EC>(
+        &self,
+        (a0, b0): (usize, usize),
+        images: (Option<usize>, Option<usize>),
+        edge_cost: &mut EC,
+    ) -> K
+    where
+        EC: EdgeCost<G0, G1, K>,
+    {
+        let e0 = find_edge(&self.adjacency0, a0, b0);
+        let e1 = match images {
+            (Some(a1), Some(b1)) => find_edge(&self.adjacency1, a1, b1),
+            _ => None,
+        };
+        match (e0, e1) {
+            (None, None) => K::default(),
+            (e0, e1) => edge_cost.cost(&self.g0, &self.g1, e0, e1),
+        }
+    }
+
+    /// The cost of assigning node `a` of `g0` to `target`, including the edges between `a` and the
+    /// already assigned nodes `mapped`.
+    fn assignment_cost<EC>(
+        &self,
+        a: usize,
+        target: Option<usize>,
+        mapped: &[usize],
+        assigned: &[Option<usize>],
+        edge_cost: &mut EC,
+    ) -> K
+    where
+        EC: EdgeCost<G0, G1, K>,
+    {
+        let mut cost = match target {
+            Some(b) => self.substitute[a][b],
+            None => self.delete[a],
+        };
+        cost = cost + self.edge_pair_cost((a, a), (target, target), edge_cost);
+        for &c in mapped {
+            cost = cost + self.edge_pair_cost((a, c), (target, assigned[c]), edge_cost);
+            if self.g0.is_directed() {
+                cost = cost + self.edge_pair_cost((c, a), (assigned[c], target), edge_cost);
+            }
+        }
+        cost
+    }
+
+    /// The cost of inserting the nodes of `g1` that are not `used`, and of their edges.
+    fn insertion_cost<EC>(&self, used: &FixedBitSet, edge_cost: &mut EC) -> K
+    where
+        EC: EdgeCost<G0, G1, K>,
+    {
+        let mut cost = K::default();
+        for b in (0..self.n1).filter(|&b| !used[b]) {
+            cost = cost + self.insert[b];
+        }
+        for edge in self.g1.edge_references() {
+            let (a, b) = (
+                self.g1.to_index(edge.source()),
+                self.g1.to_index(edge.target()),
+            );
+            if !used[a] || !used[b] {
+                cost = cost + edge_cost.cost(&self.g0, &self.g1, None, Some(edge));
+            }
+        }
+        cost
+    }
+
+    /// The total cost of a complete assignment of the nodes of `g0`.
+    fn total_cost<EC>(&self, assigned: &[Option<usize>], edge_cost: &mut EC) -> K
+    where
+        EC: EdgeCost<G0, G1, K>,
+    {
+        let mut used = FixedBitSet::with_capacity(self.n1);
+        let mut cost = K::default();
+        for a in 0..self.n0 {
+            let mapped: Vec<usize> = (0..a).collect();
+            cost = cost + self.assignment_cost(a, assigned[a], &mapped, assigned, edge_cost);
+            if let Some(b) = assigned[a] {
+                used.insert(b);
+            }
+        }
+        cost + self.insertion_cost(&used, edge_cost)
+    }
+
+    /// The edit path of a complete assignment of the nodes of `g0`.
+    fn edit_path(&self, assigned: &[Option<usize>]) -> EditPath<G0::NodeId, G1::NodeId> {
+        let mut used = FixedBitSet::with_capacity(self.n1);
+        let mut path = Vec::with_capacity(self.n0 + self.n1);
+        for (a, &target) in assigned.iter().enumerate() {
+            if let Some(b) = target {
+                used.insert(b);
+            }
+            path.push((
+                Some(self.g0.from_index(a)),
+                target.map(|b| self.g1.from_index(b)),
+            ));
+        }
+        for b in (0..self.n1).filter(|&b| !used[b]) {
+            path.push((None, Some(self.g1.from_index(b))));
+        }
+        path
+    }
+
+    /// The edit path given by an optimal assignment of the nodes, where the cost of substituting a
+    /// node includes an optimal assignment of its incident edges.
+    fn approximate<EC>(&self, edge_cost: &mut EC) -> (K, EditPath<G0::NodeId, G1::NodeId>)
+    where
+        EC: EdgeCost<G0, G1, K>,
+    {
+        let (n0, n1) = (self.n0, self.n1);
+        let mut stars = |list0: &[(usize, G0::EdgeRef)], list1: &[(usize, G1::EdgeRef)]| {
+            let edge0 = |i: usize| Some(list0[i].1);
+            let edge1 = |j: usize| Some(list1[j].1);
+            let mut substitute = vec![vec![K::default(); list1.len()]; list0.len()];
+            for (i, row) in substitute.iter_mut().enumerate() {
+                for (j, c) in row.iter_mut().enumerate() {
+                    *c = edge_cost.cost(&self.g0, &self.g1, edge0(i), edge1(j));
+                }
+            }
+            let delete: Vec<K> = (0..list0.len())
+                .map(|i| edge_cost.cost(&self.g0, &self.g1, edge0(i), None))
+                .collect();
+            let insert: Vec<K> = (0..list1.len())
+                .map(|j| edge_cost.cost(&self.g0, &self.g1, None, edge1(j)))
+                .collect();
+            let costs = edit_cost_matrix(
+                list0.len(),
+                list1.len(),
+                |i, j| substitute[i][j],
+                |i| delete[i],
+                |j| insert[j],
+            );
+            min_cost_sum(&costs)
+        };
+        let empty0 = Vec::new();
+        let empty1 = Vec::new();
+        let incoming0 = |a: usize| self.incoming0.get(a).unwrap_or(&empty0);
+        let incoming1 = |b: usize| self.incoming1.get(b).unwrap_or(&empty1);
+        let mut substitute = vec![vec![K::default(); n1]; n0];
+        for (a, row) in substitute.iter_mut().enumerate() {
+            for (b, c) in row.iter_mut().enumerate() {
+                *c = self.substitute[a][b]
+                    + stars(&self.adjacency0[a], &self.adjacency1[b])
+                    + stars(incoming0(a), incoming1(b));
+            }
+        }
+        let delete: Vec<K> = (0..n0)
+            .map(|a| self.delete[a] + stars(&self.adjacency0[a], &[]) + stars(incoming0(a), &[]))
+            .collect();
+        let insert: Vec<K> = (0..n1)
+            .map(|b| self.insert[b] + stars(&[], &self.adjacency1[b]) + stars(&[], incoming1(b)))
+            .collect();
+        let costs = edit_cost_matrix(
+            n0,
+            n1,
+            |a, b| substitute[a][b],
+            |a| delete[a],
+            |b| insert[b],
+        );
+        let assignment = min_cost_assignment(&costs);
+        let assigned: Vec<Option<usize>> = assignment[..n0]
+            .iter()
+            .map(|&b| if b < n1 { Some(b) } else { None })
+            .collect();
+        (
+            self.total_cost(&assigned, edge_cost),
+            self.edit_path(&assigned),
+        )
+    }
+
+    /// A lower bound of the node costs of mapping the nodes `remaining0` of `g0` to the nodes
+    /// `remaining1` of `g1`.
+    fn node_cost_bound(&self, remaining0: &[usize], remaining1: &[usize]) -> K {
+        let (r0, r1) = (remaining0.len(), remaining1.len());
+        if r0 == 0 || r1 == 0 {
+            let deletions = remaining0.iter().map(|&a| self.delete[a]);
+            let insertions = remaining1.iter().map(|&b| self.insert[b]);
+            return deletions
+                .chain(insertions)
+                .fold(K::default(), |sum, c| sum + c);
+        }
+        let costs = edit_cost_matrix(
+            r0,
+            r1,
+            |i, j| self.substitute[remaining0[i]][remaining1[j]],
+            |i| self.delete[remaining0[i]],
+            |j| self.insert[remaining1[j]],
+        );
+        min_cost_sum(&costs)
+    }
+}
+
+/// The square cost matrix of assigning `r0` items to `r1` items, where each item may also be
+/// deleted or inserted: substitutions in the top left, deletions in the top right and insertions in
+/// the bottom left.
+fn edit_cost_matrix<K, S, D, I>(
+    r0: usize,
+    r1: usize,
+    substitute: S,
+    delete: D,
+    insert: I,
+) -> Vec<Vec<K>>
+where
+    K: Measure + Copy,
+    S: Fn(usize, usize) -> K,
+    D: Fn(usize) -> K,
+    I: Fn(usize) -> K,
+{
+    let n = r0 + r1;
+    let mut costs = vec![vec![K::default(); n]; n];
+    for (i, row) in costs.iter_mut().enumerate() {
+        for (j, c) in row.iter_mut().enumerate() {
+            *c = match (i < r0, j < r1) {
+                (true, true) => substitute(i, j),
+                (true, false) => delete(i),
+                (false, true) => insert(j),
+                (false, false) => K::default(),
+            };
+        }
+    }
+    costs
+}
+
+/// The total cost of a minimum cost assignment.
+fn min_cost_sum<K>(costs: &[Vec<K>]) -> K
+where
+    K: Measure + Copy + Sub<Output = K>,
+{
+    min_cost_assignment(costs)
+        .iter()
+        .enumerate()
+        .fold(K::default(), |sum, (i, &j)| sum + costs[i][j])
+}
+
+/// A minimum cost assignment of the rows of a square matrix of non-negative costs to its columns,
+/// using the Hungarian algorithm in **O(n³)** time.
+fn min_cost_assignment<K>(costs: &[Vec<K>]) -> Vec<usize>
+where
+    K: Measure + Copy + Sub<Output = K>,
+{
+    let n = costs.len();
+    // Rows and columns are numbered from 1, column 0 holds the row being
+    // added. The reduced costs `costs[i][j] - row[i] + column[j]` stay
+    // non-negative, so no intermediate value is negative.
+    let mut row = vec![K::default(); n + 1];
+    let mut column = vec![K::default(); n + 1];
+    let mut owner = vec![0; n + 1];
+    let mut way = vec![0; n + 1];
+    for i in 1..=n {
+        owner[0] = i;
+        let mut j0 = 0;
+        let mut min_reduced: Vec<Option<K>> = vec![None; n + 1];
+        let mut used = vec![false; n + 1];
+        loop {
+            used[j0] = true;
+            let i0 = owner[j0];
+            let mut delta = None;
+            let mut j1 = 0;
+            for j in (1..=n).filter(|&j| !used[j]) {
+                let reduced = costs[i0 - 1][j - 1] + column[j] - row[i0];
+                if min_reduced[j].map_or(true, |m| reduced < m) {
+                    min_reduced[j] = Some(reduced);
+                    way[j] = j0;
+                }
+                if delta.map_or(true, |d| min_reduced[j] < Some(d)) {
+                    delta = min_reduced[j];
+                    j1 = j;
+                }
+            }
+            let delta = delta.unwrap();
+            for j in 0..=n {
+                if used[j] {
+                    row[owner[j]] = row[owner[j]] + delta;
+                    column[j] = column[j] + delta;
+                } else {
+                    min_reduced[j] = min_reduced[j].map(|m| m - delta);
+                }
+            }
+            j0 = j1;
+            if owner[j0] == 0 {
+                break;
+            }
+        }
+        // Flip the augmenting path.
+        while j0 != 0 {
+            let j1 = way[j0];
+            owner[j0] = owner[j1];
+            j0 = j1;
+        }
+    }
+    let mut assignment = vec![0; n];
+    for j in 1..=n {
+        assignment[owner[j] - 1] = j - 1;
+    }
+    assignment
+}
@@ -1,12 +1,14 @@
 use std::{
-    collections::{HashMap, VecDeque},
+    collections::{HashMap, HashSet, VecDeque},
     ops::{Index, IndexMut},
 };
 
 use crate::{
+    algo::{tarjan_scc, Measure},
     graph::{GraphIndex, NodeIndex},
-    visit::{EdgeRef, GraphProp, IntoEdgeReferences},
-    Directed,
+    unionfind::UnionFind,
+    visit::{EdgeRef, GraphProp, IntoEdgeReferences, NodeIndexable},
+    Directed, Graph,
 };
 
 use self::linked_list::{LinkedList, LinkedListEntry};
@@ -76,6 +78,379 @@ where
         .filter(move |e| node_seq[&e.source().index()] >= node_seq[&e.target().index()])
 }
 
+/// \[Generic\] Finds a minimum weight [feedback arc set]: a set of edges in the given directed
+/// graph of least total weight, which when removed, make the graph acyclic.
+///
+/// The function `edge_weight` should return the weight of a particular edge; weights must be
+/// non-negative. Use `|_| 1` for a feedback arc set with the fewest edges.
+///
+/// The graph is split into its strongly connected components, which are solved separately by
+/// iterative cycle covering: an exact minimum hitting set of a growing collection of cycles is
+/// found by branch and bound, and the shortest cycles that survive its removal are added to the
+/// collection, until none are left. The problem is NP-hard, so this is only practical for small
+/// to medium graphs, or graphs whose strongly connected components are small.
+///
+/// Loops (edges to and from the same node) are always included in the returned set.
+///
+/// **Reference**
+///
+/// * Ali Baharev, Hermann Schichl, Arnold Neumaier, Tobias Achterberg; *An exact method for the
+///   minimum feedback arc set problem*
+///
+/// # Example
+///
+/// ```
+/// use petgraph::algo::{is_cyclic_directed, minimum_feedback_arc_set};
+/// use petgraph::prelude::*;
+///
+/// let mut g = DiGraph::<(), u32>::from_edges(&[
+///     (0, 1, 1),
+///     (1, 2, 1),
+///     (2, 0, 5),
+///     (2, 3, 1),
+///     (3, 1, 2),
+/// ]);
+///
+/// // Removing the edge 1 → 2 breaks both cycles.
+/// let fas: Vec<EdgeIndex> = minimum_feedback_arc_set(&g, |e| *e.weight())
+///     .iter()
+///     .map(|e| e.id())
+///     .collect();
+/// assert_eq!(fas, [g.find_edge(1.into(), 2.into()).unwrap()]);
+///
+/// g.remove_edge(fas[0]);
+/// assert!(!is_cyclic_directed(&g));
+/// ```
+///
+/// [feedback arc set]: https://en.wikipedia.org/wiki/Feedback_arc_set
+pub fn minimum_feedback_arc_set<G, F, K>(g: G, mut edge_weight: F) -> Vec<G::EdgeRef>
+where
+    G: IntoEdgeReferences + NodeIndexable + GraphProp<EdgeType = Directed>,
+    F: FnMut(G::EdgeRef) -> K,
+    K: Measure + Copy,
+{
+    let edges: Vec<G::EdgeRef> = g.edge_references().collect();
+    let endpoints: Vec<(usize, usize)> = edges
+        .iter()
+        .map(|e| (g.to_index(e.source()), g.to_index(e.target())))
+        .collect();
+    let mut in_feedback = vec![false; edges.len()];
+    for component in cyclic_components(g.node_bound(), &endpoints, true) {
+        let finder = CycleFinder::new(&component, &endpoints, true);
+        let weights: Vec<K> = component.iter().map(|&e| edge_weight(edges[e])).collect();
+        let no_nodes_removed = vec![false; finder.node_count()];
+        let mut cycles = Vec::new();
+        let mut removed = vec![false; component.len()];
+        loop {
+            let new_cycles = finder.shortest_cycles(&removed, &no_nodes_removed);
+            if new_cycles.is_empty() {
+                break;
+            }
+            cycles.extend(new_cycles);
+            removed = vec![false; component.len()];
+            for e in minimum_hitting_set(&weights, &cycles) {
+                removed[e] = true;
+            }
+        }
+        for (&e, removed) in component.iter().zip(removed) {
+            in_feedback[e] = removed;
+        }
+    }
+    edges
+        .into_iter()
+        .zip(in_feedback)
+        .filter(|&(_, in_feedback)| in_feedback)
+        .map(|(e, _)| e)
+        .collect()
+}
+
+/// Group the edges, given by the indices of their endpoints, by the component they lie in,
+/// skipping edges between components. The components are the strongly connected components of
+/// a directed graph, and the connected components of an undirected one.
+pub(crate) fn cyclic_components(
+    node_bound: usize,
+    endpoints: &[(usize, usize)],
+    directed: bool,
+) -> Vec<Vec<usize>> {
+    let mut component = vec![0; node_bound];
+    let component_count = if directed {
+        let mut graph = Graph::<(), ()>::with_capacity(node_bound, endpoints.len());
+        for _ in 0..node_bound {
+            graph.add_node(());
+        }
+        for &(a, b) in endpoints {
+            graph.add_edge(NodeIndex::new(a), NodeIndex::new(b), ());
+        }
+        let sccs = tarjan_scc(&graph);
+        for (i, scc) in sccs.iter().enumerate() {
+            for n in scc {
+                component[n.index()] = i;
+            }
+        }
+        sccs.len()
+    } else {
+        let mut sets = UnionFind::new(node_bound);
+        for &(a, b) in endpoints {
+            sets.union(a, b);
+        }
+        for (n, c) in sets.into_labeling().into_iter().enumerate() {
+            component[n] = c;
+        }
+        node_bound
+    };
+    let mut components = vec![Vec::new(); component_count];
+    for (e, &(a, b)) in endpoints.iter().enumerate() {
+        if component[a] == component[b] {
+            components[component[a]].push(e);
+        }
+    }
+    components.retain(|c| !c.is_empty());
+    components
+}
+
+/// Adjacency lists of the edges of a component, renumbered from zero, for finding short cycles.
+pub(crate) struct CycleFinder {
+    /// The original node index of each node.
+    nodes: Vec<usize>,
+    /// The endpoints of each edge.
+    endpoints: Vec<(usize, usize)>,
+    /// The outgoing (or, if undirected, incident) edges of each node, with the other endpoint.
+    adjacency: Vec<Vec<(usize, usize)>>,
+}
+
+impl CycleFinder {
+    /// Create a finder for the edges `component` of a graph with edges `endpoints`.
+    pub(crate) fn new(component: &[usize], endpoints: &[(usize, usize)], directed: bool) -> Self {
+        let mut position = HashMap::new();
+        let mut nodes = Vec::new();
+        let mut number = |n: usize| {
+            *position.entry(n).or_insert_with(|| {
+                nodes.push(n);
+                nodes.len() - 1
+            })
+        };
+        let local: Vec<(usize, usize)> = component
+            .iter()
+            .map(|&e| (number(endpoints[e].0), number(endpoints[e].1)))
+            .collect();
+        let mut adjacency = vec![Vec::new(); nodes.len()];
+        for (e, &(a, b)) in local.iter().enumerate() {
+            adjacency[a].push((e, b));
+            if !directed && a != b {
+                adjacency[b].push((e, a));
+            }
+        }
+        CycleFinder {
+            nodes,
+            endpoints: local,
+            adjacency,
+        }
+    }
+
+    pub(crate) fn node_count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// The original node index of a node of the component.
+    pub(crate) fn node(&self, n: usize) -> usize {
+        self.nodes[n]
+    }
+
+    pub(crate) fn endpoints(&self, e: usize) -> (usize, usize) {
+        self.endpoints[e]
+    }
+
+    /// For every edge that is not removed and has no removed endpoint, a shortest cycle through
+    /// it, as the sorted list of its edges. Duplicate cycles are only listed once.
+    pub(crate) fn shortest_cycles(
+        &self,
+        edge_removed: &[bool],
+        node_removed: &[bool],
+    ) -> Vec<Vec<usize>> {
+        let n = self.nodes.len();
+        let mut cycles = Vec::new();
+        let mut seen = HashSet::new();
+        let mut predecessor: Vec<Option<usize>> = vec![None; n];
+        let mut queue = VecDeque::new();
+        for (e, &(a, b)) in self.endpoints.iter().enumerate() {
+            if edge_removed[e] || node_removed[a] || node_removed[b] {
+                continue;
+            }
+            // A shortest path from `b` back to `a` that does not use `e`.
+            for p in predecessor.iter_mut() {
+                *p = None;
+            }
+            predecessor[b] = Some(e);
+            queue.clear();
+            queue.push_back(b);
+            while let Some(x) = queue.pop_front() {
+                if x == a {
+                    break;
+                }
+                for &(f, y) in &self.adjacency[x] {
+                    if f != e && !edge_removed[f] && !node_removed[y] && predecessor[y].is_none() {
+                        predecessor[y] = Some(f);
+                        queue.push_back(y);
+                    }
+                }
+            }
+            if predecessor[a].is_none() {
+                continue;
+            }
+            let mut cycle = vec![e];
+            let mut x = a;
+            while x != b {
+                let f = predecessor[x].unwrap();
+                cycle.push(f);
+                let (s, t) = self.endpoints[f];
+                x = if t == x { s } else { t };
+            }
+            cycle.sort_unstable();
+            if seen.insert(cycle.clone()) {
+                cycles.push(cycle);
+            }
+        }
+        cycles
+    }
+}
+
+/// Find a minimum weight set of elements that contains at least one element of each set, by
+/// branch and bound. Elements are numbered `0..weights.len()` and weights must be non-negative.
+pub(crate) fn minimum_hitting_set<K>(weights: &[K], sets: &[Vec<usize>]) -> Vec<usize>
+where
+    K: Measure + Copy,
+{
+    let mut elements_of = vec![Vec::new(); weights.len()];
+    for (i, set) in sets.iter().enumerate() {
+        for &x in set {
+            elements_of[x].push(i);
+        }
+    }
+    // Smaller sets give better lower bounds when packed first.
+    let mut by_size: Vec<usize> = (0..sets.len()).collect();
+    by_size.sort_by_key(|&i| sets[i].len());
+    let mut search = HittingSetSearch {
+        weights,
+        sets,
+        sets_of: elements_of,
+        by_size,
+        hits: vec![0; sets.len()],
+        forbidden: vec![false; weights.len()],
+        packed: vec![false; weights.len()],
+        chosen: Vec::new(),
+        best: None,
+    };
+    search.search(K::default());
+    search.best.map(|(_, best)| best).unwrap_or_default()
+}
+
+struct HittingSetSearch<'a, K> {
+    weights: &'a [K],
+    sets: &'a [Vec<usize>],
+    /// The sets that contain each element.
+    sets_of: Vec<Vec<usize>>,
+    by_size: Vec<usize>,
+    /// The number of chosen elements in each set.
+    hits: Vec<usize>,
+    /// Elements excluded in the current branch.
+    forbidden: Vec<bool>,
+    packed: Vec<bool>,
+    chosen: Vec<usize>,
+    best: Option<(K, Vec<usize>)>,
+}
+
+impl<'a, K> HittingSetSearch<'a, K>
+where
+    K: Measure + Copy,
+{
+    fn search(&mut self, cost: K) {
+        // Branch on the set that is not hit yet with the fewest allowed elements.
+        let mut branch: Option<(usize, usize)> = None;
+        for (i, set) in self.sets.iter().enumerate() {
+            if self.hits[i] > 0 {
+                continue;
+            }
+            let allowed = set.iter().filter(|&&x| !self.forbidden[x]).count();
+            if allowed == 0 {
+                return;
+            }
+            if branch.map_or(true, |(_, size)| allowed < size) {
+                branch = Some((i, allowed));
+            }
+        }
+        let set = match branch {
+            Some((i, _)) => i,
+            None => {
+                if self.best.as_ref().map_or(true, |&(best, _)| cost < best) {
+                    self.best = Some((cost, self.chosen.clone()));
+                }
+                return;
+            }
+        };
+        if let Some(&(best, _)) = self.best.as_ref() {
+            if cost + self.lower_bound() >= best {
+                return;
+            }
+        }
+
+        // Try the elements that hit the most remaining sets first.
+        let mut candidates: Vec<usize> = self.sets[set]
+            .iter()
+            .cloned()
+            .filter(|&x| !self.forbidden[x])
+            .collect();
+        candidates.sort_by_key(|&x| {
+            let unhit = self.sets_of[x].iter().filter(|&&s| self.hits[s] == 0);
+            std::cmp::Reverse(unhit.count())
+        });
+        for &x in &candidates {
+            for &s in &self.sets_of[x] {
+                self.hits[s] += 1;
+            }
+            self.chosen.push(x);
+            self.search(cost + self.weights[x]);
+            self.chosen.pop();
+            for &s in &self.sets_of[x] {
+                self.hits[s] -= 1;
+            }
+            // Later branches do without `x`.
+            self.forbidden[x] = true;
+        }
+        for &x in &candidates {
+            self.forbidden[x] = false;
+        }
+    }
+
+    /// A lower bound of the weight needed to hit the remaining sets: the sum of the cheapest
+    /// allowed elements of disjoint remaining sets.
+    fn lower_bound(&mut self) -> K {
+        let mut bound = K::default();
+        for &i in &self.by_size {
+            let set = &self.sets[i];
+            if self.hits[i] > 0 || set.iter().any(|&x| !self.forbidden[x] && self.packed[x]) {
+                continue;
+            }
+            let mut cheapest: Option<K> = None;
+            for &x in set {
+                if self.forbidden[x] {
+                    continue;
+                }
+                self.packed[x] = true;
+                if cheapest.map_or(true, |c| self.weights[x] < c) {
+                    cheapest = Some(self.weights[x]);
+                }
+            }
+            if let Some(c) = cheapest {
+                bound = bound + c;
+            }
+        }
+        for p in &mut self.packed {
+            *p = false;
+        }
+        bound
+    }
+}
+
 fn good_node_sequence(
     edge_refs: impl Iterator<Item = (NodeIndex<usize>, NodeIndex<usize>)>,
 ) -> HashMap<usize, usize> {
@@ -0,0 +1,342 @@
+//! Feedback vertex sets: sets of nodes whose removal leaves a graph without cycles.
+//!
+//! Finding a feedback vertex set of minimum weight is NP-hard, in directed and undirected graphs
+//! alike. [`minimum_feedback_vertex_set`] solves it exactly by iterative cycle covering: it
+//! collects shortest cycles of the graph, finds a minimum weight set of nodes hitting all of them
+//! by branch and bound, and repeats with the cycles that survive the removal of that set, until
+//! there are none. Each strongly connected component, or connected component of an undirected
+//! graph, is solved separately. The running time is exponential in the worst case, so it is only
+//! practical for small to medium graphs.
+//!
+//! For undirected graphs, [`approximate_feedback_vertex_set`] finds a set of at most twice the
+//! minimum weight in **O(|V|² + |V||E|)** time, with the local ratio algorithm of Bafna, Berman
+//! and Fujito.
+//!
+//! **Reference**
+//!
+//! * Vineet Bafna, Piotr Berman, Toshihiro Fujito; *A 2-approximation algorithm for the undirected
+//!   feedback vertex set problem*
+//!
+//! [`minimum_feedback_vertex_set`]: fn.minimum_feedback_vertex_set.html
+//! [`approximate_feedback_vertex_set`]: fn.approximate_feedback_vertex_set.html
+
+use super::feedback_arc_set::{cyclic_components, minimum_hitting_set, CycleFinder};
+use super::Measure;
+use crate::unionfind::UnionFind;
+use crate::visit::{EdgeRef, GraphProp, IntoEdgeReferences, IntoNodeIdentifiers, NodeIndexable};
+use crate::Undirected;
+
+/// \[Generic\] Finds a minimum weight [feedback vertex set]: a set of nodes in the given graph of
+/// least total weight, which when removed, make the graph acyclic.
+///
+/// The function `node_weight` should return the weight of a particular node; weights must be
+/// non-negative. Use `|_| 1` for a feedback vertex set with the fewest nodes.
+///
+/// Directed graphs are split into their strongly connected components and undirected graphs
+/// into their connected components, which are solved separately by iterative cycle covering, as
+/// in [`minimum_feedback_arc_set`](fn.minimum_feedback_arc_set.html). The problem is NP-hard, so
+/// this is only practical for small to medium graphs.
+///
+/// Nodes with a loop are always included in the returned set. In undirected graphs, two parallel
+/// edges form a cycle.
+///
+/// Returns the nodes of the feedback vertex set, ordered by their index.
+///
+/// # Example
+///
+/// ```
+/// use petgraph::algo::minimum_feedback_vertex_set;
+/// use petgraph::prelude::*;
+///
+/// // Two triangles sharing node 2.
+/// let g = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (4, 2)]);
+/// assert_eq!(minimum_feedback_vertex_set(&g, |_| 1), [NodeIndex::new(2)]);
+///
+/// // Unless node 2 is too expensive.
+/// let fvs = minimum_feedback_vertex_set(&g, |n| if n.index() == 2 { 3 } else { 1 });
+/// assert_eq!(fvs.len(), 2);
+/// ```
+///
+/// [feedback vertex set]: https://en.wikipedia.org/wiki/Feedback_vertex_set
+pub fn minimum_feedback_vertex_set<G, F, K>(g: G, mut node_weight: F) -> Vec<G::NodeId>
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable + GraphProp,
+    F: FnMut(G::NodeId) -> K,
+    K: Measure + Copy,
+{
+    let endpoints: Vec<(usize, usize)> = g
+        .edge_references()
+        .map(|e| (g.to_index(e.source()), g.to_index(e.target())))
+        .collect();
+    let mut in_feedback = vec![false; g.node_bound()];
+    for component in cyclic_components(g.node_bound(), &endpoints, g.is_directed()) {
+        let finder = CycleFinder::new(&component, &endpoints, g.is_directed());
+        let weights: Vec<K> = (0..finder.node_count())
+            .map(|n| node_weight(g.from_index(finder.node(n))))
+            .collect();
+        let no_edges_removed = vec![false; component.len()];
+        let mut cycles = Vec::new();
+        let mut removed = vec![false; finder.node_count()];
+        loop {
+            let new_cycles = finder.shortest_cycles(&no_edges_removed, &removed);
+            if new_cycles.is_empty() {
+                break;
+            }
+            cycles.extend(new_cycles.into_iter().map(|cycle| {
+                let mut nodes: Vec<usize> = cycle
+                    .into_iter()
+                    .flat_map(|e| {
+                        let (a, b) = finder.endpoints(e);
+                        vec![a, b]
+                    })
+                    .collect();
+                nodes.sort_unstable();
+                nodes.dedup();
+                nodes
+            }));
+            removed = vec![false; finder.node_count()];
+            for n in minimum_hitting_set(&weights, &cycles) {
+                removed[n] = true;
+            }
+        }
+        for (n, removed) in removed.into_iter().enumerate() {
+            if removed {
+                in_feedback[finder.node(n)] = true;
+            }
+        }
+    }
+    g.node_identifiers()
+        .filter(|&n| in_feedback[g.to_index(n)])
+        .collect()
+}
+
+/// \[Generic\] Finds a [feedback vertex set] of an undirected graph whose weight is at most twice
+/// the minimum.
+///
+/// The function `node_weight` should return the weight of a particular node; weights must be
+/// non-negative.
+///
+/// Uses the local ratio algorithm of Bafna, Berman and Fujito: after pruning nodes of degree at
+/// most one, the weights are reduced along a cycle whose nodes all have degree two except for at
+/// most one, or in proportion to the degrees if there is none, and nodes whose weight reaches
+/// zero join the set. Redundant nodes are removed from the set in reverse order at the end.
+/// Time complexity is **O(|V|² + |V||E|)**.
+///
+/// Nodes with a loop are always included in the returned set, and two parallel edges form a
+/// cycle.
+///
+/// Returns the nodes of the feedback vertex set, ordered by their index.
+///
+/// **Reference**
+///
+/// * Vineet Bafna, Piotr Berman, Toshihiro Fujito; *A 2-approximation algorithm for the
+///   undirected feedback vertex set problem*
+///
+/// # Example
+///
+/// ```
+/// use petgraph::algo::approximate_feedback_vertex_set;
+/// use petgraph::prelude::*;
+///
+/// // A wheel: a cycle of five nodes around a hub.
+/// let g = UnGraph::<(), ()>::from_edges(&[
+///     (0, 1), (1, 2), (2, 3), (3, 4), (4, 0),
+///     (5, 0), (5, 1), (5, 2), (5, 3), (5, 4),
+/// ]);
+/// let fvs = approximate_feedback_vertex_set(&g, |_| 1.);
+/// assert!(fvs.len() <= 4);
+/// ```
+///
+/// [feedback vertex set]: https://en.wikipedia.org/wiki/Feedback_vertex_set
+pub fn approximate_feedback_vertex_set<G, F>(g: G, mut node_weight: F) -> Vec<G::NodeId>
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable + GraphProp<EdgeType = Undirected>,
+    F: FnMut(G::NodeId) -> f64,
+{
+    let n = g.node_bound();
+    let endpoints: Vec<(usize, usize)> = g
+        .edge_references()
+        .map(|e| (g.to_index(e.source()), g.to_index(e.target())))
+        .collect();
+    let mut weight = vec![0.; n];
+    for v in g.node_identifiers() {
+        weight[g.to_index(v)] = node_weight(v);
+    }
+
+    let mut reduction = LocalRatio::new(n, &endpoints);
+    // Nodes with a loop are needed in any feedback vertex set.
+    for &(a, b) in &endpoints {
+        if a == b && reduction.alive[a] {
+            reduction.remove(a);
+            reduction.removed.push(a);
+        }
+    }
+    reduction.prune();
+    loop {
+        let alive: Vec<usize> = (0..n).filter(|&v| reduction.alive[v]).collect();
+        if alive.is_empty() {
+            break;
+        }
+        match reduction.semidisjoint_cycle(&alive) {
+            Some(cycle) => {
+                let (argmin, gamma) = min_by_weight(cycle.iter().map(|&v| (v, weight[v])));
+                for &v in &cycle {
+                    weight[v] -= gamma;
+                }
+                weight[argmin] = 0.;
+            }
+            None => {
+                let excess = |v: usize| (reduction.degree[v] - 1) as f64;
+                let (argmin, gamma) =
+                    min_by_weight(alive.iter().map(|&v| (v, weight[v] / excess(v))));
+                for &v in &alive {
+                    weight[v] -= gamma * excess(v);
+                }
+                weight[argmin] = 0.;
+            }
+        }
+        for &v in &alive {
+            if weight[v] <= 0. {
+                reduction.remove(v);
+                reduction.removed.push(v);
+            }
+        }
+        reduction.prune();
+    }
+
+    // Drop the nodes that are not needed, last added first.
+    let mut in_feedback = vec![false; n];
+    for &v in &reduction.removed {
+        in_feedback[v] = true;
+    }
+    for &v in reduction.removed.iter().rev() {
+        in_feedback[v] = false;
+        if !is_forest(n, &endpoints, &in_feedback) {
+            in_feedback[v] = true;
+        }
+    }
+    g.node_identifiers()
+        .filter(|&v| in_feedback[g.to_index(v)])
+        .collect()
+}
+
+/// The node with the least weight, and its weight.
+fn min_by_weight(nodes: impl Iterator<Item = (usize, f64)>) -> (usize, f64) {
+    nodes
+        .fold(None, |min: Option<(usize, f64)>, (v, w)| match min {
+            Some((_, m)) if m <= w => min,
+            _ => Some((v, w)),
+        })
+        .unwrap()
+}
+
+/// Whether the edges that avoid the nodes in `removed` form a forest.
+fn is_forest(n: usize, endpoints: &[(usize, usize)], removed: &[bool]) -> bool {
+    let mut trees = UnionFind::new(n);
+    endpoints
+        .iter()
+        .filter(|&&(a, b)| !removed[a] && !removed[b])
+        .all(|&(a, b)| trees.union(a, b))
+}
+
+/// The remaining multigraph of the local ratio algorithm.
+struct LocalRatio {
+    /// The neighbors of each node, with multiplicity, in the original graph.
+    adjacency: Vec<Vec<usize>>,
+    alive: Vec<bool>,
+    /// The number of edges to nodes that are alive.
+    degree: Vec<usize>,
+    /// Nodes removed because their weight ran out, in order.
+    removed: Vec<usize>,
+}
+
+impl LocalRatio {
+    fn new(n: usize, endpoints: &[(usize, usize)]) -> Self {
+        let mut adjacency = vec![Vec::new(); n];
+        for &(a, b) in endpoints {
+            if a != b {
+                adjacency[a].push(b);
+                adjacency[b].push(a);
+            }
+        }
+        let degree = adjacency.iter().map(|a| a.len()).collect();
+        LocalRatio {
+            adjacency,
+            alive: vec![true; n],
+            degree,
+            removed: Vec::new(),
+        }
+    }
+
+    fn remove(&mut self, v: usize) {
+        self.alive[v] = false;
+        for &w in &self.adjacency[v] {
+            self.degree[w] -= 1;
+        }
+    }
+
+    /// Remove nodes of degree at most one, which lie on no cycle, until there are none left.
+    fn prune(&mut self) {
+        let mut stack: Vec<usize> = (0..self.alive.len())
+            .filter(|&v| self.alive[v] && self.degree[v] <= 1)
+            .collect();
+        while let Some(v) = stack.pop() {
+            if !self.alive[v] {
+                continue;
+            }
+            self.remove(v);
+            for i in 0..self.adjacency[v].len() {
+                let w = self.adjacency[v][i];
+                if self.alive[w] && self.degree[w] == 1 {
+                    stack.push(w);
+                }
+            }
+        }
+    }
+
+    /// The two neighbors of a node of degree two.
+    fn pair(&self, v: usize) -> (usize, usize) {
+        let mut neighbors = self.adjacency[v].iter().filter(|&&w| self.alive[w]);
+        (*neighbors.next().unwrap(), *neighbors.next().unwrap())
+    }
+
+    /// Follow a path of nodes of degree two from `start` through `first`, collecting its inner
+    /// nodes, and return the node it ends at: either `start` or a node of higher degree.
+    fn follow(&self, start: usize, first: usize, path: &mut Vec<usize>) -> usize {
+        let (mut previous, mut current) = (start, first);
+        while current != start && self.degree[current] == 2 {
+            path.push(current);
+            let (a, b) = self.pair(current);
+            let next = if a == previous { b } else { a };
+            previous = current;
+            current = next;
+        }
+        current
+    }
+
+    /// A cycle in which all nodes but at most one have degree two, if there is one. Assumes that
+    /// all nodes that are alive have degree at least two.
+    fn semidisjoint_cycle(&self, alive: &[usize]) -> Option<Vec<usize>> {
+        let mut seen = vec![false; self.alive.len()];
+        for &v in alive {
+            if self.degree[v] != 2 || seen[v] {
+                continue;
+            }
+            let (a, b) = self.pair(v);
+            let mut cycle = vec![v];
+            let end = self.follow(v, a, &mut cycle);
+            if end == v {
+                return Some(cycle);
+            }
+            let other_end = self.follow(v, b, &mut cycle);
+            if end == other_end {
+                cycle.push(end);
+                return Some(cycle);
+            }
+            for &w in &cycle {
+                seen[w] = true;
+            }
+        }
+        None
+    }
+}
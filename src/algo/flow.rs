@@ -0,0 +1,150 @@
+//! A flow network with Dinic's maximum flow algorithm, shared by the cut and connectivity
+//! algorithms.
+
+use std::collections::VecDeque;
+use std::ops::Sub;
+
+use crate::algo::Measure;
+
+/// A flow network on the nodes `0..n`.
+///
+/// Arcs are stored in pairs: arc `a` and arc `a ^ 1` are each other's reverse, so that pushing flow
+/// along one frees residual capacity on the other.
+#[derive(Clone, Debug)]
+pub(crate) struct FlowNetwork<K> {
+    adjacency: Vec<Vec<usize>>,
+    target: Vec<usize>,
+    capacity: Vec<K>,
+    residual: Vec<K>,
+}
+
+fn min<K: PartialOrd>(a: K, b: K) -> K {
+    if b < a {
+        b
+    } else {
+        a
+    }
+}
+
+impl<K> FlowNetwork<K>
+where
+    K: Measure + Copy + Sub<K, Output = K>,
+{
+    pub(crate) fn new(node_count: usize) -> Self {
+        FlowNetwork {
+            adjacency: vec![Vec::new(); node_count],
+            target: Vec::new(),
+            capacity: Vec::new(),
+            residual: Vec::new(),
+        }
+    }
+
+    pub(crate) fn node_count(&self) -> usize {
+        self.adjacency.len()
+    }
+
+    /// Add an arc from `a` to `b` with capacity `forward`, and its reverse arc with capacity
+    /// `backward`. Use the same capacity in both directions for an undirected edge, and zero for a
+    /// directed one.
+    pub(crate) fn add_edge(&mut self, a: usize, b: usize, forward: K, backward: K) {
+        self.adjacency[a].push(self.target.len());
+        self.target.push(b);
+        self.capacity.push(forward);
+        self.adjacency[b].push(self.target.len());
+        self.target.push(a);
+        self.capacity.push(backward);
+    }
+
+    /// Compute a maximum flow from `source` to `sink`, discarding any previously computed flow, and
+    /// return its value.
+    pub(crate) fn max_flow(&mut self, source: usize, sink: usize) -> K {
+        self.residual.clone_from(&self.capacity);
+        let mut total = K::default();
+        if source == sink {
+            return total;
+        }
+        let n = self.node_count();
+        let mut level = vec![0; n];
+        let mut next_arc = vec![0; n];
+        while self.levels(source, sink, &mut level) {
+            next_arc.iter_mut().for_each(|i| *i = 0);
+            loop {
+                let pushed = self.augment(source, sink, None, &level, &mut next_arc);
+                if pushed > K::default() {
+                    total = total + pushed;
+                } else {
+                    break;
+                }
+            }
+        }
+        total
+    }
+
+    /// Breadth-first search in the residual network, storing the distance from `source` plus one in
+    /// `level` (zero if unreachable). Return `true` if `sink` is reachable.
+    fn levels(&self, source: usize, sink: usize, level: &mut [usize]) -> bool {
+        level.iter_mut().for_each(|l| *l = 0);
+        level[source] = 1;
+        let mut queue = VecDeque::new();
+        queue.push_back(source);
+        while let Some(a) = queue.pop_front() {
+            for &arc in &self.adjacency[a] {
+                let b = self.target[arc];
+                if level[b] == 0 && self.residual[arc] > K::default() {
+                    level[b] = level[a] + 1;
+                    queue.push_back(b);
+                }
+            }
+        }
+        level[sink] != 0
+    }
+
+    /// Find an augmenting path in the level graph and push as much flow along it as possible, at
+    /// most `limit` if given.
+    fn augment(
+        &mut self,
+        a: usize,
+        sink: usize,
+        limit: Option<K>,
+        level: &[usize],
+        next_arc: &mut [usize],
+    ) -> K {
+        if a == sink {
+            // The source is never the sink, so some arc set the limit.
+            return limit.unwrap_or_default();
+        }
+        while let Some(&arc) = self.adjacency[a].get(next_arc[a]) {
+            let b = self.target[arc];
+            let residual = self.residual[arc];
+            if level[b] == level[a] + 1 && residual > K::default() {
+                let limit = Some(limit.map_or(residual, |l| min(l, residual)));
+                let pushed = self.augment(b, sink, limit, level, next_arc);
+                if pushed > K::default() {
+                    self.residual[arc] = self.residual[arc] - pushed;
+                    self.residual[arc ^ 1] = self.residual[arc ^ 1] + pushed;
+                    return pushed;
+                }
+            }
+            next_arc[a] += 1;
+        }
+        K::default()
+    }
+
+    /// Return which nodes are reachable from `source` in the residual network of the last computed
+    /// flow: the source side of a minimum cut.
+    pub(crate) fn source_side(&self, source: usize) -> Vec<bool> {
+        let mut reachable = vec![false; self.node_count()];
+        reachable[source] = true;
+        let mut stack = vec![source];
+        while let Some(a) = stack.pop() {
+            for &arc in &self.adjacency[a] {
+                let b = self.target[arc];
+                if !reachable[b] && self.residual[arc] > K::default() {
+                    reachable[b] = true;
+                    stack.push(b);
+                }
+            }
+        }
+        reachable
+    }
+}
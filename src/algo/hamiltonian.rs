@@ -0,0 +1,660 @@
+//! Hamiltonian paths and cycles.
+//!
+//! A *Hamiltonian path* visits every node of the graph exactly once, following its edges; a
+//! *Hamiltonian cycle* also has an edge from its last node back to its first. In a directed graph
+//! they follow the direction of the edges. A single node makes a cycle by itself, and two nodes
+//! with edges both ways make a cycle of two, matching the tours of the
+//! [`tsp`](../tsp/index.html) module.
+//!
+//! [`hamiltonian_path`] and [`hamiltonian_cycle`] search by backtracking, pruning partial paths
+//! that leave some node without enough free neighbors or that cut the remaining nodes off from the
+//! end of the path, or for cycles, from the start. Their `_cancellable` variants can be stopped
+//! early. The `_bitmask` variants use dynamic programming over subsets of the nodes instead, in
+//! time exponential in the number of nodes but independent of the structure of the graph.
+//!
+//! [`hamiltonian_path`]: fn.hamiltonian_path.html
+//! [`hamiltonian_cycle`]: fn.hamiltonian_cycle.html
+
+use super::isomorphism::MatchResult;
+use crate::visit::{IntoNeighbors, IntoNodeIdentifiers, NodeIndexable};
+
+/// \[Generic\] Find a Hamiltonian path of the graph: an order of its nodes where each node has an
+/// edge to the next.
+///
+/// Returns the nodes in order, or `None` if there is no such path.
+///
+/// Searches by backtracking, which takes exponential time in the worst case, but usually decides
+/// sparse graphs of dozens of nodes quickly. See the [module documentation](index.html) for
+/// details.
+///
+/// # Example
+///
+/// ```
+/// use petgraph::algo::{hamiltonian_cycle, hamiltonian_path};
+/// use petgraph::prelude::*;
+///
+/// // The Petersen graph has a Hamiltonian path, but no Hamiltonian cycle.
+/// let petersen = UnGraph::<(), ()>::from_edges(&[
+///     (0, 1), (1, 2), (2, 3), (3, 4), (4, 0),
+///     (0, 5), (1, 6), (2, 7), (3, 8), (4, 9),
+///     (5, 7), (7, 9), (9, 6), (6, 8), (8, 5),
+/// ]);
+/// let path = hamiltonian_path(&petersen).unwrap();
+/// assert_eq!(path.len(), 10);
+/// for pair in path.windows(2) {
+///     assert!(petersen.contains_edge(pair[0], pair[1]));
+/// }
+/// assert_eq!(hamiltonian_cycle(&petersen), None);
+/// ```
+pub fn hamiltonian_path<G>(graph: G) -> Option<Vec<G::NodeId>>
+where
+    G: IntoNeighbors + IntoNodeIdentifiers + NodeIndexable,
+{
+    match hamiltonian_path_cancellable(graph, || false) {
+        MatchResult::Match(path) => Some(path),
+        _ => None,
+    }
+}
+
+/// \[Generic\] Find a Hamiltonian cycle of the graph: an order of its nodes where each node has
+/// an edge to the next, and the last node has an edge to the first.
+///
+/// Returns the nodes in order, starting from the first node of the graph, or `None` if there is
+/// no such cycle.
+///
+/// Searches by backtracking, like [`hamiltonian_path`](fn.hamiltonian_path.html).
+///
+/// # Example
+///
+/// ```
+/// use petgraph::algo::hamiltonian_cycle;
+/// use petgraph::prelude::*;
+///
+/// let g = DiGraph::<(), ()>::from_edges(&[(0, 2), (2, 1), (1, 3), (3, 0), (0, 1)]);
+/// let cycle = hamiltonian_cycle(&g).unwrap();
+/// assert_eq!(cycle, [0.into(), 2.into(), 1.into(), 3.into()]);
+/// ```
+pub fn hamiltonian_cycle<G>(graph: G) -> Option<Vec<G::NodeId>>
+where
+    G: IntoNeighbors + IntoNodeIdentifiers + NodeIndexable,
+{
+    match hamiltonian_cycle_cancellable(graph, || false) {
+        MatchResult::Match(cycle) => Some(cycle),
+        _ => None,
+    }
+}
+
+/// \[Generic\] Find a Hamiltonian path of the graph, calling `cancel` before each step of the
+/// search and stopping when it returns `true`.
+///
+/// Returns [`MatchResult::Match`] with the nodes in order, [`MatchResult::NoMatch`] if there is
+/// no such path, or [`MatchResult::Unknown`] if the search was cancelled first.
+///
+/// # Example
+///
+/// ```
+/// use petgraph::algo::hamiltonian_path_cancellable;
+/// use petgraph::algo::isomorphism::MatchResult;
+/// use petgraph::prelude::*;
+///
+/// let g = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 0), (3, 4)]);
+/// assert_eq!(hamiltonian_path_cancellable(&g, || false), MatchResult::NoMatch);
+///
+/// // Stop after at most 100 steps.
+/// let mut steps = 0;
+/// let result = hamiltonian_path_cancellable(&g, || {
+///     steps += 1;
+///     steps > 100
+/// });
+/// assert_eq!(result, MatchResult::NoMatch);
+/// ```
+///
+/// [`MatchResult::Match`]: isomorphism/enum.MatchResult.html#variant.Match
+/// [`MatchResult::NoMatch`]: isomorphism/enum.MatchResult.html#variant.NoMatch
+/// [`MatchResult::Unknown`]: isomorphism/enum.MatchResult.html#variant.Unknown
+pub fn hamiltonian_path_cancellable<G, F>(graph: G, cancel: F) -> MatchResult<Vec<G::NodeId>>
+where
+    G: IntoNeighbors + IntoNodeIdentifiers + NodeIndexable,
+    F: FnMut() -> bool,
+{
+    let (nodes, adjacency) = Adjacency::new(graph);
+    match adjacency.search(false, cancel) {
+        MatchResult::Match(path) => {
+            MatchResult::Match(path.into_iter().map(|v| nodes[v]).collect())
+        }
+        MatchResult::NoMatch => MatchResult::NoMatch,
+        MatchResult::Unknown => MatchResult::Unknown,
+    }
+}
+
+/// \[Generic\] Find a Hamiltonian cycle of the graph, calling `cancel` before each step of the
+/// search and stopping when it returns `true`.
+///
+/// Returns [`MatchResult::Match`] with the nodes in order, starting from the first node of the
+/// graph, [`MatchResult::NoMatch`] if there is no such cycle, or [`MatchResult::Unknown`] if the
+/// search was cancelled first.
+///
+/// [`MatchResult::Match`]: isomorphism/enum.MatchResult.html#variant.Match
+/// [`MatchResult::NoMatch`]: isomorphism/enum.MatchResult.html#variant.NoMatch
+/// [`MatchResult::Unknown`]: isomorphism/enum.MatchResult.html#variant.Unknown
+pub fn hamiltonian_cycle_cancellable<G, F>(graph: G, cancel: F) -> MatchResult<Vec<G::NodeId>>
+where
+    G: IntoNeighbors + IntoNodeIdentifiers + NodeIndexable,
+    F: FnMut() -> bool,
+{
+    let (nodes, adjacency) = Adjacency::new(graph);
+    match adjacency.search(true, cancel) {
+        MatchResult::Match(cycle) => {
+            MatchResult::Match(cycle.into_iter().map(|v| nodes[v]).collect())
+        }
+        MatchResult::NoMatch => MatchResult::NoMatch,
+        MatchResult::Unknown => MatchResult::Unknown,
+    }
+}
+
+/// \[Generic\] Find a Hamiltonian path of the graph by dynamic programming over the subsets of
+/// its nodes.
+///
+/// Returns the nodes in order, or `None` if there is no such path.
+///
+/// Computes in **O(2^|V| (|V| + |E|))** time and **O(2^|V|)** space, which is practical for up to
+/// about 25 nodes.
+///
+/// **Panics** if the graph has more than 30 nodes.
+///
+/// **Reference**
+///
+/// * Richard Bellman; *Dynamic programming treatment of the travelling salesman problem*
+///
+/// # Example
+///
+/// ```
+/// use petgraph::algo::hamiltonian_path_bitmask;
+/// use petgraph::prelude::*;
+///
+/// let g = DiGraph::<(), ()>::from_edges(&[(1, 0), (0, 2), (2, 3), (1, 3)]);
+/// let path = hamiltonian_path_bitmask(&g).unwrap();
+/// assert_eq!(path, [1.into(), 0.into(), 2.into(), 3.into()]);
+/// ```
+pub fn hamiltonian_path_bitmask<G>(graph: G) -> Option<Vec<G::NodeId>>
+where
+    G: IntoNeighbors + IntoNodeIdentifiers + NodeIndexable,
+{
+    let (nodes, adjacency) = Adjacency::new(graph);
+    let path = adjacency.bitmask(false)?;
+    Some(path.into_iter().map(|v| nodes[v]).collect())
+}
+
+/// \[Generic\] Find a Hamiltonian cycle of the graph by dynamic programming over the subsets of
+/// its nodes.
+///
+/// Returns the nodes in order, starting from the first node of the graph, or `None` if there is
+/// no such cycle.
+///
+/// Computes in **O(2^|V| (|V| + |E|))** time and **O(2^|V|)** space, which is practical for up to
+/// about 25 nodes.
+///
+/// **Panics** if the graph has more than 30 nodes.
+///
+/// # Example
+///
+/// ```
+/// use petgraph::algo::hamiltonian_cycle_bitmask;
+/// use petgraph::prelude::*;
+///
+/// let square = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 3), (3, 0)]);
+/// assert_eq!(hamiltonian_cycle_bitmask(&square).unwrap().len(), 4);
+///
+/// let star = UnGraph::<(), ()>::from_edges(&[(0, 1), (0, 2), (0, 3)]);
+/// assert_eq!(hamiltonian_cycle_bitmask(&star), None);
+/// ```
+pub fn hamiltonian_cycle_bitmask<G>(graph: G) -> Option<Vec<G::NodeId>>
+where
+    G: IntoNeighbors + IntoNodeIdentifiers + NodeIndexable,
+{
+    let (nodes, adjacency) = Adjacency::new(graph);
+    let cycle = adjacency.bitmask(true)?;
+    Some(cycle.into_iter().map(|v| nodes[v]).collect())
+}
+
+/// The successors and predecessors of each node, by position in the list of nodes, without loops
+/// or parallel edges.
+struct Adjacency {
+    successors: Vec<Vec<usize>>,
+    predecessors: Vec<Vec<usize>>,
+    /// Whether every edge has a reverse edge, as in undirected graphs.
+    symmetric: bool,
+}
+
+impl Adjacency {
+    fn new<G>(graph: G) -> (Vec<G::NodeId>, Self)
+    where
+        G: IntoNeighbors + IntoNodeIdentifiers + NodeIndexable,
+    {
+        let nodes: Vec<G::NodeId> = graph.node_identifiers().collect();
+        let mut position = vec![0; graph.node_bound()];
+        for (i, &v) in nodes.iter().enumerate() {
+            position[graph.to_index(v)] = i;
+        }
+        let mut successors = vec![Vec::new(); nodes.len()];
+        let mut predecessors = vec![Vec::new(); nodes.len()];
+        for (a, &v) in nodes.iter().enumerate() {
+            for w in graph.neighbors(v) {
+                let b = position[graph.to_index(w)];
+                if a != b {
+                    successors[a].push(b);
+                    predecessors[b].push(a);
+                }
+            }
+        }
+        for list in successors.iter_mut().chain(&mut predecessors) {
+            list.sort_unstable();
+            list.dedup();
+        }
+        (
+            nodes,
+            Adjacency {
+                symmetric: successors == predecessors,
+                successors,
+                predecessors,
+            },
+        )
+    }
+
+    fn len(&self) -> usize {
+        self.successors.len()
+    }
+
+    /// The nodes that a Hamiltonian path may start from, without missing any path up to
+    /// reversal.
+    fn path_starts(&self) -> Vec<usize> {
+        let n = self.len();
+        let sources: Vec<usize> = (0..n)
+            .filter(|&v| self.predecessors[v].is_empty())
+            .collect();
+        if !sources.is_empty() {
+            // Nodes without predecessors must come first.
+            return if sources.len() == 1 {
+                sources
+            } else {
+                Vec::new()
+            };
+        }
+        // In a symmetric graph, a node with one neighbor must be an end of the path, and
+        // reversing the path makes it the start.
+        if let Some(leaf) = (0..n).find(|&v| self.symmetric && self.successors[v].len() == 1) {
+            return vec![leaf];
+        }
+        (0..n).collect()
+    }
+
+    fn search<F>(&self, cycle: bool, mut cancel: F) -> MatchResult<Vec<usize>>
+    where
+        F: FnMut() -> bool,
+    {
+        let n = self.len();
+        if n == 0 {
+            return MatchResult::Match(Vec::new());
+        }
+        let starts = if cycle {
+            // Every node is on the cycle, so start from the one with the fewest choices.
+            let v = (0..n).min_by_key(|&v| self.successors[v].len()).unwrap();
+            vec![v]
+        } else {
+            self.path_starts()
+        };
+        let mut search = Search {
+            adjacency: self,
+            cycle,
+            visited: vec![false; n],
+            path: Vec::with_capacity(n),
+            mark: vec![0; n],
+            round: 0,
+            order: vec![0; n],
+            low: vec![0; n],
+        };
+        for start in starts {
+            match search.run(start, &mut cancel) {
+                MatchResult::NoMatch => {}
+                MatchResult::Match(mut path) => {
+                    if cycle {
+                        let first = path.iter().position(|&v| v == 0).unwrap();
+                        path.rotate_left(first);
+                    }
+                    return MatchResult::Match(path);
+                }
+                MatchResult::Unknown => return MatchResult::Unknown,
+            }
+        }
+        MatchResult::NoMatch
+    }
+
+    fn bitmask(&self, cycle: bool) -> Option<Vec<usize>> {
+        let n = self.len();
+        assert!(n <= 30, "hamiltonian: too many nodes ({})", n);
+        if n == 0 {
+            return Some(Vec::new());
+        }
+
+        // Bit `v` of `ends[set]` is set if there is a path through the nodes in `set` that ends
+        // at `v`. Cycles start from node 0.
+        let full = (1usize << n) - 1;
+        let mut ends = vec![0u32; full + 1];
+        if cycle {
+            ends[1] = 1;
+        } else {
+            for v in 0..n {
+                ends[1 << v] = 1 << v;
+            }
+        }
+        for set in 1..full {
+            let mut bits = ends[set];
+            while bits != 0 {
+                let v = bits.trailing_zeros() as usize;
+                bits &= bits - 1;
+                for &w in &self.successors[v] {
+                    if set & (1 << w) == 0 {
+                        ends[set | 1 << w] |= 1 << w;
+                    }
+                }
+            }
+        }
+        let mut last = (0..n).find(|&v| {
+            ends[full] & (1 << v) != 0 && (!cycle || n == 1 || self.successors[v].contains(&0))
+        })?;
+
+        // Follow the paths back from the last node.
+        let mut path = vec![last];
+        let mut set = full;
+        while set.count_ones() > 1 {
+            set &= !(1 << last);
+            last = self.predecessors[last]
+                .iter()
+                .cloned()
+                .find(|&u| ends[set] & (1 << u) != 0)
+                .unwrap();
+            path.push(last);
+        }
+        path.reverse();
+        Some(path)
+    }
+}
+
+/// The state of a backtracking search.
+struct Search<'a> {
+    adjacency: &'a Adjacency,
+    cycle: bool,
+    visited: Vec<bool>,
+    path: Vec<usize>,
+    /// Scratch space, where `mark[v] == round` marks `v` in the current round.
+    mark: Vec<usize>,
+    round: usize,
+    /// Scratch space for the depth-first search of `closes_biconnected`.
+    order: Vec<usize>,
+    low: Vec<usize>,
+}
+
+impl<'a> Search<'a> {
+    fn run<F>(&mut self, start: usize, cancel: &mut F) -> MatchResult<Vec<usize>>
+    where
+        F: FnMut() -> bool,
+    {
+        let n = self.visited.len();
+        for v in &mut self.visited {
+            *v = false;
+        }
+        self.path.clear();
+        self.visit(start);
+        if n == 1 {
+            return MatchResult::Match(self.path.clone());
+        }
+
+        // The nodes left to try next at each length of the path, best last.
+        let mut stack = match self.candidates() {
+            Some(candidates) => vec![candidates],
+            None => return MatchResult::NoMatch,
+        };
+        while let Some(candidates) = stack.last_mut() {
+            if cancel() {
+                return MatchResult::Unknown;
+            }
+            match candidates.pop() {
+                Some(v) => {
+                    self.visit(v);
+                    if self.path.len() == n {
+                        if !self.cycle || self.adjacency.successors[v].contains(&self.path[0]) {
+                            return MatchResult::Match(self.path.clone());
+                        }
+                        self.leave();
+                    } else {
+                        match self.candidates() {
+                            Some(next) => stack.push(next),
+                            None => self.leave(),
+                        }
+                    }
+                }
+                None => {
+                    stack.pop();
+                    if !stack.is_empty() {
+                        self.leave();
+                    }
+                }
+            }
+        }
+        MatchResult::NoMatch
+    }
+
+    fn visit(&mut self, v: usize) {
+        self.visited[v] = true;
+        self.path.push(v);
+    }
+
+    fn leave(&mut self) {
+        let v = self.path.pop().unwrap();
+        self.visited[v] = false;
+    }
+
+    fn next_round(&mut self) -> usize {
+        self.round += 1;
+        self.round
+    }
+
+    /// The unvisited successors of the end of the path to try next, with the most promising
+    /// last, or `None` if the path cannot be extended to a Hamiltonian path or cycle.
+    fn candidates(&mut self) -> Option<Vec<usize>> {
+        let adjacency = self.adjacency;
+        let n = self.visited.len();
+        let start = self.path[0];
+        let end = *self.path.last().unwrap();
+        let remaining = n - self.path.len();
+        let available = |visited: &[bool], v: usize| !visited[v] || v == end;
+
+        // Every unvisited node needs a predecessor among the unvisited nodes and the end of the
+        // path, and a successor among the unvisited nodes (or the start, for a cycle), which
+        // differ unless it is the only one left. Only the last node of a path has no successor.
+        let mut forced = None;
+        let mut last = 0;
+        for u in 0..n {
+            if self.visited[u] {
+                continue;
+            }
+            let round = self.next_round();
+            let mut predecessors = 0;
+            let mut neighbors = 0;
+            for &w in &adjacency.predecessors[u] {
+                if available(&self.visited, w) {
+                    predecessors += 1;
+                    neighbors += 1;
+                    self.mark[w] = round;
+                }
+            }
+            let mut successors = 0;
+            for &w in &adjacency.successors[u] {
+                if !self.visited[w] || (self.cycle && w == start) {
+                    successors += 1;
+                    if self.mark[w] != round {
+                        neighbors += 1;
+                    }
+                }
+            }
+            if predecessors == 0 {
+                return None;
+            }
+            if predecessors == 1 && self.mark[end] == round && remaining > 1 {
+                // The end of the path is the only way into `u`, so it must come next.
+                if forced.is_some() {
+                    return None;
+                }
+                forced = Some(u);
+            }
+            if successors == 0 || (neighbors < 2 && remaining > 1) {
+                if self.cycle {
+                    return None;
+                }
+                last += 1;
+                if last > 1 {
+                    return None;
+                }
+            }
+        }
+
+        // The unvisited nodes must all be reachable from the end of the path.
+        let round = self.next_round();
+        let mut stack = vec![end];
+        let mut reached = 0;
+        self.mark[end] = round;
+        while let Some(v) = stack.pop() {
+            for &w in &adjacency.successors[v] {
+                if !self.visited[w] && self.mark[w] != round {
+                    self.mark[w] = round;
+                    reached += 1;
+                    stack.push(w);
+                }
+            }
+        }
+        if reached < remaining {
+            return None;
+        }
+        if self.cycle && self.path.len() < n - 1 {
+            let closes = if adjacency.symmetric {
+                self.closes_biconnected()
+            } else {
+                self.closes_reachable()
+            };
+            if !closes {
+                return None;
+            }
+        }
+
+        if let Some(u) = forced {
+            return Some(vec![u]);
+        }
+        // Try the successors with the fewest onward choices first.
+        let visited = &self.visited;
+        let mut candidates: Vec<(usize, usize)> = adjacency.successors[end]
+            .iter()
+            .filter(|&&v| !visited[v])
+            .map(|&v| {
+                let onward = adjacency.successors[v]
+                    .iter()
+                    .filter(|&&w| !visited[w])
+                    .count();
+                (onward, v)
+            })
+            .collect();
+        candidates.sort_unstable_by(|a, b| b.cmp(a));
+        Some(candidates.into_iter().map(|(_, v)| v).collect())
+    }
+    /// Whether the start of the path is reachable from every unvisited node, through unvisited
+    /// nodes, as needed to close a cycle.
+    fn closes_reachable(&mut self) -> bool {
+        let start = self.path[0];
+        let round = self.next_round();
+        let mut stack = vec![start];
+        let mut reached = 0;
+        while let Some(v) = stack.pop() {
+            for &w in &self.adjacency.predecessors[v] {
+                if !self.visited[w] && self.mark[w] != round {
+                    self.mark[w] = round;
+                    reached += 1;
+                    stack.push(w);
+                }
+            }
+        }
+        reached == self.visited.len() - self.path.len()
+    }
+
+    /// Whether the unvisited nodes, with the start and the end of the path joined by an edge,
+    /// form a biconnected graph, as needed to close a cycle in a symmetric graph: a Hamiltonian
+    /// cycle of that graph has no cut vertex.
+    fn closes_biconnected(&mut self) -> bool {
+        let adjacency = self.adjacency;
+        let start = self.path[0];
+        let end = *self.path.last().unwrap();
+        let visited = &self.visited;
+        let round = self.round + 1;
+        self.round = round;
+        // The neighbors of `v` are its unvisited successors, and the other end of the path, in
+        // that order.
+        let neighbor = |v: usize, i: usize| -> Option<Option<usize>> {
+            let successors = &adjacency.successors[v];
+            if i < successors.len() {
+                let w = successors[i];
+                Some(if !visited[w] || w == start || w == end {
+                    Some(w)
+                } else {
+                    None
+                })
+            } else if i == successors.len() && start != end && (v == start || v == end) {
+                Some(Some(if v == start { end } else { start }))
+            } else {
+                None
+            }
+        };
+
+        // Tarjan's depth-first search for cut vertices, from the end of the path.
+        let mut counter = 0;
+        self.mark[end] = round;
+        self.order[end] = counter;
+        self.low[end] = counter;
+        let mut root_children = 0;
+        // The node, its parent, whether the edge to the parent was skipped, and the next neighbor.
+        let mut stack = vec![(end, end, false, 0)];
+        while let Some(&mut (v, parent, ref mut skipped, ref mut i)) = stack.last_mut() {
+            match neighbor(v, *i) {
+                Some(next) => {
+                    *i += 1;
+                    let w = match next {
+                        Some(w) => w,
+                        None => continue,
+                    };
+                    if w == parent && !*skipped {
+                        *skipped = true;
+                    } else if self.mark[w] == round {
+                        self.low[v] = self.low[v].min(self.order[w]);
+                    } else {
+                        counter += 1;
+                        self.mark[w] = round;
+                        self.order[w] = counter;
+                        self.low[w] = counter;
+                        if v == end {
+                            root_children += 1;
+                        }
+                        stack.push((w, v, false, 0));
+                    }
+                }
+                None => {
+                    stack.pop();
+                    if v != end {
+                        self.low[parent] = self.low[parent].min(self.low[v]);
+                        if parent != end && self.low[v] >= self.order[parent] {
+                            return false;
+                        }
+                    }
+                }
+            }
+        }
+        root_children <= 1
+            && counter + self.path.len() == self.visited.len() + usize::from(start != end)
+    }
+}
@@ -11,11 +11,42 @@ use crate::visit::IntoNeighborsDirected;
 use crate::visit::NodeCompactIndexable;
 use crate::{Incoming, Outgoing};
 
-use self::semantic::EdgeMatcher;
-use self::semantic::NoSemanticMatch;
-use self::semantic::NodeMatcher;
+pub use self::matching::GraphMatcher;
+pub use self::semantic::EdgeMatcher;
+pub use self::semantic::NoSemanticMatch;
+pub use self::semantic::NodeMatcher;
 use self::state::Vf2State;
 
+/// Which kind of match a [`GraphMatcher`](struct.GraphMatcher.html) looks for
+/// between `g0` and `g1`.
+///
+/// A mapping is always an injective map from the nodes of `g0` into the nodes
+/// of `g1`; the modes differ in how the edges must correspond.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum MatchMode {
+    /// The mapping is a bijection and `a → b` is an edge of `g0` if and only
+    /// if the image of `a → b` is an edge of `g1`.
+    Isomorphism,
+    /// `g0` is isomorphic to a node-induced subgraph of `g1`: `a → b` is an
+    /// edge of `g0` if and only if the image of `a → b` is an edge of `g1`.
+    InducedSubgraph,
+    /// `g0` is isomorphic to a subgraph of `g1`, not necessarily induced:
+    /// every edge of `g0` maps to an edge of `g1`, but `g1` may have
+    /// additional edges between the mapped nodes.
+    Monomorphism,
+}
+
+/// The outcome of a search that may stop before it is decided.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum MatchResult<T> {
+    /// A match was found.
+    Match(T),
+    /// The search is complete and there are no (further) matches.
+    NoMatch,
+    /// The search ran out of budget or was cancelled first.
+    Unknown,
+}
+
 mod state {
     use super::*;
 
@@ -65,6 +96,21 @@ mod state {
             self.generation == self.mapping.len()
         }
 
+        /// Return the number of mapped nodes.
+        pub fn mapped_count(&self) -> usize {
+            self.generation
+        }
+
+        /// Return **true** if the node at `index` is in M(s) or Tout(s).
+        pub fn is_in_out(&self, index: usize) -> bool {
+            self.out[index] > 0
+        }
+
+        /// Return **true** if the node at `index` is in M(s) or Tin(s).
+        pub fn is_in_ins(&self, index: usize) -> bool {
+            self.graph.is_directed() && self.ins[index] > 0
+        }
+
         /// Add mapping **from** <-> **to** to the state.
         pub fn push_mapping(&mut self, from: G::NodeId, to: usize) {
             self.generation += 1;
@@ -151,10 +197,19 @@ mod state {
 mod semantic {
     use super::*;
 
+    /// A matcher that accepts any pair of nodes or edges, for matching the
+    /// graph structure only.
     pub struct NoSemanticMatch;
 
+    /// Semantic feasibility of mapping a node of `G0` to a node of `G1`.
+    ///
+    /// Implemented by [`NoSemanticMatch`](struct.NoSemanticMatch.html) and by
+    /// closures comparing node weights,
+    /// `FnMut(&G0::NodeWeight, &G1::NodeWeight) -> bool`.
     pub trait NodeMatcher<G0: GraphBase, G1: GraphBase> {
+        /// Return `false` if every pair of nodes matches, so `eq` can be skipped.
         fn enabled() -> bool;
+        /// Return `true` if `_n0` may be mapped to `_n1`.
         fn eq(&mut self, _g0: &G0, _g1: &G1, _n0: G0::NodeId, _n1: G1::NodeId) -> bool;
     }
 
@@ -189,8 +244,16 @@ mod semantic {
         }
     }
 
+    /// Semantic feasibility of mapping an edge of `G0` to an edge of `G1`.
+    ///
+    /// Implemented by [`NoSemanticMatch`](struct.NoSemanticMatch.html) and by
+    /// closures comparing edge weights,
+    /// `FnMut(&G0::EdgeWeight, &G1::EdgeWeight) -> bool`.
     pub trait EdgeMatcher<G0: GraphBase, G1: GraphBase> {
+        /// Return `false` if every pair of edges matches, so `eq` can be skipped.
         fn enabled() -> bool;
+        /// Return `true` if the edge `e0` may be mapped to the edge `e1`,
+        /// both given as `(source, target)`.
         fn eq(
             &mut self,
             _g0: &G0,
@@ -255,6 +318,9 @@ mod semantic {
 mod matching {
     use super::*;
 
+    use std::cmp::Reverse;
+    use std::collections::BinaryHeap;
+
     #[derive(Copy, Clone, PartialEq, Debug)]
     enum OpenList {
         Out,
@@ -284,6 +350,7 @@ mod matching {
         nodes: (G0::NodeId, G1::NodeId),
         node_match: &mut NM,
         edge_match: &mut EM,
+        mode: MatchMode,
     ) -> bool
     where
         G0: GetAdjacencyMatrix + GraphProp + NodeCompactIndexable + IntoNeighborsDirected,
@@ -306,8 +373,12 @@ mod matching {
             };
         }
 
+        // In a monomorphism, edges of G1 need not be edges of G0, so the
+        // adjacency of the G1 side is not checked.
+        let induced = mode != MatchMode::Monomorphism;
+
         macro_rules! r_succ {
-            ($j:tt) => {{
+            ($j:tt, $check:expr) => {{
                 let mut succ_count = 0;
                 for n_neigh in field!(st, $j)
                     .graph
@@ -320,7 +391,7 @@ mod matching {
                     } else {
                         field!(st, 1 - $j).graph.to_index(field!(nodes, 1 - $j))
                     };
-                    if m_neigh == std::usize::MAX {
+                    if m_neigh == std::usize::MAX || !$check {
                         continue;
                     }
                     let has_edge = field!(st, 1 - $j).graph.is_adjacent(
@@ -337,7 +408,7 @@ mod matching {
         }
 
         macro_rules! r_pred {
-            ($j:tt) => {{
+            ($j:tt, $check:expr) => {{
                 let mut pred_count = 0;
                 for n_neigh in field!(st, $j)
                     .graph
@@ -346,7 +417,7 @@ mod matching {
                     pred_count += 1;
                     // the self loop case is handled in outgoing
                     let m_neigh = field!(st, $j).mapping[field!(st, $j).graph.to_index(n_neigh)];
-                    if m_neigh == std::usize::MAX {
+                    if m_neigh == std::usize::MAX || !$check {
                         continue;
                     }
                     let has_edge = field!(st, 1 - $j).graph.is_adjacent(
@@ -379,11 +450,11 @@ mod matching {
         // R_new: Equal for G0, G1: Ñ n Pred(G, n); both Succ and Pred,
         //      Ñ is G0 - M - Tin - Tout
         // last attempt to add these did not speed up any of the testcases
-        if r_succ!(0) > r_succ!(1) {
+        if r_succ!(0, true) > r_succ!(1, induced) {
             return false;
         }
         // R_pred
-        if st.0.graph.is_directed() && r_pred!(0) > r_pred!(1) {
+        if st.0.graph.is_directed() && r_pred!(0, true) > r_pred!(1, induced) {
             return false;
         }
 
@@ -455,18 +526,41 @@ mod matching {
             }
 
             edge_feasibility!(0);
-            edge_feasibility!(1);
+            if induced {
+                edge_feasibility!(1);
+            }
         }
         true
     }
 
     fn next_candidate<G0, G1>(
         st: &mut (Vf2State<'_, G0>, Vf2State<'_, G1>),
+        order: Option<&[usize]>,
     ) -> Option<(G0::NodeId, G1::NodeId, OpenList)>
     where
         G0: GetAdjacencyMatrix + GraphProp + NodeCompactIndexable + IntoNeighborsDirected,
         G1: GetAdjacencyMatrix + GraphProp + NodeCompactIndexable + IntoNeighborsDirected,
     {
+        // With a fixed order, the nodes of G0 are mapped in that order, and
+        // the candidates in G1 come from the matching terminal set.
+        if let Some(order) = order {
+            let n = *order.get(st.0.mapped_count())?;
+            let (open_list, m) = if st.0.is_in_out(n) {
+                (OpenList::Out, st.1.next_out_index(0))
+            } else if st.0.is_in_ins(n) {
+                (OpenList::In, st.1.next_in_index(0))
+            } else {
+                (OpenList::Other, st.1.next_rest_index(0))
+            };
+            return m.map(|m| {
+                (
+                    st.0.graph.from_index(n),
+                    st.1.graph.from_index(m),
+                    open_list,
+                )
+            });
+        }
+
         let mut from_index = None;
         let mut open_list = OpenList::Out;
         let mut to_index = st.1.next_out_index(0);
@@ -557,7 +651,7 @@ mod matching {
         st: &mut (Vf2State<'_, G0>, Vf2State<'_, G1>),
         node_match: &mut NM,
         edge_match: &mut EM,
-        match_subgraph: bool,
+        mode: MatchMode,
     ) -> Option<bool>
     where
         G0: NodeCompactIndexable
@@ -574,20 +668,31 @@ mod matching {
         EM: EdgeMatcher<G0, G1>,
     {
         let mut stack = vec![Frame::Outer];
-        if isomorphisms(st, node_match, edge_match, match_subgraph, &mut stack).is_some() {
-            Some(true)
-        } else {
-            None
+        match isomorphisms(
+            st,
+            node_match,
+            edge_match,
+            mode,
+            None,
+            &mut stack,
+            &mut || false,
+        ) {
+            MatchResult::Match(_) => Some(true),
+            _ => None,
         }
     }
 
-    fn isomorphisms<G0, G1, NM, EM>(
+    /// Continue the search from `stack`, calling `should_stop` before each
+    /// step.
+    fn isomorphisms<G0, G1, NM, EM, S>(
         st: &mut (Vf2State<'_, G0>, Vf2State<'_, G1>),
         node_match: &mut NM,
         edge_match: &mut EM,
-        match_subgraph: bool,
+        mode: MatchMode,
+        order: Option<&[usize]>,
         stack: &mut Vec<Frame<G0, G1>>,
-    ) -> Option<Vec<usize>>
+        should_stop: &mut S,
+    ) -> MatchResult<Vec<usize>>
     where
         G0: NodeCompactIndexable
             + EdgeCount
@@ -601,16 +706,30 @@ mod matching {
             + IntoNeighborsDirected,
         NM: NodeMatcher<G0, G1>,
         EM: EdgeMatcher<G0, G1>,
+        S: FnMut() -> bool,
     {
+        // Only reached for an empty `g0`, which has the single empty mapping.
         if st.0.is_complete() {
-            return Some(st.0.mapping.clone());
+            return match stack.pop() {
+                Some(_) => MatchResult::Match(st.0.mapping.clone()),
+                None => MatchResult::NoMatch,
+            };
         }
 
         // A "depth first" search of a valid mapping from graph 1 to graph 2
         // F(s, n, m) -- evaluate state s and add mapping n <-> m
         // Find least T1out node (in st.out[1] but not in M[1])
         let mut result = None;
-        while let Some(frame) = stack.pop() {
+        loop {
+            // A found mapping is returned once its frames are unwound, so
+            // only stop while there is none.
+            if result.is_none() && !stack.is_empty() && should_stop() {
+                return MatchResult::Unknown;
+            }
+            let frame = match stack.pop() {
+                Some(frame) => frame,
+                None => break,
+            };
             match frame {
                 Frame::Unwind { nodes, open_list } => {
                     pop_state(st, nodes);
@@ -626,7 +745,7 @@ mod matching {
                         }
                     }
                 }
-                Frame::Outer => match next_candidate(st) {
+                Frame::Outer => match next_candidate(st, order) {
                     None => continue,
                     Some((nx, mx, open_list)) => {
                         let f = Frame::Inner {
@@ -637,19 +756,21 @@ mod matching {
                     }
                 },
                 Frame::Inner { nodes, open_list } => {
-                    if is_feasible(st, nodes, node_match, edge_match) {
+                    if is_feasible(st, nodes, node_match, edge_match, mode) {
                         push_state(st, nodes);
                         if st.0.is_complete() {
                             result = Some(st.0.mapping.clone());
                         }
                         // Check cardinalities of Tin, Tout sets
-                        if (!match_subgraph
-                            && st.0.out_size == st.1.out_size
-                            && st.0.ins_size == st.1.ins_size)
-                            || (match_subgraph
-                                && st.0.out_size <= st.1.out_size
-                                && st.0.ins_size <= st.1.ins_size)
-                        {
+                        let terminal_sets_fit = match mode {
+                            MatchMode::Isomorphism => {
+                                st.0.out_size == st.1.out_size && st.0.ins_size == st.1.ins_size
+                            }
+                            MatchMode::InducedSubgraph | MatchMode::Monomorphism => {
+                                st.0.out_size <= st.1.out_size && st.0.ins_size <= st.1.ins_size
+                            }
+                        };
+                        if terminal_sets_fit {
                             let f0 = Frame::Unwind { nodes, open_list };
                             stack.push(f0);
                             stack.push(Frame::Outer);
@@ -669,14 +790,158 @@ mod matching {
                     }
                 }
             }
-            if result.is_some() {
-                return result;
+            if let Some(mapping) = result {
+                return MatchResult::Match(mapping);
             }
         }
-        result
+        match result {
+            Some(mapping) => MatchResult::Match(mapping),
+            None => MatchResult::NoMatch,
+        }
     }
 
-    pub struct GraphMatcher<'a, 'b, 'c, G0, G1, NM, EM>
+    /// The VF2++ matching order of the nodes of `g0`, as node indices.
+    fn vf2pp_order<G0, G1, NM>(g0: &G0, g1: &G1, node_match: &mut NM, mode: MatchMode) -> Vec<usize>
+    where
+        G0: NodeCompactIndexable + GraphProp + IntoNeighborsDirected,
+        G1: NodeCompactIndexable + GraphProp + IntoNeighborsDirected,
+        NM: NodeMatcher<G0, G1>,
+    {
+        fn degrees<G>(g: G) -> Vec<usize>
+        where
+            G: NodeCompactIndexable + GraphProp + IntoNeighborsDirected,
+        {
+            (0..g.node_count())
+                .map(|i| {
+                    let n = g.from_index(i);
+                    let ins = if g.is_directed() {
+                        g.neighbors_directed(n, Incoming).count()
+                    } else {
+                        0
+                    };
+                    g.neighbors_directed(n, Outgoing).count() + ins
+                })
+                .collect()
+        }
+
+        let n0 = g0.node_count();
+        let degree0 = degrees(g0);
+        let degree1 = degrees(g1);
+        let rarity: Vec<usize> = (0..n0)
+            .map(|u| {
+                (0..g1.node_count())
+                    .filter(|&v| match mode {
+                        MatchMode::Isomorphism => degree0[u] == degree1[v],
+                        MatchMode::InducedSubgraph | MatchMode::Monomorphism => {
+                            degree0[u] <= degree1[v]
+                        }
+                    })
+                    .filter(|&v| {
+                        !NM::enabled() || node_match.eq(g0, g1, g0.from_index(u), g1.from_index(v))
+                    })
+                    .count()
+            })
+            .collect();
+        let neighbors: Vec<Vec<usize>> = (0..n0)
+            .map(|u| {
+                let n = g0.from_index(u);
+                let mut neighbors: Vec<_> = g0
+                    .neighbors_directed(n, Outgoing)
+                    .chain(g0.neighbors_directed(n, Incoming))
+                    .map(|w| g0.to_index(w))
+                    .filter(|&w| w != u)
+                    .collect();
+                neighbors.sort_unstable();
+                neighbors.dedup();
+                neighbors
+            })
+            .collect();
+
+        let mut order = Vec::with_capacity(n0);
+        let mut seen = vec![false; n0];
+        // The number of neighbors of each node that are already ordered.
+        let mut connections = vec![0; n0];
+        let mut in_level = vec![false; n0];
+        let key = |u: usize, connections: &[usize]| {
+            (connections[u], degree0[u], Reverse(rarity[u]), Reverse(u))
+        };
+        while order.len() < n0 {
+            let root = (0..n0)
+                .filter(|&u| !seen[u])
+                .min_by_key(|&u| (rarity[u], Reverse(degree0[u]), u))
+                .unwrap();
+            seen[root] = true;
+            let mut level = vec![root];
+            while !level.is_empty() {
+                let mut heap = BinaryHeap::with_capacity(level.len());
+                for &u in &level {
+                    in_level[u] = true;
+                    heap.push(key(u, &connections));
+                }
+                while let Some((c, _, _, Reverse(u))) = heap.pop() {
+                    // Skip entries that were ordered or updated since.
+                    if !in_level[u] || c != connections[u] {
+                        continue;
+                    }
+                    in_level[u] = false;
+                    order.push(u);
+                    for &w in &neighbors[u] {
+                        connections[w] += 1;
+                        if in_level[w] {
+                            heap.push(key(w, &connections));
+                        }
+                    }
+                }
+                let mut next = Vec::new();
+                for &u in &level {
+                    for &w in &neighbors[u] {
+                        if !seen[w] {
+                            seen[w] = true;
+                            next.push(w);
+                        }
+                    }
+                }
+                level = next;
+            }
+        }
+        order
+    }
+
+    /// \[Generic\] An iterator over the mappings of `g0` into `g1`, using the
+    /// VF2 algorithm.
+    ///
+    /// The [`MatchMode`](enum.MatchMode.html) selects between graph
+    /// isomorphism, node-induced subgraph isomorphism and monomorphism. Each
+    /// mapping is a `Vec` where the entry at the index of a node of `g0` is the
+    /// index of its image in `g1`.
+    ///
+    /// Searches can be bounded by a step budget or cancelled with
+    /// [`next_within_budget`](#method.next_within_budget) and
+    /// [`next_cancellable`](#method.next_cancellable), and ordered by the
+    /// VF2++ heuristic with
+    /// [`with_vf2pp_ordering`](#method.with_vf2pp_ordering).
+    ///
+    /// The graphs should not be multigraphs.
+    ///
+    /// # Example
+    /// ```rust
+    /// use petgraph::algo::isomorphism::{GraphMatcher, MatchMode, NoSemanticMatch};
+    /// use petgraph::graph::UnGraph;
+    ///
+    /// let path = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2)]);
+    /// let triangle = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 0)]);
+    ///
+    /// // A path of length two is not an induced subgraph of a triangle, but
+    /// // it maps into it in six ways when the extra edge is allowed.
+    /// let matcher = |mode| {
+    ///     GraphMatcher::new(&&path, &&triangle, NoSemanticMatch, NoSemanticMatch, mode)
+    ///         .count()
+    /// };
+    /// assert_eq!(matcher(MatchMode::InducedSubgraph), 0);
+    /// assert_eq!(matcher(MatchMode::Monomorphism), 6);
+    /// assert_eq!(matcher(MatchMode::Isomorphism), 0);
+    /// ```
+    pub struct GraphMatcher<'a, 'b, G0, G1, NM, EM>
     where
         G0: NodeCompactIndexable
             + EdgeCount
@@ -692,13 +957,14 @@ mod matching {
         EM: EdgeMatcher<G0, G1>,
     {
         st: (Vf2State<'a, G0>, Vf2State<'b, G1>),
-        node_match: &'c mut NM,
-        edge_match: &'c mut EM,
-        match_subgraph: bool,
+        node_match: NM,
+        edge_match: EM,
+        mode: MatchMode,
+        order: Option<Vec<usize>>,
         stack: Vec<Frame<G0, G1>>,
     }
 
-    impl<'a, 'b, 'c, G0, G1, NM, EM> GraphMatcher<'a, 'b, 'c, G0, G1, NM, EM>
+    impl<'a, 'b, G0, G1, NM, EM> GraphMatcher<'a, 'b, G0, G1, NM, EM>
     where
         G0: NodeCompactIndexable
             + EdgeCount
@@ -713,25 +979,125 @@ mod matching {
         NM: NodeMatcher<G0, G1>,
         EM: EdgeMatcher<G0, G1>,
     {
+        /// Create a matcher of `g0` into `g1` that finds mappings of the
+        /// given `mode`.
+        ///
+        /// Pass [`NoSemanticMatch`](struct.NoSemanticMatch.html) as
+        /// `node_match` or `edge_match` to match the graph structure only.
         pub fn new(
             g0: &'a G0,
             g1: &'b G1,
-            node_match: &'c mut NM,
-            edge_match: &'c mut EM,
-            match_subgraph: bool,
+            node_match: NM,
+            edge_match: EM,
+            mode: MatchMode,
         ) -> Self {
-            let stack = vec![Frame::Outer];
+            let possible = match mode {
+                MatchMode::Isomorphism => {
+                    g0.node_count() == g1.node_count() && g0.edge_count() == g1.edge_count()
+                }
+                MatchMode::InducedSubgraph | MatchMode::Monomorphism => {
+                    g0.node_count() <= g1.node_count() && g0.edge_count() <= g1.edge_count()
+                }
+            };
+            let stack = if possible { vec![Frame::Outer] } else { vec![] };
             Self {
                 st: (Vf2State::new(g0), Vf2State::new(g1)),
                 node_match,
                 edge_match,
-                match_subgraph,
+                mode,
+                order: None,
                 stack,
             }
         }
+
+        /// Match the nodes of `g0` in the VF2++ order instead of the VF2
+        /// order, which prunes the search much earlier on most graphs, in
+        /// particular labelled ones.
+        ///
+        /// Nodes are ordered by a breadth-first search from the node that
+        /// has the fewest candidates in `g1`. Within each level, the nodes
+        /// with the most neighbors already ordered come first, then those of
+        /// higher degree, then those with fewer candidates. The candidates of
+        /// a node are the nodes of `g1` that pass the node matcher and have a
+        /// compatible degree.
+        ///
+        /// Computing the order takes **O(|V0| |V1| + |E0| log |V0|)** time.
+        ///
+        /// **Panics** if the search has already started.
+        ///
+        /// **Reference**
+        ///
+        /// * Alpár Jüttner, Péter Madarasi;
+        ///   *VF2++ — An improved subgraph isomorphism algorithm*
+        pub fn with_vf2pp_ordering(mut self) -> Self {
+            assert!(
+                self.st.0.mapped_count() == 0
+                    && self.stack.iter().all(|f| match f {
+                        Frame::Outer => true,
+                        _ => false,
+                    }),
+                "GraphMatcher::with_vf2pp_ordering: the search has already started"
+            );
+            self.order = Some(vf2pp_order(
+                self.st.0.graph,
+                self.st.1.graph,
+                &mut self.node_match,
+                self.mode,
+            ));
+            self
+        }
+
+        /// Search for the next mapping for at most `max_steps` steps of the
+        /// search, where a step visits one state of the search tree.
+        ///
+        /// Returns [`Unknown`](enum.MatchResult.html#variant.Unknown) if the
+        /// budget runs out first. The search is left where it stopped, so
+        /// calling this again (or using the iterator) resumes it.
+        pub fn next_within_budget(&mut self, max_steps: usize) -> MatchResult<Vec<usize>> {
+            let mut steps = 0;
+            self.next_cancellable(|| {
+                steps += 1;
+                steps > max_steps
+            })
+        }
+
+        /// Search for the next mapping, calling `cancel` before each step of
+        /// the search and stopping when it returns `true`.
+        ///
+        /// Returns [`Unknown`](enum.MatchResult.html#variant.Unknown) if the
+        /// search was cancelled before a mapping was found or the search space
+        /// was exhausted. The search is left where it stopped, so calling this
+        /// again (or using the iterator) resumes it.
+        pub fn next_cancellable<F>(&mut self, mut cancel: F) -> MatchResult<Vec<usize>>
+        where
+            F: FnMut() -> bool,
+        {
+            isomorphisms(
+                &mut self.st,
+                &mut self.node_match,
+                &mut self.edge_match,
+                self.mode,
+                self.order.as_deref(),
+                &mut self.stack,
+                &mut cancel,
+            )
+        }
+
+        /// The kind of match this matcher looks for.
+        pub fn mode(&self) -> MatchMode {
+            self.mode
+        }
+
+        /// Return `true` if there is a mapping of `g0` into `g1`.
+        ///
+        /// This searches from where the matcher left off, so it only finds
+        /// mappings not yet returned by the iterator.
+        pub fn is_match(&mut self) -> bool {
+            self.next().is_some()
+        }
     }
 
-    impl<'a, 'b, 'c, G0, G1, NM, EM> Iterator for GraphMatcher<'a, 'b, 'c, G0, G1, NM, EM>
+    impl<'a, 'b, G0, G1, NM, EM> Iterator for GraphMatcher<'a, 'b, G0, G1, NM, EM>
     where
         G0: NodeCompactIndexable
             + EdgeCount
@@ -749,13 +1115,10 @@ mod matching {
         type Item = Vec<usize>;
 
         fn next(&mut self) -> Option<Self::Item> {
-            isomorphisms(
-                &mut self.st,
-                self.node_match,
-                self.edge_match,
-                self.match_subgraph,
-                &mut self.stack,
-            )
+            match self.next_cancellable(|| false) {
+                MatchResult::Match(mapping) => Some(mapping),
+                _ => None,
+            }
         }
 
         fn size_hint(&self) -> (usize, Option<usize>) {
@@ -827,8 +1190,13 @@ where
     }
 
     let mut st = (Vf2State::new(&g0), Vf2State::new(&g1));
-    self::matching::try_match(&mut st, &mut NoSemanticMatch, &mut NoSemanticMatch, false)
-        .unwrap_or(false)
+    self::matching::try_match(
+        &mut st,
+        &mut NoSemanticMatch,
+        &mut NoSemanticMatch,
+        MatchMode::Isomorphism,
+    )
+    .unwrap_or(false)
 }
 
 /// \[Generic\] Return `true` if the graphs `g0` and `g1` are isomorphic.
@@ -864,7 +1232,139 @@ where
     }
 
     let mut st = (Vf2State::new(&g0), Vf2State::new(&g1));
-    self::matching::try_match(&mut st, &mut node_match, &mut edge_match, false).unwrap_or(false)
+    self::matching::try_match(
+        &mut st,
+        &mut node_match,
+        &mut edge_match,
+        MatchMode::Isomorphism,
+    )
+    .unwrap_or(false)
+}
+
+/// \[Generic\] Decide whether the graphs `g0` and `g1` are isomorphic, giving
+/// up after `max_steps` steps of the search.
+///
+/// Examines both syntactic and semantic graph isomorphism, like
+/// [`is_isomorphic_matching`](fn.is_isomorphic_matching.html), with the VF2
+/// algorithm and the VF2++ matching order. A step visits one state of the
+/// search tree; computing the VF2++ order beforehand does not count.
+///
+/// Return [`Match`](enum.MatchResult.html#variant.Match) with a mapping, where
+/// the entry at the index of a node of `g0` is the index of its image in `g1`,
+/// [`NoMatch`](enum.MatchResult.html#variant.NoMatch) if the graphs are not
+/// isomorphic, or [`Unknown`](enum.MatchResult.html#variant.Unknown) if the
+/// budget ran out first.
+///
+/// The graphs should not be multigraphs.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::is_isomorphic_matching_within_budget;
+/// use petgraph::algo::isomorphism::MatchResult;
+/// use petgraph::graph::UnGraph;
+///
+/// let g0 = UnGraph::<u8, ()>::from_edges(&[(0, 1), (1, 2), (2, 3)]);
+/// let g1 = UnGraph::<u8, ()>::from_edges(&[(3, 2), (2, 0), (0, 1)]);
+///
+/// let result = is_isomorphic_matching_within_budget(&g0, &g1, u8::eq, |_, _| true, 0);
+/// assert_eq!(result, MatchResult::Unknown);
+/// match is_isomorphic_matching_within_budget(&g0, &g1, u8::eq, |_, _| true, 100) {
+///     MatchResult::Match(mapping) => assert_eq!(mapping.len(), 4),
+///     result => panic!("{:?}", result),
+/// }
+/// ```
+pub fn is_isomorphic_matching_within_budget<G0, G1, NM, EM>(
+    g0: G0,
+    g1: G1,
+    node_match: NM,
+    edge_match: EM,
+    max_steps: usize,
+) -> MatchResult<Vec<usize>>
+where
+    G0: NodeCompactIndexable
+        + EdgeCount
+        + DataMap
+        + GetAdjacencyMatrix
+        + GraphProp
+        + IntoEdgesDirected,
+    G1: NodeCompactIndexable
+        + EdgeCount
+        + DataMap
+        + GetAdjacencyMatrix
+        + GraphProp<EdgeType = G0::EdgeType>
+        + IntoEdgesDirected,
+    NM: FnMut(&G0::NodeWeight, &G1::NodeWeight) -> bool,
+    EM: FnMut(&G0::EdgeWeight, &G1::EdgeWeight) -> bool,
+{
+    let mut steps = 0;
+    is_isomorphic_matching_cancellable(g0, g1, node_match, edge_match, || {
+        steps += 1;
+        steps > max_steps
+    })
+}
+
+/// \[Generic\] Decide whether the graphs `g0` and `g1` are isomorphic, calling
+/// `cancel` before each step of the search and giving up when it returns
+/// `true`.
+///
+/// Examines both syntactic and semantic graph isomorphism, like
+/// [`is_isomorphic_matching`](fn.is_isomorphic_matching.html), with the VF2
+/// algorithm and the VF2++ matching order. `cancel` can check a deadline or a
+/// flag set by another thread.
+///
+/// Return [`Match`](enum.MatchResult.html#variant.Match) with a mapping, where
+/// the entry at the index of a node of `g0` is the index of its image in `g1`,
+/// [`NoMatch`](enum.MatchResult.html#variant.NoMatch) if the graphs are not
+/// isomorphic, or [`Unknown`](enum.MatchResult.html#variant.Unknown) if the
+/// search was cancelled first.
+///
+/// The graphs should not be multigraphs.
+///
+/// # Example
+/// ```rust
+/// use std::time::{Duration, Instant};
+///
+/// use petgraph::algo::is_isomorphic_matching_cancellable;
+/// use petgraph::algo::isomorphism::MatchResult;
+/// use petgraph::graph::UnGraph;
+///
+/// let g0 = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 0)]);
+/// let g1 = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 3)]);
+///
+/// let deadline = Instant::now() + Duration::from_secs(10);
+/// let result =
+///     is_isomorphic_matching_cancellable(&g0, &g1, |_, _| true, |_, _| true, || {
+///         Instant::now() > deadline
+///     });
+/// assert_eq!(result, MatchResult::NoMatch);
+/// ```
+pub fn is_isomorphic_matching_cancellable<G0, G1, NM, EM, F>(
+    g0: G0,
+    g1: G1,
+    node_match: NM,
+    edge_match: EM,
+    cancel: F,
+) -> MatchResult<Vec<usize>>
+where
+    G0: NodeCompactIndexable
+        + EdgeCount
+        + DataMap
+        + GetAdjacencyMatrix
+        + GraphProp
+        + IntoEdgesDirected,
+    G1: NodeCompactIndexable
+        + EdgeCount
+        + DataMap
+        + GetAdjacencyMatrix
+        + GraphProp<EdgeType = G0::EdgeType>
+        + IntoEdgesDirected,
+    NM: FnMut(&G0::NodeWeight, &G1::NodeWeight) -> bool,
+    EM: FnMut(&G0::EdgeWeight, &G1::EdgeWeight) -> bool,
+    F: FnMut() -> bool,
+{
+    GraphMatcher::new(&g0, &g1, node_match, edge_match, MatchMode::Isomorphism)
+        .with_vf2pp_ordering()
+        .next_cancellable(cancel)
 }
 
 /// \[Generic\] Return `true` if `g0` is isomorphic to a subgraph of `g1`.
@@ -876,7 +1376,7 @@ where
 ///
 /// # Subgraph isomorphism
 ///
-/// (adapted from [`networkx` documentation](https://networkx.github.io/documentation/stable/reference/algorithms/isomorphism.vf2.html))
+/// (adapted from [`networkx` documentation][networkx])
 ///
 /// Graph theory literature can be ambiguous about the meaning of the above statement,
 /// and we seek to clarify it now.
@@ -900,6 +1400,8 @@ where
 ///
 /// * Luigi P. Cordella, Pasquale Foggia, Carlo Sansone, Mario Vento;
 ///   *A (Sub)Graph Isomorphism Algorithm for Matching Large Graphs*
+///
+/// [networkx]: https://networkx.github.io/documentation/stable/reference/algorithms/isomorphism.vf2.html
 pub fn is_isomorphic_subgraph<G0, G1>(g0: G0, g1: G1) -> bool
 where
     G0: NodeCompactIndexable + EdgeCount + GetAdjacencyMatrix + GraphProp + IntoNeighborsDirected,
@@ -914,8 +1416,13 @@ where
     }
 
     let mut st = (Vf2State::new(&g0), Vf2State::new(&g1));
-    self::matching::try_match(&mut st, &mut NoSemanticMatch, &mut NoSemanticMatch, true)
-        .unwrap_or(false)
+    self::matching::try_match(
+        &mut st,
+        &mut NoSemanticMatch,
+        &mut NoSemanticMatch,
+        MatchMode::InducedSubgraph,
+    )
+    .unwrap_or(false)
 }
 
 /// \[Generic\] Return `true` if `g0` is isomorphic to a subgraph of `g1`.
@@ -951,7 +1458,13 @@ where
     }
 
     let mut st = (Vf2State::new(&g0), Vf2State::new(&g1));
-    self::matching::try_match(&mut st, &mut node_match, &mut edge_match, true).unwrap_or(false)
+    self::matching::try_match(
+        &mut st,
+        &mut node_match,
+        &mut edge_match,
+        MatchMode::InducedSubgraph,
+    )
+    .unwrap_or(false)
 }
 
 /// Using the VF2 algorithm, examine both syntactic and semantic graph
@@ -988,7 +1501,11 @@ where
         return None;
     }
 
-    Some(self::matching::GraphMatcher::new(
-        g0, g1, node_match, edge_match, true,
+    Some(GraphMatcher::new(
+        g0,
+        g1,
+        node_match,
+        edge_match,
+        MatchMode::InducedSubgraph,
     ))
 }
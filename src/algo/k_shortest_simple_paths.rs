@@ -0,0 +1,261 @@
+//! The k shortest simple paths between two nodes, by Yen's algorithm.
+//!
+//! [`k_shortest_simple_paths`] finds the paths in order of increasing cost. Each new path is the
+//! cheapest *deviation* from one of the paths found so far: a shortest path from one of its nodes
+//! that avoids the edges out of that node already used by earlier paths with the same prefix, and
+//! the nodes of the prefix. Deviations are kept in a priority queue, so the paths can be produced
+//! lazily, one at a time.
+//!
+//! **Reference**
+//!
+//! * J. Y. Yen; *Finding the k shortest loopless paths in a network*
+//!
+//! [`k_shortest_simple_paths`]: fn.k_shortest_simple_paths.html
+
+use std::collections::hash_map::Entry::{Occupied, Vacant};
+use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::hash::Hash;
+
+use crate::algo::Measure;
+use crate::scored::MinScored;
+use crate::visit::{EdgeRef, IntoEdges, VisitMap, Visitable};
+
+/// \[Generic\] Yen's k shortest loopless paths algorithm.
+///
+/// Return an iterator over the simple paths (paths without repeated nodes) from `start` to `goal`,
+/// in order of increasing cost. Each path is produced as a tuple of its total cost, its nodes
+/// (including `start` and `goal`) and the ids of its edges, so that parallel edges give distinct
+/// paths.
+///
+/// Paths are computed lazily: use `.take(k)` to get the `k` shortest ones. Each new path needs up
+/// to **|V|** shortest path searches, so the first `k` paths are found in
+/// **O(k|V| (|E| + |V| log |V|))** time.
+///
+/// The function `edge_cost` should return the cost for a particular edge, which is used to compute
+/// path costs. Edge costs must be non-negative.
+///
+/// # Example
+/// ```rust
+/// use petgraph::prelude::*;
+/// use petgraph::algo::k_shortest_simple_paths;
+///
+/// let mut graph = DiGraph::<(), u32>::new();
+/// let a = graph.add_node(());
+/// let b = graph.add_node(());
+/// let c = graph.add_node(());
+/// let d = graph.add_node(());
+/// graph.extend_with_edges(&[(a, b, 1), (b, d, 1), (a, c, 1), (c, d, 2), (b, c, 1)]);
+///
+/// let paths: Vec<_> = k_shortest_simple_paths(&graph, a, d, |e| *e.weight())
+///     .map(|(cost, nodes, _edges)| (cost, nodes))
+///     .collect();
+/// assert_eq!(paths, vec![
+///     (2, vec![a, b, d]),
+///     (3, vec![a, c, d]),
+///     (4, vec![a, b, c, d]),
+/// ]);
+/// ```
+pub fn k_shortest_simple_paths<G, F, K>(
+    graph: G,
+    start: G::NodeId,
+    goal: G::NodeId,
+    edge_cost: F,
+) -> KShortestSimplePaths<G, F, K>
+where
+    G: IntoEdges + Visitable,
+    G::NodeId: Eq + Hash,
+    G::EdgeId: Eq + Hash,
+    F: FnMut(G::EdgeRef) -> K,
+    K: Measure + Copy,
+{
+    KShortestSimplePaths {
+        graph,
+        start,
+        goal,
+        edge_cost,
+        found: Vec::new(),
+        candidates: BinaryHeap::new(),
+        seen: HashSet::new(),
+        done: false,
+    }
+}
+
+/// A simple path along with the cost to reach each of its nodes.
+#[derive(Clone, Debug)]
+struct CostedPath<N, E, K> {
+    nodes: Vec<N>,
+    edges: Vec<E>,
+    /// `costs[i]` is the cost of the path up to `nodes[i]`.
+    costs: Vec<K>,
+}
+
+/// An iterator over the simple paths between two nodes, shortest first.
+///
+/// Created with [`k_shortest_simple_paths`](fn.k_shortest_simple_paths.html).
+pub struct KShortestSimplePaths<G, F, K>
+where
+    G: IntoEdges,
+{
+    graph: G,
+    start: G::NodeId,
+    goal: G::NodeId,
+    edge_cost: F,
+    found: Vec<CostedPath<G::NodeId, G::EdgeId, K>>,
+    #[allow(clippy::type_complexity)]
+    candidates: BinaryHeap<MinScored<K, CostedPath<G::NodeId, G::EdgeId, K>>>,
+    seen: HashSet<Vec<G::EdgeId>>,
+    done: bool,
+}
+
+impl<G, F, K> KShortestSimplePaths<G, F, K>
+where
+    G: IntoEdges + Visitable,
+    G::NodeId: Eq + Hash,
+    G::EdgeId: Eq + Hash,
+    F: FnMut(G::EdgeRef) -> K,
+    K: Measure + Copy,
+{
+    /// Dijkstra's algorithm from `from` to the goal, starting at cost `offset` and never entering a
+    /// node of `blocked_nodes` or using an edge of `blocked_edges`.
+    fn shortest_path(
+        &mut self,
+        from: G::NodeId,
+        offset: K,
+        blocked_nodes: &[G::NodeId],
+        blocked_edges: &HashSet<G::EdgeId>,
+    ) -> Option<CostedPath<G::NodeId, G::EdgeId, K>> {
+        let graph = self.graph;
+        let mut visited = graph.visit_map();
+        for &n in blocked_nodes {
+            visited.visit(n);
+        }
+        let mut scores = HashMap::new();
+        let mut predecessor = HashMap::new();
+        let mut visit_next = BinaryHeap::new();
+        scores.insert(from, offset);
+        visit_next.push(MinScored(offset, from));
+        while let Some(MinScored(node_score, node)) = visit_next.pop() {
+            if node == self.goal {
+                let mut path = CostedPath {
+                    nodes: vec![node],
+                    edges: Vec::new(),
+                    costs: vec![node_score],
+                };
+                let mut cur = node;
+                while let Some(&(prev, edge)) = predecessor.get(&cur) {
+                    path.nodes.push(prev);
+                    path.edges.push(edge);
+                    path.costs.push(scores[&prev]);
+                    cur = prev;
+                }
+                path.nodes.reverse();
+                path.edges.reverse();
+                path.costs.reverse();
+                return Some(path);
+            }
+            if !visited.visit(node) {
+                continue;
+            }
+            for edge in graph.edges(node) {
+                let next = edge.target();
+                if visited.is_visited(&next) || blocked_edges.contains(&edge.id()) {
+                    continue;
+                }
+                let next_score = node_score + (self.edge_cost)(edge);
+                match scores.entry(next) {
+                    Occupied(ent) => {
+                        if next_score < *ent.get() {
+                            *ent.into_mut() = next_score;
+                            visit_next.push(MinScored(next_score, next));
+                            predecessor.insert(next, (node, edge.id()));
+                        }
+                    }
+                    Vacant(ent) => {
+                        ent.insert(next_score);
+                        visit_next.push(MinScored(next_score, next));
+                        predecessor.insert(next, (node, edge.id()));
+                    }
+                }
+            }
+        }
+        None
+    }
+
+    /// Push every deviation of the last found path to the candidates.
+    fn push_deviations(&mut self) {
+        let last = match self.found.last() {
+            Some(last) => last.clone(),
+            None => return,
+        };
+        for i in 0..last.edges.len() {
+            // The root path `last.nodes[..=i]` is kept, and the spur path from
+            // `last.nodes[i]` must differ from every found path sharing the root.
+            let root_edges = &last.edges[..i];
+            let blocked_edges = self
+                .found
+                .iter()
+                .filter(|p| p.edges.len() > i && &p.edges[..i] == root_edges)
+                .map(|p| p.edges[i])
+                .collect();
+            let spur = self.shortest_path(
+                last.nodes[i],
+                last.costs[i],
+                &last.nodes[..i],
+                &blocked_edges,
+            );
+            if let Some(spur) = spur {
+                let mut path = CostedPath {
+                    nodes: last.nodes[..i].to_vec(),
+                    edges: root_edges.to_vec(),
+                    costs: last.costs[..i].to_vec(),
+                };
+                path.nodes.extend(spur.nodes);
+                path.edges.extend(spur.edges);
+                path.costs.extend(spur.costs);
+                if self.seen.insert(path.edges.clone()) {
+                    let cost = *path.costs.last().unwrap();
+                    self.candidates.push(MinScored(cost, path));
+                }
+            }
+        }
+    }
+}
+
+impl<G, F, K> Iterator for KShortestSimplePaths<G, F, K>
+where
+    G: IntoEdges + Visitable,
+    G::NodeId: Eq + Hash,
+    G::EdgeId: Eq + Hash,
+    F: FnMut(G::EdgeRef) -> K,
+    K: Measure + Copy,
+{
+    type Item = (K, Vec<G::NodeId>, Vec<G::EdgeId>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let path = if self.found.is_empty() {
+            self.shortest_path(self.start, K::default(), &[], &HashSet::new())
+        } else {
+            self.push_deviations();
+            self.candidates.pop().map(|MinScored(_, path)| path)
+        };
+        match path {
+            Some(path) => {
+                let item = (
+                    *path.costs.last().unwrap(),
+                    path.nodes.clone(),
+                    path.edges.clone(),
+                );
+                self.seen.insert(path.edges.clone());
+                self.found.push(path);
+                Some(item)
+            }
+            None => {
+                self.done = true;
+                None
+            }
+        }
+    }
+}
@@ -246,16 +246,21 @@ where
     G: Visitable + IntoNeighbors,
     F: FnMut(G::NodeId),
 {
-    if visited.visit(source) {
-        for target in graph.neighbors(source) {
-            if !visited.is_visited(&target) {
+    // Follow the path iteratively: it can be as long as the graph, which
+    // would overflow the stack if every step were a recursive call.
+    let mut current = source;
+    while visited.visit(current) {
+        // Non-backtracking traversal, only continue from the first unvisited
+        // neighbor.
+        match graph
+            .neighbors(current)
+            .find(|target| !visited.is_visited(target))
+        {
+            Some(target) => {
                 visitor(target);
-                non_backtracking_dfs(graph, target, visited, visitor);
-
-                // Non-backtracking traversal, stop iterating over the
-                // neighbors.
-                break;
+                current = target;
             }
+            None => break,
         }
     }
 }
@@ -0,0 +1,344 @@
+//! Minimum cuts of undirected graphs.
+//!
+//! A *cut* splits the nodes of a graph in two sets, and its value is the total weight of the edges
+//! crossing between them.
+
+use std::collections::{BinaryHeap, HashMap};
+use std::hash::Hash;
+use std::ops::Sub;
+
+use indexmap::IndexMap;
+
+use super::flow::FlowNetwork;
+use crate::algo::Measure;
+use crate::scored::MaxScored;
+use crate::visit::{EdgeRef, IntoEdgeReferences, IntoNodeIdentifiers, NodeIndexable};
+
+/// Number the nodes of `graph` compactly, returning the nodes and a map from `to_index` to their
+/// position (`usize::MAX` for unused indices).
+fn compact_nodes<G>(graph: G) -> (Vec<G::NodeId>, Vec<usize>)
+where
+    G: IntoNodeIdentifiers + NodeIndexable,
+{
+    let nodes: Vec<_> = graph.node_identifiers().collect();
+    let mut position = vec![std::usize::MAX; graph.node_bound()];
+    for (i, &n) in nodes.iter().enumerate() {
+        position[graph.to_index(n)] = i;
+    }
+    (nodes, position)
+}
+
+/// \[Generic\] Compute a *global minimum cut* of an undirected graph using the [Stoer–Wagner
+/// algorithm][1].
+///
+/// The input graph is treated as if undirected. The function `edge_weight` should return the weight
+/// of a particular edge; weights must be non-negative. Parallel edges add up and self loops are
+/// ignored.
+///
+/// Return the value of the minimum cut and the nodes on one of its sides; the other side is made of
+/// the remaining nodes. A disconnected graph has a cut of value zero. Return `None` if the graph
+/// has fewer than two nodes.
+///
+/// Computes in **O(|V| (|E| + |V|) log |V|)** time.
+///
+/// [1]: https://en.wikipedia.org/wiki/Stoer%E2%80%93Wagner_algorithm
+///
+/// # Example
+/// ```rust
+/// use petgraph::prelude::*;
+/// use petgraph::algo::stoer_wagner_min_cut;
+///
+/// // Two triangles joined by a light edge.
+/// let graph = UnGraph::<(), u32>::from_edges(&[
+///     (0, 1, 3), (1, 2, 3), (2, 0, 3),
+///     (3, 4, 3), (4, 5, 3), (5, 3, 3),
+///     (2, 3, 1),
+/// ]);
+///
+/// let (value, mut side) = stoer_wagner_min_cut(&graph, |e| *e.weight()).unwrap();
+/// side.sort();
+/// assert_eq!(value, 1);
+/// assert!(side == [0, 1, 2].map(NodeIndex::new) || side == [3, 4, 5].map(NodeIndex::new));
+/// ```
+pub fn stoer_wagner_min_cut<G, F, K>(graph: G, mut edge_weight: F) -> Option<(K, Vec<G::NodeId>)>
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable,
+    F: FnMut(G::EdgeRef) -> K,
+    K: Measure + Copy,
+{
+    let (nodes, position) = compact_nodes(graph);
+    let n = nodes.len();
+    if n < 2 {
+        return None;
+    }
+
+    // Weighted adjacency between the merged nodes, and the original nodes
+    // that each merged node stands for.
+    let mut adjacency: Vec<IndexMap<usize, K>> = vec![IndexMap::new(); n];
+    for edge in graph.edge_references() {
+        let a = position[graph.to_index(edge.source())];
+        let b = position[graph.to_index(edge.target())];
+        if a != b {
+            let w = edge_weight(edge);
+            let ab = adjacency[a].entry(b).or_default();
+            *ab = *ab + w;
+            let ba = adjacency[b].entry(a).or_default();
+            *ba = *ba + w;
+        }
+    }
+    let mut members: Vec<Vec<usize>> = (0..n).map(|i| vec![i]).collect();
+    let mut active: Vec<usize> = (0..n).collect();
+
+    let mut best: Option<(K, Vec<usize>)> = None;
+    let mut added = vec![false; n];
+    let mut key = vec![K::default(); n];
+    while active.len() > 1 {
+        // A maximum adjacency ordering: repeatedly add the node most tightly
+        // connected to the nodes added so far.
+        let mut heap = BinaryHeap::with_capacity(active.len());
+        for &v in &active {
+            added[v] = false;
+            key[v] = K::default();
+            heap.push(MaxScored(K::default(), v));
+        }
+        let mut previous = active[0];
+        let mut last = active[0];
+        while let Some(MaxScored(_, v)) = heap.pop() {
+            if added[v] {
+                continue;
+            }
+            added[v] = true;
+            previous = last;
+            last = v;
+            for (&u, &w) in &adjacency[v] {
+                if !added[u] {
+                    key[u] = key[u] + w;
+                    heap.push(MaxScored(key[u], u));
+                }
+            }
+        }
+
+        // The cut separating `last` from everything else is a minimum cut
+        // between `previous` and `last`.
+        let (s, t) = (previous, last);
+        if best.as_ref().map_or(true, |&(value, _)| key[t] < value) {
+            best = Some((key[t], members[t].clone()));
+        }
+
+        // Merge `t` into `s`.
+        let t_edges = std::mem::take(&mut adjacency[t]);
+        for (u, w) in t_edges {
+            adjacency[u].swap_remove(&t);
+            if u != s {
+                let su = adjacency[s].entry(u).or_default();
+                *su = *su + w;
+                let us = adjacency[u].entry(s).or_default();
+                *us = *us + w;
+            }
+        }
+        let t_members = std::mem::take(&mut members[t]);
+        members[s].extend(t_members);
+        active.retain(|&v| v != t);
+    }
+
+    best.map(|(value, side)| (value, side.into_iter().map(|i| nodes[i]).collect()))
+}
+
+/// \[Generic\] Compute a [Gomory–Hu tree][1] of an undirected graph.
+///
+/// A Gomory–Hu tree has the same nodes as the graph, and for every pair of nodes, the lightest edge
+/// on the tree path between them has the weight of a minimum cut between them in the graph.
+/// Removing that edge splits the tree into the two sides of such a cut. This answers all-pairs
+/// minimum cut queries after only **|V| - 1** maximum flow computations.
+///
+/// The input graph is treated as if undirected. The function `capacity` should return the capacity
+/// of a particular edge; capacities must be non-negative. With unit capacities, the minimum cut
+/// between two nodes is their *local edge connectivity*: the number of edge-disjoint paths between
+/// them.
+///
+/// Uses [Gusfield's algorithm][2] with Dinic's maximum flow algorithm, in **O(|V|³ |E|)** time in
+/// the worst case.
+///
+/// [1]: https://en.wikipedia.org/wiki/Gomory%E2%80%93Hu_tree
+/// [2]: https://doi.org/10.1137/0219009
+///
+/// # Example
+/// ```rust
+/// use petgraph::prelude::*;
+/// use petgraph::algo::gomory_hu_tree;
+///
+/// let graph = UnGraph::<(), u32>::from_edges(&[
+///     (0, 1, 1), (1, 2, 2), (2, 3, 2), (3, 1, 1), (0, 3, 3),
+/// ]);
+/// let n: Vec<_> = graph.node_indices().collect();
+///
+/// let tree = gomory_hu_tree(&graph, |e| *e.weight());
+/// assert_eq!(tree.edges().count(), 3);
+/// assert_eq!(tree.min_cut_value(n[0], n[2]), Some(4));
+/// assert_eq!(tree.min_cut_value(n[1], n[3]), Some(4));
+/// assert_eq!(tree.edge_connectivity(), Some(4));
+///
+/// let (value, side) = tree.min_cut(n[0], n[2]).unwrap();
+/// assert_eq!(value, 4);
+/// assert!(side.contains(&n[0]) && !side.contains(&n[2]));
+/// ```
+pub fn gomory_hu_tree<G, F, K>(graph: G, mut capacity: F) -> GomoryHuTree<G::NodeId, K>
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable,
+    G::NodeId: Eq + Hash,
+    F: FnMut(G::EdgeRef) -> K,
+    K: Measure + Copy + Sub<K, Output = K>,
+{
+    let (nodes, position) = compact_nodes(graph);
+    let n = nodes.len();
+    let mut network = FlowNetwork::new(n);
+    for edge in graph.edge_references() {
+        let a = position[graph.to_index(edge.source())];
+        let b = position[graph.to_index(edge.target())];
+        if a != b {
+            let c = capacity(edge);
+            network.add_edge(a, b, c, c);
+        }
+    }
+
+    let mut parent = vec![0; n];
+    let mut weight = vec![K::default(); n];
+    for s in 1..n {
+        let t = parent[s];
+        let value = network.max_flow(s, t);
+        let side = network.source_side(s);
+        weight[s] = value;
+        for i in 0..n {
+            if i != s && side[i] && parent[i] == t {
+                parent[i] = s;
+            }
+        }
+        if side[parent[t]] {
+            parent[s] = parent[t];
+            parent[t] = s;
+            weight[s] = weight[t];
+            weight[t] = value;
+        }
+    }
+
+    let index = nodes.iter().enumerate().map(|(i, &n)| (n, i)).collect();
+    GomoryHuTree {
+        nodes,
+        index,
+        parent,
+        weight,
+    }
+}
+
+/// A Gomory–Hu tree, answering minimum cut queries between any two nodes.
+///
+/// Created with [`gomory_hu_tree`](fn.gomory_hu_tree.html).
+#[derive(Clone, Debug)]
+pub struct GomoryHuTree<N, K> {
+    nodes: Vec<N>,
+    index: HashMap<N, usize>,
+    /// The tree is rooted at the first node; every other node has an edge to its parent, with
+    /// weight `weight[i]`.
+    parent: Vec<usize>,
+    weight: Vec<K>,
+}
+
+impl<N, K> GomoryHuTree<N, K>
+where
+    N: Copy + Eq + Hash,
+    K: Measure + Copy,
+{
+    /// Return an iterator over the edges of the tree, as pairs of nodes and the value of the
+    /// minimum cut between them.
+    pub fn edges(&self) -> impl Iterator<Item = (N, N, K)> + '_ {
+        (1..self.nodes.len())
+            .map(move |i| (self.nodes[i], self.nodes[self.parent[i]], self.weight[i]))
+    }
+
+    /// Return the lightest tree edge on the path between `a` and `b`, as the position of its child
+    /// endpoint.
+    fn lightest_edge(&self, a: N, b: N) -> Option<usize> {
+        let a = *self.index.get(&a)?;
+        let b = *self.index.get(&b)?;
+        if a == b {
+            return None;
+        }
+        // The lightest edge between `a` and each of its ancestors.
+        let mut from_a = HashMap::new();
+        let mut lightest = None;
+        let mut v = a;
+        from_a.insert(v, lightest);
+        while v != 0 {
+            lightest = self.lighter(lightest, v);
+            v = self.parent[v];
+            from_a.insert(v, lightest);
+        }
+        let mut lightest = None;
+        let mut v = b;
+        while !from_a.contains_key(&v) {
+            lightest = self.lighter(lightest, v);
+            v = self.parent[v];
+        }
+        match from_a[&v] {
+            Some(edge) => self.lighter(lightest, edge),
+            None => lightest,
+        }
+    }
+
+    fn lighter(&self, current: Option<usize>, edge: usize) -> Option<usize> {
+        match current {
+            Some(c) if self.weight[c] <= self.weight[edge] => Some(c),
+            _ => Some(edge),
+        }
+    }
+
+    /// Return the value of a minimum cut between `a` and `b`.
+    ///
+    /// Return `None` if `a` and `b` are equal or not both nodes of the graph.
+    pub fn min_cut_value(&self, a: N, b: N) -> Option<K> {
+        self.lightest_edge(a, b).map(|edge| self.weight[edge])
+    }
+
+    /// Return the value of a minimum cut between `a` and `b`, and the nodes on the side of `a`.
+    ///
+    /// Return `None` if `a` and `b` are equal or not both nodes of the graph.
+    pub fn min_cut(&self, a: N, b: N) -> Option<(K, Vec<N>)> {
+        let edge = self.lightest_edge(a, b)?;
+        // The subtree below `edge` is one side of the cut.
+        let n = self.nodes.len();
+        let mut in_subtree: Vec<Option<bool>> = vec![None; n];
+        in_subtree[edge] = Some(true);
+        in_subtree[0] = Some(false);
+        let mut path = Vec::new();
+        for i in 0..n {
+            let mut v = i;
+            while in_subtree[v].is_none() {
+                path.push(v);
+                v = self.parent[v];
+            }
+            let found = in_subtree[v];
+            for u in path.drain(..) {
+                in_subtree[u] = found;
+            }
+        }
+        let a_side = in_subtree[self.index[&a]];
+        let side = (0..n)
+            .filter(|&i| in_subtree[i] == a_side)
+            .map(|i| self.nodes[i])
+            .collect();
+        Some((self.weight[edge], side))
+    }
+
+    /// Return the value of a global minimum cut of the graph: the lightest edge of the tree.
+    ///
+    /// With unit capacities, this is the *edge connectivity* of the graph: the least number of
+    /// edges whose removal disconnects it.
+    ///
+    /// Return `None` if the graph has fewer than two nodes.
+    pub fn edge_connectivity(&self) -> Option<K> {
+        let mut edges = 1..self.nodes.len();
+        let first = edges.next()?;
+        let lightest = edges.fold(first, |c, e| self.lighter(Some(c), e).unwrap());
+        Some(self.weight[lightest])
+    }
+}
@@ -4,17 +4,40 @@
 //! so that they are generally applicable. For now, some of these still require
 //! the `Graph` type.
 
+pub mod all_shortest_paths;
 pub mod astar;
 pub mod bellman_ford;
+pub mod bipartite;
+pub mod canonical;
+pub mod chordal;
+pub mod community;
+pub mod connectivity;
+pub mod covering;
 pub mod dijkstra;
+pub mod distance_measures;
 pub mod dominators;
+pub mod edit_distance;
 pub mod feedback_arc_set;
+pub mod feedback_vertex_set;
+mod flow;
 pub mod floyd_warshall;
+pub mod hamiltonian;
 pub mod isomorphism;
 pub mod k_shortest_path;
+pub mod k_shortest_simple_paths;
 pub mod matching;
+pub mod min_cut;
+pub mod partition;
+pub mod path_cover;
+pub mod planarity;
 pub mod simple_paths;
+pub mod spectral;
+pub mod steiner_tree;
+pub mod topological;
 pub mod tred;
+pub mod treewidth;
+pub mod tsp;
+pub mod two_sat;
 
 use std::collections::{BinaryHeap, HashMap};
 use std::num::NonZeroUsize;
@@ -33,18 +56,55 @@ use crate::scored::MinScored;
 use crate::visit::Walker;
 use crate::visit::{Data, IntoNodeReferences, NodeRef};
 
+pub use all_shortest_paths::{all_shortest_paths, shortest_path_counts};
 pub use astar::astar;
 pub use bellman_ford::{bellman_ford, find_negative_cycle};
+pub use bipartite::{bipartition, OddCycle};
+pub use canonical::{canonical_labeling, weisfeiler_lehman_hash};
+pub use chordal::{chordal_completion, is_chordal, ChordlessCycle, EliminationHeuristic};
+pub use community::{edge_betweenness, girvan_newman, Dendrogram, GirvanNewman};
+pub use connectivity::{
+    edge_connectivity, k_edge_connected_subgraphs, k_vertex_connected_components, node_connectivity,
+};
+pub use covering::{
+    approximate_vertex_cover, bipartite_vertex_cover, greedy_dominating_set,
+    greedy_independent_set, maximum_independent_set,
+};
 pub use dijkstra::dijkstra;
-pub use feedback_arc_set::greedy_feedback_arc_set;
+pub use distance_measures::{
+    center, diameter, eccentricities, eccentricity, periphery, radius, weighted_center,
+    weighted_diameter, weighted_eccentricities, weighted_eccentricity, weighted_periphery,
+    weighted_radius,
+};
+pub use edit_distance::{
+    approximate_graph_edit_distance, graph_edit_distance, graph_edit_distance_within_budget,
+};
+pub use feedback_arc_set::{greedy_feedback_arc_set, minimum_feedback_arc_set};
+pub use feedback_vertex_set::{approximate_feedback_vertex_set, minimum_feedback_vertex_set};
 pub use floyd_warshall::floyd_warshall;
+pub use hamiltonian::{
+    hamiltonian_cycle, hamiltonian_cycle_bitmask, hamiltonian_cycle_cancellable, hamiltonian_path,
+    hamiltonian_path_bitmask, hamiltonian_path_cancellable,
+};
 pub use isomorphism::{
-    is_isomorphic, is_isomorphic_matching, is_isomorphic_subgraph, is_isomorphic_subgraph_matching,
+    is_isomorphic, is_isomorphic_matching, is_isomorphic_matching_cancellable,
+    is_isomorphic_matching_within_budget, is_isomorphic_subgraph, is_isomorphic_subgraph_matching,
     subgraph_isomorphisms_iter,
 };
 pub use k_shortest_path::k_shortest_path;
+pub use k_shortest_simple_paths::k_shortest_simple_paths;
 pub use matching::{greedy_matching, maximum_matching, Matching};
-pub use simple_paths::all_simple_paths;
+pub use min_cut::{gomory_hu_tree, stoer_wagner_min_cut, GomoryHuTree};
+pub use partition::{fiduccia_mattheyses, kernighan_lin, multilevel_partition};
+pub use path_cover::{maximum_antichain, minimum_chain_cover, minimum_path_cover};
+pub use planarity::{is_planar, planar_embedding, KuratowskiSubgraph, PlanarEmbedding};
+pub use simple_paths::{all_simple_edge_paths, all_simple_paths};
+pub use spectral::{algebraic_connectivity, fiedler_vector, spectral_bisection};
+pub use steiner_tree::{metric_closure_steiner_tree, steiner_tree};
+pub use topological::{all_topological_sorts, topological_generations, toposort_by};
+pub use treewidth::treewidth;
+pub use tsp::{christofides, held_karp, nearest_neighbor_tour, or_opt, two_opt};
+pub use two_sat::{two_sat, Literal, UnsatCore};
 
 /// \[Generic\] Return the number of connected components of the graph.
 ///
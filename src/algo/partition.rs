@@ -0,0 +1,803 @@
+//! Partitioning graphs into parts of balanced weight with few edges between them.
+//!
+//! All functions treat the graph as undirected. Parallel edges add up and self loops are ignored. A
+//! partition is returned as the total weight of the edges between different parts, the *edge cut*,
+//! and a vector of part ids indexed by
+//! [`NodeIndexable::to_index`](../visit/trait.NodeIndexable.html), with `usize::MAX` for indices
+//! that are not in use by a node.
+
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+use std::ops::Sub;
+
+use crate::adj::List;
+use crate::algo::{greedy_matching, Measure};
+use crate::scored::MaxScored;
+use crate::visit::{EdgeRef, IntoEdgeReferences, IntoNodeIdentifiers, NodeIndexable};
+
+/// Graphs with at most this many nodes are bisected directly, rather than coarsened further.
+const COARSEST: usize = 32;
+
+/// A graph with weighted nodes and edges, with nodes numbered compactly and parallel edges merged.
+struct Weighted<K> {
+    adjacency: Vec<Vec<(usize, K)>>,
+    weights: Vec<usize>,
+}
+
+impl<K> Weighted<K>
+where
+    K: Measure + Copy + Sub<K, Output = K>,
+{
+    /// Merge parallel edges, given each node's edges in both directions.
+    fn new(edges: Vec<Vec<(usize, K)>>, weights: Vec<usize>) -> Self {
+        let mut slot = vec![std::usize::MAX; weights.len()];
+        let adjacency = edges
+            .into_iter()
+            .map(|edges| {
+                let mut merged: Vec<(usize, K)> = Vec::with_capacity(edges.len());
+                for (b, w) in edges {
+                    if slot[b] == std::usize::MAX {
+                        slot[b] = merged.len();
+                        merged.push((b, w));
+                    } else {
+                        merged[slot[b]].1 = merged[slot[b]].1 + w;
+                    }
+                }
+                for &(b, _) in &merged {
+                    slot[b] = std::usize::MAX;
+                }
+                merged
+            })
+            .collect();
+        Weighted { adjacency, weights }
+    }
+
+    fn len(&self) -> usize {
+        self.weights.len()
+    }
+
+    fn total_weight(&self) -> usize {
+        self.weights.iter().sum()
+    }
+
+    /// The total weight of the edges between different parts.
+    fn cut(&self, parts: &[usize]) -> K {
+        let mut cut = K::default();
+        for (a, edges) in self.adjacency.iter().enumerate() {
+            for &(b, w) in edges {
+                if a < b && parts[a] != parts[b] {
+                    cut = cut + w;
+                }
+            }
+        }
+        cut
+    }
+
+    /// The largest total weight of the edges at a node.
+    fn max_degree(&self) -> K {
+        let mut max = K::default();
+        for edges in &self.adjacency {
+            let degree = edges.iter().fold(K::default(), |sum, &(_, w)| sum + w);
+            if degree > max {
+                max = degree;
+            }
+        }
+        max
+    }
+
+    /// The subgraph induced by `nodes`, numbered in their order.
+    fn induced(&self, nodes: &[usize]) -> Self {
+        let mut position = vec![std::usize::MAX; self.len()];
+        for (i, &v) in nodes.iter().enumerate() {
+            position[v] = i;
+        }
+        let adjacency = nodes
+            .iter()
+            .map(|&v| {
+                self.adjacency[v]
+                    .iter()
+                    .filter(|&&(u, _)| position[u] != std::usize::MAX)
+                    .map(|&(u, w)| (position[u], w))
+                    .collect()
+            })
+            .collect();
+        let weights = nodes.iter().map(|&v| self.weights[v]).collect();
+        Weighted { adjacency, weights }
+    }
+
+    /// Contract the edges of a heavy-edge matching whose ends weigh at most `max_weight` together.
+    /// Returns the coarse graph and the coarse node of each node, or `None` if too few edges could
+    /// be contracted.
+    fn coarsen(&self, max_weight: usize) -> Option<(Self, Vec<usize>)> {
+        let n = self.len();
+        // The greedy matching tries nodes in order and follows their neighbors in order, so list
+        // the light nodes first and their heaviest edges first.
+        let mut order: Vec<usize> = (0..n).collect();
+        order.sort_by_key(|&v| self.weights[v]);
+        let mut position = vec![0; n];
+        for (i, &v) in order.iter().enumerate() {
+            position[v] = i;
+        }
+        let mut candidates = List::<(), usize>::with_capacity(n);
+        for &v in &order {
+            let mut edges: Vec<(usize, K)> = self.adjacency[v]
+                .iter()
+                .filter(|&&(u, _)| self.weights[v] + self.weights[u] <= max_weight)
+                .cloned()
+                .collect();
+            edges.sort_by(|a, b| heavier_first(&a.1, &b.1));
+            candidates.add_node_from_edges(edges.into_iter().map(|(u, _)| (position[u], ())));
+        }
+        let matching = greedy_matching(&candidates);
+        let matched = matching.len();
+        if matched * 10 < n {
+            return None;
+        }
+        let mate: Vec<usize> = (0..n)
+            .map(|v| {
+                matching
+                    .mate(position[v])
+                    .map_or(std::usize::MAX, |m| order[m])
+            })
+            .collect();
+
+        let mut coarse = vec![std::usize::MAX; n];
+        let mut weights = Vec::with_capacity(n - matched);
+        for &v in &order {
+            if coarse[v] != std::usize::MAX {
+                continue;
+            }
+            coarse[v] = weights.len();
+            let mut weight = self.weights[v];
+            if mate[v] != std::usize::MAX {
+                coarse[mate[v]] = weights.len();
+                weight += self.weights[mate[v]];
+            }
+            weights.push(weight);
+        }
+        let mut edges = vec![Vec::new(); weights.len()];
+        for (a, adjacency) in self.adjacency.iter().enumerate() {
+            for &(b, w) in adjacency {
+                if coarse[a] != coarse[b] {
+                    edges[coarse[a]].push((coarse[b], w));
+                }
+            }
+        }
+        Some((Weighted::new(edges, weights), coarse))
+    }
+}
+
+/// Order heavier keys first, and incomparable keys such as NaN last, so that sorting by it is
+/// well defined.
+fn heavier_first<K: PartialOrd>(a: &K, b: &K) -> Ordering {
+    let is_nan = |k: &K| k.partial_cmp(k).is_none();
+    b.partial_cmp(a)
+        .unwrap_or_else(|| is_nan(a).cmp(&is_nan(b)))
+}
+
+/// Number the nodes of `graph` compactly and collect their weights and edges.
+fn compact<G, F, FN, K>(
+    graph: G,
+    mut edge_weight: F,
+    node_weight: FN,
+) -> (Vec<G::NodeId>, Weighted<K>)
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable,
+    F: FnMut(G::EdgeRef) -> K,
+    FN: FnMut(G::NodeId) -> usize,
+    K: Measure + Copy + Sub<K, Output = K>,
+{
+    let nodes: Vec<_> = graph.node_identifiers().collect();
+    let mut position = vec![std::usize::MAX; graph.node_bound()];
+    for (i, &v) in nodes.iter().enumerate() {
+        position[graph.to_index(v)] = i;
+    }
+    let mut edges = vec![Vec::new(); nodes.len()];
+    for edge in graph.edge_references() {
+        let a = position[graph.to_index(edge.source())];
+        let b = position[graph.to_index(edge.target())];
+        if a != b {
+            let w = edge_weight(edge);
+            edges[a].push((b, w));
+            edges[b].push((a, w));
+        }
+    }
+    let weights = nodes.iter().cloned().map(node_weight).collect();
+    (nodes, Weighted::new(edges, weights))
+}
+
+/// The part of each node, indexed by `to_index`.
+fn by_index<G>(graph: G, nodes: &[G::NodeId], parts: &[usize]) -> Vec<usize>
+where
+    G: NodeIndexable,
+{
+    let mut result = vec![std::usize::MAX; graph.node_bound()];
+    for (&v, &part) in nodes.iter().zip(parts) {
+        result[graph.to_index(v)] = part;
+    }
+    result
+}
+
+/// The part of each node in `initial`, which is indexed by `to_index`.
+fn from_index<G>(graph: G, nodes: &[G::NodeId], initial: &[usize]) -> Vec<usize>
+where
+    G: NodeIndexable,
+{
+    nodes
+        .iter()
+        .map(|&v| {
+            let part = initial[graph.to_index(v)];
+            assert!(
+                part < 2,
+                "initial bisection has a part id other than 0 or 1"
+            );
+            part
+        })
+        .collect()
+}
+
+/// The weight of the edges from each node to its own part and to the other part of a bisection.
+struct Gains<K> {
+    internal: Vec<K>,
+    external: Vec<K>,
+    /// The largest degree, which keeps `key` non-negative.
+    offset: K,
+}
+
+impl<K> Gains<K>
+where
+    K: Measure + Copy + Sub<K, Output = K>,
+{
+    fn new(graph: &Weighted<K>, side: &[usize]) -> Self {
+        let mut internal = vec![K::default(); graph.len()];
+        let mut external = vec![K::default(); graph.len()];
+        for (a, edges) in graph.adjacency.iter().enumerate() {
+            for &(b, w) in edges {
+                if side[a] == side[b] {
+                    internal[a] = internal[a] + w;
+                } else {
+                    external[a] = external[a] + w;
+                }
+            }
+        }
+        Gains {
+            internal,
+            external,
+            offset: graph.max_degree(),
+        }
+    }
+
+    /// How much moving `v` to the other part would reduce the cut, plus `offset`.
+    fn key(&self, v: usize) -> K {
+        (self.external[v] + self.offset) - self.internal[v]
+    }
+
+    /// Move `v` to the other part, returning the new cut.
+    fn flip(&mut self, graph: &Weighted<K>, side: &mut [usize], v: usize, cut: K) -> K {
+        let cut = (cut + self.internal[v]) - self.external[v];
+        std::mem::swap(&mut self.internal[v], &mut self.external[v]);
+        for &(u, w) in &graph.adjacency[v] {
+            if side[u] == side[v] {
+                self.internal[u] = self.internal[u] - w;
+                self.external[u] = self.external[u] + w;
+            } else {
+                self.external[u] = self.external[u] - w;
+                self.internal[u] = self.internal[u] + w;
+            }
+        }
+        side[v] = 1 - side[v];
+        cut
+    }
+}
+
+/// Improve a bisection by Kernighan–Lin passes, returning its cut.
+fn kernighan_lin_refine<K>(graph: &Weighted<K>, side: &mut [usize]) -> K
+where
+    K: Measure + Copy + Sub<K, Output = K>,
+{
+    let n = graph.len();
+    let mut weight_to = vec![None; n];
+    loop {
+        let mut gains = Gains::new(graph, side);
+        let start_cut = graph.cut(side);
+        let mut cut = start_cut;
+        let (mut best_cut, mut best_len) = (cut, 0);
+        let mut locked = vec![false; n];
+        let mut swaps = Vec::new();
+        loop {
+            let mut candidates = [Vec::new(), Vec::new()];
+            for v in (0..n).filter(|&v| !locked[v]) {
+                candidates[side[v]].push((gains.key(v), v));
+            }
+            for candidates in &mut candidates {
+                candidates.sort_by(|a, b| heavier_first(&a.0, &b.0));
+            }
+            if candidates[0].is_empty() || candidates[1].is_empty() {
+                break;
+            }
+            // Swapping `a` and `b` gains their gains, less twice the weight of
+            // an edge between them, so only pairs of large gains are tried.
+            let mut best: Option<(K, usize, usize)> = None;
+            for &(key_a, a) in &candidates[0] {
+                let beaten = |bound: K, best: &Option<(K, usize, usize)>| {
+                    best.as_ref().map_or(false, |&(score, _, _)| bound <= score)
+                };
+                if beaten(key_a + candidates[1][0].0, &best) {
+                    break;
+                }
+                for &(u, w) in &graph.adjacency[a] {
+                    weight_to[u] = Some(w);
+                }
+                for &(key_b, b) in &candidates[1] {
+                    if beaten(key_a + key_b, &best) {
+                        break;
+                    }
+                    let w = weight_to[b].unwrap_or_default();
+                    let score = (key_a - w) + (key_b - w);
+                    if !beaten(score, &best) {
+                        best = Some((score, a, b));
+                    }
+                }
+                for &(u, _) in &graph.adjacency[a] {
+                    weight_to[u] = None;
+                }
+            }
+            let (_, a, b) = best.unwrap();
+            cut = gains.flip(graph, side, a, cut);
+            cut = gains.flip(graph, side, b, cut);
+            locked[a] = true;
+            locked[b] = true;
+            swaps.push((a, b));
+            if cut < best_cut {
+                best_cut = cut;
+                best_len = swaps.len();
+            }
+        }
+        for &(a, b) in &swaps[best_len..] {
+            side[a] = 1 - side[a];
+            side[b] = 1 - side[b];
+        }
+        // Rounding can make the tracked cut look smaller than it is, so check
+        // the improvement on the recomputed cut before another pass.
+        if best_len == 0 || graph.cut(side) >= start_cut {
+            for &(a, b) in &swaps[..best_len] {
+                side[a] = 1 - side[a];
+                side[b] = 1 - side[b];
+            }
+            return start_cut;
+        }
+    }
+}
+
+/// How far the parts of a bisection are above their weight limits.
+fn overweight(part_weights: [usize; 2], limits: [usize; 2]) -> usize {
+    part_weights[0].saturating_sub(limits[0]) + part_weights[1].saturating_sub(limits[1])
+}
+
+/// Move nodes out of an overweight part, the ones that reduce the cut most first, until neither
+/// part is above its limit or no single move brings them closer.
+fn rebalance<K>(graph: &Weighted<K>, side: &mut [usize], limits: [usize; 2])
+where
+    K: Measure + Copy + Sub<K, Output = K>,
+{
+    let mut part_weights = [0, 0];
+    for v in 0..graph.len() {
+        part_weights[side[v]] += graph.weights[v];
+    }
+    let mut gains = Gains::new(graph, side);
+    let mut cut = graph.cut(side);
+    loop {
+        let over = overweight(part_weights, limits);
+        if over == 0 {
+            return;
+        }
+        let mut chosen: Option<(K, usize)> = None;
+        for (v, &s) in side.iter().enumerate() {
+            let mut moved = part_weights;
+            moved[s] -= graph.weights[v];
+            moved[1 - s] += graph.weights[v];
+            let key = gains.key(v);
+            if overweight(moved, limits) < over && chosen.map_or(true, |(best, _)| key > best) {
+                chosen = Some((key, v));
+            }
+        }
+        let v = match chosen {
+            Some((_, v)) => v,
+            None => return,
+        };
+        part_weights[side[v]] -= graph.weights[v];
+        part_weights[1 - side[v]] += graph.weights[v];
+        cut = gains.flip(graph, side, v, cut);
+    }
+}
+
+/// Improve a bisection by Fiduccia–Mattheyses passes, keeping part `s` at most `limits[s]` heavy,
+/// or making it lighter if it is above. Returns the cut.
+///
+/// Within a pass, a part may go over its limit by up to the heaviest node, so that balanced
+/// bisections can still trade nodes one move at a time, but only the best state of the pass is
+/// kept.
+fn fiduccia_mattheyses_refine<K>(graph: &Weighted<K>, side: &mut [usize], limits: [usize; 2]) -> K
+where
+    K: Measure + Copy + Sub<K, Output = K>,
+{
+    // A pass only tries the best node of each part, which may never lead to a balanced state.
+    rebalance(graph, side, limits);
+    let n = graph.len();
+    let heaviest = graph.weights.iter().cloned().max().unwrap_or(0);
+    loop {
+        let mut gains = Gains::new(graph, side);
+        let start_cut = graph.cut(side);
+        let mut cut = start_cut;
+        let mut part_weights = [0, 0];
+        for v in 0..n {
+            part_weights[side[v]] += graph.weights[v];
+        }
+        let start_over = overweight(part_weights, limits);
+        let (mut best_over, mut best_cut, mut best_len) = (start_over, cut, 0);
+        let mut heaps = [BinaryHeap::new(), BinaryHeap::new()];
+        for v in 0..n {
+            heaps[side[v]].push(MaxScored(gains.key(v), v));
+        }
+        let mut locked = vec![false; n];
+        let mut moves = Vec::new();
+        loop {
+            // Take the node of largest gain whose move the weight limits
+            // allow, from either part.
+            let mut chosen: Option<(K, usize)> = None;
+            for (s, heap) in heaps.iter_mut().enumerate() {
+                while let Some(&MaxScored(key, v)) = heap.peek() {
+                    if locked[v] || key != gains.key(v) {
+                        heap.pop();
+                        continue;
+                    }
+                    let to = part_weights[1 - s] + graph.weights[v];
+                    let allowed = to <= limits[1 - s] + heaviest
+                        || (part_weights[s] > limits[s] && to < part_weights[s]);
+                    if allowed && chosen.as_ref().map_or(true, |&(best, _)| key > best) {
+                        chosen = Some((key, s));
+                    }
+                    break;
+                }
+            }
+            let s = match chosen {
+                Some((_, s)) => s,
+                None => break,
+            };
+            let MaxScored(_, v) = heaps[s].pop().unwrap();
+            cut = gains.flip(graph, side, v, cut);
+            locked[v] = true;
+            part_weights[s] -= graph.weights[v];
+            part_weights[1 - s] += graph.weights[v];
+            for &(u, _) in &graph.adjacency[v] {
+                if !locked[u] {
+                    heaps[side[u]].push(MaxScored(gains.key(u), u));
+                }
+            }
+            moves.push(v);
+            let over = overweight(part_weights, limits);
+            if over < best_over || (over == best_over && cut < best_cut) {
+                best_over = over;
+                best_cut = cut;
+                best_len = moves.len();
+            }
+        }
+        for &v in &moves[best_len..] {
+            side[v] = 1 - side[v];
+        }
+        // As in `kernighan_lin_refine`, only repeat on a real improvement.
+        let improved = best_over < start_over || graph.cut(side) < start_cut;
+        if best_len == 0 || !improved {
+            for &v in &moves[..best_len] {
+                side[v] = 1 - side[v];
+            }
+            return start_cut;
+        }
+    }
+}
+
+/// Bisect by growing part 0 breadth-first from a few start nodes until it reaches `target` weight,
+/// refining each with Fiduccia–Mattheyses, and keep the best.
+fn grow_bisection<K>(graph: &Weighted<K>, target: usize, limits: [usize; 2]) -> Vec<usize>
+where
+    K: Measure + Copy + Sub<K, Output = K>,
+{
+    let n = graph.len();
+    let mut best: Option<(usize, K, Vec<usize>)> = None;
+    for start in (0..4).map(|i| i * n / 4).filter(|&v| v < n) {
+        let mut side = vec![1; n];
+        let mut weight = 0;
+        let mut queue = std::collections::VecDeque::new();
+        let mut next_start = start;
+        while weight < target {
+            let v = match queue.pop_front() {
+                Some(v) => v,
+                None => {
+                    // Continue from another component.
+                    while side[next_start] == 0 {
+                        next_start = (next_start + 1) % n;
+                    }
+                    next_start
+                }
+            };
+            if side[v] == 0 {
+                continue;
+            }
+            side[v] = 0;
+            weight += graph.weights[v];
+            queue.extend(
+                graph.adjacency[v]
+                    .iter()
+                    .map(|&(u, _)| u)
+                    .filter(|&u| side[u] == 1),
+            );
+        }
+        let cut = fiduccia_mattheyses_refine(graph, &mut side, limits);
+        let mut part_weights = [0, 0];
+        for v in 0..n {
+            part_weights[side[v]] += graph.weights[v];
+        }
+        let over = overweight(part_weights, limits);
+        let better = best.as_ref().map_or(true, |&(best_over, best_cut, _)| {
+            over < best_over || (over == best_over && cut < best_cut)
+        });
+        if better {
+            best = Some((over, cut, side));
+        }
+    }
+    best.map_or_else(Vec::new, |(_, _, side)| side)
+}
+
+/// Bisect by coarsening, bisecting the coarsest graph and refining the bisection at each level on
+/// the way back.
+fn multilevel_bisection<K>(
+    graph: &Weighted<K>,
+    target: usize,
+    limits: [usize; 2],
+    max_weight: usize,
+) -> Vec<usize>
+where
+    K: Measure + Copy + Sub<K, Output = K>,
+{
+    if graph.len() > COARSEST {
+        if let Some((coarse, map)) = graph.coarsen(max_weight) {
+            let coarse_side = multilevel_bisection(&coarse, target, limits, max_weight);
+            let mut side: Vec<usize> = map.iter().map(|&c| coarse_side[c]).collect();
+            fiduccia_mattheyses_refine(graph, &mut side, limits);
+            return side;
+        }
+    }
+    grow_bisection(graph, target, limits)
+}
+
+/// Split `graph` into `k` parts numbered from `first`, by recursive bisection, writing the part of
+/// each node `nodes[i]` to `parts`.
+fn recursive_bisection<K>(
+    graph: &Weighted<K>,
+    nodes: &[usize],
+    k: usize,
+    first: usize,
+    tolerance: f64,
+    parts: &mut [usize],
+) where
+    K: Measure + Copy + Sub<K, Output = K>,
+{
+    if k == 1 || graph.len() == 0 {
+        for &v in nodes {
+            parts[v] = first;
+        }
+        return;
+    }
+    let ks = [k / 2, k - k / 2];
+    let total = graph.total_weight();
+    let share = |s: usize| total as f64 * ks[s] as f64 / k as f64;
+    let limit = |s: usize| {
+        let limit = (share(s) * (1. + tolerance)).floor() as usize;
+        limit.max(share(s).ceil() as usize)
+    };
+    let limits = [limit(0), limit(1)];
+    let target = share(0).round() as usize;
+    // Coarse nodes may weigh up to a fraction of the coarsest graph.
+    let max_weight = (3 * total / (2 * COARSEST)).max(2);
+    let side = multilevel_bisection(graph, target, limits, max_weight);
+    for s in 0..2 {
+        let members: Vec<usize> = (0..graph.len()).filter(|&v| side[v] == s).collect();
+        let subgraph = graph.induced(&members);
+        let sub_nodes: Vec<usize> = members.iter().map(|&v| nodes[v]).collect();
+        let sub_first = first + s * ks[0];
+        recursive_bisection(&subgraph, &sub_nodes, ks[s], sub_first, tolerance, parts);
+    }
+}
+
+/// \[Generic\] Improve a bisection of a graph with the [Kernighan–Lin algorithm][1], which swaps
+/// pairs of nodes between the two parts.
+///
+/// The function `edge_weight` should return the weight of a particular edge; weights must be
+/// non-negative. `initial` gives the part of each node, 0 or 1, indexed by `to_index`; if it is
+/// `None`, the first half of the nodes in `node_identifiers` order are in part 0. As nodes are
+/// swapped, the parts keep their sizes.
+///
+/// Returns the edge cut and the part of each node, as described in the [module
+/// documentation](index.html).
+///
+/// Each pass takes **O(|V|³)** time in the worst case, but usually much less, and passes repeat
+/// while they reduce the cut.
+///
+/// **Panics** if `initial` has a part id other than 0 or 1.
+///
+/// **Reference**
+///
+/// * B. W. Kernighan, S. Lin; *An efficient heuristic procedure for partitioning graphs*
+///
+/// [1]: https://en.wikipedia.org/wiki/Kernighan%E2%80%93Lin_algorithm
+///
+/// # Example
+/// ```rust
+/// use petgraph::prelude::*;
+/// use petgraph::algo::kernighan_lin;
+///
+/// // Two squares joined by two edges, starting from a poor bisection.
+/// let graph = UnGraph::<(), u32>::from_edges(&[
+///     (0, 1, 1), (1, 2, 1), (2, 3, 1), (3, 0, 1),
+///     (4, 5, 1), (5, 6, 1), (6, 7, 1), (7, 4, 1),
+///     (0, 4, 1), (2, 6, 1),
+/// ]);
+///
+/// let (cut, parts) = kernighan_lin(&graph, Some(&[0, 1, 0, 1, 0, 1, 0, 1]), |e| *e.weight());
+/// assert_eq!(cut, 2);
+/// assert!(parts == [0, 0, 0, 0, 1, 1, 1, 1] || parts == [1, 1, 1, 1, 0, 0, 0, 0]);
+/// ```
+pub fn kernighan_lin<G, F, K>(
+    graph: G,
+    initial: Option<&[usize]>,
+    edge_weight: F,
+) -> (K, Vec<usize>)
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable,
+    F: FnMut(G::EdgeRef) -> K,
+    K: Measure + Copy + Sub<K, Output = K>,
+{
+    let (nodes, weighted) = compact(graph, edge_weight, |_| 1);
+    let mut side = match initial {
+        Some(initial) => from_index(graph, &nodes, initial),
+        None => (0..nodes.len())
+            .map(|i| usize::from(i >= nodes.len() / 2))
+            .collect(),
+    };
+    let cut = kernighan_lin_refine(&weighted, &mut side);
+    (cut, by_index(graph, &nodes, &side))
+}
+
+/// \[Generic\] Find a balanced bisection of a graph with weighted nodes, with the
+/// [Fiduccia–Mattheyses algorithm][1], which moves one node at a time between the two parts.
+///
+/// The function `edge_weight` should return the weight of a particular edge; weights must be
+/// non-negative. `node_weight` returns the weight of a node, and each part may weigh up to
+/// `1 + imbalance` times half the total weight, rounded up. If no bisection keeps within that, the
+/// function minimizes how far the parts go above it before minimizing the cut.
+///
+/// `initial` gives the part of each node, 0 or 1, indexed by `to_index`; if it is `None`, the
+/// function grows part 0 breadth-first from a few start nodes and keeps the best result.
+///
+/// Returns the edge cut and the part of each node, as described in the [module
+/// documentation](index.html).
+///
+/// Each pass takes **O(|E| log |V|)** time, and passes repeat while they improve the bisection.
+///
+/// **Panics** if `initial` has a part id other than 0 or 1.
+///
+/// **Reference**
+///
+/// * C. M. Fiduccia, R. M. Mattheyses; *A linear-time heuristic for improving network partitions*
+///
+/// [1]: https://en.wikipedia.org/wiki/Fiduccia%E2%80%93Mattheyses_algorithm
+///
+/// # Example
+/// ```rust
+/// use petgraph::prelude::*;
+/// use petgraph::algo::fiduccia_mattheyses;
+///
+/// // A path, where node 0 weighs as much as all the others.
+/// let graph = UnGraph::<u32, u32>::from_edges(&[(0, 1, 1), (1, 2, 1), (2, 3, 1), (3, 4, 1)]);
+/// let node_weight = |n: NodeIndex| if n.index() == 0 { 4 } else { 1 };
+///
+/// let (cut, parts) = fiduccia_mattheyses(&graph, None, 0.0, |e| *e.weight(), node_weight);
+/// assert_eq!(cut, 1);
+/// assert_eq!(parts[1..], [1 - parts[0]; 4]);
+/// ```
+pub fn fiduccia_mattheyses<G, F, FN, K>(
+    graph: G,
+    initial: Option<&[usize]>,
+    imbalance: f64,
+    edge_weight: F,
+    node_weight: FN,
+) -> (K, Vec<usize>)
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable,
+    F: FnMut(G::EdgeRef) -> K,
+    FN: FnMut(G::NodeId) -> usize,
+    K: Measure + Copy + Sub<K, Output = K>,
+{
+    let (nodes, weighted) = compact(graph, edge_weight, node_weight);
+    let total = weighted.total_weight();
+    let half = total as f64 / 2.;
+    let limit = ((half * (1. + imbalance)).floor() as usize).max(half.ceil() as usize);
+    let limits = [limit, limit];
+    let mut side = match initial {
+        Some(initial) => from_index(graph, &nodes, initial),
+        None => grow_bisection(&weighted, half.round() as usize, limits),
+    };
+    let cut = fiduccia_mattheyses_refine(&weighted, &mut side, limits);
+    (cut, by_index(graph, &nodes, &side))
+}
+
+/// \[Generic\] Partition a graph with weighted nodes into `k` balanced parts with a small edge cut,
+/// by multilevel recursive bisection.
+///
+/// The function `edge_weight` should return the weight of a particular edge; weights must be
+/// non-negative. `node_weight` returns the weight of a node. The partitioner tries to keep each
+/// part within `1 + imbalance` times the average part weight, rounded up, as
+/// [`fiduccia_mattheyses`](fn.fiduccia_mattheyses.html) does.
+///
+/// Each bisection coarsens the graph by contracting a matching of its heaviest edges, over and
+/// over, bisects the small coarsest graph, and then undoes the contractions, refining the bisection
+/// with Fiduccia–Mattheyses passes at each level. The parts are then bisected in turn, until there
+/// are `k` of them.
+///
+/// Returns the edge cut and the part of each node, from `0` to `k - 1`, as described in the [module
+/// documentation](index.html). Some parts are empty if there are fewer than `k` nodes.
+///
+/// Computes in about **O((|V| + |E|) log |V| log k)** time.
+///
+/// **Panics** if `k` is zero.
+///
+/// **Reference**
+///
+/// * G. Karypis, V. Kumar; *A fast and high quality multilevel scheme for partitioning irregular
+///   graphs*
+///
+/// # Example
+/// ```rust
+/// use petgraph::prelude::*;
+/// use petgraph::algo::multilevel_partition;
+///
+/// // A 16 × 16 grid, which splits into 4 quadrants with a cut of 32.
+/// let mut edges = Vec::new();
+/// for i in 0..16 {
+///     for j in 0..16 {
+///         if i < 15 { edges.push((i * 16 + j, (i + 1) * 16 + j)); }
+///         if j < 15 { edges.push((i * 16 + j, i * 16 + j + 1)); }
+///     }
+/// }
+/// let graph = UnGraph::<(), ()>::from_edges(&edges);
+///
+/// let (cut, parts) = multilevel_partition(&graph, 4, 0.05, |_| 1, |_| 1);
+/// assert_eq!(cut, 32);
+/// for part in 0..4 {
+///     assert!(parts.iter().filter(|&&p| p == part).count() <= 68);
+/// }
+/// ```
+pub fn multilevel_partition<G, F, FN, K>(
+    graph: G,
+    k: usize,
+    imbalance: f64,
+    edge_weight: F,
+    node_weight: FN,
+) -> (K, Vec<usize>)
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable,
+    F: FnMut(G::EdgeRef) -> K,
+    FN: FnMut(G::NodeId) -> usize,
+    K: Measure + Copy + Sub<K, Output = K>,
+{
+    assert!(k > 0, "cannot partition a graph into zero parts");
+    let (nodes, weighted) = compact(graph, edge_weight, node_weight);
+    // Split the imbalance between the levels of bisection, so that it
+    // compounds to at most `imbalance`.
+    let depth = (0u32..).find(|&d| 1 << d >= k).unwrap();
+    let tolerance = (1. + imbalance).powf(1. / f64::from(depth.max(1))) - 1.;
+    let all: Vec<usize> = (0..nodes.len()).collect();
+    let mut parts = vec![0; nodes.len()];
+    recursive_bisection(&weighted, &all, k, 0, tolerance, &mut parts);
+    (weighted.cut(&parts), by_index(graph, &nodes, &parts))
+}
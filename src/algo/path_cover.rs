@@ -0,0 +1,202 @@
+//! Path covers, chain covers and antichains of directed acyclic graphs.
+//!
+//! A *path cover* of a DAG is a set of vertex-disjoint paths that together contain every node. A
+//! *chain* is a set of nodes that a single path of the DAG passes through, possibly along with
+//! other nodes; a *chain cover* partitions the nodes into chains, and an *antichain* is a set of
+//! nodes no two of which are on a common path. For example, if the DAG orders tasks, a chain is
+//! work for one worker, and the fewest chains that cover the DAG is the fewest workers that can
+//! do all the tasks without waiting for each other.
+//!
+//! Both covers reduce to a maximum matching in a bipartite *split graph*, with a copy of each node
+//! on either side, and an edge from the first copy of `a` to the second copy of `b` for each edge
+//! `a → b` of the DAG, or of its transitive closure for chains. Each matched edge joins two paths
+//! into one, so the fewest paths is the number of nodes minus the size of the matching. By
+//! Dilworth's theorem, the largest antichain has as many nodes as the smallest chain cover, and
+//! can be read off a minimum vertex cover of the split graph.
+
+use super::tred::dag_transitive_reduction_closure;
+use super::{bipartite_vertex_cover, maximum_matching, toposort, Cycle};
+use crate::adj::{List, UnweightedList};
+use crate::graph::{NodeIndex, UnGraph};
+use crate::visit::{
+    IntoNeighbors, IntoNeighborsDirected, IntoNodeIdentifiers, NodeCount, NodeIndexable, Visitable,
+};
+use crate::Direction::Incoming;
+
+/// \[Generic\] Find a minimum path cover of a directed acyclic graph: the fewest vertex-disjoint
+/// paths that together contain every node.
+///
+/// Returns the paths, each as its nodes in order, sorted by the topological order of their first
+/// nodes, or a node on a cycle if the graph is not acyclic.
+///
+/// Computes in **O(|V|³)** time, dominated by [`maximum_matching`](fn.maximum_matching.html).
+///
+/// # Example
+///
+/// ```
+/// use petgraph::algo::minimum_path_cover;
+/// use petgraph::prelude::*;
+///
+/// // Two tasks that both need 0 done first, and a third task that needs 1.
+/// let g = DiGraph::<(), ()>::from_edges(&[(0, 1), (0, 2), (1, 3)]);
+/// let paths = minimum_path_cover(&g).unwrap();
+/// assert_eq!(paths.len(), 2);
+/// assert_eq!(paths[0][0], 0.into());
+/// ```
+pub fn minimum_path_cover<G>(graph: G) -> Result<Vec<Vec<G::NodeId>>, Cycle<G::NodeId>>
+where
+    G: IntoNeighborsDirected + IntoNodeIdentifiers + NodeIndexable + Visitable,
+{
+    let (order, dag) = toposorted_list(graph)?;
+    let split = split_graph(&dag);
+    Ok(paths(&order, &split))
+}
+
+/// \[Generic\] Find a minimum chain cover of a directed acyclic graph: the fewest paths that
+/// together contain every node, where the paths may share nodes.
+///
+/// Returns a partition of the nodes into chains, each sorted in topological order, so that a
+/// path of the graph passes through the nodes of each chain in order. The chains are sorted by
+/// the topological order of their first nodes. If the graph is not acyclic, returns a node on a
+/// cycle instead.
+///
+/// Matches in the split graph of the transitive closure, which
+/// [`dag_transitive_reduction_closure`](tred/fn.dag_transitive_reduction_closure.html)
+/// computes. Computes in **O(|V|³)** time.
+///
+/// **Reference**
+///
+/// * D. R. Fulkerson; *Note on Dilworth's decomposition theorem for partially ordered sets*
+///
+/// # Example
+///
+/// ```
+/// use petgraph::algo::{minimum_chain_cover, minimum_path_cover};
+/// use petgraph::prelude::*;
+///
+/// // Tasks 0 and 1 both come before 2, and 3 and 4 both come after it.
+/// let g = DiGraph::<(), ()>::from_edges(&[(0, 2), (1, 2), (2, 3), (2, 4)]);
+/// assert_eq!(minimum_path_cover(&g).unwrap().len(), 3);
+///
+/// // Two workers suffice, as paths through 2 can share it.
+/// let chains = minimum_chain_cover(&g).unwrap();
+/// assert_eq!(chains.len(), 2);
+/// assert_eq!(chains.iter().map(|chain| chain.len()).sum::<usize>(), 5);
+/// ```
+pub fn minimum_chain_cover<G>(graph: G) -> Result<Vec<Vec<G::NodeId>>, Cycle<G::NodeId>>
+where
+    G: IntoNeighborsDirected + IntoNodeIdentifiers + NodeIndexable + Visitable,
+{
+    let (order, dag) = toposorted_list(graph)?;
+    let (_, closure) = dag_transitive_reduction_closure(&dag);
+    let split = split_graph(&closure);
+    Ok(paths(&order, &split))
+}
+
+/// \[Generic\] Find a maximum antichain of a directed acyclic graph: a largest set of nodes no two
+/// of which are on a common path.
+///
+/// By Dilworth's theorem, it has as many nodes as a
+/// [`minimum_chain_cover`](fn.minimum_chain_cover.html) has chains. Takes the nodes that have
+/// neither copy in a [`bipartite_vertex_cover`](fn.bipartite_vertex_cover.html) of the split graph
+/// of the transitive closure.
+///
+/// Returns the nodes of the antichain, in topological order, or a node on a cycle if the graph is
+/// not acyclic. Computes in **O(|V|³)** time.
+///
+/// **Reference**
+///
+/// * R. P. Dilworth; *A decomposition theorem for partially ordered sets*
+///
+/// # Example
+///
+/// ```
+/// use petgraph::algo::maximum_antichain;
+/// use petgraph::prelude::*;
+///
+/// let g = DiGraph::<(), ()>::from_edges(&[(0, 2), (1, 2), (2, 3), (2, 4)]);
+/// let antichain = maximum_antichain(&g).unwrap();
+/// assert_eq!(antichain.len(), 2);
+/// ```
+pub fn maximum_antichain<G>(graph: G) -> Result<Vec<G::NodeId>, Cycle<G::NodeId>>
+where
+    G: IntoNeighborsDirected + IntoNodeIdentifiers + NodeIndexable + Visitable,
+{
+    let (order, dag) = toposorted_list(graph)?;
+    let n = order.len();
+    let (_, closure) = dag_transitive_reduction_closure(&dag);
+    let split = split_graph(&closure);
+    // The split graph has no odd cycles.
+    let cover = bipartite_vertex_cover(&split).unwrap();
+    let mut covered = vec![false; n];
+    for v in cover {
+        covered[v.index() % n] = true;
+    }
+    Ok((0..n).filter(|&v| !covered[v]).map(|v| order[v]).collect())
+}
+
+/// The nodes in topological order, and the graph as a list in that order.
+type Toposorted<N> = (Vec<N>, UnweightedList<usize>);
+
+/// The graph in topological order, as an adjacency list where node `i` is the `i`th node of the
+/// order, and the successors of each node are in topological order, as
+/// [`dag_transitive_reduction_closure`] requires.
+fn toposorted_list<G>(graph: G) -> Result<Toposorted<G::NodeId>, Cycle<G::NodeId>>
+where
+    G: IntoNeighborsDirected + IntoNodeIdentifiers + NodeIndexable + Visitable,
+{
+    let order = toposort(graph, None)?;
+    let mut rank = vec![0; graph.node_bound()];
+    let mut list = List::with_capacity(order.len());
+    for (i, &v) in order.iter().enumerate() {
+        rank[graph.to_index(v)] = i;
+        list.add_node();
+        // Adding the edges into each node in order keeps the successors sorted.
+        for u in graph.neighbors_directed(v, Incoming) {
+            list.add_edge(rank[graph.to_index(u)], i, ());
+        }
+    }
+    Ok((order, list))
+}
+
+/// The split graph of a DAG: node `a` of the DAG is node `a` on the first side, and node `n + a`
+/// on the second, with an edge between `a` and `n + b` for each edge `a → b`.
+fn split_graph(dag: &UnweightedList<usize>) -> UnGraph<(), ()> {
+    let n = dag.node_count();
+    let mut split = UnGraph::with_capacity(2 * n, dag.edge_count());
+    for _ in 0..2 * n {
+        split.add_node(());
+    }
+    for a in 0..n {
+        for b in dag.neighbors(a) {
+            split.add_edge(NodeIndex::new(a), NodeIndex::new(n + b), ());
+        }
+    }
+    split
+}
+
+/// The paths given by a maximum matching of the split graph, where each matched edge between
+/// `a` and `n + b` makes `b` follow `a`.
+fn paths<N: Copy>(order: &[N], split: &UnGraph<(), ()>) -> Vec<Vec<N>> {
+    let n = order.len();
+    let matching = maximum_matching(split);
+    let mut next = vec![None; n];
+    let mut has_previous = vec![false; n];
+    for (a, next) in next.iter_mut().enumerate() {
+        if let Some(b) = matching.mate(NodeIndex::new(a)) {
+            *next = Some(b.index() - n);
+            has_previous[b.index() - n] = true;
+        }
+    }
+    let mut paths = Vec::with_capacity(n - matching.len());
+    for start in (0..n).filter(|&v| !has_previous[v]) {
+        let mut path = vec![order[start]];
+        let mut v = start;
+        while let Some(w) = next[v] {
+            path.push(order[w]);
+            v = w;
+        }
+        paths.push(path);
+    }
+    paths
+}
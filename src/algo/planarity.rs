@@ -0,0 +1,723 @@
+//! Planarity testing with the left-right algorithm.
+//!
+//! A graph is *planar* if it can be drawn in the plane without crossing edges. A drawing is
+//! described, up to deformation, by a *combinatorial embedding*: the clockwise order of the
+//! neighbors around every node. By Kuratowski's theorem, a graph is planar if and only if it does
+//! not contain a subdivision of **K₅** or **K₃,₃**.
+//!
+//! All functions in this module treat the input graph as if undirected, and ignore self loops and
+//! parallel edges.
+
+use super::chordal::compact_adjacency;
+use crate::visit::{GraphBase, IntoNeighbors, IntoNodeIdentifiers, NodeIndexable};
+
+/// The clockwise order of the neighbors around each node of a planar graph.
+///
+/// Returned by [`planar_embedding`](fn.planar_embedding.html).
+#[derive(Clone, Debug)]
+pub struct PlanarEmbedding<G: GraphBase> {
+    graph: G,
+    /// The neighbors of each node in clockwise order, by node index.
+    rotation: Vec<Vec<G::NodeId>>,
+}
+
+impl<G> PlanarEmbedding<G>
+where
+    G: NodeIndexable,
+{
+    /// Return the neighbors of `node` in clockwise order.
+    ///
+    /// Return an empty slice if the node does not exist.
+    pub fn neighbors_cw(&self, node: G::NodeId) -> &[G::NodeId] {
+        self.rotation
+            .get(self.graph.to_index(node))
+            .map_or(&[], |neighbors| &neighbors[..])
+    }
+
+    /// Return the faces of the embedding, each as the cycle of nodes along its boundary.
+    ///
+    /// Every edge is traversed once in each direction. A connected graph with **|V|** nodes and
+    /// **|E| > 0** edges has **|E| - |V| + 2** faces, including the outer face; isolated nodes do
+    /// not form faces.
+    pub fn faces(&self) -> Vec<Vec<G::NodeId>> {
+        // Position of each half-edge `a -> rotation[a][i]`, flattened.
+        let mut offset = Vec::with_capacity(self.rotation.len() + 1);
+        offset.push(0);
+        for neighbors in &self.rotation {
+            offset.push(offset.last().unwrap() + neighbors.len());
+        }
+        let mut visited = vec![false; *offset.last().unwrap()];
+        let mut faces = Vec::new();
+        for a in 0..self.rotation.len() {
+            for i in 0..self.rotation[a].len() {
+                if visited[offset[a] + i] {
+                    continue;
+                }
+                let mut face = Vec::new();
+                let (mut a, mut i) = (a, i);
+                while !visited[offset[a] + i] {
+                    visited[offset[a] + i] = true;
+                    face.push(self.graph.from_index(a));
+                    // Continue along the edge, then turn to the neighbor that
+                    // precedes `a` in clockwise order.
+                    let b = self.graph.to_index(self.rotation[a][i]);
+                    let back = self.rotation[b]
+                        .iter()
+                        .position(|&n| self.graph.to_index(n) == a)
+                        .unwrap();
+                    let len = self.rotation[b].len();
+                    a = b;
+                    i = (back + len - 1) % len;
+                }
+                faces.push(face);
+            }
+        }
+        faces
+    }
+}
+
+/// A subdivision of **K₅** or **K₃,₃** in a graph, proving that it is not planar.
+///
+/// Returned by [`planar_embedding`](fn.planar_embedding.html).
+#[derive(Clone, Debug, PartialEq)]
+pub struct KuratowskiSubgraph<N>(Vec<(N, N)>);
+
+impl<N> KuratowskiSubgraph<N> {
+    /// Return the edges of the subgraph.
+    pub fn edges(&self) -> &[(N, N)] {
+        &self.0
+    }
+
+    /// Return the edges of the subgraph.
+    pub fn into_edges(self) -> Vec<(N, N)> {
+        self.0
+    }
+}
+
+/// \[Generic\] Test whether a graph is planar.
+///
+/// Uses the left-right planarity test of de Fraysseix and Rosenstiehl, as described by Brandes [1].
+///
+/// Computes in **O(|V| + |E| log |V|)** time.
+///
+/// [1]: https://citeseerx.ist.psu.edu/viewdoc/summary?doi=10.1.1.217.9208
+///
+/// # Example
+/// ```rust
+/// use petgraph::prelude::*;
+/// use petgraph::algo::is_planar;
+///
+/// // K₄ is planar, K₅ is not.
+/// let k4 = UnGraph::<(), ()>::from_edges(&[(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3)]);
+/// assert!(is_planar(&k4));
+///
+/// let mut k5 = k4.clone();
+/// k5.extend_with_edges(&[(4, 0), (4, 1), (4, 2), (4, 3)]);
+/// assert!(!is_planar(&k5));
+/// ```
+pub fn is_planar<G>(graph: G) -> bool
+where
+    G: IntoNeighbors + IntoNodeIdentifiers + NodeIndexable,
+{
+    let (_, adjacency) = compact_adjacency(graph);
+    LeftRight::new(&adjacency).run(false).is_some()
+}
+
+/// \[Generic\] Compute a planar embedding of a graph, or a Kuratowski subgraph if it is not planar.
+///
+/// Uses the left-right planarity test of de Fraysseix and Rosenstiehl, as described by Brandes [1].
+/// If the graph is planar, return the clockwise order of the neighbors around each node. Otherwise,
+/// return the edges of a subdivision of **K₅** or **K₃,₃**, found by removing every edge whose
+/// removal leaves the graph non-planar.
+///
+/// Computes in **O(|V| + |E| log |V|)** time if the graph is planar, and in
+/// **O(|E| (|V| + |E| log |V|))** time otherwise.
+///
+/// [1]: https://citeseerx.ist.psu.edu/viewdoc/summary?doi=10.1.1.217.9208
+///
+/// # Example
+/// ```rust
+/// use petgraph::prelude::*;
+/// use petgraph::algo::planar_embedding;
+///
+/// // A square with a center node: the four triangles and the outer square
+/// // are the faces.
+/// let graph = UnGraph::<(), ()>::from_edges(&[
+///     (0, 1), (1, 2), (2, 3), (3, 0),
+///     (4, 0), (4, 1), (4, 2), (4, 3),
+/// ]);
+/// let embedding = planar_embedding(&graph).unwrap();
+/// assert_eq!(embedding.neighbors_cw(4.into()).len(), 4);
+/// assert_eq!(embedding.faces().len(), 5);
+///
+/// // K₃,₃ is not planar, and is its own Kuratowski subgraph.
+/// let k33 = UnGraph::<(), ()>::from_edges(&[
+///     (0, 3), (0, 4), (0, 5),
+///     (1, 3), (1, 4), (1, 5),
+///     (2, 3), (2, 4), (2, 5),
+/// ]);
+/// let kuratowski = planar_embedding(&k33).unwrap_err();
+/// assert_eq!(kuratowski.edges().len(), 9);
+/// ```
+pub fn planar_embedding<G>(graph: G) -> Result<PlanarEmbedding<G>, KuratowskiSubgraph<G::NodeId>>
+where
+    G: IntoNeighbors + IntoNodeIdentifiers + NodeIndexable,
+{
+    let (nodes, adjacency) = compact_adjacency(graph);
+    if let Some(rotation) = LeftRight::new(&adjacency).run(true) {
+        let mut by_index = vec![Vec::new(); graph.node_bound()];
+        for (v, neighbors) in rotation.into_iter().enumerate() {
+            by_index[graph.to_index(nodes[v])] = neighbors.into_iter().map(|w| nodes[w]).collect();
+        }
+        return Ok(PlanarEmbedding {
+            graph,
+            rotation: by_index,
+        });
+    }
+
+    // Remove every edge that can be removed while staying non-planar: what
+    // remains is a minimal non-planar graph, a Kuratowski subdivision.
+    let edges: Vec<(usize, usize)> = adjacency
+        .iter()
+        .enumerate()
+        .flat_map(|(a, neighbors)| {
+            neighbors
+                .iter()
+                .filter(move |&&b| a < b)
+                .map(move |&b| (a, b))
+        })
+        .collect();
+    let mut kept = vec![true; edges.len()];
+    let mut kept_count = edges.len();
+    for i in 0..edges.len() {
+        kept[i] = false;
+        kept_count -= 1;
+        let non_planar = nodes.len() > 2 && kept_count > 3 * nodes.len() - 6 || {
+            let mut adjacency = vec![Vec::new(); nodes.len()];
+            for (&(a, b), _) in edges.iter().zip(&kept).filter(|&(_, &k)| k) {
+                adjacency[a].push(b);
+                adjacency[b].push(a);
+            }
+            LeftRight::new(&adjacency).run(false).is_none()
+        };
+        if !non_planar {
+            kept[i] = true;
+            kept_count += 1;
+        }
+    }
+    Err(KuratowskiSubgraph(
+        edges
+            .into_iter()
+            .zip(kept)
+            .filter(|&(_, k)| k)
+            .map(|((a, b), _)| (nodes[a], nodes[b]))
+            .collect(),
+    ))
+}
+
+const NONE: usize = std::usize::MAX;
+
+/// A sequence of return edges, from `low` (lowest return point) to `high`, linked through
+/// `LeftRight::reference`.
+#[derive(Copy, Clone, Debug)]
+struct Interval {
+    low: usize,
+    high: usize,
+}
+
+impl Interval {
+    fn empty() -> Self {
+        Interval {
+            low: NONE,
+            high: NONE,
+        }
+    }
+
+    fn single(edge: usize) -> Self {
+        Interval {
+            low: edge,
+            high: edge,
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.low == NONE && self.high == NONE
+    }
+}
+
+/// Two intervals of return edges that must be on opposite sides.
+#[derive(Copy, Clone, Debug)]
+struct ConflictPair {
+    left: Interval,
+    right: Interval,
+}
+
+impl ConflictPair {
+    fn swap(&mut self) {
+        std::mem::swap(&mut self.left, &mut self.right);
+    }
+}
+
+/// The state of the left-right planarity test on a simple undirected graph.
+///
+/// Edges are numbered in the order in which the depth-first search orients them. The half-edges of
+/// edge `e` are `2e`, from its source, and `2e + 1`, from its target.
+struct LeftRight<'a> {
+    adjacency: &'a [Vec<usize>],
+    source: Vec<usize>,
+    target: Vec<usize>,
+    /// The edges oriented away from each node, in the order they were oriented.
+    out_edges: Vec<Vec<usize>>,
+    /// The outgoing edges of each node, sorted by nesting depth.
+    ordered: Vec<Vec<usize>>,
+    /// Whether the edge to each position in `adjacency` has been oriented.
+    oriented: Vec<Vec<bool>>,
+    height: Vec<usize>,
+    parent_edge: Vec<usize>,
+    lowpt: Vec<usize>,
+    lowpt2: Vec<usize>,
+    nesting_depth: Vec<isize>,
+    reference: Vec<usize>,
+    side: Vec<isize>,
+    stack: Vec<ConflictPair>,
+    stack_bottom: Vec<usize>,
+    lowpt_edge: Vec<usize>,
+    /// The next position to consider in the adjacency of each node, for the iterative depth-first
+    /// searches.
+    next: Vec<usize>,
+}
+
+impl<'a> LeftRight<'a> {
+    fn new(adjacency: &'a [Vec<usize>]) -> Self {
+        let n = adjacency.len();
+        let m = adjacency.iter().map(|a| a.len()).sum::<usize>() / 2;
+        LeftRight {
+            adjacency,
+            source: Vec::with_capacity(m),
+            target: Vec::with_capacity(m),
+            out_edges: vec![Vec::new(); n],
+            ordered: Vec::new(),
+            oriented: adjacency.iter().map(|a| vec![false; a.len()]).collect(),
+            height: vec![NONE; n],
+            parent_edge: vec![NONE; n],
+            lowpt: Vec::with_capacity(m),
+            lowpt2: Vec::with_capacity(m),
+            nesting_depth: Vec::with_capacity(m),
+            reference: vec![NONE; m],
+            side: vec![1; m],
+            stack: Vec::new(),
+            stack_bottom: vec![0; m],
+            lowpt_edge: vec![NONE; m],
+            next: vec![0; n],
+        }
+    }
+
+    /// Run the test, and return the clockwise rotation of the neighbors of each node if the graph
+    /// is planar and `embed` is set, or an empty rotation if it is planar and `embed` is not set.
+    fn run(mut self, embed: bool) -> Option<Vec<Vec<usize>>> {
+        let n = self.adjacency.len();
+        let m = self.reference.len();
+        if n > 2 && m > 3 * n - 6 {
+            return None;
+        }
+        let mut roots = Vec::new();
+        for v in 0..n {
+            if self.height[v] == NONE {
+                self.height[v] = 0;
+                roots.push(v);
+                self.orient(v);
+            }
+        }
+
+        self.ordered = self.sorted_out_edges();
+        self.next.iter_mut().for_each(|i| *i = 0);
+        for &root in &roots {
+            if !self.test(root) {
+                return None;
+            }
+        }
+        if !embed {
+            return Some(Vec::new());
+        }
+
+        for e in 0..m {
+            self.nesting_depth[e] *= self.sign(e);
+        }
+        self.ordered = self.sorted_out_edges();
+        let mut rotation = Rotation::new(n, m);
+        for v in 0..n {
+            let mut previous = NONE;
+            for &e in &self.ordered[v] {
+                rotation.add_cw(v, 2 * e, previous);
+                previous = 2 * e;
+            }
+        }
+        self.next.iter_mut().for_each(|i| *i = 0);
+        for &root in &roots {
+            self.embed(root, &mut rotation);
+        }
+        Some(
+            (0..n)
+                .map(|v| {
+                    rotation
+                        .half_edges_cw(v)
+                        .map(|h| {
+                            if h % 2 == 0 {
+                                self.target[h / 2]
+                            } else {
+                                self.source[h / 2]
+                            }
+                        })
+                        .collect()
+                })
+                .collect(),
+        )
+    }
+
+    fn sorted_out_edges(&self) -> Vec<Vec<usize>> {
+        self.out_edges
+            .iter()
+            .map(|edges| {
+                let mut edges = edges.clone();
+                edges.sort_by_key(|&e| self.nesting_depth[e]);
+                edges
+            })
+            .collect()
+    }
+
+    /// Orient the edges in a depth-first search from `root`, and compute their lowpoints and
+    /// nesting depths.
+    fn orient(&mut self, root: usize) {
+        let mut dfs_stack = vec![root];
+        while let Some(v) = dfs_stack.pop() {
+            let e = self.parent_edge[v];
+            while self.next[v] < self.adjacency[v].len() {
+                let i = self.next[v];
+                let w = self.adjacency[v][i];
+                let vw = if self.oriented[v][i] {
+                    // Returning from the tree edge to `w`.
+                    match self.out_edges[v].last() {
+                        Some(&vw) if self.target[vw] == w && self.parent_edge[w] == vw => vw,
+                        _ => {
+                            self.next[v] += 1;
+                            continue;
+                        }
+                    }
+                } else {
+                    self.oriented[v][i] = true;
+                    let j = self.adjacency[w].binary_search(&v).unwrap();
+                    self.oriented[w][j] = true;
+                    let vw = self.source.len();
+                    self.source.push(v);
+                    self.target.push(w);
+                    self.out_edges[v].push(vw);
+                    self.lowpt.push(self.height[v]);
+                    self.lowpt2.push(self.height[v]);
+                    self.nesting_depth.push(0);
+                    if self.height[w] == NONE {
+                        self.parent_edge[w] = vw;
+                        self.height[w] = self.height[v] + 1;
+                        dfs_stack.push(v);
+                        dfs_stack.push(w);
+                        break;
+                    }
+                    self.lowpt[vw] = self.height[w];
+                    vw
+                };
+
+                self.nesting_depth[vw] = 2 * self.lowpt[vw] as isize;
+                if self.lowpt2[vw] < self.height[v] {
+                    // `vw` is chordal.
+                    self.nesting_depth[vw] += 1;
+                }
+                if e != NONE {
+                    if self.lowpt[vw] < self.lowpt[e] {
+                        self.lowpt2[e] = self.lowpt[e].min(self.lowpt2[vw]);
+                        self.lowpt[e] = self.lowpt[vw];
+                    } else if self.lowpt[vw] > self.lowpt[e] {
+                        self.lowpt2[e] = self.lowpt2[e].min(self.lowpt[vw]);
+                    } else {
+                        self.lowpt2[e] = self.lowpt2[e].min(self.lowpt2[vw]);
+                    }
+                }
+                self.next[v] += 1;
+            }
+        }
+    }
+
+    /// Test the constraints of the edges in a depth-first search from `root`.
+    fn test(&mut self, root: usize) -> bool {
+        let mut dfs_stack = vec![root];
+        while let Some(v) = dfs_stack.pop() {
+            let e = self.parent_edge[v];
+            let mut descended = false;
+            while self.next[v] < self.ordered[v].len() {
+                let ei = self.ordered[v][self.next[v]];
+                let w = self.target[ei];
+                // A tree edge is first seen before descending, then again
+                // after returning, when its stack bottom is already set.
+                let returning = self.parent_edge[w] == ei && self.next[w] == NONE;
+                if !returning {
+                    self.stack_bottom[ei] = self.stack.len();
+                    if self.parent_edge[w] == ei {
+                        dfs_stack.push(v);
+                        dfs_stack.push(w);
+                        descended = true;
+                        break;
+                    }
+                    self.lowpt_edge[ei] = ei;
+                    self.stack.push(ConflictPair {
+                        left: Interval::empty(),
+                        right: Interval::single(ei),
+                    });
+                }
+                if self.lowpt[ei] < self.height[v] {
+                    if ei == self.ordered[v][0] {
+                        self.lowpt_edge[e] = self.lowpt_edge[ei];
+                    } else if !self.add_constraints(ei, e) {
+                        return false;
+                    }
+                }
+                self.next[v] += 1;
+            }
+            if !descended {
+                // Mark `v` as finished for its parent.
+                self.next[v] = NONE;
+                if e != NONE {
+                    self.remove_back_edges(e);
+                }
+            }
+        }
+        true
+    }
+
+    fn conflicting(&self, interval: Interval, edge: usize) -> bool {
+        !interval.is_empty() && self.lowpt[interval.high] > self.lowpt[edge]
+    }
+
+    fn lowest(&self, pair: ConflictPair) -> usize {
+        if pair.left.is_empty() {
+            self.lowpt[pair.right.low]
+        } else if pair.right.is_empty() {
+            self.lowpt[pair.left.low]
+        } else {
+            self.lowpt[pair.left.low].min(self.lowpt[pair.right.low])
+        }
+    }
+
+    fn add_constraints(&mut self, ei: usize, e: usize) -> bool {
+        let mut p = ConflictPair {
+            left: Interval::empty(),
+            right: Interval::empty(),
+        };
+        // Merge the return edges of `ei` into `p.right`.
+        loop {
+            let mut q = self.stack.pop().unwrap();
+            if !q.left.is_empty() {
+                q.swap();
+            }
+            if !q.left.is_empty() {
+                return false;
+            }
+            if self.lowpt[q.right.low] > self.lowpt[e] {
+                if p.right.is_empty() {
+                    p.right = q.right;
+                } else {
+                    self.reference[p.right.low] = q.right.high;
+                }
+                p.right.low = q.right.low;
+            } else {
+                self.reference[q.right.low] = self.lowpt_edge[e];
+            }
+            if self.stack.len() == self.stack_bottom[ei] {
+                break;
+            }
+        }
+        // Merge the conflicting return edges of the previous siblings of `ei`
+        // into `p.left`.
+        while let Some(&top) = self.stack.last() {
+            if !self.conflicting(top.left, ei) && !self.conflicting(top.right, ei) {
+                break;
+            }
+            let mut q = self.stack.pop().unwrap();
+            if self.conflicting(q.right, ei) {
+                q.swap();
+            }
+            if self.conflicting(q.right, ei) {
+                return false;
+            }
+            if p.right.low != NONE {
+                self.reference[p.right.low] = q.right.high;
+            }
+            if q.right.low != NONE {
+                p.right.low = q.right.low;
+            }
+            if p.left.is_empty() {
+                p.left = q.left;
+            } else {
+                self.reference[p.left.low] = q.left.high;
+            }
+            p.left.low = q.left.low;
+        }
+        if !p.left.is_empty() || !p.right.is_empty() {
+            self.stack.push(p);
+        }
+        true
+    }
+
+    fn remove_back_edges(&mut self, e: usize) {
+        let u = self.source[e];
+        // Drop the conflict pairs whose return edges all end at `u`.
+        while let Some(&top) = self.stack.last() {
+            if self.lowest(top) != self.height[u] {
+                break;
+            }
+            let p = self.stack.pop().unwrap();
+            if p.left.low != NONE {
+                self.side[p.left.low] = -1;
+            }
+        }
+        // Trim the return edges ending at `u` from the next conflict pair.
+        if let Some(mut p) = self.stack.pop() {
+            while p.left.high != NONE && self.target[p.left.high] == u {
+                p.left.high = self.reference[p.left.high];
+            }
+            if p.left.high == NONE && p.left.low != NONE {
+                self.reference[p.left.low] = p.right.low;
+                self.side[p.left.low] = -1;
+                p.left.low = NONE;
+            }
+            while p.right.high != NONE && self.target[p.right.high] == u {
+                p.right.high = self.reference[p.right.high];
+            }
+            if p.right.high == NONE && p.right.low != NONE {
+                self.reference[p.right.low] = p.left.low;
+                self.side[p.right.low] = -1;
+                p.right.low = NONE;
+            }
+            self.stack.push(p);
+        }
+        // The side of `e` is the side of a highest return edge.
+        if self.lowpt[e] < self.height[u] {
+            let top = *self.stack.last().unwrap();
+            let (hl, hr) = (top.left.high, top.right.high);
+            self.reference[e] = if hl != NONE && (hr == NONE || self.lowpt[hl] > self.lowpt[hr]) {
+                hl
+            } else {
+                hr
+            };
+        }
+    }
+
+    /// Resolve the side of `e` relative to the edges it references.
+    fn sign(&mut self, e: usize) -> isize {
+        let mut chain = vec![e];
+        let mut last = e;
+        while self.reference[last] != NONE {
+            last = self.reference[last];
+            chain.push(last);
+        }
+        for i in (0..chain.len() - 1).rev() {
+            let (f, g) = (chain[i], chain[i + 1]);
+            self.side[f] *= self.side[g];
+            self.reference[f] = NONE;
+        }
+        self.side[e]
+    }
+
+    /// Add the half-edges towards each node to the rotation, in a depth-first search from `root`.
+    fn embed(&mut self, root: usize, rotation: &mut Rotation) {
+        let mut left_ref = vec![NONE; self.adjacency.len()];
+        let mut right_ref = vec![NONE; self.adjacency.len()];
+        let mut dfs_stack = vec![root];
+        while let Some(v) = dfs_stack.pop() {
+            while self.next[v] < self.ordered[v].len() {
+                let ei = self.ordered[v][self.next[v]];
+                self.next[v] += 1;
+                let w = self.target[ei];
+                if self.parent_edge[w] == ei {
+                    rotation.add_first(w, 2 * ei + 1);
+                    left_ref[v] = 2 * ei;
+                    right_ref[v] = 2 * ei;
+                    dfs_stack.push(v);
+                    dfs_stack.push(w);
+                    break;
+                } else if self.side[ei] == 1 {
+                    rotation.add_cw(w, 2 * ei + 1, right_ref[w]);
+                } else {
+                    rotation.add_ccw(w, 2 * ei + 1, left_ref[w]);
+                    left_ref[w] = 2 * ei + 1;
+                }
+            }
+        }
+    }
+}
+
+/// The circular clockwise order of the half-edges around each node.
+struct Rotation {
+    cw: Vec<usize>,
+    ccw: Vec<usize>,
+    first: Vec<usize>,
+}
+
+impl Rotation {
+    fn new(node_count: usize, edge_count: usize) -> Self {
+        Rotation {
+            cw: vec![NONE; 2 * edge_count],
+            ccw: vec![NONE; 2 * edge_count],
+            first: vec![NONE; node_count],
+        }
+    }
+
+    /// Insert the half-edge `h` around `v` right after `reference` in clockwise order, or as the
+    /// only one if `reference` is `NONE`.
+    fn add_cw(&mut self, v: usize, h: usize, reference: usize) {
+        if reference == NONE {
+            self.cw[h] = h;
+            self.ccw[h] = h;
+            self.first[v] = h;
+        } else {
+            let next = self.cw[reference];
+            self.cw[reference] = h;
+            self.ccw[h] = reference;
+            self.cw[h] = next;
+            self.ccw[next] = h;
+        }
+    }
+
+    /// Insert the half-edge `h` around `v` right before `reference` in clockwise order, or as the
+    /// only one if `reference` is `NONE`.
+    fn add_ccw(&mut self, v: usize, h: usize, reference: usize) {
+        if reference == NONE {
+            self.add_cw(v, h, NONE);
+        } else {
+            self.add_cw(v, h, self.ccw[reference]);
+            if self.first[v] == reference {
+                self.first[v] = h;
+            }
+        }
+    }
+
+    /// Insert the half-edge `h` as the first one around `v`.
+    fn add_first(&mut self, v: usize, h: usize) {
+        let first = self.first[v];
+        self.add_ccw(v, h, first);
+        self.first[v] = h;
+    }
+
+    fn half_edges_cw(&self, v: usize) -> impl Iterator<Item = usize> + '_ {
+        let first = self.first[v];
+        let mut current = first;
+        std::iter::from_fn(move || {
+            if current == NONE {
+                return None;
+            }
+            let h = current;
+            current = self.cw[h];
+            if current == first {
+                current = NONE;
+            }
+            Some(h)
+        })
+    }
+}
@@ -1,4 +1,5 @@
 use std::{
+    collections::HashSet,
     hash::Hash,
     iter::{from_fn, FromIterator},
 };
@@ -6,7 +7,8 @@ use std::{
 use indexmap::IndexSet;
 
 use crate::{
-    visit::{IntoNeighborsDirected, NodeCount},
+    algo::Measure,
+    visit::{EdgeRef, IntoEdgesDirected, IntoNeighborsDirected, NodeCount},
     Direction::Outgoing,
 };
 
@@ -101,15 +103,113 @@ where
     })
 }
 
+/// Returns an iterator that produces all simple paths from `from` node to any of the `targets`,
+/// as sequences of edge references. The simple path is a path without repetitions of nodes.
+///
+/// Since paths are made of edges, parallel edges between the same nodes give distinct paths.
+/// A path is produced as soon as it reaches one of the `targets`, and the search continues
+/// past it towards the other targets.
+///
+/// The search can be bounded: `edge_cost` gives the cost of each edge, and paths whose
+/// cumulative cost exceeds `max_cost` are pruned, which assumes edge costs are non-negative.
+/// If `max_paths` is given, the iterator stops after producing that many paths.
+///
+/// # Example
+/// ```
+/// use petgraph::{algo, prelude::*};
+///
+/// let mut graph = DiGraph::<&str, i32>::new();
+///
+/// let a = graph.add_node("a");
+/// let b = graph.add_node("b");
+/// let c = graph.add_node("c");
+/// let d = graph.add_node("d");
+///
+/// graph.extend_with_edges(&[(a, b, 1), (b, c, 1), (c, d, 1), (a, b, 2), (b, d, 1)]);
+///
+/// // Both `c` and `d` are targets, and paths cost at most 3.
+/// let ways = algo::all_simple_edge_paths::<Vec<_>, _, _, _>(
+///     &graph, a, [c, d], Some(3), None, |e| *e.weight(),
+/// )
+/// .map(|path| path.iter().map(|e| e.id().index()).collect::<Vec<_>>())
+/// .collect::<Vec<_>>();
+///
+/// assert_eq!(ways, vec![vec![3, 4], vec![3, 1], vec![0, 4], vec![0, 1], vec![0, 1, 2]]);
+/// ```
+pub fn all_simple_edge_paths<TargetColl, G, F, K>(
+    graph: G,
+    from: G::NodeId,
+    targets: impl IntoIterator<Item = G::NodeId>,
+    max_cost: Option<K>,
+    max_paths: Option<usize>,
+    mut edge_cost: F,
+) -> impl Iterator<Item = TargetColl>
+where
+    G: IntoEdgesDirected,
+    G::NodeId: Eq + Hash,
+    F: FnMut(G::EdgeRef) -> K,
+    K: Measure + Copy,
+    TargetColl: FromIterator<G::EdgeRef>,
+{
+    let targets: HashSet<G::NodeId> = targets.into_iter().collect();
+    let mut remaining = max_paths.unwrap_or(std::usize::MAX);
+
+    // nodes of the current path, starting with `from`
+    let mut visited: IndexSet<G::NodeId> = IndexSet::from_iter(Some(from));
+    // edges of the current path and the cumulative cost at each of its nodes
+    let mut path: Vec<G::EdgeRef> = Vec::new();
+    let mut costs = vec![K::default()];
+    // outgoing edges left to explore from each node of the current path;
+    // `None` if there is nothing to find past that node
+    let mut stack = vec![Some(graph.edges_directed(from, Outgoing))];
+
+    from_fn(move || {
+        if remaining == 0 {
+            return None;
+        }
+        while let Some(edges) = stack.last_mut() {
+            if let Some(edge) = edges.as_mut().and_then(Iterator::next) {
+                let next = edge.target();
+                if visited.contains(&next) {
+                    continue;
+                }
+                let cost = costs[costs.len() - 1] + edge_cost(edge);
+                if max_cost.map_or(false, |max| cost > max) {
+                    continue;
+                }
+                let is_target = targets.contains(&next);
+                visited.insert(next);
+                path.push(edge);
+                costs.push(cost);
+                if is_target && targets.len() == 1 {
+                    stack.push(None);
+                } else {
+                    stack.push(Some(graph.edges_directed(next, Outgoing)));
+                }
+                if is_target {
+                    remaining -= 1;
+                    return Some(path.iter().cloned().collect::<TargetColl>());
+                }
+            } else {
+                stack.pop();
+                visited.pop();
+                path.pop();
+                costs.pop();
+            }
+        }
+        None
+    })
+}
+
 #[cfg(test)]
 mod test {
     use std::{collections::HashSet, iter::FromIterator};
 
     use itertools::assert_equal;
 
-    use crate::{dot::Dot, prelude::DiGraph};
+    use crate::{dot::Dot, prelude::DiGraph, visit::EdgeRef};
 
-    use super::all_simple_paths;
+    use super::{all_simple_edge_paths, all_simple_paths};
 
     #[test]
     fn test_all_simple_paths() {
@@ -179,4 +279,80 @@ mod test {
 
         assert_eq!(actual_simple_paths_0_to_2.len(), 0);
     }
+
+    #[test]
+    fn test_all_simple_edge_paths_parallel_edges() {
+        let graph = DiGraph::<i32, i32, _>::from_edges(&[
+            (0, 1, 1),
+            (0, 1, 1),
+            (1, 2, 1),
+            (0, 2, 5),
+            (2, 0, 1),
+        ]);
+
+        let paths: HashSet<Vec<_>> = all_simple_edge_paths::<Vec<_>, _, _, _>(
+            &graph,
+            0u32.into(),
+            Some(2u32.into()),
+            None,
+            None,
+            |e| *e.weight(),
+        )
+        .map(|path| path.iter().map(|e| e.id().index()).collect())
+        .collect();
+        assert_eq!(
+            paths,
+            HashSet::from_iter(vec![vec![0, 2], vec![1, 2], vec![3]])
+        );
+    }
+
+    #[test]
+    fn test_all_simple_edge_paths_bounds() {
+        let graph = DiGraph::<i32, i32, _>::from_edges(&[
+            (0, 1, 1),
+            (1, 2, 1),
+            (2, 3, 1),
+            (0, 2, 3),
+            (1, 3, 4),
+            (0, 3, 6),
+        ]);
+        let paths = |max_cost, max_paths| -> HashSet<Vec<usize>> {
+            all_simple_edge_paths::<Vec<_>, _, _, _>(
+                &graph,
+                0u32.into(),
+                Some(3u32.into()),
+                max_cost,
+                max_paths,
+                |e| *e.weight(),
+            )
+            .map(|path| path.iter().map(|e| e.target().index()).collect())
+            .collect()
+        };
+
+        assert_eq!(paths(None, None).len(), 4);
+        assert_eq!(
+            paths(Some(4), None),
+            HashSet::from_iter(vec![vec![1, 2, 3], vec![2, 3]])
+        );
+        assert_eq!(paths(Some(2), None).len(), 0);
+        assert_eq!(paths(None, Some(3)).len(), 3);
+        assert_eq!(paths(Some(5), Some(0)).len(), 0);
+    }
+
+    #[test]
+    fn test_all_simple_edge_paths_multiple_targets() {
+        let graph = DiGraph::<i32, i32, _>::from_edges(&[(0, 1, 1), (1, 2, 1), (0, 2, 1)]);
+        let mut paths: Vec<Vec<_>> = all_simple_edge_paths::<Vec<_>, _, _, _>(
+            &graph,
+            0u32.into(),
+            vec![1u32.into(), 2u32.into()],
+            None,
+            None,
+            |e| *e.weight(),
+        )
+        .map(|path| path.iter().map(|e| e.target().index()).collect())
+        .collect();
+        paths.sort();
+        assert_eq!(paths, vec![vec![1], vec![1, 2], vec![2]]);
+    }
 }
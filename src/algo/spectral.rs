@@ -0,0 +1,773 @@
+//! Spectral graph theory: the Fiedler vector, algebraic connectivity and spectral bisection.
+//!
+//! All functions treat the graph as undirected, with parallel edges adding up, and take the weight
+//! of each edge from a function returning `f64`. Vectors are indexed by
+//! [`NodeIndexable::to_index`](../visit/trait.NodeIndexable.html).
+
+use std::fmt;
+
+use crate::visit::{EdgeRef, IntoEdgeReferences, IntoNodeIdentifiers, NodeIndexable};
+
+/// The most iterations of LOBPCG before falling back to the Lanczos iteration.
+const MAX_LOBPCG: usize = 200;
+
+/// The most vectors in the Lanczos basis.
+const MAX_KRYLOV: usize = 100;
+
+/// The most restarts of the Lanczos iteration.
+const MAX_RESTARTS: usize = 1000;
+
+/// Residuals below this, relative to the norm of the Laplacian, count as converged.
+const TOLERANCE: f64 = 1e-10;
+
+/// A square sparse matrix of `f64` values, stored in compressed sparse row (CSR) form, with a row
+/// and a column for each index of `to_index`. Indices that are not in use by a node have empty rows
+/// and columns.
+#[derive(Clone, PartialEq)]
+pub(crate) struct SparseMatrix {
+    /// The entries of row `i` are at `row_start[i]..row_start[i + 1]`.
+    row_start: Vec<usize>,
+    columns: Vec<usize>,
+    values: Vec<f64>,
+}
+
+impl SparseMatrix {
+    /// Build an `n` × `n` matrix from `(row, column, value)` entries, adding up entries at the same
+    /// position.
+    fn from_entries(n: usize, mut entries: Vec<(usize, usize, f64)>) -> Self {
+        entries.sort_by_key(|&(row, column, _)| (row, column));
+        let mut row_start = vec![0; n + 1];
+        let mut columns: Vec<usize> = Vec::with_capacity(entries.len());
+        let mut values: Vec<f64> = Vec::with_capacity(entries.len());
+        let mut last = None;
+        for (row, column, value) in entries {
+            if last == Some((row, column)) {
+                *values.last_mut().unwrap() += value;
+            } else {
+                row_start[row + 1] += 1;
+                columns.push(column);
+                values.push(value);
+                last = Some((row, column));
+            }
+        }
+        for i in 0..n {
+            row_start[i + 1] += row_start[i];
+        }
+        SparseMatrix {
+            row_start,
+            columns,
+            values,
+        }
+    }
+
+    /// Return the number of rows, which is also the number of columns.
+    pub(crate) fn dim(&self) -> usize {
+        self.row_start.len() - 1
+    }
+
+    /// Return an iterator of the stored entries of `row`, as pairs of column and value in
+    /// increasing order of column.
+    ///
+    /// **Panics** if `row` is out of bounds.
+    pub(crate) fn row(&self, row: usize) -> impl Iterator<Item = (usize, f64)> + '_ {
+        let range = self.row_start[row]..self.row_start[row + 1];
+        self.columns[range.clone()]
+            .iter()
+            .cloned()
+            .zip(self.values[range].iter().cloned())
+    }
+
+    /// Return the product of the matrix and the vector `x`.
+    ///
+    /// **Panics** if `x` does not have `dim()` entries.
+    pub(crate) fn mul_vec(&self, x: &[f64]) -> Vec<f64> {
+        assert_eq!(x.len(), self.dim(), "vector length does not match matrix");
+        (0..self.dim())
+            .map(|row| self.row(row).map(|(column, value)| value * x[column]).sum())
+            .collect()
+    }
+
+    /// Return the submatrix of the rows and columns `indices`, numbered in their order.
+    fn restrict(&self, indices: &[usize]) -> Self {
+        let mut position = vec![std::usize::MAX; self.dim()];
+        for (i, &v) in indices.iter().enumerate() {
+            position[v] = i;
+        }
+        let entries = indices
+            .iter()
+            .enumerate()
+            .flat_map(|(i, &v)| self.row(v).map(move |(column, value)| (i, column, value)))
+            .filter(|&(_, column, _)| position[column] != std::usize::MAX)
+            .map(|(i, column, value)| (i, position[column], value))
+            .collect();
+        SparseMatrix::from_entries(indices.len(), entries)
+    }
+}
+
+impl fmt::Debug for SparseMatrix {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut map = f.debug_map();
+        for row in 0..self.dim() {
+            for (column, value) in self.row(row) {
+                map.entry(&(row, column), &value);
+            }
+        }
+        map.finish()
+    }
+}
+
+/// Return the weighted adjacency matrix of a graph.
+///
+/// The entry at row `a` and column `b` is the total weight of the edges between the nodes of index
+/// `a` and `b`, in either direction, so the matrix is symmetric. A self loop adds its weight to the
+/// diagonal once.
+pub(crate) fn adjacency_matrix<G, F>(graph: G, mut edge_weight: F) -> SparseMatrix
+where
+    G: IntoEdgeReferences + NodeIndexable,
+    F: FnMut(G::EdgeRef) -> f64,
+{
+    let mut entries = Vec::new();
+    for edge in graph.edge_references() {
+        let a = graph.to_index(edge.source());
+        let b = graph.to_index(edge.target());
+        let w = edge_weight(edge);
+        entries.push((a, b, w));
+        if a != b {
+            entries.push((b, a, w));
+        }
+    }
+    SparseMatrix::from_entries(graph.node_bound(), entries)
+}
+
+/// The off-diagonal entries of `adjacency` and the sum of each of its rows without the diagonal,
+/// which is the weighted degree ignoring self loops.
+fn off_diagonal(adjacency: &SparseMatrix) -> (Vec<(usize, usize, f64)>, Vec<f64>) {
+    let mut entries = Vec::new();
+    let mut degrees = vec![0.; adjacency.dim()];
+    for (a, degree) in degrees.iter_mut().enumerate() {
+        for (b, w) in adjacency.row(a).filter(|&(b, _)| b != a) {
+            entries.push((a, b, w));
+            *degree += w;
+        }
+    }
+    (entries, degrees)
+}
+
+/// Return the combinatorial Laplacian matrix *L = D − A* of a graph.
+///
+/// *A* is the `adjacency_matrix` without self loops and *D* is the diagonal matrix of weighted
+/// degrees, so each row adds up to zero.
+pub(crate) fn laplacian_matrix<G, F>(graph: G, edge_weight: F) -> SparseMatrix
+where
+    G: IntoEdgeReferences + NodeIndexable,
+    F: FnMut(G::EdgeRef) -> f64,
+{
+    let adjacency = adjacency_matrix(graph, edge_weight);
+    let (entries, degrees) = off_diagonal(&adjacency);
+    let laplacian = entries
+        .into_iter()
+        .map(|(a, b, w)| (a, b, -w))
+        .chain(
+            degrees
+                .into_iter()
+                .enumerate()
+                .filter(|&(_, d)| d != 0.)
+                .map(|(a, d)| (a, a, d)),
+        )
+        .collect();
+    SparseMatrix::from_entries(adjacency.dim(), laplacian)
+}
+
+/// Return the normalized Laplacian matrix *I − D<sup>−1/2</sup> A D<sup>−1/2</sup>* of a graph.
+///
+/// *A* is the `adjacency_matrix` without self loops and *D* is the diagonal matrix of weighted
+/// degrees. Nodes of degree zero have a zero row, including the diagonal. The eigenvalues are
+/// between 0 and 2.
+#[cfg(test)]
+pub(crate) fn normalized_laplacian_matrix<G, F>(graph: G, edge_weight: F) -> SparseMatrix
+where
+    G: IntoEdgeReferences + NodeIndexable,
+    F: FnMut(G::EdgeRef) -> f64,
+{
+    let adjacency = adjacency_matrix(graph, edge_weight);
+    let (entries, degrees) = off_diagonal(&adjacency);
+    let scale: Vec<f64> = degrees
+        .iter()
+        .map(|&d| if d > 0. { 1. / d.sqrt() } else { 0. })
+        .collect();
+    let laplacian = entries
+        .into_iter()
+        .map(|(a, b, w)| (a, b, -w * scale[a] * scale[b]))
+        .chain(
+            degrees
+                .iter()
+                .enumerate()
+                .filter(|&(_, &d)| d > 0.)
+                .map(|(a, _)| (a, a, 1.)),
+        )
+        .collect();
+    SparseMatrix::from_entries(adjacency.dim(), laplacian)
+}
+
+fn dot(x: &[f64], y: &[f64]) -> f64 {
+    x.iter().zip(y).map(|(a, b)| a * b).sum()
+}
+
+fn norm(x: &[f64]) -> f64 {
+    dot(x, x).sqrt()
+}
+
+/// Subtract from `x` its projection on the unit vector `q`.
+fn orthogonalize(x: &mut [f64], q: &[f64]) {
+    let c = dot(x, q);
+    for (a, b) in x.iter_mut().zip(q) {
+        *a -= c * b;
+    }
+}
+
+/// Subtract the mean of `x` from each entry, which projects out the constant eigenvector of the
+/// Laplacian.
+fn center(x: &mut [f64]) {
+    let mean = x.iter().sum::<f64>() / x.len() as f64;
+    for a in x.iter_mut() {
+        *a -= mean;
+    }
+}
+
+/// Compute the eigenvalues of a small dense symmetric matrix, in increasing order, with unit
+/// eigenvectors as the columns of a matrix stored by rows, by cyclic Jacobi rotations.
+fn symmetric_eigen(mut a: Vec<Vec<f64>>) -> (Vec<f64>, Vec<Vec<f64>>) {
+    let n = a.len();
+    let mut v: Vec<Vec<f64>> = (0..n)
+        .map(|i| (0..n).map(|j| if i == j { 1. } else { 0. }).collect())
+        .collect();
+    for _ in 0..100 {
+        let off: f64 = (0..n)
+            .flat_map(|p| (p + 1..n).map(move |q| (p, q)))
+            .map(|(p, q)| a[p][q] * a[p][q])
+            .sum();
+        let diagonal: f64 = (0..n).map(|p| a[p][p] * a[p][p]).sum();
+        if off <= std::f64::EPSILON * std::f64::EPSILON * diagonal {
+            break;
+        }
+        for p in 0..n {
+            for q in p + 1..n {
+                if a[p][q] == 0. {
+                    continue;
+                }
+                // Choose the rotation that zeroes `a[p][q]`.
+                let theta = (a[q][q] - a[p][p]) / (2. * a[p][q]);
+                let t = 1. / (theta.abs() + theta.hypot(1.));
+                let t = if theta < 0. { -t } else { t };
+                let c = 1. / t.hypot(1.);
+                let s = t * c;
+                for row in a.iter_mut() {
+                    let (x, y) = (row[p], row[q]);
+                    row[p] = c * x - s * y;
+                    row[q] = s * x + c * y;
+                }
+                let (top, bottom) = a.split_at_mut(q);
+                for (x, y) in top[p].iter_mut().zip(bottom[0].iter_mut()) {
+                    let (u, w) = (*x, *y);
+                    *x = c * u - s * w;
+                    *y = s * u + c * w;
+                }
+                for row in v.iter_mut() {
+                    let (x, y) = (row[p], row[q]);
+                    row[p] = c * x - s * y;
+                    row[q] = s * x + c * y;
+                }
+            }
+        }
+    }
+    let mut order: Vec<usize> = (0..n).collect();
+    order.sort_by(|&i, &j| a[i][i].partial_cmp(&a[j][j]).unwrap());
+    let values = order.iter().map(|&i| a[i][i]).collect();
+    let vectors = v
+        .iter()
+        .map(|row| order.iter().map(|&i| row[i]).collect())
+        .collect();
+    (values, vectors)
+}
+
+/// Combine the `vectors` with the coefficients in column `column` of `coefficients`.
+fn combine(vectors: &[Vec<f64>], coefficients: &[Vec<f64>], column: usize) -> Vec<f64> {
+    let mut result = vec![0.; vectors[0].len()];
+    for (vector, row) in vectors.iter().zip(coefficients) {
+        let c = row[column];
+        for (r, x) in result.iter_mut().zip(vector) {
+            *r += c * x;
+        }
+    }
+    result
+}
+
+/// Find the smallest eigenvalue of `laplacian` on vectors orthogonal to the constant vector, with
+/// its eigenvector, starting from the centered unit vector `x`. Returns the best vector found as an
+/// error if the residual is still above the tolerance after `MAX_LOBPCG` iterations.
+///
+/// Uses the locally optimal block preconditioned conjugate gradient method (LOBPCG) with a single
+/// vector: each step minimizes the Rayleigh quotient on the span of the current vector, its
+/// residual scaled by the inverse of the diagonal, and the previous step.
+fn lobpcg(
+    laplacian: &SparseMatrix,
+    mut x: Vec<f64>,
+    scale: f64,
+) -> Result<(f64, Vec<f64>), Vec<f64>> {
+    let diagonal: Vec<f64> = (0..laplacian.dim())
+        .map(|row| {
+            laplacian
+                .row(row)
+                .find(|&(column, _)| column == row)
+                .map_or(0., |(_, v)| v)
+        })
+        .collect();
+    let mut lx = laplacian.mul_vec(&x);
+    let mut step: Option<Vec<f64>> = None;
+    for _ in 0..MAX_LOBPCG {
+        let value = dot(&x, &lx);
+        let residual: Vec<f64> = lx.iter().zip(&x).map(|(p, a)| p - value * a).collect();
+        if norm(&residual) <= TOLERANCE * scale {
+            return Ok((value.max(0.), x));
+        }
+        let preconditioned = residual
+            .iter()
+            .zip(&diagonal)
+            .map(|(&r, &d)| if d > 0. { r / d } else { r })
+            .collect();
+
+        // An orthonormal basis of the search space, with `x` first, dropping directions that are
+        // nearly dependent on the others.
+        let mut basis = vec![x];
+        for mut w in Some(preconditioned).into_iter().chain(step.take()) {
+            let before = norm(&w);
+            // Twice, which keeps the basis orthogonal in floating point.
+            for _ in 0..2 {
+                center(&mut w);
+                for q in &basis {
+                    orthogonalize(&mut w, q);
+                }
+            }
+            let b = norm(&w);
+            if b > 1e-8 * before {
+                for a in w.iter_mut() {
+                    *a /= b;
+                }
+                basis.push(w);
+            }
+        }
+        let product: Vec<Vec<f64>> = basis.iter().map(|q| laplacian.mul_vec(q)).collect();
+        let projection = basis
+            .iter()
+            .map(|q| product.iter().map(|p| dot(q, p)).collect())
+            .collect();
+        let (_, vectors) = symmetric_eigen(projection);
+        x = combine(&basis, &vectors, 0);
+        lx = combine(&product, &vectors, 0);
+        // The part of the new vector outside the old one is the next step.
+        if basis.len() > 1 {
+            step = Some(combine(&basis[1..], &vectors[1..], 0));
+        }
+        let length = norm(&x);
+        for (a, p) in x.iter_mut().zip(lx.iter_mut()) {
+            *a /= length;
+            *p /= length;
+        }
+    }
+    Err(x)
+}
+
+/// A centered unit vector of length `n ≥ 2`, pseudo-random but fixed, so that results are
+/// reproducible.
+fn start_vector(n: usize) -> Vec<f64> {
+    let mut state = 0x2545_f491_4f6c_dd1d_u64;
+    let mut x: Vec<f64> = (0..n)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            (state >> 11) as f64 / (1u64 << 53) as f64 - 0.5
+        })
+        .collect();
+    center(&mut x);
+    let length = norm(&x);
+    for a in x.iter_mut() {
+        *a /= length;
+    }
+    x
+}
+
+/// Find the smallest eigenvalue of the Laplacian `laplacian`, of `n ≥ 2` compactly numbered nodes,
+/// on vectors orthogonal to the constant vector, with its unit eigenvector.
+///
+/// Tries LOBPCG first, which takes few products with the Laplacian per step, and falls back to the
+/// Lanczos iteration, from the best vector found, if it converges slowly.
+fn fiedler_pair(laplacian: &SparseMatrix) -> (f64, Vec<f64>) {
+    let n = laplacian.dim();
+    // Gershgorin's bound on the largest eigenvalue.
+    let scale = (0..n)
+        .map(|row| laplacian.row(row).map(|(_, v)| v.abs()).sum::<f64>())
+        .fold(0., f64::max)
+        .max(std::f64::MIN_POSITIVE);
+
+    let x = start_vector(n);
+    let (value, mut vector) = match lobpcg(laplacian, x, scale) {
+        Ok(pair) => pair,
+        Err(x) => lanczos(laplacian, x, scale),
+    };
+    center(&mut vector);
+    let length = norm(&vector);
+    // Fix the sign, so that the largest entry is positive.
+    let largest = vector
+        .iter()
+        .cloned()
+        .fold(0., |m: f64, v| if v.abs() > m.abs() { v } else { m });
+    let sign = if largest < 0. { -1. } else { 1. };
+    for a in vector.iter_mut() {
+        *a *= sign / length;
+    }
+    (value, vector)
+}
+
+/// Find the smallest eigenvalue of `laplacian` on vectors orthogonal to the constant vector, with
+/// its eigenvector, starting from the centered unit vector `x`.
+///
+/// Uses the Lanczos iteration with full reorthogonalization and thick restarts: when the basis is
+/// full, it restarts from the Ritz vectors of the smallest Ritz values, and goes on extending the
+/// basis by the Laplacian times the best of them.
+fn lanczos(laplacian: &SparseMatrix, x: Vec<f64>, scale: f64) -> (f64, Vec<f64>) {
+    let n = laplacian.dim();
+    let krylov = (n - 1).min(MAX_KRYLOV);
+    let keep = (krylov / 4).max(1);
+    // The basis, the Laplacian times each basis vector, and the projection
+    // of the Laplacian on the basis.
+    let mut product = vec![laplacian.mul_vec(&x)];
+    let mut projection = vec![vec![dot(&x, &product[0])]];
+    let mut basis = vec![x];
+    let mut result = (0., Vec::new());
+    for _ in 0..MAX_RESTARTS {
+        let mut complete = false;
+        while basis.len() < krylov {
+            let mut w = product.last().unwrap().clone();
+            // Orthogonalize against the whole basis, and again if that
+            // cancelled most of `w`, which keeps the basis orthogonal in
+            // floating point.
+            let mut b = norm(&w);
+            for _ in 0..2 {
+                center(&mut w);
+                for q in &basis {
+                    orthogonalize(&mut w, q);
+                }
+                let before = b;
+                b = norm(&w);
+                if b > 0.7 * before {
+                    break;
+                }
+            }
+            if b <= TOLERANCE * scale {
+                // The basis spans an invariant subspace.
+                complete = true;
+                break;
+            }
+            for a in w.iter_mut() {
+                *a /= b;
+            }
+            let lw = laplacian.mul_vec(&w);
+            for (row, q) in projection.iter_mut().zip(&basis) {
+                row.push(dot(q, &lw));
+            }
+            let mut last: Vec<f64> = projection.iter().map(|row| *row.last().unwrap()).collect();
+            last.push(dot(&w, &lw));
+            projection.push(last);
+            basis.push(w);
+            product.push(lw);
+        }
+        complete |= basis.len() == n - 1;
+
+        let (values, vectors) = symmetric_eigen(projection);
+        let ritz = combine(&basis, &vectors, 0);
+        let ritz_product = combine(&product, &vectors, 0);
+        let residual = norm(
+            &ritz_product
+                .iter()
+                .zip(&ritz)
+                .map(|(p, x)| p - values[0] * x)
+                .collect::<Vec<_>>(),
+        );
+        result = (values[0].max(0.), ritz);
+        if complete || residual <= TOLERANCE * scale {
+            break;
+        }
+        // Keep the best Ritz vectors, on which the projection is diagonal,
+        // with the smallest last, to extend the basis from.
+        let kept: Vec<usize> = (0..keep.min(values.len())).rev().collect();
+        let new_basis = kept.iter().map(|&i| combine(&basis, &vectors, i)).collect();
+        let new_product = kept
+            .iter()
+            .map(|&i| combine(&product, &vectors, i))
+            .collect();
+        basis = new_basis;
+        product = new_product;
+        projection = kept
+            .iter()
+            .map(|&i| {
+                kept.iter()
+                    .map(|&j| if i == j { values[i] } else { 0. })
+                    .collect()
+            })
+            .collect();
+    }
+    result
+}
+
+/// The Laplacian of the graph restricted to the indices in use by a node, and those indices.
+fn compact_laplacian<G, F>(graph: G, edge_weight: F) -> (Vec<usize>, SparseMatrix)
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable,
+    F: FnMut(G::EdgeRef) -> f64,
+{
+    let indices: Vec<usize> = graph
+        .node_identifiers()
+        .map(|v| graph.to_index(v))
+        .collect();
+    let laplacian = laplacian_matrix(graph, edge_weight).restrict(&indices);
+    (indices, laplacian)
+}
+
+/// \[Generic\] Compute the *Fiedler vector* of a graph: an eigenvector of the second smallest
+/// eigenvalue of its Laplacian matrix *L = D − A*.
+///
+/// *A* is the weighted adjacency matrix, with parallel edges adding up and without self loops, and
+/// *D* is the diagonal matrix of weighted degrees. The function `edge_weight` should return the
+/// weight of a particular edge; weights must be non-negative.
+///
+/// Return the eigenvalue, which is the [`algebraic_connectivity`](fn.algebraic_connectivity.html),
+/// and the eigenvector, indexed by `to_index`, with `NAN` at indices that are not in use by a node.
+/// The vector has unit length, is orthogonal to the constant vector, and has its largest entry, in
+/// absolute value, positive. Return `None` if the graph has fewer than two nodes.
+///
+/// Uses LOBPCG with a diagonal preconditioner, taking **O(|V| + |E|)** time per step, until the
+/// residual is below 10⁻¹⁰ relative to the largest degree. If that takes more than 200 steps, it
+/// goes on with the Lanczos iteration, restarting with up to 100 vectors.
+///
+/// **Reference**
+///
+/// * M. Fiedler; *Algebraic connectivity of graphs*
+/// * A. V. Knyazev; *Toward the optimal preconditioned eigensolver: locally optimal block
+///   preconditioned conjugate gradient method*
+///
+/// # Example
+/// ```rust
+/// use petgraph::prelude::*;
+/// use petgraph::algo::fiedler_vector;
+///
+/// // On a path, the vector goes from one end to the other.
+/// let graph = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 3), (3, 4)]);
+/// let (value, vector) = fiedler_vector(&graph, |_| 1.).unwrap();
+/// assert!((value - 2. * (1. - (std::f64::consts::PI / 5.).cos())).abs() < 1e-9);
+/// assert!(vector.windows(2).all(|w| w[0] < w[1]) || vector.windows(2).all(|w| w[0] > w[1]));
+/// assert!(vector[2].abs() < 1e-9);
+/// ```
+pub fn fiedler_vector<G, F>(graph: G, edge_weight: F) -> Option<(f64, Vec<f64>)>
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable,
+    F: FnMut(G::EdgeRef) -> f64,
+{
+    let (indices, laplacian) = compact_laplacian(graph, edge_weight);
+    if indices.len() < 2 {
+        return None;
+    }
+    let (value, compact) = fiedler_pair(&laplacian);
+    let mut vector = vec![std::f64::NAN; graph.node_bound()];
+    for (&i, &x) in indices.iter().zip(&compact) {
+        vector[i] = x;
+    }
+    Some((value, vector))
+}
+
+/// \[Generic\] Compute the *algebraic connectivity* of a graph: the second smallest eigenvalue of
+/// its Laplacian matrix.
+///
+/// It is zero if and only if the graph is disconnected, and larger for graphs that are harder to
+/// cut apart. Computed as for [`fiedler_vector`](fn.fiedler_vector.html). Return `None` if the
+/// graph has fewer than two nodes.
+///
+/// # Example
+/// ```rust
+/// use petgraph::prelude::*;
+/// use petgraph::algo::algebraic_connectivity;
+///
+/// // A complete graph on four nodes.
+/// let graph = UnGraph::<(), ()>::from_edges(&[(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3)]);
+/// let value = algebraic_connectivity(&graph, |_| 1.).unwrap();
+/// assert!((value - 4.).abs() < 1e-9);
+/// ```
+pub fn algebraic_connectivity<G, F>(graph: G, edge_weight: F) -> Option<f64>
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable,
+    F: FnMut(G::EdgeRef) -> f64,
+{
+    let (indices, laplacian) = compact_laplacian(graph, edge_weight);
+    if indices.len() < 2 {
+        return None;
+    }
+    Some(fiedler_pair(&laplacian).0)
+}
+
+/// \[Generic\] Bisect a graph by its [`fiedler_vector`](fn.fiedler_vector.html): the half of the
+/// nodes with the smallest entries go to part 0, and the rest to part 1.
+///
+/// The function `edge_weight` should return the weight of a particular edge; weights must be
+/// non-negative. Return the edge cut, the total weight of the edges between the parts, and the part
+/// of each node, indexed by `to_index`, with `usize::MAX` at indices that are not in use by a node,
+/// as the functions of [`partition`](partition/index.html) do, whose
+/// [`kernighan_lin`](fn.kernighan_lin.html) can refine the result.
+///
+/// **Reference**
+///
+/// * A. Pothen, H. D. Simon, K.-P. Liou; *Partitioning sparse matrices with eigenvectors of graphs*
+///
+/// # Example
+/// ```rust
+/// use petgraph::prelude::*;
+/// use petgraph::algo::spectral_bisection;
+///
+/// // Two triangles joined by an edge.
+/// let graph = UnGraph::<(), ()>::from_edges(&[
+///     (0, 1), (1, 2), (2, 0),
+///     (3, 4), (4, 5), (5, 3),
+///     (2, 3),
+/// ]);
+/// let (cut, parts) = spectral_bisection(&graph, |_| 1.);
+/// assert_eq!(cut, 1.);
+/// assert!(parts == [0, 0, 0, 1, 1, 1] || parts == [1, 1, 1, 0, 0, 0]);
+/// ```
+pub fn spectral_bisection<G, F>(graph: G, mut edge_weight: F) -> (f64, Vec<usize>)
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable,
+    F: FnMut(G::EdgeRef) -> f64,
+{
+    let mut parts = vec![std::usize::MAX; graph.node_bound()];
+    let mut nodes: Vec<usize> = graph
+        .node_identifiers()
+        .map(|v| graph.to_index(v))
+        .collect();
+    let vector = match fiedler_vector(graph, &mut edge_weight) {
+        Some((_, vector)) => vector,
+        None => {
+            for &v in &nodes {
+                parts[v] = 0;
+            }
+            return (0., parts);
+        }
+    };
+    nodes.sort_by(|&a, &b| vector[a].partial_cmp(&vector[b]).unwrap());
+    let half = nodes.len() / 2;
+    for (i, &v) in nodes.iter().enumerate() {
+        parts[v] = usize::from(i >= half);
+    }
+    let cut = graph
+        .edge_references()
+        .filter(|e| parts[graph.to_index(e.source())] != parts[graph.to_index(e.target())])
+        .map(edge_weight)
+        .sum();
+    (cut, parts)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::{DiGraph, UnGraph};
+    use crate::stable_graph::StableDiGraph;
+
+    fn dense(matrix: &SparseMatrix) -> Vec<Vec<f64>> {
+        (0..matrix.dim())
+            .map(|i| {
+                let mut row = vec![0.; matrix.dim()];
+                for (j, v) in matrix.row(i) {
+                    row[j] = v;
+                }
+                row
+            })
+            .collect()
+    }
+
+    fn scale(laplacian: &SparseMatrix) -> f64 {
+        (0..laplacian.dim())
+            .map(|row| laplacian.row(row).map(|(_, v)| v.abs()).sum::<f64>())
+            .fold(0., f64::max)
+    }
+
+    #[test]
+    fn matrices_of_small_graph() {
+        let graph =
+            UnGraph::<(), f64>::from_edges(&[(0, 1, 1.), (1, 2, 2.), (2, 2, 5.), (0, 1, 3.)]);
+        let a = dense(&adjacency_matrix(&graph, |e| *e.weight()));
+        assert_eq!(a, [[0., 4., 0.], [4., 0., 2.], [0., 2., 5.]]);
+        let l = dense(&laplacian_matrix(&graph, |e| *e.weight()));
+        assert_eq!(l, [[4., -4., 0.], [-4., 6., -2.], [0., -2., 2.]]);
+        let n = dense(&normalized_laplacian_matrix(&graph, |e| *e.weight()));
+        assert_eq!(n[0][0], 1.);
+        assert!((n[0][1] + 4. / 24f64.sqrt()).abs() < 1e-12);
+        assert!((n[1][2] + 2. / 12f64.sqrt()).abs() < 1e-12);
+        assert_eq!(n[1][2], n[2][1]);
+    }
+
+    #[test]
+    fn matrices_of_directed_graph_with_removed_node() {
+        // Directed edges count in both directions, and vacant indices are empty.
+        let mut graph = StableDiGraph::<(), ()>::new();
+        let nodes: Vec<_> = (0..4).map(|_| graph.add_node(())).collect();
+        graph.add_edge(nodes[0], nodes[3], ());
+        graph.add_edge(nodes[3], nodes[2], ());
+        graph.remove_node(nodes[1]);
+        let l = laplacian_matrix(&graph, |_| 1.);
+        assert_eq!(l.dim(), 4);
+        assert_eq!(l.row(1).count(), 0);
+        assert_eq!(l.row(3).collect::<Vec<_>>(), [(0, -1.), (2, -1.), (3, 2.)]);
+        let restricted = l.restrict(&[0, 2, 3]);
+        assert_eq!(
+            dense(&restricted),
+            [[1., 0., -1.], [0., 1., -1.], [-1., -1., 2.]]
+        );
+    }
+
+    #[test]
+    fn normalized_laplacian_eigenvalues() {
+        // A path is bipartite, so its normalized Laplacian has 2 as an eigenvalue, and the
+        // isolated node adds a zero row.
+        let mut graph = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 3)]);
+        graph.add_node(());
+        let (values, _) = symmetric_eigen(dense(&normalized_laplacian_matrix(&graph, |_| 1.)));
+        assert!(values[0].abs() < 1e-12 && values[1].abs() < 1e-12);
+        assert!((values[4] - 2.).abs() < 1e-12);
+
+        let triangle = DiGraph::<(), f64>::from_edges(&[(0, 1, 1.), (1, 2, 2.), (2, 0, 3.)]);
+        let (values, _) = symmetric_eigen(dense(&normalized_laplacian_matrix(&triangle, |e| {
+            *e.weight()
+        })));
+        assert!(values[0].abs() < 1e-12);
+        assert!(values.iter().all(|&v| v > -1e-12 && v < 2.));
+    }
+
+    #[test]
+    fn solvers_match_dense_eigenvalues() {
+        let graphs = vec![
+            UnGraph::<(), f64>::from_edges(&[(0, 1, 1.), (1, 2, 1.), (2, 3, 1.), (3, 4, 1.)]),
+            UnGraph::from_edges(&[(0, 1, 1.), (1, 2, 2.), (2, 3, 0.5), (3, 0, 1.), (0, 2, 3.)]),
+            UnGraph::from_edges(&[(0, 1, 1.), (1, 2, 1.), (3, 4, 1.), (4, 5, 1.), (5, 3, 1.)]),
+            UnGraph::from_edges((0..8).map(|i| (i, (i + 1) % 8, 1. + f64::from(i) / 4.))),
+            UnGraph::from_edges(&[(0, 1, 1.)]),
+        ];
+        for graph in graphs {
+            let (_, laplacian) = compact_laplacian(&graph, |e| *e.weight());
+            let (values, _) = symmetric_eigen(dense(&laplacian));
+            let close = |value: f64| (value - values[1].max(0.)).abs() < 1e-9;
+            let x = start_vector(laplacian.dim());
+            let (value, _) = lobpcg(&laplacian, x.clone(), scale(&laplacian)).unwrap();
+            assert!(close(value), "{} against {}", value, values[1]);
+            let (value, _) = lanczos(&laplacian, x, scale(&laplacian));
+            assert!(close(value), "{} against {}", value, values[1]);
+        }
+    }
+}
@@ -0,0 +1,382 @@
+//! Approximate Steiner trees: trees of least weight that connect a set of terminal nodes.
+//!
+//! A Steiner tree may pass through nodes that are not terminals, and finding one of minimum weight
+//! is NP-hard. Both functions here find a tree of at most twice the minimum weight, or more
+//! precisely **2 − 2/ℓ** times it for a minimum tree with **ℓ** leaves, by building a minimum
+//! spanning tree of the terminals under shortest path distances and expanding its edges into
+//! paths. Doubling the edges of a minimum Steiner tree gives a closed walk through all terminals,
+//! whose weight bounds that of the spanning tree.
+//!
+//! [`steiner_tree`] uses Mehlhorn's variant, which replaces the complete graph of distances between
+//! terminals by the edges between the regions of a single shortest path search from all terminals
+//! at once. Its minimum spanning tree is also one of the complete graph, so the bound is the same,
+//! in **O(|E| log |V|)** time instead of one search per terminal for
+//! [`metric_closure_steiner_tree`].
+//!
+//! **Reference**
+//!
+//! * Kurt Mehlhorn; *A faster approximation algorithm for the Steiner problem in graphs*
+//! * L. Kou, G. Markowsky, L. Berman; *A fast algorithm for Steiner trees*
+//!
+//! [`steiner_tree`]: fn.steiner_tree.html
+//! [`metric_closure_steiner_tree`]: fn.metric_closure_steiner_tree.html
+
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap};
+
+use super::{min_spanning_tree, Measure};
+use crate::data::Element;
+use crate::graph::{NodeIndex, UnGraph};
+use crate::scored::MinScored;
+use crate::visit::{EdgeRef, GraphProp, IntoEdgeReferences, NodeIndexable};
+use crate::Undirected;
+
+/// \[Generic\] Finds a [Steiner tree] connecting the nodes `terminals` of an undirected graph,
+/// whose weight is at most twice the minimum.
+///
+/// The function `edge_weight` should return the weight of a particular edge; weights must be
+/// non-negative.
+///
+/// Uses Mehlhorn's variant of the metric closure heuristic: a single shortest path search from
+/// all terminals at once splits the graph into regions around the nearest terminal, and the
+/// minimum spanning tree of the terminals, joined by the cheapest path through each edge between
+/// two regions, is expanded into a subgraph. The result is a minimum spanning tree of that
+/// subgraph, with any leaves that are not terminals removed. Time complexity is
+/// **O(|E| log |V|)**.
+///
+/// See [`metric_closure_steiner_tree`](fn.metric_closure_steiner_tree.html) for the original
+/// heuristic, which runs one search per terminal and is sometimes slightly better.
+///
+/// Returns the total weight and the edges of the tree, or `None` if the terminals are not all
+/// connected.
+///
+/// **Reference**
+///
+/// * Kurt Mehlhorn; *A faster approximation algorithm for the Steiner problem in graphs*
+///
+/// # Example
+///
+/// ```
+/// use petgraph::algo::steiner_tree;
+/// use petgraph::prelude::*;
+///
+/// // A star with a heavy outer cycle.
+/// let g = UnGraph::<(), u32>::from_edges(&[
+///     (0, 1, 2), (0, 2, 2), (0, 3, 2),
+///     (1, 2, 5), (2, 3, 5), (3, 1, 5),
+/// ]);
+/// let terminals = [NodeIndex::new(1), NodeIndex::new(2), NodeIndex::new(3)];
+/// let (weight, edges) = steiner_tree(&g, &terminals, |e| *e.weight()).unwrap();
+/// assert_eq!(weight, 6);
+/// assert!(edges.iter().all(|e| e.source() == NodeIndex::new(0)));
+/// ```
+///
+/// [Steiner tree]: https://en.wikipedia.org/wiki/Steiner_tree_problem
+pub fn steiner_tree<G, F, K>(
+    graph: G,
+    terminals: &[G::NodeId],
+    edge_weight: F,
+) -> Option<(K, Vec<G::EdgeRef>)>
+where
+    G: IntoEdgeReferences + NodeIndexable + GraphProp<EdgeType = Undirected>,
+    F: FnMut(G::EdgeRef) -> K,
+    K: Measure + Copy,
+{
+    let (edges, instance) = SteinerInstance::new(graph, terminals, edge_weight);
+    let terminals = &instance.terminals;
+    if terminals.len() <= 1 {
+        return Some((K::default(), Vec::new()));
+    }
+    let forest = instance.shortest_path_forest(terminals);
+
+    // The cheapest path between each pair of neighboring regions.
+    let mut bridges: HashMap<(usize, usize), (K, usize)> = HashMap::new();
+    for (e, &(a, b)) in instance.endpoints.iter().enumerate() {
+        let (da, db) = match (forest.distance[a], forest.distance[b]) {
+            (Some(da), Some(db)) => (da, db),
+            _ => continue,
+        };
+        let (ra, rb) = (forest.origin[a], forest.origin[b]);
+        if ra == rb {
+            continue;
+        }
+        let length = da + instance.weights[e] + db;
+        match bridges.entry((ra.min(rb), ra.max(rb))) {
+            Entry::Occupied(mut bridge) => {
+                if length < bridge.get().0 {
+                    bridge.insert((length, e));
+                }
+            }
+            Entry::Vacant(bridge) => {
+                bridge.insert((length, e));
+            }
+        }
+    }
+    let mut closure = UnGraph::<(), _>::with_capacity(terminals.len(), bridges.len());
+    for _ in terminals {
+        closure.add_node(());
+    }
+    for (&(ra, rb), &bridge) in &bridges {
+        closure.add_edge(NodeIndex::new(ra), NodeIndex::new(rb), bridge);
+    }
+
+    let mut subgraph = Vec::new();
+    let mut joined = 0;
+    for element in min_spanning_tree(&closure) {
+        if let Element::Edge { weight: (_, e), .. } = element {
+            let (a, b) = instance.endpoints[e];
+            subgraph.push(e);
+            subgraph.extend(forest.path_to_origin(&instance, a));
+            subgraph.extend(forest.path_to_origin(&instance, b));
+            joined += 1;
+        }
+    }
+    if joined + 1 < terminals.len() {
+        return None;
+    }
+    Some(instance.finish(&edges, subgraph))
+}
+
+/// \[Generic\] Finds a [Steiner tree] connecting the nodes `terminals` of an undirected graph,
+/// whose weight is at most twice the minimum, using the metric closure.
+///
+/// The function `edge_weight` should return the weight of a particular edge; weights must be
+/// non-negative.
+///
+/// Computes the shortest paths from every terminal and a minimum spanning tree of the complete
+/// graph of terminals, weighted by their distances. The paths along its edges are joined into a
+/// subgraph, and the result is a minimum spanning tree of that subgraph, with any leaves that are
+/// not terminals removed. Time complexity is **O(|T||E| log |V|)** for terminals **T**; prefer
+/// [`steiner_tree`](fn.steiner_tree.html) for many terminals.
+///
+/// Returns the total weight and the edges of the tree, or `None` if the terminals are not all
+/// connected.
+///
+/// **Reference**
+///
+/// * L. Kou, G. Markowsky, L. Berman; *A fast algorithm for Steiner trees*
+///
+/// # Example
+///
+/// ```
+/// use petgraph::algo::metric_closure_steiner_tree;
+/// use petgraph::prelude::*;
+///
+/// let g = UnGraph::<(), f64>::from_edges(&[(0, 1, 1.), (1, 2, 1.), (2, 3, 1.), (0, 3, 2.5)]);
+/// let terminals = [NodeIndex::new(0), NodeIndex::new(3)];
+/// let (weight, edges) = metric_closure_steiner_tree(&g, &terminals, |e| *e.weight()).unwrap();
+/// assert_eq!(weight, 2.5);
+/// assert_eq!(edges.len(), 1);
+/// ```
+///
+/// [Steiner tree]: https://en.wikipedia.org/wiki/Steiner_tree_problem
+pub fn metric_closure_steiner_tree<G, F, K>(
+    graph: G,
+    terminals: &[G::NodeId],
+    edge_weight: F,
+) -> Option<(K, Vec<G::EdgeRef>)>
+where
+    G: IntoEdgeReferences + NodeIndexable + GraphProp<EdgeType = Undirected>,
+    F: FnMut(G::EdgeRef) -> K,
+    K: Measure + Copy,
+{
+    let (edges, instance) = SteinerInstance::new(graph, terminals, edge_weight);
+    let terminals = &instance.terminals;
+    if terminals.len() <= 1 {
+        return Some((K::default(), Vec::new()));
+    }
+    let forests: Vec<ShortestPathForest<K>> = terminals
+        .iter()
+        .map(|&t| instance.shortest_path_forest(&[t]))
+        .collect();
+    if terminals.iter().any(|&t| forests[0].distance[t].is_none()) {
+        return None;
+    }
+
+    let mut closure = UnGraph::<(), _>::with_capacity(terminals.len(), terminals.len().pow(2) / 2);
+    for _ in terminals {
+        closure.add_node(());
+    }
+    for (i, forest) in forests.iter().enumerate() {
+        for (j, &t) in terminals.iter().enumerate().skip(i + 1) {
+            let distance = forest.distance[t].unwrap();
+            closure.add_edge(NodeIndex::new(i), NodeIndex::new(j), distance);
+        }
+    }
+
+    let mut subgraph = Vec::new();
+    for element in min_spanning_tree(&closure) {
+        if let Element::Edge { source, target, .. } = element {
+            subgraph.extend(forests[source].path_to_origin(&instance, terminals[target]));
+        }
+    }
+    Some(instance.finish(&edges, subgraph))
+}
+
+/// An undirected graph with compact node and edge indices.
+struct SteinerInstance<K> {
+    node_bound: usize,
+    endpoints: Vec<(usize, usize)>,
+    weights: Vec<K>,
+    /// The incident edges of each node, with the other endpoint.
+    adjacency: Vec<Vec<(usize, usize)>>,
+    /// The distinct terminals.
+    terminals: Vec<usize>,
+}
+
+impl<K> SteinerInstance<K>
+where
+    K: Measure + Copy,
+{
+    fn new<G, F>(graph: G, terminals: &[G::NodeId], mut edge_weight: F) -> (Vec<G::EdgeRef>, Self)
+    where
+        G: IntoEdgeReferences + NodeIndexable,
+        F: FnMut(G::EdgeRef) -> K,
+    {
+        let node_bound = graph.node_bound();
+        let edges: Vec<G::EdgeRef> = graph.edge_references().collect();
+        let endpoints: Vec<(usize, usize)> = edges
+            .iter()
+            .map(|e| (graph.to_index(e.source()), graph.to_index(e.target())))
+            .collect();
+        let weights = edges.iter().map(|&e| edge_weight(e)).collect();
+        let mut adjacency = vec![Vec::new(); node_bound];
+        for (e, &(a, b)) in endpoints.iter().enumerate() {
+            adjacency[a].push((e, b));
+            adjacency[b].push((e, a));
+        }
+        let mut is_terminal = vec![false; node_bound];
+        let mut distinct = Vec::new();
+        for &t in terminals {
+            let t = graph.to_index(t);
+            if !is_terminal[t] {
+                is_terminal[t] = true;
+                distinct.push(t);
+            }
+        }
+        let instance = SteinerInstance {
+            node_bound,
+            endpoints,
+            weights,
+            adjacency,
+            terminals: distinct,
+        };
+        (edges, instance)
+    }
+
+    /// Dijkstra's algorithm from several sources at once.
+    fn shortest_path_forest(&self, sources: &[usize]) -> ShortestPathForest<K> {
+        let mut forest = ShortestPathForest {
+            distance: vec![None; self.node_bound],
+            predecessor: vec![None; self.node_bound],
+            origin: vec![0; self.node_bound],
+        };
+        let mut visited = vec![false; self.node_bound];
+        let mut visit_next = BinaryHeap::new();
+        for (i, &s) in sources.iter().enumerate() {
+            forest.distance[s] = Some(K::default());
+            forest.origin[s] = i;
+            visit_next.push(MinScored(K::default(), s));
+        }
+        while let Some(MinScored(distance, v)) = visit_next.pop() {
+            if visited[v] {
+                continue;
+            }
+            visited[v] = true;
+            for &(e, w) in &self.adjacency[v] {
+                if visited[w] {
+                    continue;
+                }
+                let next = distance + self.weights[e];
+                if forest.distance[w].map_or(true, |d| next < d) {
+                    forest.distance[w] = Some(next);
+                    forest.predecessor[w] = Some(e);
+                    forest.origin[w] = forest.origin[v];
+                    visit_next.push(MinScored(next, w));
+                }
+            }
+        }
+        forest
+    }
+
+    /// Reduce the subgraph with the given edges to a minimum spanning tree whose leaves are
+    /// terminals.
+    fn finish<E: Copy>(&self, edges: &[E], mut subgraph: Vec<usize>) -> (K, Vec<E>) {
+        subgraph.sort_unstable();
+        subgraph.dedup();
+        let mut reduced = UnGraph::<(), _>::with_capacity(self.node_bound, subgraph.len());
+        for _ in 0..self.node_bound {
+            reduced.add_node(());
+        }
+        for &e in &subgraph {
+            let (a, b) = self.endpoints[e];
+            reduced.add_edge(NodeIndex::new(a), NodeIndex::new(b), (self.weights[e], e));
+        }
+        let mut tree: Vec<usize> = min_spanning_tree(&reduced)
+            .filter_map(|element| match element {
+                Element::Edge { weight: (_, e), .. } => Some(e),
+                Element::Node { .. } => None,
+            })
+            .collect();
+
+        // Remove leaves that are not terminals, until there are none.
+        let mut is_terminal = vec![false; self.node_bound];
+        for &t in &self.terminals {
+            is_terminal[t] = true;
+        }
+        let mut degree = vec![0; self.node_bound];
+        let mut incident = vec![Vec::new(); self.node_bound];
+        for &e in &tree {
+            let (a, b) = self.endpoints[e];
+            for &v in &[a, b] {
+                degree[v] += 1;
+                incident[v].push(e);
+            }
+        }
+        let mut removed = vec![false; self.endpoints.len()];
+        let mut leaves: Vec<usize> = (0..self.node_bound)
+            .filter(|&v| degree[v] == 1 && !is_terminal[v])
+            .collect();
+        while let Some(v) = leaves.pop() {
+            let e = match incident[v].iter().find(|&&e| !removed[e]) {
+                Some(&e) => e,
+                None => continue,
+            };
+            removed[e] = true;
+            let (a, b) = self.endpoints[e];
+            let w = if a == v { b } else { a };
+            degree[v] -= 1;
+            degree[w] -= 1;
+            if degree[w] == 1 && !is_terminal[w] {
+                leaves.push(w);
+            }
+        }
+        tree.retain(|&e| !removed[e]);
+        tree.sort_unstable();
+        let weight = tree
+            .iter()
+            .fold(K::default(), |sum, &e| sum + self.weights[e]);
+        (weight, tree.into_iter().map(|e| edges[e]).collect())
+    }
+}
+
+struct ShortestPathForest<K> {
+    distance: Vec<Option<K>>,
+    /// The last edge on the shortest path to each node.
+    predecessor: Vec<Option<usize>>,
+    /// The position of the source that each node is closest to.
+    origin: Vec<usize>,
+}
+
+impl<K> ShortestPathForest<K> {
+    /// The edges of the shortest path from `v` back to its source.
+    fn path_to_origin(&self, instance: &SteinerInstance<K>, mut v: usize) -> Vec<usize> {
+        let mut path = Vec::new();
+        while let Some(e) = self.predecessor[v] {
+            path.push(e);
+            let (a, b) = instance.endpoints[e];
+            v = if a == v { b } else { a };
+        }
+        path
+    }
+}
@@ -0,0 +1,348 @@
+//! Layered, priority-driven and exhaustive topological sorting.
+//!
+//! [`toposort`](../fn.toposort.html) and [`Topo`](../../visit/struct.Topo.html) produce a single,
+//! arbitrary topological order. The functions in this module give more control over which order is
+//! produced.
+
+use std::cmp::Ordering;
+
+use fixedbitset::FixedBitSet;
+
+use super::Cycle;
+use crate::visit::{IntoNeighbors, IntoNeighborsDirected, IntoNodeIdentifiers, NodeIndexable};
+use crate::Direction::Incoming;
+
+/// Count the incoming edges of every node, indexed by `to_index`.
+fn in_degrees<G>(g: G) -> Vec<usize>
+where
+    G: IntoNeighbors + IntoNodeIdentifiers + NodeIndexable,
+{
+    let mut in_degree = vec![0; g.node_bound()];
+    for a in g.node_identifiers() {
+        for b in g.neighbors(a) {
+            in_degree[g.to_index(b)] += 1;
+        }
+    }
+    in_degree
+}
+
+/// Find a node on a cycle among the nodes that were never `placed`.
+///
+/// Every node that is not placed still has a predecessor that is not placed, so walking backwards
+/// must eventually revisit a node, which is on a cycle.
+fn find_cycle<G>(g: G, placed: &FixedBitSet) -> Option<Cycle<G::NodeId>>
+where
+    G: IntoNeighborsDirected + IntoNodeIdentifiers + NodeIndexable,
+{
+    let mut node = g.node_identifiers().find(|&n| !placed[g.to_index(n)])?;
+    let mut seen = FixedBitSet::with_capacity(g.node_bound());
+    while !seen.put(g.to_index(node)) {
+        node = g
+            .neighbors_directed(node, Incoming)
+            .find(|&p| !placed[g.to_index(p)])?;
+    }
+    Some(Cycle(node))
+}
+
+/// \[Generic\] Partition the nodes of a directed acyclic graph into *topological generations*.
+///
+/// The first generation holds every node without incoming edges, and each following generation
+/// holds the nodes whose predecessors all belong to earlier generations. The nodes of a generation
+/// have no edges between them, so each generation can be processed in parallel once the previous
+/// ones are done. Concatenating the generations gives a topological order.
+///
+/// The nodes within a generation are ordered by their index.
+///
+/// If the graph has a cycle, a `Cycle` error is returned instead. Self loops are also cycles.
+///
+/// Computes in **O(|V| + |E|)** time.
+///
+/// # Example
+/// ```rust
+/// use petgraph::prelude::*;
+/// use petgraph::algo::topological_generations;
+///
+/// let mut graph = DiGraph::<(), ()>::new();
+/// let a = graph.add_node(());
+/// let b = graph.add_node(());
+/// let c = graph.add_node(());
+/// let d = graph.add_node(());
+/// graph.extend_with_edges(&[(a, c), (b, c), (c, d), (a, d)]);
+///
+/// let generations = topological_generations(&graph).unwrap();
+/// assert_eq!(generations, vec![vec![a, b], vec![c], vec![d]]);
+/// ```
+pub fn topological_generations<G>(g: G) -> Result<Vec<Vec<G::NodeId>>, Cycle<G::NodeId>>
+where
+    G: IntoNeighborsDirected + IntoNodeIdentifiers + NodeIndexable,
+{
+    let mut in_degree = in_degrees(g);
+    let mut placed = FixedBitSet::with_capacity(g.node_bound());
+    let mut generations = Vec::new();
+    let mut current: Vec<_> = g
+        .node_identifiers()
+        .filter(|&n| in_degree[g.to_index(n)] == 0)
+        .collect();
+    current.sort_by_key(|&n| g.to_index(n));
+
+    while !current.is_empty() {
+        let mut next = Vec::new();
+        for &a in &current {
+            placed.insert(g.to_index(a));
+            for b in g.neighbors(a) {
+                let ix = g.to_index(b);
+                in_degree[ix] -= 1;
+                if in_degree[ix] == 0 {
+                    next.push(b);
+                }
+            }
+        }
+        next.sort_by_key(|&b| g.to_index(b));
+        generations.push(current);
+        current = next;
+    }
+
+    match find_cycle(g, &placed) {
+        Some(cycle) => Err(cycle),
+        None => Ok(generations),
+    }
+}
+
+/// A binary min-heap ordered by a user supplied comparison function.
+struct HeapBy<T> {
+    data: Vec<T>,
+}
+
+impl<T: Copy> HeapBy<T> {
+    fn push<F>(&mut self, item: T, cmp: &mut F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        self.data.push(item);
+        let mut pos = self.data.len() - 1;
+        while pos > 0 {
+            let parent = (pos - 1) / 2;
+            if cmp(&self.data[pos], &self.data[parent]) != Ordering::Less {
+                break;
+            }
+            self.data.swap(pos, parent);
+            pos = parent;
+        }
+    }
+
+    fn pop<F>(&mut self, cmp: &mut F) -> Option<T>
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        let last = self.data.len().checked_sub(1)?;
+        self.data.swap(0, last);
+        let item = self.data.pop();
+        let len = self.data.len();
+        let mut pos = 0;
+        loop {
+            let left = 2 * pos + 1;
+            let right = left + 1;
+            let mut least = pos;
+            if left < len && cmp(&self.data[left], &self.data[least]) == Ordering::Less {
+                least = left;
+            }
+            if right < len && cmp(&self.data[right], &self.data[least]) == Ordering::Less {
+                least = right;
+            }
+            if least == pos {
+                break;
+            }
+            self.data.swap(pos, least);
+            pos = least;
+        }
+        item
+    }
+}
+
+/// \[Generic\] Perform a topological sort of a directed graph, choosing among the ready nodes with
+/// a comparison function.
+///
+/// Whenever several nodes have all of their predecessors ordered, the least one according to
+/// `compare` is emitted next. Passing `|a, b| a.cmp(b)` yields the lexicographically smallest
+/// topological order, and any total order makes the result deterministic regardless of the graph's
+/// internal edge order.
+///
+/// If the graph has a cycle, a `Cycle` error is returned instead. Self loops are also cycles.
+///
+/// Computes in **O(|V| log |V| + |E|)** time, counting calls to `compare`.
+///
+/// # Example
+/// ```rust
+/// use petgraph::prelude::*;
+/// use petgraph::algo::toposort_by;
+///
+/// let mut graph = DiGraph::<&str, ()>::new();
+/// let d = graph.add_node("d");
+/// let b = graph.add_node("b");
+/// let a = graph.add_node("a");
+/// let c = graph.add_node("c");
+/// graph.extend_with_edges(&[(d, c), (b, c)]);
+///
+/// // Order ready nodes by their weight.
+/// let order = toposort_by(&graph, |&x, &y| graph[x].cmp(graph[y])).unwrap();
+/// assert_eq!(order, vec![a, b, d, c]);
+/// ```
+pub fn toposort_by<G, F>(g: G, mut compare: F) -> Result<Vec<G::NodeId>, Cycle<G::NodeId>>
+where
+    G: IntoNeighborsDirected + IntoNodeIdentifiers + NodeIndexable,
+    F: FnMut(&G::NodeId, &G::NodeId) -> Ordering,
+{
+    let mut in_degree = in_degrees(g);
+    let mut placed = FixedBitSet::with_capacity(g.node_bound());
+    let mut order = Vec::new();
+    let mut ready = HeapBy { data: Vec::new() };
+    for n in g.node_identifiers() {
+        if in_degree[g.to_index(n)] == 0 {
+            ready.push(n, &mut compare);
+        }
+    }
+
+    while let Some(a) = ready.pop(&mut compare) {
+        placed.insert(g.to_index(a));
+        order.push(a);
+        for b in g.neighbors(a) {
+            let ix = g.to_index(b);
+            in_degree[ix] -= 1;
+            if in_degree[ix] == 0 {
+                ready.push(b, &mut compare);
+            }
+        }
+    }
+
+    match find_cycle(g, &placed) {
+        Some(cycle) => Err(cycle),
+        None => Ok(order),
+    }
+}
+
+/// \[Generic\] Return an iterator over every topological order of a directed acyclic graph.
+///
+/// The orders are produced by backtracking, choosing among the ready nodes in `node_identifiers`
+/// order, so the first order is the one where earlier nodes are placed as soon as possible. A graph
+/// with **n** independent nodes has **n!** orders, so this is only practical for small graphs.
+///
+/// If the graph has a cycle, the iterator produces no orders.
+///
+/// # Example
+/// ```rust
+/// use petgraph::prelude::*;
+/// use petgraph::algo::all_topological_sorts;
+///
+/// let mut graph = DiGraph::<(), ()>::new();
+/// let a = graph.add_node(());
+/// let b = graph.add_node(());
+/// let c = graph.add_node(());
+/// graph.extend_with_edges(&[(a, c), (b, c)]);
+///
+/// let orders: Vec<_> = all_topological_sorts(&graph).collect();
+/// assert_eq!(orders, vec![vec![a, b, c], vec![b, a, c]]);
+/// ```
+pub fn all_topological_sorts<G>(g: G) -> AllTopologicalSorts<G>
+where
+    G: IntoNeighbors + IntoNodeIdentifiers + NodeIndexable,
+{
+    let in_degree = in_degrees(g);
+    let nodes: Vec<_> = g.node_identifiers().collect();
+    let roots = nodes
+        .iter()
+        .cloned()
+        .filter(|&n| in_degree[g.to_index(n)] == 0)
+        .collect();
+    AllTopologicalSorts {
+        graph: g,
+        nodes,
+        in_degree,
+        placed: FixedBitSet::with_capacity(g.node_bound()),
+        order: Vec::new(),
+        stack: vec![(roots, 0)],
+    }
+}
+
+/// An iterator over all topological orders of a graph.
+///
+/// Created with [`all_topological_sorts`](fn.all_topological_sorts.html).
+#[derive(Clone, Debug)]
+pub struct AllTopologicalSorts<G>
+where
+    G: IntoNeighbors,
+{
+    graph: G,
+    nodes: Vec<G::NodeId>,
+    in_degree: Vec<usize>,
+    placed: FixedBitSet,
+    order: Vec<G::NodeId>,
+    /// For every position in `order`, the ready candidates and the index of the next one to try.
+    stack: Vec<(Vec<G::NodeId>, usize)>,
+}
+
+impl<G> AllTopologicalSorts<G>
+where
+    G: IntoNeighbors + NodeIndexable,
+{
+    fn place(&mut self, a: G::NodeId) {
+        let g = self.graph;
+        self.placed.insert(g.to_index(a));
+        self.order.push(a);
+        for b in g.neighbors(a) {
+            self.in_degree[g.to_index(b)] -= 1;
+        }
+    }
+
+    fn unplace(&mut self) {
+        let g = self.graph;
+        if let Some(a) = self.order.pop() {
+            self.placed.set(g.to_index(a), false);
+            for b in g.neighbors(a) {
+                self.in_degree[g.to_index(b)] += 1;
+            }
+        }
+    }
+}
+
+impl<G> Iterator for AllTopologicalSorts<G>
+where
+    G: IntoNeighbors + NodeIndexable,
+{
+    type Item = Vec<G::NodeId>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let g = self.graph;
+        if self.nodes.is_empty() {
+            // The empty graph has exactly one (empty) order.
+            return self.stack.pop().map(|_| Vec::new());
+        }
+        loop {
+            let (candidates, next) = self.stack.last_mut()?;
+            if let Some(&a) = candidates.get(*next) {
+                *next += 1;
+                self.place(a);
+                if self.order.len() == self.nodes.len() {
+                    let order = self.order.clone();
+                    self.unplace();
+                    return Some(order);
+                }
+                let ready = self
+                    .nodes
+                    .iter()
+                    .cloned()
+                    .filter(|&n| {
+                        let ix = g.to_index(n);
+                        !self.placed[ix] && self.in_degree[ix] == 0
+                    })
+                    .collect();
+                self.stack.push((ready, 0));
+            } else {
+                // All candidates at this position are exhausted: backtrack.
+                self.stack.pop();
+                if !self.stack.is_empty() {
+                    self.unplace();
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,107 @@
+//! Tree decompositions, and upper bounds on the treewidth of a graph.
+//!
+//! [`treewidth`] eliminates the nodes one at a time in a greedy order chosen by an
+//! [`EliminationHeuristic`]: `MinDegree` eliminates a node with the fewest neighbors, and `MinFill`
+//! a node whose neighbors need the fewest edges to become a clique. Computing the treewidth exactly
+//! is NP-hard, so both only give an upper bound in general. They are exact on forests. `MinFill` is
+//! slower but usually tighter, and it is also exact on chordal graphs, where it only eliminates
+//! nodes whose neighbors already form a clique.
+//!
+//! [`treewidth`]: fn.treewidth.html
+//! [`EliminationHeuristic`]: ../chordal/enum.EliminationHeuristic.html
+
+use super::chordal::{compact_adjacency, EliminationGame, EliminationHeuristic};
+use crate::graph::{NodeIndex, UnGraph};
+use crate::visit::{IntoNeighbors, IntoNodeIdentifiers, NodeIndexable};
+
+/// \[Generic\] Compute a tree decomposition of a graph with an elimination heuristic, and its
+/// width.
+///
+/// A tree decomposition is a tree of *bags*, sets of nodes of the graph, such that every edge has
+/// both endpoints in some bag and the bags containing any given node form a connected subtree. Its
+/// width is the size of its largest bag minus one, and the treewidth of the graph is the smallest
+/// width of any of its tree decompositions. Computing the treewidth is NP-hard: this function finds
+/// an upper bound.
+///
+/// The nodes are eliminated in the order chosen by `heuristic`, as in [`chordal_completion`], and
+/// each node forms a bag with its neighbors at the time of its elimination. Bags that would be
+/// contained in a neighboring bag are merged into it.
+///
+/// Return the width and the decomposition, as an undirected graph whose node weights are the bags.
+/// The graph is treated as undirected; self loops and parallel edges are ignored. An empty graph
+/// has an empty decomposition of width zero.
+///
+/// Computes in **O(|V| Δ⁴ log |V|)** time for `MinFill` and **O(|V| Δ² log |V|)** time for
+/// `MinDegree`, where **Δ** is the width of the decomposition.
+///
+/// [`chordal_completion`]: ../chordal/fn.chordal_completion.html
+///
+/// # Example
+/// ```rust
+/// use petgraph::prelude::*;
+/// use petgraph::algo::{treewidth, EliminationHeuristic};
+///
+/// // A cycle has treewidth 2.
+/// let graph = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 3), (3, 4), (4, 0)]);
+///
+/// let (width, decomposition) = treewidth(&graph, EliminationHeuristic::MinFill);
+/// assert_eq!(width, 2);
+/// assert_eq!(decomposition.node_count(), 3);
+/// assert!(decomposition.node_weights().all(|bag| bag.len() == 3));
+/// ```
+pub fn treewidth<G>(
+    graph: G,
+    heuristic: EliminationHeuristic,
+) -> (usize, UnGraph<Vec<G::NodeId>, ()>)
+where
+    G: IntoNeighbors + IntoNodeIdentifiers + NodeIndexable,
+{
+    let (nodes, adjacency) = compact_adjacency(graph);
+    let mut game = EliminationGame::new(&adjacency, heuristic);
+    let mut eliminations = Vec::with_capacity(nodes.len());
+    let mut fill = Vec::new();
+    while let Some(elimination) = game.eliminate_next(&mut fill) {
+        eliminations.push(elimination);
+    }
+    let mut rank = vec![0; nodes.len()];
+    for (i, &(v, _)) in eliminations.iter().enumerate() {
+        rank[v] = i;
+    }
+
+    // The remaining neighbors of a node are all in the bag of the first of
+    // them to be eliminated, which is built first when going backwards.
+    let mut decomposition: UnGraph<Vec<G::NodeId>, ()> =
+        UnGraph::with_capacity(nodes.len(), nodes.len());
+    let mut bag_of = vec![NodeIndex::end(); nodes.len()];
+    let mut root: Option<NodeIndex> = None;
+    for (v, neighbors) in eliminations.into_iter().rev() {
+        let parent = neighbors.iter().cloned().min_by_key(|&u| rank[u]);
+        match parent.map(|u| bag_of[u]) {
+            Some(b) if decomposition[b].len() == neighbors.len() => {
+                // The bag is exactly the neighbors of `v`: extend it.
+                decomposition[b].push(nodes[v]);
+                bag_of[v] = b;
+            }
+            parent_bag => {
+                let mut bag: Vec<_> = neighbors.iter().map(|&u| nodes[u]).collect();
+                bag.push(nodes[v]);
+                let b = decomposition.add_node(bag);
+                // Connected components are attached to an arbitrary bag.
+                match parent_bag.or(root) {
+                    Some(p) => {
+                        decomposition.add_edge(p, b, ());
+                    }
+                    None => root = Some(b),
+                }
+                bag_of[v] = b;
+            }
+        }
+    }
+    let width = decomposition
+        .node_weights()
+        .map(|bag| bag.len())
+        .max()
+        .unwrap_or(1)
+        - 1;
+    (width, decomposition)
+}
@@ -0,0 +1,1207 @@
+//! Travelling salesman heuristics and exact solutions.
+//!
+//! A *tour* visits every node of the graph exactly once and returns to its first node. The
+//! functions in this module take the tour as a list of nodes, where the first node follows the
+//! last, and return its total cost along with it; a single node makes a tour of no edges. They use
+//! the cheapest edge between each pair of nodes, and return `None` if there is no tour in the
+//! graph, for example because an edge is missing. Complete graphs, such as a
+//! [`MatrixGraph`](../../matrix_graph/struct.MatrixGraph.html) of distances, always have a tour.
+//!
+//! * [`nearest_neighbor_tour`] and [`christofides`] build a tour from scratch. The tours of
+//!   Christofides' algorithm cost at most one and a half times the minimum on undirected graphs
+//!   whose costs satisfy the triangle inequality.
+//! * [`two_opt`] and [`or_opt`] improve a tour by local search, until no move reduces its cost.
+//! * [`held_karp`] finds a tour of minimum cost, for graphs of up to about 20 nodes.
+//!
+//! [`nearest_neighbor_tour`]: fn.nearest_neighbor_tour.html
+//! [`christofides`]: fn.christofides.html
+//! [`two_opt`]: fn.two_opt.html
+//! [`or_opt`]: fn.or_opt.html
+//! [`held_karp`]: fn.held_karp.html
+
+use std::ops::{Div, Sub};
+
+use super::{min_spanning_tree, Measure};
+use crate::data::Element;
+use crate::graph::{NodeIndex, UnGraph};
+use crate::visit::{EdgeRef, GraphProp, IntoEdgeReferences, IntoNodeIdentifiers, NodeIndexable};
+use crate::Undirected;
+
+/// \[Generic\] Build a tour with the nearest neighbor heuristic: starting from `start`, always
+/// move to the closest node that has not been visited yet.
+///
+/// The function `edge_cost` should return the cost for a particular edge.
+///
+/// Returns the cost and the nodes of the tour, starting from `start`, or `None` if the heuristic
+/// gets stuck at a node without edges to the unvisited nodes, or cannot return to `start`.
+///
+/// Computes in **O(|V|² + |E|)** time.
+///
+/// # Example
+///
+/// ```
+/// use petgraph::algo::nearest_neighbor_tour;
+/// use petgraph::prelude::*;
+///
+/// let g = UnGraph::<(), u32>::from_edges(&[
+///     (0, 1, 1), (1, 2, 1), (2, 3, 1), (3, 0, 1),
+///     (0, 2, 2), (1, 3, 2),
+/// ]);
+/// let (cost, tour) = nearest_neighbor_tour(&g, 0.into(), |e| *e.weight()).unwrap();
+/// assert_eq!(cost, 4);
+/// assert_eq!(tour, [0.into(), 1.into(), 2.into(), 3.into()]);
+/// ```
+pub fn nearest_neighbor_tour<G, F, K>(
+    graph: G,
+    start: G::NodeId,
+    edge_cost: F,
+) -> Option<(K, Vec<G::NodeId>)>
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable + GraphProp,
+    F: FnMut(G::EdgeRef) -> K,
+    K: Measure + Copy,
+{
+    let (nodes, costs) = CostMatrix::new(graph, edge_cost);
+    let start = nodes.iter().position(|&n| n == start)?;
+    let mut visited = vec![false; nodes.len()];
+    visited[start] = true;
+    let mut tour = vec![start];
+    let mut current = start;
+    for _ in 1..nodes.len() {
+        let mut nearest: Option<(K, usize)> = None;
+        for (next, &seen) in visited.iter().enumerate() {
+            if let (false, Some(c)) = (seen, costs.get(current, next)) {
+                if nearest.map_or(true, |(best, _)| c < best) {
+                    nearest = Some((c, next));
+                }
+            }
+        }
+        current = nearest?.1;
+        visited[current] = true;
+        tour.push(current);
+    }
+    costs.finish(&nodes, tour)
+}
+
+/// \[Generic\] Build a tour of an undirected graph with Christofides' algorithm.
+///
+/// The function `edge_cost` should return the cost for a particular edge; costs must be
+/// non-negative.
+///
+/// Finds a minimum spanning tree and a minimum weight perfect matching of its nodes of odd degree,
+/// follows an Euler tour of their union, and skips the nodes that were already visited. If the
+/// costs satisfy the triangle inequality, the tour costs at most one and a half times the
+/// minimum.
+///
+/// Returns the cost and the nodes of the tour, or `None` if the graph is not complete.
+///
+/// Computes in **O(|V|³)** time, dominated by the matching.
+///
+/// **Reference**
+///
+/// * Nicos Christofides; *Worst-case analysis of a new heuristic for the travelling salesman
+///   problem*
+/// * Zvi Galil; *Efficient algorithms for finding maximum matching in graphs*
+///
+/// # Example
+///
+/// ```
+/// use petgraph::algo::christofides;
+/// use petgraph::prelude::*;
+///
+/// // The corners of a 3 × 4 rectangle, with their Euclidean distances.
+/// let g = UnGraph::<(), f64>::from_edges(&[
+///     (0, 1, 3.), (1, 2, 4.), (2, 3, 3.), (3, 0, 4.),
+///     (0, 2, 5.), (1, 3, 5.),
+/// ]);
+/// let (cost, tour) = christofides(&g, |e| *e.weight()).unwrap();
+/// assert_eq!(cost, 14.);
+/// assert_eq!(tour.len(), 4);
+/// ```
+pub fn christofides<G, F, K>(graph: G, edge_cost: F) -> Option<(K, Vec<G::NodeId>)>
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable + GraphProp<EdgeType = Undirected>,
+    F: FnMut(G::EdgeRef) -> K,
+    K: Measure + Copy + Sub<Output = K> + Div<Output = K> + From<u8>,
+{
+    let (nodes, costs) = CostMatrix::new(graph, edge_cost);
+    let n = nodes.len();
+    let mut complete = UnGraph::<(), K>::with_capacity(n, n * n.saturating_sub(1) / 2);
+    for _ in 0..n {
+        complete.add_node(());
+    }
+    for a in 0..n {
+        for b in a + 1..n {
+            let cost = costs.get(a, b)?;
+            complete.add_edge(NodeIndex::new(a), NodeIndex::new(b), cost);
+        }
+    }
+
+    // The edges of the spanning tree, and of the matching of its nodes of odd degree.
+    let mut multigraph = Vec::with_capacity(n + n / 2);
+    let mut degree = vec![0; n];
+    for element in min_spanning_tree(&complete) {
+        if let Element::Edge { source, target, .. } = element {
+            multigraph.push((source, target));
+            degree[source] += 1;
+            degree[target] += 1;
+        }
+    }
+    let odd: Vec<usize> = (0..n).filter(|&v| degree[v] % 2 == 1).collect();
+    let mut odd_costs = Vec::with_capacity(odd.len() * odd.len() / 2);
+    for (i, &a) in odd.iter().enumerate() {
+        for (j, &b) in odd.iter().enumerate().skip(i + 1) {
+            odd_costs.push((i, j, costs.get(a, b).unwrap()));
+        }
+    }
+    for (i, j) in perfect_matching::min_weight_perfect_matching(odd.len(), &odd_costs) {
+        multigraph.push((odd[i], odd[j]));
+    }
+
+    let mut tour = Vec::with_capacity(n);
+    let mut visited = vec![false; n];
+    for v in euler_circuit(n, &multigraph) {
+        if !visited[v] {
+            visited[v] = true;
+            tour.push(v);
+        }
+    }
+    costs.finish(&nodes, tour)
+}
+
+/// \[Generic\] Improve a tour of an undirected graph with the 2-opt heuristic: replace two of
+/// its edges `(a, b)` and `(c, d)` by `(a, c)` and `(b, d)`, reversing the path between them,
+/// as long as that reduces its cost.
+///
+/// The function `edge_cost` should return the cost for a particular edge.
+///
+/// Returns the cost and the nodes of the improved tour, which starts from the same node, or
+/// `None` if `tour` is not a tour of the graph.
+///
+/// Each pass over all pairs of edges takes **O(|V|²)** time.
+///
+/// **Panics** if `tour` does not contain every node of the graph exactly once.
+///
+/// # Example
+///
+/// ```
+/// use petgraph::algo::two_opt;
+/// use petgraph::prelude::*;
+///
+/// let g = UnGraph::<(), u32>::from_edges(&[
+///     (0, 1, 1), (1, 2, 1), (2, 3, 1), (3, 0, 1),
+///     (0, 2, 3), (1, 3, 3),
+/// ]);
+/// // A tour that crosses itself.
+/// let tour = [0.into(), 2.into(), 1.into(), 3.into()];
+/// let (cost, tour) = two_opt(&g, &tour, |e| *e.weight()).unwrap();
+/// assert_eq!(cost, 4);
+/// assert_eq!(tour, [0.into(), 1.into(), 2.into(), 3.into()]);
+/// ```
+pub fn two_opt<G, F, K>(graph: G, tour: &[G::NodeId], edge_cost: F) -> Option<(K, Vec<G::NodeId>)>
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable + GraphProp<EdgeType = Undirected>,
+    F: FnMut(G::EdgeRef) -> K,
+    K: Measure + Copy,
+{
+    let (nodes, costs) = CostMatrix::new(graph, edge_cost);
+    let mut tour = costs.positions(graph, &nodes, tour);
+    costs.tour_cost(&tour)?;
+    let n = tour.len();
+    let mut improved = true;
+    while improved {
+        improved = false;
+        for i in 0..n.saturating_sub(2) {
+            for j in i + 2..n {
+                let (a, b) = (tour[i], tour[i + 1]);
+                let (c, d) = (tour[j], tour[(j + 1) % n]);
+                if d == a {
+                    continue;
+                }
+                let before = costs.sum(&[(a, b), (c, d)]);
+                let after = costs.sum(&[(a, c), (b, d)]);
+                if improves(after, before) {
+                    tour[i + 1..=j].reverse();
+                    improved = true;
+                }
+            }
+        }
+    }
+    costs.finish(&nodes, tour)
+}
+
+/// \[Generic\] Improve a tour of an undirected graph with the Or-opt heuristic: move a path of
+/// up to three consecutive nodes elsewhere in the tour, possibly reversed, as long as that reduces
+/// its cost.
+///
+/// The function `edge_cost` should return the cost for a particular edge.
+///
+/// Returns the cost and the nodes of the improved tour, which starts from the same node, or
+/// `None` if `tour` is not a tour of the graph.
+///
+/// Each pass over all moves takes **O(|V|²)** time.
+///
+/// **Panics** if `tour` does not contain every node of the graph exactly once.
+///
+/// # Example
+///
+/// ```
+/// use petgraph::algo::or_opt;
+/// use petgraph::prelude::*;
+///
+/// // Nodes on a line, at positions 0, 1, 2, 3 and 4.
+/// let mut g = UnGraph::<(), u32>::new_undirected();
+/// let nodes: Vec<_> = (0..5).map(|_| g.add_node(())).collect();
+/// for a in 0..5 {
+///     for b in a + 1..5 {
+///         g.add_edge(nodes[a], nodes[b], (b - a) as u32);
+///     }
+/// }
+/// // Node 2 is out of place.
+/// let tour = [nodes[0], nodes[1], nodes[3], nodes[4], nodes[2]];
+/// let (cost, _) = or_opt(&g, &tour, |e| *e.weight()).unwrap();
+/// assert_eq!(cost, 8);
+/// ```
+pub fn or_opt<G, F, K>(graph: G, tour: &[G::NodeId], edge_cost: F) -> Option<(K, Vec<G::NodeId>)>
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable + GraphProp<EdgeType = Undirected>,
+    F: FnMut(G::EdgeRef) -> K,
+    K: Measure + Copy,
+{
+    let (nodes, costs) = CostMatrix::new(graph, edge_cost);
+    let mut tour = costs.positions(graph, &nodes, tour);
+    costs.tour_cost(&tour)?;
+    let start = tour.first().cloned();
+    let n = tour.len();
+    let mut improved = true;
+    while improved {
+        improved = false;
+        'moves: for length in 1..=3 {
+            if length + 2 > n {
+                break;
+            }
+            for i in 0..n {
+                // Remove the path `tour[i..i + length]`, from between `p` and `q`.
+                let path: Vec<usize> = (0..length).map(|k| tour[(i + k) % n]).collect();
+                let (first, last) = (path[0], path[length - 1]);
+                let p = tour[(i + n - 1) % n];
+                let q = tour[(i + length) % n];
+                let removed = costs.sum(&[(p, first), (last, q)]);
+                let joined = costs.get(p, q);
+                // And insert it between `x` and `y`, elsewhere in the tour.
+                for k in 0..n - length - 1 {
+                    let x = tour[(i + length + k) % n];
+                    let y = tour[(i + length + k + 1) % n];
+                    let before = costs.sum(&[(x, y)]).and_then(|c| Some(c + removed?));
+                    for &reversed in &[false, true] {
+                        let (a, b) = if reversed {
+                            (last, first)
+                        } else {
+                            (first, last)
+                        };
+                        let after = costs.sum(&[(x, a), (b, y)]).and_then(|c| Some(c + joined?));
+                        if improves(after, before) {
+                            let mut rest: Vec<usize> = (0..n - length)
+                                .map(|k| tour[(i + length + k) % n])
+                                .collect();
+                            let at = rest.iter().position(|&v| v == x).unwrap() + 1;
+                            if reversed {
+                                rest.splice(at..at, path.iter().rev().cloned());
+                            } else {
+                                rest.splice(at..at, path.iter().cloned());
+                            }
+                            tour = rest;
+                            improved = true;
+                            break 'moves;
+                        }
+                    }
+                }
+            }
+        }
+    }
+    if let Some(at) = tour.iter().position(|&v| Some(v) == start) {
+        tour.rotate_left(at);
+    }
+    costs.finish(&nodes, tour)
+}
+
+/// \[Generic\] Find a tour of minimum cost with the Held–Karp dynamic programming algorithm.
+///
+/// The function `edge_cost` should return the cost for a particular edge. The graph may be
+/// directed, in which case the tour follows the direction of the edges.
+///
+/// Returns the cost and the nodes of the tour, starting from the first node of the graph, or
+/// `None` if there is no tour.
+///
+/// Computes in **O(2^|V| |V|²)** time and **O(2^|V| |V|)** space, which is practical for up to
+/// about 20 nodes.
+///
+/// **Panics** if the graph has more than 30 nodes.
+///
+/// **Reference**
+///
+/// * Michael Held, Richard M. Karp; *A dynamic programming approach to sequencing problems*
+///
+/// # Example
+///
+/// ```
+/// use petgraph::algo::held_karp;
+/// use petgraph::prelude::*;
+///
+/// let g = DiGraph::<(), u32>::from_edges(&[
+///     (0, 1, 1), (1, 2, 1), (2, 0, 1),
+///     (0, 2, 5), (2, 1, 5), (1, 0, 5),
+/// ]);
+/// let (cost, tour) = held_karp(&g, |e| *e.weight()).unwrap();
+/// assert_eq!(cost, 3);
+/// assert_eq!(tour, [0.into(), 1.into(), 2.into()]);
+/// ```
+pub fn held_karp<G, F, K>(graph: G, edge_cost: F) -> Option<(K, Vec<G::NodeId>)>
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable + GraphProp,
+    F: FnMut(G::EdgeRef) -> K,
+    K: Measure + Copy,
+{
+    let (nodes, costs) = CostMatrix::new(graph, edge_cost);
+    let n = nodes.len();
+    assert!(n <= 30, "held_karp: too many nodes ({})", n);
+    if n <= 1 {
+        return costs.finish(&nodes, (0..n).collect());
+    }
+
+    // `best[set * m + j]` is the least cost of a path from node 0 through the nodes in `set`,
+    // ending at node `j + 1`, where bit `k` of `set` stands for node `k + 1`.
+    let m = n - 1;
+    let full = (1usize << m) - 1;
+    let mut best: Vec<Option<K>> = vec![None; (full + 1) * m];
+    for j in 0..m {
+        best[(1 << j) * m + j] = costs.get(0, j + 1);
+    }
+    for set in 1..=full {
+        for j in 0..m {
+            if set & (1 << j) == 0 {
+                continue;
+            }
+            let cost = match best[set * m + j] {
+                Some(cost) => cost,
+                None => continue,
+            };
+            for k in 0..m {
+                if set & (1 << k) != 0 {
+                    continue;
+                }
+                if let Some(c) = costs.get(j + 1, k + 1) {
+                    let next = &mut best[(set | 1 << k) * m + k];
+                    if next.map_or(true, |old| cost + c < old) {
+                        *next = Some(cost + c);
+                    }
+                }
+            }
+        }
+    }
+
+    let mut last = None;
+    for j in 0..m {
+        if let (Some(path), Some(c)) = (best[full * m + j], costs.get(j + 1, 0)) {
+            if last.map_or(true, |(total, _)| path + c < total) {
+                last = Some((path + c, j));
+            }
+        }
+    }
+    let (total, mut j) = last?;
+
+    // Follow the paths back, finding a predecessor that gives each least cost.
+    let mut tour = vec![j + 1];
+    let mut set = full;
+    while set != 1 << j {
+        let cost = best[set * m + j].unwrap();
+        let previous = set & !(1 << j);
+        j = (0..m)
+            .find(|&k| {
+                previous & (1 << k) != 0
+                    && match (best[previous * m + k], costs.get(k + 1, j + 1)) {
+                        (Some(path), Some(c)) => path + c == cost,
+                        _ => false,
+                    }
+            })
+            .unwrap();
+        tour.push(j + 1);
+        set = previous;
+    }
+    tour.push(0);
+    tour.reverse();
+    Some((total, tour.into_iter().map(|v| nodes[v]).collect()))
+}
+
+/// Whether a cost, where `None` is infinite, is less than another.
+fn improves<K: PartialOrd>(after: Option<K>, before: Option<K>) -> bool {
+    match (after, before) {
+        (Some(after), Some(before)) => after < before,
+        (Some(_), None) => true,
+        (None, _) => false,
+    }
+}
+
+/// The cheapest edge between each pair of nodes.
+struct CostMatrix<K> {
+    n: usize,
+    costs: Vec<Option<K>>,
+}
+
+impl<K> CostMatrix<K>
+where
+    K: Measure + Copy,
+{
+    fn new<G, F>(graph: G, mut edge_cost: F) -> (Vec<G::NodeId>, Self)
+    where
+        G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable + GraphProp,
+        F: FnMut(G::EdgeRef) -> K,
+    {
+        let nodes: Vec<G::NodeId> = graph.node_identifiers().collect();
+        let n = nodes.len();
+        let mut position = vec![0; graph.node_bound()];
+        for (i, &v) in nodes.iter().enumerate() {
+            position[graph.to_index(v)] = i;
+        }
+        let mut costs = vec![None; n * n];
+        for edge in graph.edge_references() {
+            let a = position[graph.to_index(edge.source())];
+            let b = position[graph.to_index(edge.target())];
+            if a == b {
+                continue;
+            }
+            let cost = edge_cost(edge);
+            let mut update = |a: usize, b: usize| {
+                let old: &mut Option<K> = &mut costs[a * n + b];
+                if old.map_or(true, |old| cost < old) {
+                    *old = Some(cost);
+                }
+            };
+            update(a, b);
+            if !graph.is_directed() {
+                update(b, a);
+            }
+        }
+        (nodes, CostMatrix { n, costs })
+    }
+
+    fn get(&self, a: usize, b: usize) -> Option<K> {
+        self.costs[a * self.n + b]
+    }
+
+    fn sum(&self, edges: &[(usize, usize)]) -> Option<K> {
+        edges
+            .iter()
+            .try_fold(K::default(), |sum, &(a, b)| Some(sum + self.get(a, b)?))
+    }
+
+    fn tour_cost(&self, tour: &[usize]) -> Option<K> {
+        let n = tour.len();
+        if n == 1 {
+            return Some(K::default());
+        }
+        (0..n).try_fold(K::default(), |sum, i| {
+            Some(sum + self.get(tour[i], tour[(i + 1) % n])?)
+        })
+    }
+
+    /// The positions of the nodes of a given tour.
+    fn positions<G>(&self, graph: G, nodes: &[G::NodeId], tour: &[G::NodeId]) -> Vec<usize>
+    where
+        G: NodeIndexable,
+    {
+        let mut position = vec![None; graph.node_bound()];
+        for (i, &v) in nodes.iter().enumerate() {
+            position[graph.to_index(v)] = Some(i);
+        }
+        let mut seen = vec![false; self.n];
+        let tour: Vec<usize> = tour
+            .iter()
+            .map(|&v| {
+                let i = position[graph.to_index(v)].expect("tour: unknown node");
+                assert!(!seen[i], "tour: node visited twice");
+                seen[i] = true;
+                i
+            })
+            .collect();
+        assert_eq!(tour.len(), self.n, "tour: not every node is visited");
+        tour
+    }
+
+    /// The cost of the tour, and its nodes, if it is a tour of the graph.
+    fn finish<N: Copy>(&self, nodes: &[N], tour: Vec<usize>) -> Option<(K, Vec<N>)> {
+        let cost = self.tour_cost(&tour)?;
+        Some((cost, tour.into_iter().map(|v| nodes[v]).collect()))
+    }
+}
+
+/// The nodes of an Euler circuit of a connected multigraph whose nodes all have even degree,
+/// with Hierholzer's algorithm.
+fn euler_circuit(n: usize, edges: &[(usize, usize)]) -> Vec<usize> {
+    let mut incident = vec![Vec::new(); n];
+    for (e, &(a, b)) in edges.iter().enumerate() {
+        incident[a].push(e);
+        incident[b].push(e);
+    }
+    let mut used = vec![false; edges.len()];
+    let mut circuit = Vec::with_capacity(edges.len() + 1);
+    let mut stack: Vec<usize> = (0..n).take(1).collect();
+    while let Some(&v) = stack.last() {
+        match incident[v].pop() {
+            Some(e) if !used[e] => {
+                used[e] = true;
+                let (a, b) = edges[e];
+                stack.push(if a == v { b } else { a });
+            }
+            Some(_) => {}
+            None => {
+                circuit.push(v);
+                stack.pop();
+            }
+        }
+    }
+    circuit
+}
+
+mod perfect_matching {
+    //! Maximum weight matching in general graphs, with Edmonds' blossom algorithm in the
+    //! **O(|V|³)** formulation of Galil, following the implementation by Joris van Rantwijk.
+
+    use std::ops::{Div, Sub};
+
+    use crate::algo::Measure;
+
+    const NONE: usize = std::usize::MAX;
+
+    /// A minimum weight perfect matching of the complete graph on `n` nodes, with an even `n`
+    /// and the given edge weights, as pairs of matched nodes.
+    pub(super) fn min_weight_perfect_matching<K>(
+        n: usize,
+        edges: &[(usize, usize, K)],
+    ) -> Vec<(usize, usize)>
+    where
+        K: Measure + Copy + Sub<Output = K> + Div<Output = K> + From<u8>,
+    {
+        if edges.is_empty() {
+            return Vec::new();
+        }
+        // A perfect matching of least weight is a maximum weight matching when every edge
+        // weighs `bound - weight`, where `bound` is more than the weight of any perfect matching:
+        // then any perfect matching outweighs every smaller matching.
+        let max = edges.iter().fold(
+            K::default(),
+            |max, &(_, _, w)| if max < w { w } else { max },
+        );
+        let bound = (0..n / 2).fold(K::from(1), |sum, _| sum + max);
+        let edges: Vec<(usize, usize, K)> =
+            edges.iter().map(|&(a, b, w)| (a, b, bound - w)).collect();
+        let mate = Blossoms::new(n, &edges).max_weight_matching();
+        let pairs: Vec<(usize, usize)> = (0..n)
+            .filter(|&v| v < mate[v])
+            .map(|v| (v, mate[v]))
+            .collect();
+        assert_eq!(2 * pairs.len(), n);
+        pairs
+    }
+
+    /// The state of the algorithm. Nodes are numbered `0..n`, and blossoms `n..2 * n`. Edge `k`
+    /// has the endpoints `2 * k` and `2 * k + 1`, so that `p ^ 1` is the other endpoint of `p`.
+    struct Blossoms<'a, K> {
+        n: usize,
+        edges: &'a [(usize, usize, K)],
+        /// The node of each endpoint.
+        endpoint: Vec<usize>,
+        /// The remote endpoints of the edges incident to each node.
+        neighbor_ends: Vec<Vec<usize>>,
+        /// The remote endpoint of the matched edge of each node, or `NONE`.
+        mate: Vec<usize>,
+        /// The label of each top-level blossom, and of each node: 0 for free, 1 for S, 2 for T.
+        /// Bit 4 marks blossoms during `scan_blossom`.
+        label: Vec<u8>,
+        /// The endpoint through which a blossom or node got its label, or `NONE`.
+        label_end: Vec<usize>,
+        /// The top-level blossom containing each node.
+        in_blossom: Vec<usize>,
+        blossom_parent: Vec<usize>,
+        /// The sub-blossoms of each blossom, in order around it, starting with its base.
+        blossom_children: Vec<Vec<usize>>,
+        blossom_base: Vec<usize>,
+        /// The endpoints connecting consecutive sub-blossoms of each blossom.
+        blossom_endpoints: Vec<Vec<usize>>,
+        /// The least-slack edge to an S-blossom, for free nodes and S-blossoms.
+        best_edge: Vec<usize>,
+        /// The least-slack edges to other S-blossoms, for each S-blossom.
+        blossom_best_edges: Vec<Option<Vec<usize>>>,
+        unused_blossoms: Vec<usize>,
+        /// Twice the dual variable of each node, and the dual variable of each blossom.
+        dual: Vec<K>,
+        allowed: Vec<bool>,
+        queue: Vec<usize>,
+    }
+
+    impl<'a, K> Blossoms<'a, K>
+    where
+        K: Measure + Copy + Sub<Output = K> + Div<Output = K> + From<u8>,
+    {
+        fn new(n: usize, edges: &'a [(usize, usize, K)]) -> Self {
+            let max = edges.iter().fold(
+                K::default(),
+                |max, &(_, _, w)| if max < w { w } else { max },
+            );
+            let mut endpoint = Vec::with_capacity(2 * edges.len());
+            let mut neighbor_ends = vec![Vec::new(); n];
+            for (k, &(i, j, _)) in edges.iter().enumerate() {
+                endpoint.push(i);
+                endpoint.push(j);
+                neighbor_ends[i].push(2 * k + 1);
+                neighbor_ends[j].push(2 * k);
+            }
+            let mut dual = vec![max; n];
+            dual.resize(2 * n, K::default());
+            Blossoms {
+                n,
+                edges,
+                endpoint,
+                neighbor_ends,
+                mate: vec![NONE; n],
+                label: vec![0; 2 * n],
+                label_end: vec![NONE; 2 * n],
+                in_blossom: (0..n).collect(),
+                blossom_parent: vec![NONE; 2 * n],
+                blossom_children: vec![Vec::new(); 2 * n],
+                blossom_base: (0..n).chain((n..2 * n).map(|_| NONE)).collect(),
+                blossom_endpoints: vec![Vec::new(); 2 * n],
+                best_edge: vec![NONE; 2 * n],
+                blossom_best_edges: vec![None; 2 * n],
+                unused_blossoms: (n..2 * n).collect(),
+                dual,
+                allowed: vec![false; edges.len()],
+                queue: Vec::new(),
+            }
+        }
+
+        fn slack(&self, k: usize) -> K {
+            let (i, j, w) = self.edges[k];
+            self.dual[i] + self.dual[j] - (w + w)
+        }
+
+        fn leaves(&self, b: usize) -> Vec<usize> {
+            let mut leaves = Vec::new();
+            let mut stack = vec![b];
+            while let Some(b) = stack.pop() {
+                if b < self.n {
+                    leaves.push(b);
+                } else {
+                    stack.extend(self.blossom_children[b].iter().rev());
+                }
+            }
+            leaves
+        }
+
+        fn assign_label(&mut self, w: usize, t: u8, p: usize) {
+            let b = self.in_blossom[w];
+            self.label[w] = t;
+            self.label[b] = t;
+            self.label_end[w] = p;
+            self.label_end[b] = p;
+            self.best_edge[w] = NONE;
+            self.best_edge[b] = NONE;
+            if t == 1 {
+                let leaves = self.leaves(b);
+                self.queue.extend(leaves);
+            } else {
+                let base = self.blossom_base[b];
+                let mate = self.mate[base];
+                self.assign_label(self.endpoint[mate], 1, mate ^ 1);
+            }
+        }
+
+        /// Trace back from `v` and `w` to find a new blossom or an augmenting path. Returns the
+        /// base of the blossom, or `NONE` for an augmenting path.
+        fn scan_blossom(&mut self, mut v: usize, mut w: usize) -> usize {
+            let mut path = Vec::new();
+            let mut base = NONE;
+            while v != NONE || w != NONE {
+                let mut b = self.in_blossom[v];
+                if self.label[b] & 4 != 0 {
+                    base = self.blossom_base[b];
+                    break;
+                }
+                path.push(b);
+                self.label[b] = 5;
+                if self.label_end[b] == NONE {
+                    v = NONE;
+                } else {
+                    v = self.endpoint[self.label_end[b]];
+                    b = self.in_blossom[v];
+                    v = self.endpoint[self.label_end[b]];
+                }
+                if w != NONE {
+                    std::mem::swap(&mut v, &mut w);
+                }
+            }
+            for b in path {
+                self.label[b] = 1;
+            }
+            base
+        }
+
+        fn add_blossom(&mut self, base: usize, k: usize) {
+            let (mut v, mut w, _) = self.edges[k];
+            let bb = self.in_blossom[base];
+            let mut bv = self.in_blossom[v];
+            let mut bw = self.in_blossom[w];
+            let b = self.unused_blossoms.pop().unwrap();
+            self.blossom_base[b] = base;
+            self.blossom_parent[b] = NONE;
+            self.blossom_parent[bb] = b;
+            let mut path = Vec::new();
+            let mut endps = Vec::new();
+            while bv != bb {
+                self.blossom_parent[bv] = b;
+                path.push(bv);
+                endps.push(self.label_end[bv]);
+                v = self.endpoint[self.label_end[bv]];
+                bv = self.in_blossom[v];
+            }
+            path.push(bb);
+            path.reverse();
+            endps.reverse();
+            endps.push(2 * k);
+            while bw != bb {
+                self.blossom_parent[bw] = b;
+                path.push(bw);
+                endps.push(self.label_end[bw] ^ 1);
+                w = self.endpoint[self.label_end[bw]];
+                bw = self.in_blossom[w];
+            }
+            self.label[b] = 1;
+            self.label_end[b] = self.label_end[bb];
+            self.dual[b] = K::default();
+            self.blossom_children[b] = path.clone();
+            self.blossom_endpoints[b] = endps;
+            for v in self.leaves(b) {
+                if self.label[self.in_blossom[v]] == 2 {
+                    self.queue.push(v);
+                }
+                self.in_blossom[v] = b;
+            }
+
+            let mut best_edge_to = vec![NONE; 2 * self.n];
+            for &bv in &path {
+                let lists: Vec<Vec<usize>> = match self.blossom_best_edges[bv].take() {
+                    Some(list) => vec![list],
+                    None => self
+                        .leaves(bv)
+                        .into_iter()
+                        .map(|v| self.neighbor_ends[v].iter().map(|p| p / 2).collect())
+                        .collect(),
+                };
+                for list in lists {
+                    for k in list {
+                        let (i, j, _) = self.edges[k];
+                        let j = if self.in_blossom[j] == b { i } else { j };
+                        let bj = self.in_blossom[j];
+                        if bj != b
+                            && self.label[bj] == 1
+                            && (best_edge_to[bj] == NONE
+                                || self.slack(k) < self.slack(best_edge_to[bj]))
+                        {
+                            best_edge_to[bj] = k;
+                        }
+                    }
+                }
+                self.best_edge[bv] = NONE;
+            }
+            let best_edges: Vec<usize> = best_edge_to.into_iter().filter(|&k| k != NONE).collect();
+            self.best_edge[b] = NONE;
+            for &k in &best_edges {
+                if self.best_edge[b] == NONE || self.slack(k) < self.slack(self.best_edge[b]) {
+                    self.best_edge[b] = k;
+                }
+            }
+            self.blossom_best_edges[b] = Some(best_edges);
+        }
+
+        fn expand_blossom(&mut self, b: usize, end_stage: bool) {
+            for s in self.blossom_children[b].clone() {
+                self.blossom_parent[s] = NONE;
+                if s < self.n {
+                    self.in_blossom[s] = s;
+                } else if end_stage && self.dual[s] == K::default() {
+                    self.expand_blossom(s, end_stage);
+                } else {
+                    for v in self.leaves(s) {
+                        self.in_blossom[v] = s;
+                    }
+                }
+            }
+            if !end_stage && self.label[b] == 2 {
+                // Relabel the sub-blossoms on the even path from where the blossom was entered
+                // to its base.
+                let children = self.blossom_children[b].clone();
+                let endps = self.blossom_endpoints[b].clone();
+                let len = children.len() as isize;
+                let at = |j: isize| j.rem_euclid(len) as usize;
+                let entry_child = self.in_blossom[self.endpoint[self.label_end[b] ^ 1]];
+                let mut j = children.iter().position(|&c| c == entry_child).unwrap() as isize;
+                let (step, trick) = if j & 1 != 0 {
+                    j -= len;
+                    (1, 0)
+                } else {
+                    (-1, 1)
+                };
+                let mut p = self.label_end[b];
+                while j != 0 {
+                    let q = endps[at(j - trick)] ^ trick as usize;
+                    self.label[self.endpoint[p ^ 1]] = 0;
+                    self.label[self.endpoint[q ^ 1]] = 0;
+                    self.assign_label(self.endpoint[p ^ 1], 2, p);
+                    self.allowed[q / 2] = true;
+                    j += step;
+                    p = endps[at(j - trick)] ^ trick as usize;
+                    self.allowed[p / 2] = true;
+                    j += step;
+                }
+                let bv = children[at(j)];
+                let ep = self.endpoint[p ^ 1];
+                self.label[ep] = 2;
+                self.label[bv] = 2;
+                self.label_end[ep] = p;
+                self.label_end[bv] = p;
+                self.best_edge[bv] = NONE;
+                j += step;
+                while children[at(j)] != entry_child {
+                    let bv = children[at(j)];
+                    if self.label[bv] == 1 {
+                        j += step;
+                        continue;
+                    }
+                    if let Some(v) = self.leaves(bv).into_iter().find(|&v| self.label[v] != 0) {
+                        self.label[v] = 0;
+                        let base_mate = self.mate[self.blossom_base[bv]];
+                        self.label[self.endpoint[base_mate]] = 0;
+                        self.assign_label(v, 2, self.label_end[v]);
+                    }
+                    j += step;
+                }
+            }
+            self.label[b] = 0;
+            self.label_end[b] = NONE;
+            self.blossom_children[b].clear();
+            self.blossom_endpoints[b].clear();
+            self.blossom_base[b] = NONE;
+            self.blossom_best_edges[b] = None;
+            self.best_edge[b] = NONE;
+            self.unused_blossoms.push(b);
+        }
+
+        /// Swap the matched and unmatched edges along the path from `v` to the base of `b`,
+        /// making `v` the new base.
+        fn augment_blossom(&mut self, b: usize, v: usize) {
+            let mut t = v;
+            while self.blossom_parent[t] != b {
+                t = self.blossom_parent[t];
+            }
+            if t >= self.n {
+                self.augment_blossom(t, v);
+            }
+            let len = self.blossom_children[b].len() as isize;
+            let at = |j: isize| j.rem_euclid(len) as usize;
+            let i = self.blossom_children[b]
+                .iter()
+                .position(|&c| c == t)
+                .unwrap();
+            let mut j = i as isize;
+            let (step, trick) = if j & 1 != 0 {
+                j -= len;
+                (1, 0)
+            } else {
+                (-1, 1)
+            };
+            while j != 0 {
+                j += step;
+                let t = self.blossom_children[b][at(j)];
+                let p = self.blossom_endpoints[b][at(j - trick)] ^ trick as usize;
+                if t >= self.n {
+                    self.augment_blossom(t, self.endpoint[p]);
+                }
+                j += step;
+                let t = self.blossom_children[b][at(j)];
+                if t >= self.n {
+                    self.augment_blossom(t, self.endpoint[p ^ 1]);
+                }
+                self.mate[self.endpoint[p]] = p ^ 1;
+                self.mate[self.endpoint[p ^ 1]] = p;
+            }
+            self.blossom_children[b].rotate_left(i);
+            self.blossom_endpoints[b].rotate_left(i);
+            self.blossom_base[b] = self.blossom_base[self.blossom_children[b][0]];
+        }
+
+        fn augment_matching(&mut self, k: usize) {
+            let (v, w, _) = self.edges[k];
+            for &(mut s, mut p) in &[(v, 2 * k + 1), (w, 2 * k)] {
+                loop {
+                    let bs = self.in_blossom[s];
+                    if bs >= self.n {
+                        self.augment_blossom(bs, s);
+                    }
+                    self.mate[s] = p;
+                    if self.label_end[bs] == NONE {
+                        break;
+                    }
+                    let t = self.endpoint[self.label_end[bs]];
+                    let bt = self.in_blossom[t];
+                    s = self.endpoint[self.label_end[bt]];
+                    let j = self.endpoint[self.label_end[bt] ^ 1];
+                    if bt >= self.n {
+                        self.augment_blossom(bt, j);
+                    }
+                    self.mate[j] = self.label_end[bt];
+                    p = self.label_end[bt] ^ 1;
+                }
+            }
+        }
+
+        /// The mate of each node in a maximum weight matching, or `NONE`.
+        fn max_weight_matching(mut self) -> Vec<usize> {
+            let n = self.n;
+            let two = K::from(2);
+            for _ in 0..n {
+                for l in &mut self.label {
+                    *l = 0;
+                }
+                for e in &mut self.best_edge {
+                    *e = NONE;
+                }
+                for b in n..2 * n {
+                    self.blossom_best_edges[b] = None;
+                }
+                for a in &mut self.allowed {
+                    *a = false;
+                }
+                self.queue.clear();
+                for v in 0..n {
+                    if self.mate[v] == NONE && self.label[self.in_blossom[v]] == 0 {
+                        self.assign_label(v, 1, NONE);
+                    }
+                }
+
+                let mut augmented = false;
+                loop {
+                    while let Some(v) = self.queue.pop() {
+                        for idx in 0..self.neighbor_ends[v].len() {
+                            let p = self.neighbor_ends[v][idx];
+                            let k = p / 2;
+                            let w = self.endpoint[p];
+                            if self.in_blossom[v] == self.in_blossom[w] {
+                                continue;
+                            }
+                            let mut k_slack = None;
+                            if !self.allowed[k] {
+                                let s = self.slack(k);
+                                if s <= K::default() {
+                                    self.allowed[k] = true;
+                                }
+                                k_slack = Some(s);
+                            }
+                            if self.allowed[k] {
+                                if self.label[self.in_blossom[w]] == 0 {
+                                    self.assign_label(w, 2, p ^ 1);
+                                } else if self.label[self.in_blossom[w]] == 1 {
+                                    let base = self.scan_blossom(v, w);
+                                    if base != NONE {
+                                        self.add_blossom(base, k);
+                                    } else {
+                                        self.augment_matching(k);
+                                        augmented = true;
+                                        break;
+                                    }
+                                } else if self.label[w] == 0 {
+                                    self.label[w] = 2;
+                                    self.label_end[w] = p ^ 1;
+                                }
+                            } else if self.label[self.in_blossom[w]] == 1 {
+                                let b = self.in_blossom[v];
+                                if self.best_edge[b] == NONE
+                                    || k_slack.unwrap() < self.slack(self.best_edge[b])
+                                {
+                                    self.best_edge[b] = k;
+                                }
+                            } else if self.label[w] == 0
+                                && (self.best_edge[w] == NONE
+                                    || k_slack.unwrap() < self.slack(self.best_edge[w]))
+                            {
+                                self.best_edge[w] = k;
+                            }
+                        }
+                        if augmented {
+                            break;
+                        }
+                    }
+                    if augmented {
+                        break;
+                    }
+
+                    // No augmenting path: change the dual variables to allow more edges.
+                    let mut delta =
+                        self.dual[..n].iter().fold(
+                            self.dual[0],
+                            |min, &d| {
+                                if d < min {
+                                    d
+                                } else {
+                                    min
+                                }
+                            },
+                        );
+                    let mut delta_type = 1;
+                    let mut delta_edge = NONE;
+                    let mut delta_blossom = NONE;
+                    for v in 0..n {
+                        if self.label[self.in_blossom[v]] == 0 && self.best_edge[v] != NONE {
+                            let d = self.slack(self.best_edge[v]);
+                            if d < delta {
+                                delta = d;
+                                delta_type = 2;
+                                delta_edge = self.best_edge[v];
+                            }
+                        }
+                    }
+                    for b in 0..2 * n {
+                        if self.blossom_parent[b] == NONE
+                            && self.label[b] == 1
+                            && self.best_edge[b] != NONE
+                        {
+                            let d = self.slack(self.best_edge[b]) / two;
+                            if d < delta {
+                                delta = d;
+                                delta_type = 3;
+                                delta_edge = self.best_edge[b];
+                            }
+                        }
+                    }
+                    for b in n..2 * n {
+                        if self.blossom_base[b] != NONE
+                            && self.blossom_parent[b] == NONE
+                            && self.label[b] == 2
+                            && self.dual[b] < delta
+                        {
+                            delta = self.dual[b];
+                            delta_type = 4;
+                            delta_blossom = b;
+                        }
+                    }
+
+                    for v in 0..n {
+                        match self.label[self.in_blossom[v]] {
+                            1 => self.dual[v] = self.dual[v] - delta,
+                            2 => self.dual[v] = self.dual[v] + delta,
+                            _ => {}
+                        }
+                    }
+                    for b in n..2 * n {
+                        if self.blossom_base[b] != NONE && self.blossom_parent[b] == NONE {
+                            match self.label[b] {
+                                1 => self.dual[b] = self.dual[b] + delta,
+                                2 => self.dual[b] = self.dual[b] - delta,
+                                _ => {}
+                            }
+                        }
+                    }
+
+                    match delta_type {
+                        1 => break,
+                        2 => {
+                            self.allowed[delta_edge] = true;
+                            let (mut i, j, _) = self.edges[delta_edge];
+                            if self.label[self.in_blossom[i]] == 0 {
+                                i = j;
+                            }
+                            self.queue.push(i);
+                        }
+                        3 => {
+                            self.allowed[delta_edge] = true;
+                            let (i, _, _) = self.edges[delta_edge];
+                            self.queue.push(i);
+                        }
+                        _ => self.expand_blossom(delta_blossom, false),
+                    }
+                }
+                if !augmented {
+                    break;
+                }
+                for b in n..2 * n {
+                    if self.blossom_parent[b] == NONE
+                        && self.blossom_base[b] != NONE
+                        && self.label[b] == 1
+                        && self.dual[b] == K::default()
+                    {
+                        self.expand_blossom(b, true);
+                    }
+                }
+            }
+            self.mate
+                .iter()
+                .map(|&p| if p == NONE { NONE } else { self.endpoint[p] })
+                .collect()
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::min_weight_perfect_matching;
+
+        /// The least weight of a perfect matching, trying all of them.
+        fn brute_force(n: usize, weight: &[Vec<u64>]) -> u64 {
+            fn search(unmatched: &mut Vec<usize>, weight: &[Vec<u64>]) -> u64 {
+                if unmatched.is_empty() {
+                    return 0;
+                }
+                let a = unmatched.remove(0);
+                let mut best = std::u64::MAX;
+                for i in 0..unmatched.len() {
+                    let b = unmatched.remove(i);
+                    best = best.min(weight[a][b] + search(unmatched, weight));
+                    unmatched.insert(i, b);
+                }
+                unmatched.insert(0, a);
+                best
+            }
+            search(&mut (0..n).collect(), weight)
+        }
+
+        #[test]
+        fn matches_brute_force() {
+            // A linear congruential generator, to avoid depending on `rand`.
+            let mut state = 12345u64;
+            let mut random = |bound: u64| {
+                state = state.wrapping_mul(6364136223846793005).wrapping_add(1);
+                (state >> 33) % bound
+            };
+            for round in 0..400 {
+                let n = 2 * (1 + round % 5);
+                let range = if round % 3 == 0 { 3 } else { 100 };
+                let edges: Vec<(usize, usize, u64)> = (0..n)
+                    .flat_map(|a| (a + 1..n).map(move |b| (a, b)))
+                    .map(|(a, b)| (a, b, random(range)))
+                    .collect();
+                let mut weight = vec![vec![0; n]; n];
+                for &(a, b, w) in &edges {
+                    weight[a][b] = w;
+                    weight[b][a] = w;
+                }
+                let pairs = min_weight_perfect_matching(n, &edges);
+                let mut matched = vec![false; n];
+                for &(a, b) in &pairs {
+                    assert!(!matched[a] && !matched[b]);
+                    matched[a] = true;
+                    matched[b] = true;
+                }
+                let total: u64 = pairs.iter().map(|&(a, b)| weight[a][b]).sum();
+                assert_eq!(total, brute_force(n, &weight));
+            }
+        }
+    }
+}
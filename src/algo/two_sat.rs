@@ -0,0 +1,156 @@
+//! 2-SAT: satisfiability of formulas in conjunctive normal form with two literals per clause.
+//!
+//! Unlike general satisfiability, 2-SAT is solvable in linear time. Each clause `a ∨ b` is the pair
+//! of implications `¬a → b` and `¬b → a`, and a formula is satisfiable if and only if no variable
+//! and its negation imply each other, which is a question about the strongly connected components
+//! of the implication graph. [`two_sat`] returns a satisfying assignment read from the order of
+//! the components, or an [`UnsatCore`]: the clauses on the two chains of implications between the
+//! literals of a variable.
+//!
+//! [`two_sat`]: fn.two_sat.html
+//! [`UnsatCore`]: struct.UnsatCore.html
+
+use std::collections::VecDeque;
+
+use super::tarjan_scc;
+use crate::graph::{DiGraph, NodeIndex};
+use crate::visit::EdgeRef;
+
+/// A literal of a 2-SAT formula: `(variable, value)` holds when the variable has that value.
+pub type Literal = (usize, bool);
+
+/// A set of clauses that cannot be satisfied together, proving that a 2-SAT formula is
+/// unsatisfiable.
+///
+/// Returned by [`two_sat`](fn.two_sat.html).
+#[derive(Clone, Debug, PartialEq)]
+pub struct UnsatCore {
+    variable: usize,
+    clauses: Vec<usize>,
+}
+
+impl UnsatCore {
+    /// Return the variable that the clauses force to be both `true` and `false`.
+    pub fn variable(&self) -> usize {
+        self.variable
+    }
+
+    /// Return the indices of the clauses, in increasing order.
+    pub fn clauses(&self) -> &[usize] {
+        &self.clauses
+    }
+}
+
+/// Solve a [2-SAT] formula: a conjunction of clauses `a ∨ b` of two literals each.
+///
+/// The variables are numbered `0..variables`, and each clause is a pair of [`Literal`]s
+/// `(variable, value)`. Returns a satisfying assignment of the variables, or an [`UnsatCore`]:
+/// the clauses along a chain of implications from one value of a variable to the other, and
+/// back.
+///
+/// Builds the implication graph, with an edge `¬a → b` and `¬b → a` for each clause, and finds
+/// its strongly connected components with [`tarjan_scc`](fn.tarjan_scc.html). The formula is
+/// satisfiable if and only if no variable has both of its literals in the same component.
+/// Computes in **O(|V| + |C|)** time for variables **V** and clauses **C**.
+///
+/// **Panics** if a clause refers to a variable that is not less than `variables`.
+///
+/// **Reference**
+///
+/// * Bengt Aspvall, Michael F. Plass, Robert Endre Tarjan; *A linear-time algorithm for testing
+///   the truth of certain quantified boolean formulas*
+///
+/// # Example
+///
+/// ```
+/// use petgraph::algo::two_sat;
+///
+/// // (x0 ∨ x1) ∧ (¬x0 ∨ x1) ∧ (¬x1 ∨ ¬x2)
+/// let clauses = [
+///     ((0, true), (1, true)),
+///     ((0, false), (1, true)),
+///     ((1, false), (2, false)),
+/// ];
+/// let assignment = two_sat(3, &clauses).unwrap();
+/// assert!(assignment[1] && !assignment[2]);
+///
+/// // Adding (x2 ∨ x2) makes it unsatisfiable.
+/// let clauses = [clauses[0], clauses[1], clauses[2], ((2, true), (2, true))];
+/// let core = two_sat(3, &clauses).unwrap_err();
+/// assert_eq!(core.clauses(), [0, 1, 2, 3]);
+/// ```
+///
+/// [2-SAT]: https://en.wikipedia.org/wiki/2-satisfiability
+/// [`Literal`]: type.Literal.html
+/// [`UnsatCore`]: struct.UnsatCore.html
+pub fn two_sat(variables: usize, clauses: &[(Literal, Literal)]) -> Result<Vec<bool>, UnsatCore> {
+    // The literal `(v, true)` is node `2 * v` and `(v, false)` is node `2 * v + 1`, so negation
+    // flips the lowest bit.
+    let node = |(v, value): Literal| {
+        assert!(v < variables, "variable {} out of bounds", v);
+        NodeIndex::new(2 * v + !value as usize)
+    };
+    let not = |n: NodeIndex| NodeIndex::new(n.index() ^ 1);
+    let mut implications = DiGraph::<(), usize>::with_capacity(2 * variables, 2 * clauses.len());
+    for _ in 0..2 * variables {
+        implications.add_node(());
+    }
+    for (i, &(a, b)) in clauses.iter().enumerate() {
+        let (a, b) = (node(a), node(b));
+        implications.add_edge(not(a), b, i);
+        implications.add_edge(not(b), a, i);
+    }
+
+    // Tarjan's algorithm finds the components in reverse topological order, and a literal is
+    // true if its component comes after its negation's in topological order.
+    let mut component = vec![0; 2 * variables];
+    for (c, scc) in tarjan_scc(&implications).into_iter().enumerate() {
+        for n in scc {
+            component[n.index()] = c;
+        }
+    }
+    let mut assignment = Vec::with_capacity(variables);
+    for v in 0..variables {
+        let (positive, negative) = (component[2 * v], component[2 * v + 1]);
+        if positive == negative {
+            let (x, not_x) = (NodeIndex::new(2 * v), NodeIndex::new(2 * v + 1));
+            let mut clauses = implication_path(&implications, x, not_x);
+            clauses.extend(implication_path(&implications, not_x, x));
+            clauses.sort_unstable();
+            clauses.dedup();
+            return Err(UnsatCore {
+                variable: v,
+                clauses,
+            });
+        }
+        assignment.push(positive < negative);
+    }
+    Ok(assignment)
+}
+
+/// The clauses on a shortest chain of implications from `from` to `to`.
+fn implication_path(graph: &DiGraph<(), usize>, from: NodeIndex, to: NodeIndex) -> Vec<usize> {
+    let mut predecessor = vec![None; graph.node_count()];
+    let mut queue = VecDeque::new();
+    queue.push_back(from);
+    while let Some(n) = queue.pop_front() {
+        if n == to {
+            break;
+        }
+        for edge in graph.edges(n) {
+            let m = edge.target();
+            if m != from && predecessor[m.index()].is_none() {
+                predecessor[m.index()] = Some(edge.id());
+                queue.push_back(m);
+            }
+        }
+    }
+    let mut clauses = Vec::new();
+    let mut n = to;
+    while n != from {
+        let edge = predecessor[n.index()].unwrap();
+        clauses.push(graph[edge]);
+        n = graph.edge_endpoints(edge).unwrap().0;
+    }
+    clauses
+}
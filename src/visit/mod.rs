@@ -17,10 +17,15 @@
 //!
 //! There is also the callback based traversal [`depth_first_search`][dfs].
 //!
+//! [`MaxCardinalitySearch`][mcs] and [`LexBfs`][lexbfs] are walkers that visit
+//! every node of an undirected graph in an order suited to chordal graphs.
+//!
 //! [bfs]: struct.Bfs.html
 //! [dfspo]: struct.DfsPostOrder.html
 //! [topo]: struct.Topo.html
 //! [dfs]: fn.depth_first_search.html
+//! [mcs]: struct.MaxCardinalitySearch.html
+//! [lexbfs]: struct.LexBfs.html
 //! [w]: trait.Walker.html
 //!
 //! ### Other Graph Traits
@@ -1,6 +1,7 @@
-use super::{GraphRef, IntoNodeIdentifiers, Reversed};
+use super::{GraphRef, IntoNodeIdentifiers, NodeIndexable, Reversed};
 use super::{IntoNeighbors, IntoNeighborsDirected, VisitMap, Visitable};
 use crate::Incoming;
+use fixedbitset::FixedBitSet;
 use std::collections::VecDeque;
 
 /// Visit nodes of a graph in a depth-first-search (DFS) emitting nodes in
@@ -409,6 +410,245 @@ where
     }
 }
 
+/// A maximum cardinality search (MCS) of an undirected graph.
+///
+/// Every node of the graph is visited: the next node is always an unvisited
+/// node with the largest number of visited neighbors. On a chordal graph, the
+/// reverse of the visit order is a perfect elimination ordering.
+///
+/// Like the other traversals, `MaxCardinalitySearch` only borrows the graph
+/// during the `.next()` call. The graph must not change during the traversal.
+///
+/// See also [`is_chordal`](../algo/chordal/fn.is_chordal.html).
+///
+/// ```
+/// use petgraph::prelude::*;
+/// use petgraph::visit::MaxCardinalitySearch;
+///
+/// // A triangle 0, 1, 2 with a pendant node 3 attached to 2.
+/// let graph = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 0), (2, 3)]);
+///
+/// let mut mcs = MaxCardinalitySearch::new(&graph);
+/// let mut order = Vec::new();
+/// while let Some(nx) = mcs.next(&graph) {
+///     order.push(nx.index());
+/// }
+/// // Once two nodes of the triangle are visited, the third one has two
+/// // visited neighbors and comes before the pendant node.
+/// assert_eq!(order[3], 3);
+/// order.sort();
+/// assert_eq!(order, vec![0, 1, 2, 3]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct MaxCardinalitySearch<N> {
+    /// The number of visited neighbors of each node, by node index
+    cardinality: Vec<usize>,
+    /// The visited nodes, by node index
+    visited: FixedBitSet,
+    /// The last visited node that was counted for each node, by node index,
+    /// so that parallel edges are only counted once
+    counted_for: Vec<usize>,
+    /// `buckets[k]` holds nodes with `k` visited neighbors. A node is pushed
+    /// again each time its cardinality grows, so outdated entries are skipped.
+    buckets: Vec<Vec<N>>,
+    /// The largest non-empty bucket
+    max: usize,
+}
+
+impl<N> MaxCardinalitySearch<N>
+where
+    N: Copy,
+{
+    /// Create a new `MaxCardinalitySearch` that visits every node of `graph`.
+    pub fn new<G>(graph: G) -> Self
+    where
+        G: IntoNodeIdentifiers<NodeId = N> + NodeIndexable,
+    {
+        let bound = graph.node_bound();
+        let mut start: Vec<N> = graph.node_identifiers().collect();
+        start.reverse();
+        MaxCardinalitySearch {
+            cardinality: vec![0; bound],
+            visited: FixedBitSet::with_capacity(bound),
+            counted_for: vec![std::usize::MAX; bound],
+            buckets: vec![start],
+            max: 0,
+        }
+    }
+
+    /// Return the next node in the search, or `None` if every node has been
+    /// visited.
+    pub fn next<G>(&mut self, graph: G) -> Option<N>
+    where
+        G: IntoNeighbors<NodeId = N> + NodeIndexable,
+    {
+        loop {
+            let node = match self.buckets[self.max].pop() {
+                Some(node) => node,
+                None if self.max == 0 => return None,
+                None => {
+                    self.max -= 1;
+                    continue;
+                }
+            };
+            let ix = graph.to_index(node);
+            if self.visited[ix] || self.cardinality[ix] != self.max {
+                continue;
+            }
+            self.visited.insert(ix);
+            for succ in graph.neighbors(node) {
+                let jx = graph.to_index(succ);
+                if self.visited[jx] || self.counted_for[jx] == ix {
+                    continue;
+                }
+                self.counted_for[jx] = ix;
+                self.cardinality[jx] += 1;
+                let k = self.cardinality[jx];
+                if k == self.buckets.len() {
+                    self.buckets.push(Vec::new());
+                }
+                self.buckets[k].push(succ);
+                if k > self.max {
+                    self.max = k;
+                }
+            }
+            return Some(node);
+        }
+    }
+}
+
+/// A class of nodes with the same label in a `LexBfs`, stored as the range
+/// `start..end` of its unvisited nodes.
+#[derive(Clone, Debug)]
+struct LexClass {
+    start: usize,
+    end: usize,
+    /// The step at which this class was split off another one.
+    created: usize,
+    /// The step at which this class was last split, and the class split off.
+    split: (usize, usize),
+}
+
+/// A lexicographic breadth-first search (LexBFS) of an undirected graph.
+///
+/// Every node of the graph is visited. Unvisited nodes are compared by their
+/// visited neighbors: at the first visited node where two of them differ, the
+/// one adjacent to it comes first. This breaks the ties of an ordinary
+/// breadth-first search in a way that, on a chordal graph, makes the reverse
+/// of the visit order a perfect elimination ordering.
+///
+/// The labels are maintained by partition refinement, so that the whole
+/// traversal takes **O(|V| + |E|)** time.
+///
+/// Like the other traversals, `LexBfs` only borrows the graph during the
+/// `.next()` call. The graph must not change during the traversal.
+///
+/// ```
+/// use petgraph::prelude::*;
+/// use petgraph::visit::LexBfs;
+///
+/// // A cycle 0 - 1 - 2 - 3 - 0.
+/// let graph = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 3), (3, 0)]);
+///
+/// let mut lex_bfs = LexBfs::new(&graph);
+/// let mut order = Vec::new();
+/// while let Some(nx) = lex_bfs.next(&graph) {
+///     order.push(nx.index());
+/// }
+/// // 1 and 3 are both adjacent to 0, and 2 comes last since it is not.
+/// assert_eq!(order[0], 0);
+/// assert_eq!(order[3], 2);
+/// ```
+#[derive(Clone, Debug)]
+pub struct LexBfs<N> {
+    /// The visited nodes in visit order, followed by the unvisited nodes
+    /// grouped by class, in decreasing order of label.
+    order: Vec<N>,
+    /// The number of visited nodes
+    visited: usize,
+    /// The position of each node in `order`, by node index
+    position: Vec<usize>,
+    /// The class of each unvisited node, by node index
+    class: Vec<usize>,
+    classes: Vec<LexClass>,
+}
+
+impl<N> LexBfs<N>
+where
+    N: Copy,
+{
+    /// Create a new `LexBfs` that visits every node of `graph`.
+    pub fn new<G>(graph: G) -> Self
+    where
+        G: IntoNodeIdentifiers<NodeId = N> + NodeIndexable,
+    {
+        let order: Vec<N> = graph.node_identifiers().collect();
+        let mut position = vec![0; graph.node_bound()];
+        for (i, &node) in order.iter().enumerate() {
+            position[graph.to_index(node)] = i;
+        }
+        let never = std::usize::MAX;
+        LexBfs {
+            classes: vec![LexClass {
+                start: 0,
+                end: order.len(),
+                created: never,
+                split: (never, 0),
+            }],
+            class: vec![0; graph.node_bound()],
+            position,
+            visited: 0,
+            order,
+        }
+    }
+
+    /// Return the next node in the search, or `None` if every node has been
+    /// visited.
+    pub fn next<G>(&mut self, graph: G) -> Option<N>
+    where
+        G: IntoNeighbors<NodeId = N> + NodeIndexable,
+    {
+        let node = *self.order.get(self.visited)?;
+        // The first unvisited node is at the start of the first class.
+        self.classes[self.class[graph.to_index(node)]].start += 1;
+        self.visited += 1;
+        let step = self.visited;
+
+        // Move every unvisited neighbor to a new class just before its own.
+        for succ in graph.neighbors(node) {
+            let jx = graph.to_index(succ);
+            let pos = self.position[jx];
+            let old = self.class[jx];
+            if pos < self.visited || self.classes[old].created == step {
+                continue;
+            }
+            let new = if self.classes[old].split.0 == step {
+                self.classes[old].split.1
+            } else {
+                let start = self.classes[old].start;
+                self.classes.push(LexClass {
+                    start,
+                    end: start,
+                    created: step,
+                    split: (std::usize::MAX, 0),
+                });
+                let new = self.classes.len() - 1;
+                self.classes[old].split = (step, new);
+                new
+            };
+            let start = self.classes[old].start;
+            let other = self.order[start];
+            self.order.swap(pos, start);
+            self.position[graph.to_index(other)] = pos;
+            self.position[jx] = start;
+            self.classes[old].start += 1;
+            self.classes[new].end += 1;
+            self.class[jx] = new;
+        }
+        Some(node)
+    }
+}
+
 /// A walker is a traversal state, but where part of the traversal
 /// information is supplied manually to each next call.
 ///
@@ -517,3 +757,23 @@ where
         self.next(context)
     }
 }
+
+impl<G> Walker<G> for MaxCardinalitySearch<G::NodeId>
+where
+    G: IntoNeighbors + NodeIndexable,
+{
+    type Item = G::NodeId;
+    fn walk_next(&mut self, context: G) -> Option<Self::Item> {
+        self.next(context)
+    }
+}
+
+impl<G> Walker<G> for LexBfs<G::NodeId>
+where
+    G: IntoNeighbors + NodeIndexable,
+{
+    type Item = G::NodeId;
+    fn walk_next(&mut self, context: G) -> Option<Self::Item> {
+        self.next(context)
+    }
+}
@@ -0,0 +1,92 @@
+use petgraph::acyclic::Acyclic;
+use petgraph::prelude::*;
+use petgraph::visit::{EdgeRef, IntoEdgeReferences};
+
+fn assert_order_is_topological<G>(dag: &Acyclic<G>)
+where
+    G: petgraph::visit::GraphBase<NodeId = NodeIndex>,
+    for<'a> &'a G: IntoEdgeReferences<NodeId = NodeIndex>,
+{
+    let order = dag.topological_order();
+    for edge in dag.inner().edge_references() {
+        let source = order.iter().position(|&n| n == edge.source()).unwrap();
+        let target = order.iter().position(|&n| n == edge.target()).unwrap();
+        assert!(source < target);
+    }
+}
+
+#[test]
+fn rejects_cycles() {
+    let mut dag = Acyclic::<DiGraph<(), ()>>::new();
+    let a = dag.add_node(());
+    let b = dag.add_node(());
+    let c = dag.add_node(());
+    let d = dag.add_node(());
+
+    assert!(dag.try_add_edge(a, b, ()).is_ok());
+    assert!(dag.try_add_edge(b, c, ()).is_ok());
+    assert!(dag.try_add_edge(c, d, ()).is_ok());
+    assert!(dag.try_add_edge(a, d, ()).is_ok());
+
+    assert_eq!(dag.try_add_edge(d, a, ()).unwrap_err().cycle()[..2], [d, a]);
+    assert_eq!(
+        dag.try_add_edge(c, a, ()).unwrap_err().into_cycle(),
+        vec![c, a, b]
+    );
+    assert_eq!(dag.try_add_edge(b, b, ()).unwrap_err().cycle(), &[b]);
+    assert!(!dag.is_valid_edge(d, b));
+    assert!(dag.is_valid_edge(b, d));
+    assert_eq!(dag.inner().edge_count(), 4);
+    assert_eq!(dag.topological_order(), &[a, b, c, d]);
+}
+
+#[test]
+fn reorders_nodes() {
+    let mut dag = Acyclic::<DiGraph<(), ()>>::new();
+    let nodes: Vec<_> = (0..5).map(|_| dag.add_node(())).collect();
+    // Insert edges against the insertion order.
+    for w in nodes.windows(2) {
+        dag.try_add_edge(w[1], w[0], ()).unwrap();
+        assert_order_is_topological(&dag);
+    }
+    let reversed: Vec<_> = nodes.iter().rev().cloned().collect();
+    assert_eq!(dag.topological_order(), &reversed[..]);
+    assert_eq!(dag.position(nodes[4]), 0);
+}
+
+#[test]
+fn from_graph() {
+    let g = DiGraph::<(), ()>::from_edges(&[(2, 1), (1, 0), (2, 0)]);
+    let dag = Acyclic::try_from_graph(g).unwrap();
+    assert_eq!(
+        dag.topological_order(),
+        &[NodeIndex::new(2), NodeIndex::new(1), NodeIndex::new(0)]
+    );
+
+    let g = DiGraph::<(), ()>::from_edges(&[(0, 1), (1, 0)]);
+    assert!(Acyclic::try_from_graph(g).is_err());
+}
+
+#[test]
+fn stable_graph_removal() {
+    let mut dag = Acyclic::<StableDiGraph<u32, ()>>::new();
+    let a = dag.add_node(0);
+    let b = dag.add_node(1);
+    let c = dag.add_node(2);
+    dag.try_add_edge(c, b, ()).unwrap();
+    let e = dag.try_add_edge(b, a, ()).unwrap().unwrap();
+    assert!(dag.try_add_edge(a, c, ()).is_err());
+
+    assert_eq!(dag.remove_edge(e), Some(()));
+    assert!(dag.try_add_edge(a, c, ()).is_ok());
+    assert_eq!(dag.topological_order(), &[a, c, b]);
+
+    assert_eq!(dag.remove_node(c), Some(2));
+    assert_eq!(dag.topological_order(), &[a, b]);
+    let d = dag.add_node(3);
+    dag.try_add_edge(d, a, ()).unwrap();
+    assert!(dag.position(d) < dag.position(a));
+    assert_order_is_topological(&dag);
+    *dag.node_weight_mut(d).unwrap() = 4;
+    assert_eq!(dag.inner()[d], 4);
+}
@@ -0,0 +1,34 @@
+use petgraph::algo::{all_shortest_paths, shortest_path_counts};
+use petgraph::prelude::*;
+
+#[test]
+fn unreachable_and_trivial() {
+    let mut graph = DiGraph::<(), u32>::new();
+    let a = graph.add_node(());
+    let b = graph.add_node(());
+    let c = graph.add_node(());
+    graph.add_edge(a, b, 1);
+
+    assert_eq!(all_shortest_paths(&graph, a, c, |e| *e.weight()).count(), 0);
+    assert_eq!(
+        all_shortest_paths(&graph, a, a, |e| *e.weight()).collect::<Vec<_>>(),
+        vec![vec![a]]
+    );
+
+    let counts = shortest_path_counts(&graph, a, |e| *e.weight());
+    assert_eq!(counts.len(), 2);
+    assert_eq!(counts[&a], 1);
+    assert_eq!(counts[&b], 1);
+}
+
+#[test]
+fn parallel_edges_counted_once() {
+    let mut graph = DiGraph::<(), u32>::new();
+    let a = graph.add_node(());
+    let b = graph.add_node(());
+    graph.add_edge(a, b, 1);
+    graph.add_edge(a, b, 1);
+    graph.add_edge(a, b, 2);
+    assert_eq!(all_shortest_paths(&graph, a, b, |e| *e.weight()).count(), 1);
+    assert_eq!(shortest_path_counts(&graph, a, |e| *e.weight())[&b], 1);
+}
@@ -0,0 +1,53 @@
+use petgraph::algo::{bipartition, is_bipartite_undirected};
+use petgraph::prelude::*;
+
+#[test]
+fn bipartition_covers_all_components() {
+    // The first component is bipartite, the second is a triangle, which
+    // `is_bipartite_undirected` misses from the first.
+    let graph = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (3, 4), (4, 5), (5, 3)]);
+    assert!(is_bipartite_undirected(&graph, NodeIndex::new(0)));
+    let cycle = bipartition(&graph).unwrap_err().into_cycle();
+    assert_eq!(cycle.len(), 3);
+    assert!(cycle.iter().all(|n| n.index() >= 3));
+
+    let graph = UnGraph::<(), ()>::from_edges(&[(0, 1), (2, 2)]);
+    assert_eq!(
+        bipartition(&graph).unwrap_err().cycle(),
+        [NodeIndex::new(2)]
+    );
+}
+
+#[test]
+fn bipartition_of_large_graphs() {
+    // An even cycle is bipartite, and adding a chord between two nodes at an
+    // even distance makes it not.
+    let n = 100_000;
+    let mut graph = UnGraph::<(), ()>::with_capacity(n, n + 1);
+    for _ in 0..n {
+        graph.add_node(());
+    }
+    for i in 0..n {
+        graph.add_edge(NodeIndex::new(i), NodeIndex::new((i + 1) % n), ());
+    }
+    let (left, right) = bipartition(&graph).unwrap();
+    assert_eq!((left.len(), right.len()), (n / 2, n / 2));
+    graph.add_edge(NodeIndex::new(10), NodeIndex::new(n / 2), ());
+    let cycle = bipartition(&graph).unwrap_err().into_cycle();
+    assert_eq!(cycle.len() % 2, 1);
+    assert!(cycle.contains(&NodeIndex::new(10)) && cycle.contains(&NodeIndex::new(n / 2)));
+}
+
+#[test]
+fn bipartition_skips_removed_nodes() {
+    let mut graph = StableUnGraph::<(), ()>::default();
+    let nodes: Vec<NodeIndex> = (0..5).map(|_| graph.add_node(())).collect();
+    for i in 0..5 {
+        graph.add_edge(nodes[i], nodes[(i + 1) % 5], ());
+    }
+    assert_eq!(bipartition(&graph).unwrap_err().cycle().len(), 5);
+    graph.remove_node(nodes[2]);
+    let (left, right) = bipartition(&graph).unwrap();
+    assert_eq!(left, [nodes[0], nodes[3]]);
+    assert_eq!(right, [nodes[1], nodes[4]]);
+}
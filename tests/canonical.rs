@@ -0,0 +1,188 @@
+use std::collections::HashSet;
+
+use petgraph::algo::{canonical_labeling, weisfeiler_lehman_hash};
+use petgraph::prelude::*;
+use petgraph::visit::NodeRef;
+use petgraph::EdgeType;
+
+/// The same graph with node `v` renumbered `v * 7 % n` and the edges in reverse order, for a
+/// number of nodes `n` that is not a multiple of 7.
+fn permuted<Ty: EdgeType>(graph: &Graph<u8, (), Ty>) -> Graph<u8, (), Ty> {
+    let n = graph.node_count();
+    assert!(n % 7 != 0);
+    let map = |v: NodeIndex| NodeIndex::new(v.index() * 7 % n);
+    let mut result = Graph::default();
+    for _ in 0..n {
+        result.add_node(0);
+    }
+    for v in graph.node_indices() {
+        result[map(v)] = graph[v];
+    }
+    let edges: Vec<_> = graph.edge_references().collect();
+    for e in edges.into_iter().rev() {
+        result.add_edge(map(e.source()), map(e.target()), ());
+    }
+    result
+}
+
+/// The node weights and edges of the graph, renumbered in canonical order.
+fn canonical_form<Ty: EdgeType>(graph: &Graph<u8, (), Ty>) -> (Vec<u8>, Vec<(usize, usize)>) {
+    let order = canonical_labeling(graph, |n| *n.weight(), |_| ());
+    let mut sorted = order.clone();
+    sorted.sort();
+    assert_eq!(sorted, graph.node_indices().collect::<Vec<_>>());
+    let mut position = vec![0; order.len()];
+    for (i, n) in order.iter().enumerate() {
+        position[n.index()] = i;
+    }
+    let mut edges: Vec<_> = graph
+        .edge_references()
+        .map(|e| {
+            let (a, b) = (position[e.source().index()], position[e.target().index()]);
+            if Ty::is_directed() {
+                (a, b)
+            } else {
+                (a.min(b), a.max(b))
+            }
+        })
+        .collect();
+    edges.sort();
+    (order.iter().map(|&n| graph[n]).collect(), edges)
+}
+
+fn wl_hash<Ty: EdgeType>(graph: &Graph<u8, (), Ty>) -> u64 {
+    weisfeiler_lehman_hash(graph, 3, |n| *n.weight(), |_| ())
+}
+
+#[test]
+fn canonical_labeling_of_symmetric_graphs() {
+    let n = 30;
+    let mut graphs: Vec<UnGraph<u8, ()>> = Vec::new();
+    // Empty and complete graphs, a cycle, a hypercube and disjoint triangles.
+    let mut empty = UnGraph::default();
+    for _ in 0..n {
+        empty.add_node(0);
+    }
+    let mut complete = empty.clone();
+    for a in 0..n {
+        for b in a + 1..n {
+            complete.add_edge(NodeIndex::new(a), NodeIndex::new(b), ());
+        }
+    }
+    let mut cycle = empty.clone();
+    for a in 0..n {
+        cycle.add_edge(NodeIndex::new(a), NodeIndex::new((a + 1) % n), ());
+    }
+    let mut hypercube = UnGraph::default();
+    for _ in 0..32 {
+        hypercube.add_node(0);
+    }
+    for a in 0..32usize {
+        for bit in 0..5 {
+            if a & (1 << bit) == 0 {
+                hypercube.add_edge(NodeIndex::new(a), NodeIndex::new(a | (1 << bit)), ());
+            }
+        }
+    }
+    let mut triangles = empty.clone();
+    for a in 0..n / 3 {
+        for &(x, y) in &[(0, 1), (1, 2), (2, 0)] {
+            triangles.add_edge(NodeIndex::new(3 * a + x), NodeIndex::new(3 * a + y), ());
+        }
+    }
+    // The 4x4 rook's graph and the Shrikhande graph are strongly regular with
+    // the same parameters, but not isomorphic.
+    let mut rook = UnGraph::default();
+    let mut shrikhande = UnGraph::default();
+    for _ in 0..16 {
+        rook.add_node(0);
+        shrikhande.add_node(0);
+    }
+    for a in 0..16usize {
+        for b in a + 1..16 {
+            let (di, dj) = ((b / 4 + 4 - a / 4) % 4, (b % 4 + 4 - a % 4) % 4);
+            if di == 0 || dj == 0 {
+                rook.add_edge(NodeIndex::new(a), NodeIndex::new(b), ());
+            }
+            if [(0, 1), (0, 3), (1, 0), (3, 0), (1, 1), (3, 3)].contains(&(di, dj)) {
+                shrikhande.add_edge(NodeIndex::new(a), NodeIndex::new(b), ());
+            }
+        }
+    }
+    assert_eq!(wl_hash(&rook), wl_hash(&shrikhande));
+    graphs.extend(vec![
+        empty, complete, cycle, hypercube, triangles, rook, shrikhande,
+    ]);
+
+    let forms: HashSet<_> = graphs
+        .iter()
+        .map(|g| {
+            let form = canonical_form(g);
+            assert_eq!(canonical_form(&permuted(g)), form);
+            form
+        })
+        .collect();
+    assert_eq!(forms.len(), graphs.len());
+}
+
+#[test]
+fn canonical_labeling_edge_labels() {
+    let mut g0 = UnGraph::<u8, u8>::default();
+    let a = g0.add_node(0);
+    let b = g0.add_node(0);
+    let c = g0.add_node(0);
+    g0.extend_with_edges(&[(a, b, 1), (b, c, 2)]);
+    let mut g1 = g0.clone();
+    *g1.edge_weight_mut(g1.find_edge(a, b).unwrap()).unwrap() = 2;
+    *g1.edge_weight_mut(g1.find_edge(b, c).unwrap()).unwrap() = 1;
+    let mut g2 = g0.clone();
+    *g2.edge_weight_mut(g2.find_edge(b, c).unwrap()).unwrap() = 1;
+
+    let form = |g: &UnGraph<u8, u8>| {
+        let order = canonical_labeling(g, |_| (), |e| *e.weight());
+        let mut edges: Vec<_> = g
+            .edge_references()
+            .map(|e| {
+                let pa = order.iter().position(|&n| n == e.source()).unwrap();
+                let pb = order.iter().position(|&n| n == e.target()).unwrap();
+                (pa.min(pb), pa.max(pb), *e.weight())
+            })
+            .collect();
+        edges.sort();
+        edges
+    };
+    assert_eq!(form(&g0), form(&g1));
+    assert_ne!(form(&g0), form(&g2));
+
+    let hash = |g: &UnGraph<u8, u8>| weisfeiler_lehman_hash(g, 2, |_| (), |e| *e.weight());
+    assert_eq!(hash(&g0), hash(&g1));
+    assert_ne!(hash(&g0), hash(&g2));
+}
+
+#[test]
+fn weisfeiler_lehman_hash_limits() {
+    // WL refinement cannot tell regular graphs apart, but the canonical
+    // labeling can.
+    let six_cycle =
+        UnGraph::<u8, ()>::from_edges(&[(0, 1), (1, 2), (2, 3), (3, 4), (4, 5), (5, 0)]);
+    let triangles =
+        UnGraph::<u8, ()>::from_edges(&[(0, 1), (1, 2), (2, 0), (3, 4), (4, 5), (5, 3)]);
+    assert_eq!(wl_hash(&six_cycle), wl_hash(&triangles));
+    assert_ne!(canonical_form(&six_cycle), canonical_form(&triangles));
+
+    // The number of iterations and the node labels matter.
+    let path = UnGraph::<u8, ()>::from_edges(&[(0, 1), (1, 2), (2, 3)]);
+    let star = UnGraph::<u8, ()>::from_edges(&[(0, 1), (0, 2), (0, 3)]);
+    let hash = |g: &UnGraph<u8, ()>, i| weisfeiler_lehman_hash(g, i, |_| (), |_| ());
+    assert_eq!(hash(&path, 0), hash(&star, 0));
+    assert_ne!(hash(&path, 1), hash(&star, 1));
+    let mut labeled = path.clone();
+    labeled[NodeIndex::new(0)] = 1;
+    assert_ne!(wl_hash(&path), wl_hash(&labeled));
+}
+
+#[test]
+fn canonical_labeling_empty_graph() {
+    let graph = UnGraph::<u8, ()>::default();
+    assert!(canonical_labeling(&graph, |_| (), |_| ()).is_empty());
+}
@@ -0,0 +1,114 @@
+use std::collections::HashSet;
+
+use petgraph::algo::{chordal_completion, is_chordal, EliminationHeuristic};
+use petgraph::prelude::*;
+use petgraph::visit::{LexBfs, MaxCardinalitySearch, Walker};
+
+fn adjacent(graph: &UnGraph<(), ()>, a: NodeIndex, b: NodeIndex) -> bool {
+    graph.find_edge(a, b).is_some()
+}
+
+fn assert_perfect_elimination_ordering(graph: &UnGraph<(), ()>, order: &[NodeIndex]) {
+    let mut sorted = order.to_vec();
+    sorted.sort();
+    assert_eq!(sorted, graph.node_indices().collect::<Vec<_>>());
+    for (i, &v) in order.iter().enumerate() {
+        let later: Vec<_> = order[i + 1..]
+            .iter()
+            .cloned()
+            .filter(|&u| adjacent(graph, u, v))
+            .collect();
+        for &a in &later {
+            for &b in &later {
+                assert!(a == b || adjacent(graph, a, b), "{:?}", order);
+            }
+        }
+    }
+}
+
+fn assert_chordless_cycle(graph: &UnGraph<(), ()>, cycle: &[NodeIndex]) {
+    let n = cycle.len();
+    assert!(n >= 4, "{:?}", cycle);
+    assert_eq!(cycle.iter().collect::<HashSet<_>>().len(), n);
+    for i in 0..n {
+        for j in i + 1..n {
+            let consecutive = j == i + 1 || (i == 0 && j == n - 1);
+            assert_eq!(
+                adjacent(graph, cycle[i], cycle[j]),
+                consecutive,
+                "{:?}",
+                cycle
+            );
+        }
+    }
+}
+
+#[test]
+fn is_chordal_certificates() {
+    // A square with a pendant node has the square as its only chordless cycle.
+    let mut graph = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 3), (3, 0), (3, 4)]);
+    let cycle = is_chordal(&graph).unwrap_err();
+    assert_chordless_cycle(&graph, cycle.cycle());
+    let mut nodes: Vec<_> = cycle.cycle().iter().map(|n| n.index()).collect();
+    nodes.sort();
+    assert_eq!(nodes, vec![0, 1, 2, 3]);
+
+    // A chord makes it chordal.
+    graph.add_edge(NodeIndex::new(0), NodeIndex::new(2), ());
+    assert_perfect_elimination_ordering(&graph, &is_chordal(&graph).unwrap());
+    let (order, fill) = chordal_completion(&graph, EliminationHeuristic::MinFill);
+    assert!(fill.is_empty());
+    assert_perfect_elimination_ordering(&graph, &order);
+}
+
+#[test]
+fn lex_bfs_order() {
+    // After 0, 1 and 2 come in either order. A plain BFS could then visit 3
+    // before 4, but LexBFS prefers 4, which is adjacent to both of them.
+    let graph = UnGraph::<(), ()>::from_edges(&[(0, 1), (0, 2), (1, 3), (2, 4), (1, 4)]);
+    let order: Vec<_> = LexBfs::new(&graph)
+        .iter(&graph)
+        .map(|n| n.index())
+        .collect();
+    assert_eq!(order[0], 0);
+    assert_eq!(&order[3..], &[4, 3]);
+}
+
+#[test]
+fn chordal_completion_of_cycle() {
+    // A hexagon needs three chords, which both heuristics find.
+    let graph = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 3), (3, 4), (4, 5), (5, 0)]);
+    for &heuristic in &[
+        EliminationHeuristic::MinDegree,
+        EliminationHeuristic::MinFill,
+    ] {
+        let mut completed = graph.clone();
+        let (order, fill) = chordal_completion(&graph, heuristic);
+        assert_eq!(fill.len(), 3);
+        for &(a, b) in &fill {
+            assert!(a != b && !adjacent(&graph, a, b));
+            completed.add_edge(a, b, ());
+        }
+        assert!(is_chordal(&completed).is_ok());
+        assert_perfect_elimination_ordering(&completed, &order);
+    }
+}
+
+#[test]
+fn traversals_skip_removed_nodes() {
+    let mut graph = StableUnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 3), (3, 0)]);
+    graph.remove_node(NodeIndex::new(1));
+    let mut order: Vec<_> = MaxCardinalitySearch::new(&graph)
+        .iter(&graph)
+        .map(|n| n.index())
+        .collect();
+    order.sort();
+    assert_eq!(order, vec![0, 2, 3]);
+    let mut order: Vec<_> = LexBfs::new(&graph)
+        .iter(&graph)
+        .map(|n| n.index())
+        .collect();
+    order.sort();
+    assert_eq!(order, vec![0, 2, 3]);
+    assert!(is_chordal(&graph).is_ok());
+}
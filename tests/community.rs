@@ -0,0 +1,95 @@
+use petgraph::algo::{edge_betweenness, girvan_newman};
+use petgraph::prelude::*;
+
+#[test]
+fn edge_betweenness_known_values() {
+    // On a path, an edge is crossed by every pair it separates.
+    let path = UnGraph::<(), ()>::from_edges((0..9).map(|i| (i, i + 1)));
+    let expected: Vec<_> = (0..9).map(|i| ((i + 1) * (9 - i)) as f64).collect();
+    assert_eq!(edge_betweenness(&path), expected);
+
+    // On a star, an edge is crossed by the paths from its leaf.
+    let star = UnGraph::<(), ()>::from_edges((1..8).map(|i| (0, i)));
+    assert_eq!(edge_betweenness(&star), vec![7.; 7]);
+
+    // Parallel edges share the paths, and self loops have none.
+    let multi = UnGraph::<(), ()>::from_edges(&[(0, 1), (0, 1), (1, 2), (1, 1)]);
+    assert_eq!(edge_betweenness(&multi), vec![1., 1., 2., 0.]);
+
+    // Removed edges have no value.
+    let mut stable = StableUnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 3)]);
+    stable.remove_edge(EdgeIndex::new(1));
+    let betweenness = edge_betweenness(&stable);
+    assert_eq!(betweenness[0], 1.);
+    assert!(betweenness[1].is_nan());
+    assert_eq!(betweenness[2], 1.);
+}
+
+/// Cliques of `size` nodes, with one edge from each clique to the next.
+fn chain_of_cliques(cliques: usize, size: usize) -> UnGraph<(), ()> {
+    let mut edges = Vec::new();
+    for c in 0..cliques {
+        let first = (c * size) as u32;
+        for a in 0..size as u32 {
+            for b in a + 1..size as u32 {
+                edges.push((first + a, first + b));
+            }
+        }
+        if c + 1 < cliques {
+            edges.push((first + size as u32 - 1, first + size as u32));
+        }
+    }
+    UnGraph::from_edges(&edges)
+}
+
+#[test]
+fn girvan_newman_splits_cliques() {
+    let graph = chain_of_cliques(4, 5);
+    let n: Vec<_> = graph.node_indices().collect();
+    let partitions: Vec<_> = girvan_newman(&graph).take(3).collect();
+    for (k, partition) in partitions.iter().enumerate() {
+        assert_eq!(partition.len(), k + 2);
+    }
+    let cliques: Vec<Vec<_>> = n.chunks(5).map(|c| c.to_vec()).collect();
+    assert_eq!(partitions[2], cliques);
+    // The middle bridge carries the most paths, and goes first.
+    assert_eq!(partitions[0], vec![n[..10].to_vec(), n[10..].to_vec()]);
+}
+
+#[test]
+fn girvan_newman_of_empty_and_single_node_graphs() {
+    let empty = UnGraph::<(), ()>::default();
+    assert_eq!(girvan_newman(&empty).next(), None);
+    let dendrogram = girvan_newman(&empty).dendrogram();
+    assert!(dendrogram.merges().is_empty());
+    assert_eq!(dendrogram.cut(3), Vec::<Vec<NodeIndex>>::new());
+
+    let single = UnGraph::<(), ()>::from_edges(&[(0, 0)]);
+    assert_eq!(girvan_newman(&single).next(), None);
+}
+
+#[test]
+fn girvan_newman_ignores_directions_and_removed_nodes() {
+    // Directions and removed nodes are ignored, and the input is unchanged.
+    let mut graph = StableDiGraph::<(), ()>::from_edges(&[(0, 1), (2, 1), (1, 3), (3, 4), (4, 3)]);
+    graph.remove_node(NodeIndex::new(2));
+    let partitions: Vec<_> = girvan_newman(&graph).collect();
+    let n = |i| NodeIndex::new(i);
+    assert_eq!(
+        partitions,
+        vec![
+            vec![vec![n(0), n(1)], vec![n(3), n(4)]],
+            vec![vec![n(0)], vec![n(1)], vec![n(3), n(4)]],
+            vec![vec![n(0)], vec![n(1)], vec![n(3)], vec![n(4)]],
+        ]
+    );
+    assert_eq!(graph.edge_count(), 4);
+}
+
+#[test]
+fn girvan_newman_large() {
+    let graph = chain_of_cliques(10, 12);
+    let first = girvan_newman(&graph).nth(8).unwrap();
+    assert_eq!(first.len(), 10);
+    assert!(first.iter().all(|c| c.len() == 12));
+}
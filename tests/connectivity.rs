@@ -0,0 +1,103 @@
+use petgraph::algo::{
+    edge_connectivity, k_edge_connected_subgraphs, k_vertex_connected_components, node_connectivity,
+};
+use petgraph::prelude::*;
+
+fn sorted(mut sets: Vec<Vec<NodeIndex>>) -> Vec<Vec<usize>> {
+    let mut sets: Vec<Vec<usize>> = sets
+        .iter_mut()
+        .map(|s| {
+            let mut s: Vec<_> = s.iter().map(|n| n.index()).collect();
+            s.sort_unstable();
+            s
+        })
+        .collect();
+    sets.sort();
+    sets
+}
+
+#[test]
+fn connectivity_numbers() {
+    let graph = UnGraph::<(), ()>::new_undirected();
+    assert_eq!(edge_connectivity(&graph), 0);
+    assert_eq!(node_connectivity(&graph), 0);
+
+    // A cycle with a double edge has edge connectivity 2, node connectivity 2.
+    let graph = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 3), (3, 0), (0, 1)]);
+    assert_eq!(edge_connectivity(&graph), 2);
+    assert_eq!(node_connectivity(&graph), 2);
+
+    // Two K4s sharing a node: removing it disconnects the graph.
+    let mut edges = Vec::new();
+    for &(offset, nodes) in &[(0u32, [0u32, 1, 2, 3]), (3, [0, 1, 2, 3])] {
+        for i in 0..4 {
+            for j in i + 1..4 {
+                edges.push((offset + nodes[i], offset + nodes[j]));
+            }
+        }
+    }
+    let graph = UnGraph::<(), ()>::from_edges(&edges);
+    assert_eq!(node_connectivity(&graph), 1);
+    assert_eq!(edge_connectivity(&graph), 3);
+}
+
+#[test]
+fn k_edge_connected_subgraphs_example() {
+    // K4 on 0..4 attached to a triangle 4..7 by two edges, and a pendant node 7.
+    let graph = UnGraph::<(), ()>::from_edges(&[
+        (0, 1),
+        (0, 2),
+        (0, 3),
+        (1, 2),
+        (1, 3),
+        (2, 3),
+        (3, 4),
+        (2, 5),
+        (4, 5),
+        (5, 6),
+        (6, 4),
+        (6, 7),
+    ]);
+    assert_eq!(
+        sorted(k_edge_connected_subgraphs(&graph, 3)),
+        vec![vec![0, 1, 2, 3], vec![4], vec![5], vec![6], vec![7]]
+    );
+    assert_eq!(
+        sorted(k_edge_connected_subgraphs(&graph, 2)),
+        vec![vec![0, 1, 2, 3, 4, 5, 6], vec![7]]
+    );
+}
+
+#[test]
+fn k_vertex_connected_components_example() {
+    // Two K4s sharing the edge 2-3, and a triangle hanging off node 0.
+    let graph = UnGraph::<(), ()>::from_edges(&[
+        (0, 1),
+        (0, 2),
+        (0, 3),
+        (1, 2),
+        (1, 3),
+        (2, 3),
+        (2, 4),
+        (2, 5),
+        (3, 4),
+        (3, 5),
+        (4, 5),
+        (0, 6),
+        (6, 7),
+        (7, 0),
+    ]);
+    assert_eq!(
+        sorted(k_vertex_connected_components(&graph, 3)),
+        vec![vec![0, 1, 2, 3], vec![2, 3, 4, 5]]
+    );
+    assert_eq!(
+        sorted(k_vertex_connected_components(&graph, 2)),
+        vec![vec![0, 1, 2, 3, 4, 5], vec![0, 6, 7]]
+    );
+    assert_eq!(
+        sorted(k_vertex_connected_components(&graph, 1)),
+        vec![vec![0, 1, 2, 3, 4, 5, 6, 7]]
+    );
+    assert!(k_vertex_connected_components(&graph, 4).is_empty());
+}
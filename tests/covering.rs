@@ -0,0 +1,83 @@
+use petgraph::algo::{
+    approximate_vertex_cover, bipartite_vertex_cover, greedy_dominating_set,
+    greedy_independent_set, maximum_independent_set, maximum_matching,
+};
+use petgraph::prelude::*;
+
+#[test]
+fn bipartite_vertex_cover_of_directed_graph() {
+    // Edges are covered whichever way they point.
+    let graph =
+        DiGraph::<(), ()>::from_edges(&[(3, 0), (8, 7), (3, 7), (2, 6), (0, 6), (7, 1), (5, 4)]);
+    let cover = bipartite_vertex_cover(&graph).unwrap();
+    assert_eq!(cover.len(), 4);
+    for e in graph.edge_references() {
+        assert!(cover.contains(&e.source()) || cover.contains(&e.target()));
+    }
+    assert_eq!(
+        cover.len(),
+        graph.node_count() - maximum_independent_set(&graph).len()
+    );
+}
+
+#[test]
+fn bipartite_vertex_cover_of_grid() {
+    // A grid has a perfect matching, so a minimum cover takes one side of the bipartition.
+    let (width, height) = (8, 10);
+    let mut graph = UnGraph::<(), ()>::default();
+    let n = |i: usize, j: usize| NodeIndex::new(i * width + j);
+    for _ in 0..width * height {
+        graph.add_node(());
+    }
+    for i in 0..height {
+        for j in 0..width {
+            if i + 1 < height {
+                graph.add_edge(n(i, j), n(i + 1, j), ());
+            }
+            if j + 1 < width {
+                graph.add_edge(n(i, j), n(i, j + 1), ());
+            }
+        }
+    }
+    let cover = bipartite_vertex_cover(&graph).unwrap();
+    assert_eq!(cover.len(), width * height / 2);
+    assert_eq!(cover.len(), maximum_matching(&graph).len());
+    assert_eq!(maximum_independent_set(&graph).len(), width * height / 2);
+    assert!(greedy_independent_set(&graph).len() <= width * height / 2);
+}
+
+#[test]
+fn maximum_independent_set_of_path() {
+    // A path of 2k nodes, where the degree one reduction alone finds the k nodes of a maximum
+    // independent set.
+    let k = 25;
+    let mut graph = UnGraph::<(), ()>::default();
+    let nodes: Vec<_> = (0..2 * k).map(|_| graph.add_node(())).collect();
+    for i in 0..k {
+        graph.add_edge(nodes[2 * i], nodes[2 * i + 1], ());
+        if i + 1 < k {
+            graph.add_edge(nodes[2 * i + 1], nodes[2 * i + 2], ());
+        }
+    }
+    assert_eq!(maximum_independent_set(&graph).len(), k);
+}
+
+#[test]
+fn covering_with_loops_and_removed_nodes() {
+    let mut graph = StableUnGraph::<(), ()>::default();
+    let nodes: Vec<_> = (0..5).map(|_| graph.add_node(())).collect();
+    graph.add_edge(nodes[0], nodes[1], ());
+    graph.add_edge(nodes[1], nodes[2], ());
+    graph.add_edge(nodes[2], nodes[3], ());
+    graph.add_edge(nodes[3], nodes[4], ());
+    graph.add_edge(nodes[2], nodes[2], ());
+    let set = maximum_independent_set(&graph);
+    assert_eq!(set.len(), 2);
+    assert!(!set.contains(&nodes[2]));
+    assert!(approximate_vertex_cover(&graph).contains(&nodes[2]));
+    assert!(bipartite_vertex_cover(&graph).is_err());
+
+    graph.remove_node(nodes[1]);
+    assert_eq!(greedy_independent_set(&graph), [nodes[0], nodes[3]]);
+    assert_eq!(greedy_dominating_set(&graph), [nodes[3], nodes[0]]);
+}
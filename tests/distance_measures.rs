@@ -0,0 +1,120 @@
+use std::collections::HashMap;
+
+use petgraph::algo::{
+    center, diameter, eccentricities, eccentricity, periphery, radius, weighted_center,
+    weighted_diameter, weighted_eccentricities, weighted_eccentricity, weighted_periphery,
+    weighted_radius,
+};
+use petgraph::graph::EdgeReference;
+use petgraph::prelude::*;
+
+#[test]
+fn distance_measures_of_weighted_path() {
+    // A path where the first edge is long, so the weights move the center.
+    let graph = UnGraph::<(), u32>::from_edges(&[(0, 1, 5), (1, 2, 1), (2, 3, 1), (3, 4, 1)]);
+    let cost = |e: EdgeReference<u32>| *e.weight();
+    let n = NodeIndex::new;
+    assert_eq!(eccentricity(&graph, n(1)), Some(3));
+    assert_eq!(weighted_eccentricity(&graph, n(1), cost), Some(5));
+    assert_eq!(diameter(&graph), Some(4));
+    assert_eq!(weighted_diameter(&graph, cost), Some(8));
+    assert_eq!(radius(&graph), Some(2));
+    assert_eq!(weighted_radius(&graph, cost), Some(5));
+    assert_eq!(center(&graph), [n(2)]);
+    assert_eq!(weighted_center(&graph, cost), [n(1)]);
+    assert_eq!(periphery(&graph), [n(0), n(4)]);
+    assert_eq!(weighted_periphery(&graph, cost), [n(0), n(4)]);
+    let expected: HashMap<_, _> = (0..5).map(|i| (n(i), [4, 3, 2, 3, 4][i])).collect();
+    assert_eq!(eccentricities(&graph), Some(expected));
+    assert_eq!(weighted_eccentricities(&graph, cost).unwrap()[&n(3)], 7);
+
+    // A directed path is not strongly connected.
+    let graph = DiGraph::<(), u32>::from_edges(&[(0, 1, 1), (1, 2, 1)]);
+    assert_eq!(eccentricity(&graph, n(0)), Some(2));
+    assert_eq!(eccentricity(&graph, n(1)), None);
+    assert_eq!(diameter(&graph), None);
+    assert_eq!(weighted_eccentricities(&graph, cost), None);
+    assert!(center(&graph).is_empty());
+}
+
+#[test]
+fn distance_measures_of_large_grid() {
+    let size = 300;
+    let mut graph = UnGraph::<(), ()>::with_capacity(size * size, 2 * size * size);
+    for _ in 0..size * size {
+        graph.add_node(());
+    }
+    let n = |i: usize, j: usize| NodeIndex::new(i * size + j);
+    for i in 0..size {
+        for j in 0..size {
+            if i + 1 < size {
+                graph.add_edge(n(i, j), n(i + 1, j), ());
+            }
+            if j + 1 < size {
+                graph.add_edge(n(i, j), n(i, j + 1), ());
+            }
+        }
+    }
+    assert_eq!(diameter(&graph), Some(2 * size - 2));
+    assert_eq!(radius(&graph), Some(size));
+    let middle = size / 2;
+    assert_eq!(
+        center(&graph),
+        [
+            n(middle - 1, middle - 1),
+            n(middle - 1, middle),
+            n(middle, middle - 1),
+            n(middle, middle)
+        ]
+    );
+    assert_eq!(
+        periphery(&graph),
+        [
+            n(0, 0),
+            n(0, size - 1),
+            n(size - 1, 0),
+            n(size - 1, size - 1)
+        ]
+    );
+    assert_eq!(
+        weighted_diameter(&graph, |_| 1.5),
+        Some(1.5 * (2 * size - 2) as f64)
+    );
+}
+
+#[test]
+fn distance_measures_skip_removed_nodes() {
+    let mut graph = StableDiGraph::<(), u32>::default();
+    let nodes: Vec<NodeIndex> = (0..5).map(|_| graph.add_node(())).collect();
+    for i in 0..5 {
+        graph.add_edge(nodes[i], nodes[(i + 1) % 5], 1);
+    }
+    graph.add_edge(nodes[0], nodes[2], 1);
+    graph.add_edge(nodes[2], nodes[0], 3);
+    assert_eq!(diameter(&graph), Some(3));
+    assert_eq!(weighted_radius(&graph, |e| *e.weight()), Some(3));
+
+    // Removing a node on the cycle leaves the graph strongly connected
+    // through the shortcut.
+    graph.remove_node(nodes[1]);
+    assert_eq!(eccentricity(&graph, nodes[0]), Some(3));
+    assert_eq!(diameter(&graph), Some(3));
+    assert_eq!(center(&graph), [nodes[2]]);
+    graph.remove_node(nodes[3]);
+    assert_eq!(diameter(&graph), None);
+    assert_eq!(eccentricity(&graph, nodes[4]), Some(2));
+}
+
+#[test]
+fn distance_measures_of_empty_and_single_node_graphs() {
+    let empty = UnGraph::<(), ()>::default();
+    assert_eq!(diameter(&empty), None);
+    assert_eq!(radius(&empty), None);
+    assert!(center(&empty).is_empty());
+    assert_eq!(eccentricities(&empty), None);
+
+    let single = UnGraph::<(), ()>::from_edges(&[(0, 0)]);
+    assert_eq!(diameter(&single), Some(0));
+    assert_eq!(weighted_radius(&single, |_| 2), Some(0));
+    assert_eq!(periphery(&single), [NodeIndex::new(0)]);
+}
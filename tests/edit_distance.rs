@@ -0,0 +1,219 @@
+use std::collections::HashSet;
+
+use petgraph::algo::edit_distance::UnitCost;
+use petgraph::algo::isomorphism::NoSemanticMatch;
+use petgraph::algo::{
+    approximate_graph_edit_distance, graph_edit_distance, graph_edit_distance_within_budget,
+};
+use petgraph::prelude::*;
+use petgraph::EdgeType;
+use rand::{ChaChaRng, Rng, SeedableRng};
+
+type Path = Vec<(Option<NodeIndex>, Option<NodeIndex>)>;
+
+fn node_cost(a: Option<&u8>, b: Option<&u8>) -> u32 {
+    match (a, b) {
+        (Some(a), Some(b)) => 2 * (*a != *b) as u32,
+        _ => 3,
+    }
+}
+
+fn edge_cost(a: Option<&u8>, b: Option<&u8>) -> u32 {
+    match (a, b) {
+        (Some(a), Some(b)) => (*a as i32 - *b as i32).abs() as u32,
+        _ => 2,
+    }
+}
+
+/// The cost of the edit path given by a node assignment, from the definition.
+fn path_cost<Ty: EdgeType>(g0: &Graph<u8, u8, Ty>, g1: &Graph<u8, u8, Ty>, path: &Path) -> u32 {
+    let mut image = vec![None; g0.node_count()];
+    let mut inserted = HashSet::new();
+    let mut cost = 0;
+    for &(a, b) in path {
+        match a {
+            Some(a) => image[a.index()] = b,
+            None => assert!(inserted.insert(b.unwrap())),
+        }
+        cost += node_cost(a.map(|a| &g0[a]), b.map(|b| &g1[b]));
+    }
+    let mut substituted = HashSet::new();
+    for e in g0.edge_references() {
+        let edge1 = match (image[e.source().index()], image[e.target().index()]) {
+            (Some(a), Some(b)) => g1.find_edge(a, b),
+            _ => None,
+        };
+        match edge1 {
+            Some(f) => {
+                substituted.insert(f);
+                cost += edge_cost(Some(e.weight()), Some(&g1[f]));
+            }
+            None => cost += edge_cost(Some(e.weight()), None),
+        }
+    }
+    for f in g1.edge_references() {
+        if !substituted.contains(&f.id()) {
+            cost += edge_cost(None, Some(f.weight()));
+        }
+    }
+    cost
+}
+
+/// Check that the path assigns every node of `g0` once, in order, and every
+/// node of `g1` at most once, inserting the others.
+fn assert_valid_path<Ty: EdgeType>(g0: &Graph<u8, u8, Ty>, g1: &Graph<u8, u8, Ty>, path: &Path) {
+    assert_eq!(path.len(), {
+        let substituted = path.iter().filter(|p| p.0.is_some() && p.1.is_some());
+        g0.node_count() + g1.node_count() - substituted.count()
+    });
+    let sources: Vec<_> = path.iter().filter_map(|p| p.0).collect();
+    assert_eq!(sources, g0.node_indices().collect::<Vec<_>>());
+    let targets: HashSet<_> = path.iter().filter_map(|p| p.1).collect();
+    assert_eq!(targets, g1.node_indices().collect());
+}
+
+#[test]
+fn approximate_edit_distance_of_large_graphs() {
+    // A shuffled copy of a graph with distinct node weights is found exactly.
+    let mut rng = ChaChaRng::from_seed([44; 32]);
+    let n = 80;
+    let mut g0 = UnGraph::<u32, ()>::default();
+    for i in 0..n {
+        g0.add_node(i);
+    }
+    for _ in 0..3 * n {
+        let a = NodeIndex::new(rng.gen_range(0, n as usize));
+        let b = NodeIndex::new(rng.gen_range(0, n as usize));
+        g0.update_edge(a, b, ());
+    }
+    let mut permutation: Vec<usize> = (0..n as usize).collect();
+    rng.shuffle(&mut permutation);
+    let mut g1 = UnGraph::<u32, ()>::default();
+    let mut nodes = vec![NodeIndex::new(0); n as usize];
+    for &p in &permutation {
+        nodes[p] = g1.add_node(p as u32);
+    }
+    for e in g0.edge_references() {
+        g1.add_edge(nodes[e.source().index()], nodes[e.target().index()], ());
+    }
+    let removed = g1.edge_indices().next().unwrap();
+    g1.remove_edge(removed);
+
+    let node_cost = |a: Option<&u32>, b: Option<&u32>| match (a, b) {
+        (Some(a), Some(b)) if a == b => 0,
+        _ => 1,
+    };
+    let edge_cost = |a: Option<&()>, b: Option<&()>| (a.is_none() || b.is_none()) as u32;
+    let (distance, path) = approximate_graph_edit_distance(&g0, &g1, node_cost, edge_cost);
+    assert_eq!(distance, 1);
+    for (a, b) in path {
+        assert_eq!(g0[a.unwrap()], g1[b.unwrap()]);
+    }
+}
+
+#[test]
+fn edit_distance_of_empty_graphs() {
+    let empty = DiGraph::<u8, u8>::new();
+    let g = DiGraph::<u8, u8>::from_edges(&[(0, 1, 1), (1, 1, 2)]);
+    assert_eq!(
+        graph_edit_distance(&empty, &empty, node_cost, edge_cost),
+        (0, vec![])
+    );
+    let (distance, path) = graph_edit_distance(&empty, &g, node_cost, edge_cost);
+    assert_eq!(distance, 3 + 3 + 2 + 2);
+    assert_eq!(path, vec![(None, Some(0.into())), (None, Some(1.into()))]);
+    let (distance, path) = graph_edit_distance(&g, &empty, node_cost, edge_cost);
+    assert_eq!(distance, 10);
+    assert_eq!(path, vec![(Some(0.into()), None), (Some(1.into()), None)]);
+}
+
+#[test]
+fn edit_distance_within_budget_improves() {
+    // Two cycles of six nodes with different chords.
+    let mut g0 = UnGraph::<u8, u8>::from_edges((0..6).map(|i| (i, (i + 1) % 6, 0)));
+    let mut g1 = g0.clone();
+    g0.extend_with_edges(&[(0, 3, 1), (1, 4, 2)]);
+    g1.extend_with_edges(&[(0, 2, 1), (3, 5, 2)]);
+    for (i, w) in g1.node_weights_mut().enumerate() {
+        *w = (i % 3) as u8;
+    }
+    let (distance, _) = graph_edit_distance(&g0, &g1, node_cost, edge_cost);
+    let (approximate, _) = approximate_graph_edit_distance(&g0, &g1, node_cost, edge_cost);
+
+    let (bound, path, exact) = graph_edit_distance_within_budget(&g0, &g1, node_cost, edge_cost, 0);
+    assert!(!exact);
+    assert_eq!(bound, approximate);
+    assert_eq!(path_cost(&g0, &g1, &path), bound);
+
+    // The best distance found never gets worse with a larger budget.
+    let mut previous = bound;
+    let mut budget = 1;
+    loop {
+        let (bound, path, exact) =
+            graph_edit_distance_within_budget(&g0, &g1, node_cost, edge_cost, budget);
+        assert_valid_path(&g0, &g1, &path);
+        assert_eq!(path_cost(&g0, &g1, &path), bound);
+        assert!(distance <= bound && bound <= previous);
+        previous = bound;
+        if exact {
+            assert_eq!(bound, distance);
+            break;
+        }
+        budget *= 2;
+    }
+}
+
+#[test]
+fn edit_distance_with_unit_costs() {
+    let g0 = DiGraph::<u8, u8>::from_edges(&[(0, 1, 1), (1, 2, 1), (2, 0, 2)]);
+    let mut g1 = DiGraph::<u8, u8>::from_edges(&[(1, 2, 1), (2, 0, 1), (0, 1, 3)]);
+    g1[NodeIndex::new(1)] = 5;
+
+    // The structure alone is isomorphic.
+    let (distance, _) = graph_edit_distance(
+        &g0,
+        &g1,
+        UnitCost(NoSemanticMatch),
+        UnitCost(NoSemanticMatch),
+    );
+    assert_eq!(distance, 0);
+    // One node and one edge weight differ.
+    let (distance, path) = graph_edit_distance(
+        &g0,
+        &g1,
+        UnitCost(u8::eq),
+        UnitCost(|a: &u8, b: &u8| a == b),
+    );
+    assert_eq!(distance, 2);
+    assert_eq!(path.len(), 3);
+    let (approximate, _) =
+        approximate_graph_edit_distance(&g0, &g1, UnitCost(u8::eq), UnitCost(NoSemanticMatch));
+    assert!(approximate >= 1);
+
+    // Unit costs of insertions and deletions.
+    let g2 = DiGraph::<u8, u8>::from_edges(&[(0, 1, 1), (1, 2, 1), (2, 3, 1)]);
+    let (distance, _) = graph_edit_distance(
+        &g0,
+        &g2,
+        UnitCost(NoSemanticMatch),
+        UnitCost(NoSemanticMatch),
+    );
+    assert_eq!(distance, 3);
+}
+
+#[test]
+fn edit_distance_with_nan_costs() {
+    // NaN costs are not supported, but must not make the search panic.
+    let g0 = UnGraph::<f64, ()>::from_edges(&[(0, 1), (1, 2), (2, 0)]);
+    let mut g1 = g0.clone();
+    g1[NodeIndex::new(1)] = std::f64::NAN;
+    let node_cost = |a: Option<&f64>, b: Option<&f64>| match (a, b) {
+        (Some(a), Some(b)) => (a - b).abs(),
+        _ => 1.,
+    };
+    let edge_cost = |a: Option<&()>, b: Option<&()>| (a.is_none() || b.is_none()) as u8 as f64;
+    let (_, path) = graph_edit_distance(&g0, &g1, node_cost, edge_cost);
+    assert_eq!(path.len(), 3);
+    let (_, path, _) = graph_edit_distance_within_budget(&g0, &g1, node_cost, edge_cost, 1000);
+    assert_eq!(path.len(), 3);
+}
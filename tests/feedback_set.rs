@@ -0,0 +1,90 @@
+use petgraph::algo::{
+    approximate_feedback_vertex_set, minimum_feedback_arc_set, minimum_feedback_vertex_set,
+};
+use petgraph::prelude::*;
+
+#[test]
+fn feedback_sets_are_weighted() {
+    // Two directed triangles sharing the edge 0 -> 1, which is expensive to remove.
+    let graph =
+        DiGraph::<u32, u32>::from_edges(&[(0, 1, 5), (1, 2, 1), (2, 0, 1), (1, 3, 2), (3, 0, 3)]);
+    let fas: Vec<EdgeIndex> = minimum_feedback_arc_set(&graph, |e| *e.weight())
+        .iter()
+        .map(|e| e.id())
+        .collect();
+    assert_eq!(fas, [EdgeIndex::new(1), EdgeIndex::new(3)]);
+    assert_eq!(minimum_feedback_arc_set(&graph, |_| 1).len(), 1);
+
+    // Nodes 0 and 1 lie on both triangles, and node 1 is cheaper.
+    let weight = |v: NodeIndex| if v.index() == 0 { 3 } else { 1 };
+    assert_eq!(
+        minimum_feedback_vertex_set(&graph, weight),
+        [NodeIndex::new(1)]
+    );
+
+    // Two undirected triangles sharing node 0.
+    let graph = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 0), (0, 3), (3, 4), (4, 0)]);
+    assert_eq!(
+        minimum_feedback_vertex_set(&graph, |v| if v.index() == 0 { 5 } else { 3 }),
+        [NodeIndex::new(0)]
+    );
+}
+
+#[test]
+fn feedback_sets_of_larger_graphs() {
+    // A grid of directed squares, each needing one of its own edges removed.
+    let size = 6;
+    let mut graph = DiGraph::<(), ()>::new();
+    let nodes: Vec<Vec<NodeIndex>> = (0..size)
+        .map(|_| (0..size).map(|_| graph.add_node(())).collect())
+        .collect();
+    for i in (0..size).step_by(2) {
+        for j in (0..size).step_by(2) {
+            let square = [
+                nodes[i][j],
+                nodes[i][j + 1],
+                nodes[i + 1][j + 1],
+                nodes[i + 1][j],
+            ];
+            for k in 0..4 {
+                graph.add_edge(square[k], square[(k + 1) % 4], ());
+            }
+        }
+    }
+    assert_eq!(minimum_feedback_arc_set(&graph, |_| 1).len(), 9);
+    assert_eq!(minimum_feedback_vertex_set(&graph, |_| 1).len(), 9);
+
+    // A complete undirected graph needs all but two of its nodes removed.
+    let n = 9;
+    let mut complete = UnGraph::<(), ()>::new_undirected();
+    let nodes: Vec<NodeIndex> = (0..n).map(|_| complete.add_node(())).collect();
+    for a in 0..n {
+        for b in a + 1..n {
+            complete.add_edge(nodes[a], nodes[b], ());
+        }
+    }
+    assert_eq!(minimum_feedback_vertex_set(&complete, |_| 1).len(), n - 2);
+    assert!(approximate_feedback_vertex_set(&complete, |_| 1.).len() <= 2 * (n - 2));
+}
+
+#[test]
+fn feedback_sets_with_loops() {
+    let graph = DiGraph::<(), ()>::from_edges(&[(0, 0), (0, 1), (1, 2)]);
+    let fas = minimum_feedback_arc_set(&graph, |_| 1);
+    assert_eq!(fas.len(), 1);
+    assert_eq!(fas[0].source(), fas[0].target());
+    assert_eq!(
+        minimum_feedback_vertex_set(&graph, |_| 1),
+        [NodeIndex::new(0)]
+    );
+
+    // Parallel edges form a cycle in undirected graphs.
+    let graph = UnGraph::<u32, ()>::from_edges(&[(0, 1), (1, 0), (1, 2), (2, 2)]);
+    let weight = |v: NodeIndex| if v.index() == 0 { 2 } else { 1 };
+    let fvs = minimum_feedback_vertex_set(&graph, weight);
+    assert_eq!(fvs, [NodeIndex::new(1), NodeIndex::new(2)]);
+    assert_eq!(
+        approximate_feedback_vertex_set(&graph, |v| weight(v) as f64),
+        fvs
+    );
+}
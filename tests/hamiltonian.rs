@@ -0,0 +1,148 @@
+use petgraph::algo::isomorphism::MatchResult;
+use petgraph::algo::{
+    hamiltonian_cycle, hamiltonian_cycle_bitmask, hamiltonian_cycle_cancellable, hamiltonian_path,
+    hamiltonian_path_bitmask, hamiltonian_path_cancellable,
+};
+use petgraph::prelude::*;
+use petgraph::EdgeType;
+
+/// Check that `order` contains every node once, with an edge from each node to the next.
+fn assert_valid<Ty: EdgeType>(graph: &Graph<(), (), Ty>, order: &[NodeIndex], cycle: bool) {
+    let mut sorted = order.to_vec();
+    sorted.sort();
+    assert_eq!(sorted, graph.node_indices().collect::<Vec<_>>());
+    for pair in order.windows(2) {
+        assert!(graph.contains_edge(pair[0], pair[1]));
+    }
+    if cycle && order.len() > 1 {
+        assert_eq!(order[0], NodeIndex::new(0));
+        assert!(graph.contains_edge(order[order.len() - 1], order[0]));
+    }
+}
+
+#[test]
+fn knights_tour() {
+    let size = 8;
+    let mut board = UnGraph::<(), ()>::with_capacity(size * size, 4 * size * size);
+    for _ in 0..size * size {
+        board.add_node(());
+    }
+    for i in 0..size as i32 {
+        for j in 0..size as i32 {
+            for &(di, dj) in &[(1, 2), (2, 1), (2, -1), (1, -2)] {
+                let (k, l) = (i + di, j + dj);
+                if k < size as i32 && l >= 0 && l < size as i32 {
+                    let a = NodeIndex::new(i as usize * size + j as usize);
+                    let b = NodeIndex::new(k as usize * size + l as usize);
+                    board.add_edge(a, b, ());
+                }
+            }
+        }
+    }
+    let tour = hamiltonian_cycle(&board).unwrap();
+    assert_valid(&board, &tour, true);
+    let path = hamiltonian_path(&board).unwrap();
+    assert_valid(&board, &path, false);
+}
+
+#[test]
+fn hamiltonian_cancellation() {
+    let petersen = UnGraph::<(), ()>::from_edges(&[
+        (0, 1),
+        (1, 2),
+        (2, 3),
+        (3, 4),
+        (4, 0),
+        (0, 5),
+        (1, 6),
+        (2, 7),
+        (3, 8),
+        (4, 9),
+        (5, 7),
+        (7, 9),
+        (9, 6),
+        (6, 8),
+        (8, 5),
+    ]);
+    assert_eq!(
+        hamiltonian_cycle_cancellable(&petersen, || true),
+        MatchResult::Unknown
+    );
+    assert_eq!(
+        hamiltonian_path_cancellable(&petersen, || true),
+        MatchResult::Unknown
+    );
+    let mut steps = 0;
+    let result = hamiltonian_cycle_cancellable(&petersen, || {
+        steps += 1;
+        steps > 10_000
+    });
+    assert_eq!(result, MatchResult::NoMatch);
+
+    // Two cliques sharing a node have a Hamiltonian path, but no cycle, which the search sees
+    // without taking a step.
+    let mut cliques = UnGraph::<(), ()>::default();
+    let nodes: Vec<_> = (0..15).map(|_| cliques.add_node(())).collect();
+    for a in 0..15 {
+        for b in a + 1..15 {
+            if (a < 8 && b < 8) || (a >= 7 && b >= 7) {
+                cliques.add_edge(nodes[a], nodes[b], ());
+            }
+        }
+    }
+    assert_eq!(
+        hamiltonian_cycle_cancellable(&cliques, || true),
+        MatchResult::NoMatch
+    );
+    match hamiltonian_path_cancellable(&cliques, || false) {
+        MatchResult::Match(path) => assert_eq!(path.len(), 15),
+        other => panic!("expected a path, found {:?}", other),
+    }
+}
+
+#[test]
+fn hamiltonian_skips_removed_nodes() {
+    let mut graph = StableUnGraph::<(), ()>::default();
+    let nodes: Vec<_> = (0..6).map(|_| graph.add_node(())).collect();
+    for i in 0..6 {
+        graph.add_edge(nodes[i], nodes[(i + 1) % 6], ());
+    }
+    graph.add_edge(nodes[1], nodes[3], ());
+    assert_eq!(hamiltonian_cycle(&graph).map(|c| c.len()), Some(6));
+
+    // Removing a node breaks the cycle, but the shortcut keeps one of five nodes.
+    graph.remove_node(nodes[2]);
+    let cycle = hamiltonian_cycle(&graph).unwrap();
+    assert_eq!(cycle[0], nodes[0]);
+    assert_eq!(cycle.len(), 5);
+    assert_eq!(hamiltonian_cycle_bitmask(&graph).map(|c| c.len()), Some(5));
+
+    graph.remove_node(nodes[3]);
+    assert_eq!(hamiltonian_cycle(&graph), None);
+    assert_eq!(
+        hamiltonian_path(&graph),
+        Some(vec![nodes[1], nodes[0], nodes[5], nodes[4]])
+    );
+}
+
+#[test]
+fn hamiltonian_of_graphs_with_few_nodes() {
+    let empty = UnGraph::<(), ()>::default();
+    assert_eq!(hamiltonian_path(&empty), Some(vec![]));
+    assert_eq!(hamiltonian_cycle_bitmask(&empty), Some(vec![]));
+
+    let single = DiGraph::<(), ()>::from_edges(&[(0, 0)]);
+    assert_eq!(hamiltonian_cycle(&single), Some(vec![0.into()]));
+    assert_eq!(hamiltonian_path_bitmask(&single), Some(vec![0.into()]));
+
+    let edge = UnGraph::<(), ()>::from_edges(&[(0, 1)]);
+    assert_eq!(hamiltonian_cycle(&edge), Some(vec![0.into(), 1.into()]));
+    let arc = DiGraph::<(), ()>::from_edges(&[(0, 1)]);
+    assert_eq!(hamiltonian_cycle(&arc), None);
+    assert_eq!(hamiltonian_cycle_bitmask(&arc), None);
+    assert_eq!(hamiltonian_path(&arc), Some(vec![0.into(), 1.into()]));
+
+    let two_sources = DiGraph::<(), ()>::from_edges(&[(0, 2), (1, 2)]);
+    assert_eq!(hamiltonian_path(&two_sources), None);
+    assert_eq!(hamiltonian_path_bitmask(&two_sources), None);
+}
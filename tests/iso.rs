@@ -8,8 +8,10 @@ use petgraph::graph::{edge_index, node_index};
 use petgraph::prelude::*;
 use petgraph::EdgeType;
 
+use petgraph::algo::isomorphism::{GraphMatcher, MatchMode, MatchResult, NoSemanticMatch};
 use petgraph::algo::{
-    is_isomorphic, is_isomorphic_matching, is_isomorphic_subgraph, subgraph_isomorphisms_iter,
+    is_isomorphic, is_isomorphic_matching, is_isomorphic_matching_cancellable,
+    is_isomorphic_matching_within_budget, is_isomorphic_subgraph, subgraph_isomorphisms_iter,
 };
 
 /// Petersen A and B are isomorphic
@@ -636,3 +638,398 @@ const COXETER_B: &str = "
  0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 1 0 0 0 1 0 0 1
  0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 1 0 1 0 0 0 0 0 0 0 0 1 0
 ";
+
+const MODES: [MatchMode; 3] = [
+    MatchMode::Isomorphism,
+    MatchMode::InducedSubgraph,
+    MatchMode::Monomorphism,
+];
+
+/// The mappings of `g0` into `g1` found by a structural `GraphMatcher`.
+fn structural_mappings<Ty: EdgeType>(
+    g0: &Graph<(), (), Ty>,
+    g1: &Graph<(), (), Ty>,
+    mode: MatchMode,
+) -> Vec<Vec<usize>> {
+    GraphMatcher::new(&g0, &g1, NoSemanticMatch, NoSemanticMatch, mode).collect()
+}
+
+/// The number of search steps to decide if there is a match.
+fn steps_to_decide<Ty: EdgeType>(
+    g0: &Graph<u8, (), Ty>,
+    g1: &Graph<u8, (), Ty>,
+    mode: MatchMode,
+    vf2pp: bool,
+) -> usize {
+    let node_match = |a: &u8, b: &u8| a == b;
+    let edge_match = |_: &(), _: &()| true;
+    let mut matcher = GraphMatcher::new(&g0, &g1, node_match, edge_match, mode);
+    if vf2pp {
+        matcher = matcher.with_vf2pp_ordering();
+    }
+    let mut steps = 0;
+    let result = matcher.next_cancellable(|| {
+        steps += 1;
+        false
+    });
+    assert_ne!(result, MatchResult::Unknown);
+    steps
+}
+
+/// All mappings of `g0` into `g1` of the given mode, trying every injective
+/// map of the nodes.
+#[cfg(feature = "generate")]
+fn brute_force_mappings<Ty: EdgeType>(
+    g0: &Graph<(), (), Ty>,
+    g1: &Graph<(), (), Ty>,
+    mode: MatchMode,
+) -> HashSet<Vec<usize>> {
+    let (n0, n1) = (g0.node_count(), g1.node_count());
+    let mut result = HashSet::new();
+    if mode == MatchMode::Isomorphism && n0 != n1 || n0 > n1 {
+        return result;
+    }
+    let has_edge = |g: &Graph<(), (), Ty>, a: usize, b: usize| {
+        g.find_edge(node_index(a), node_index(b)).is_some()
+    };
+    let mut mapping = vec![0; n0];
+    let mut stack = vec![0];
+    while let Some(&next) = stack.last() {
+        let depth = stack.len() - 1;
+        if depth == n0 {
+            let preserves_edges = (0..n0).all(|a| {
+                (0..n0).all(|b| {
+                    let e0 = has_edge(g0, a, b);
+                    let e1 = has_edge(g1, mapping[a], mapping[b]);
+                    match mode {
+                        MatchMode::Monomorphism => !e0 || e1,
+                        _ => e0 == e1,
+                    }
+                })
+            });
+            if preserves_edges {
+                result.insert(mapping.clone());
+            }
+            stack.pop();
+        } else if next == n1 {
+            stack.pop();
+        } else {
+            *stack.last_mut().unwrap() += 1;
+            if !mapping[..depth].contains(&next) {
+                mapping[depth] = next;
+                stack.push(0);
+            }
+        }
+    }
+    result
+}
+
+#[cfg(feature = "generate")]
+fn check_modes_against_brute_force<Ty: EdgeType>(sizes: &[(usize, usize)]) {
+    use petgraph::generate::Generator;
+
+    for &(size0, size1) in sizes {
+        let targets: Vec<_> = Generator::<Ty>::all(size1, true).collect();
+        for g0 in Generator::<Ty>::all(size0, true) {
+            for g1 in &targets {
+                for &mode in &MODES {
+                    let expected = brute_force_mappings(&g0, g1, mode);
+                    let mut found = HashSet::new();
+                    for mapping in structural_mappings(&g0, g1, mode) {
+                        assert!(found.insert(mapping), "{:?} {:?} {:?}", mode, g0, g1);
+                    }
+                    assert_eq!(found, expected, "{:?} {:?} {:?}", mode, g0, g1);
+                    let vf2pp: HashSet<_> =
+                        GraphMatcher::new(&&g0, &g1, NoSemanticMatch, NoSemanticMatch, mode)
+                            .with_vf2pp_ordering()
+                            .collect();
+                    assert_eq!(vf2pp, expected, "{:?} {:?} {:?}", mode, g0, g1);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(feature = "generate")]
+#[test]
+#[cfg_attr(miri, ignore = "Takes too long to run in Miri")]
+fn graph_matcher_modes_undirected() {
+    check_modes_against_brute_force::<Undirected>(&[(0, 2), (1, 3), (2, 3), (3, 3), (3, 4)]);
+}
+
+#[cfg(feature = "generate")]
+#[test]
+#[cfg_attr(miri, ignore = "Takes too long to run in Miri")]
+fn graph_matcher_modes_directed() {
+    check_modes_against_brute_force::<Directed>(&[(1, 2), (2, 2), (1, 3), (2, 3)]);
+}
+
+#[test]
+fn graph_matcher_monomorphism() {
+    // A 4-cycle is a subgraph of the complete graph on four nodes, but not an
+    // induced one.
+    let cycle = Graph::<(), (), Undirected>::from_edges(&[(0, 1), (1, 2), (2, 3), (3, 0)]);
+    let complete =
+        Graph::<(), (), Undirected>::from_edges(&[(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3)]);
+    let count = |mode| structural_mappings(&cycle, &complete, mode).len();
+    assert_eq!(count(MatchMode::Isomorphism), 0);
+    assert_eq!(count(MatchMode::InducedSubgraph), 0);
+    assert_eq!(count(MatchMode::Monomorphism), 24);
+    assert!(!is_isomorphic_subgraph(&cycle, &complete));
+
+    // Edge weights are only compared for the edges of `g0`.
+    let mut g0 = Graph::<u8, u8>::new();
+    let a = g0.add_node(0);
+    let b = g0.add_node(1);
+    g0.add_edge(a, b, 5);
+    let mut g1 = Graph::<u8, u8>::new();
+    let x = g1.add_node(1);
+    let y = g1.add_node(0);
+    g1.add_edge(y, x, 5);
+    g1.add_edge(x, y, 7);
+    let mut node_match = |x: &u8, y: &u8| x == y;
+    let mut edge_match = |x: &u8, y: &u8| x == y;
+    let (g0, g1) = (&g0, &g1);
+    let mut matcher = GraphMatcher::new(
+        &g0,
+        &g1,
+        &mut node_match,
+        &mut edge_match,
+        MatchMode::Monomorphism,
+    );
+    assert_eq!(matcher.mode(), MatchMode::Monomorphism);
+    assert_eq!(matcher.next(), Some(vec![1, 0]));
+    assert!(!matcher.is_match());
+    assert!(GraphMatcher::new(
+        &g0,
+        &g1,
+        &mut node_match,
+        &mut edge_match,
+        MatchMode::InducedSubgraph,
+    )
+    .next()
+    .is_none());
+}
+
+#[test]
+fn graph_matcher_empty_pattern() {
+    let empty = Graph::<(), ()>::new();
+    let g = Graph::<(), ()>::from_edges(&[(0, 1)]);
+    for &mode in &MODES[1..] {
+        assert_eq!(
+            structural_mappings(&empty, &g, mode),
+            vec![Vec::<usize>::new()]
+        );
+    }
+    assert!(structural_mappings(&empty, &g, MatchMode::Isomorphism).is_empty());
+    assert_eq!(
+        structural_mappings(&empty, &empty, MatchMode::Isomorphism),
+        vec![Vec::<usize>::new()]
+    );
+}
+
+#[test]
+fn graph_matcher_vf2pp_ordering_prunes_early() {
+    // A chain of carbon atoms with an oxygen at its far end, to be found in
+    // a larger chain with a single oxygen: VF2 starts from the carbon at
+    // index 0, VF2++ from the rare oxygen.
+    let chain = |carbons: usize| {
+        let mut g = Graph::<u8, (), Undirected>::default();
+        let mut prev = g.add_node(6);
+        for _ in 1..carbons {
+            let next = g.add_node(6);
+            g.add_edge(prev, next, ());
+            prev = next;
+        }
+        let oxygen = g.add_node(8);
+        g.add_edge(prev, oxygen, ());
+        g
+    };
+    let pattern = chain(8);
+    let mut target = chain(40);
+    // Close the first carbons into a ring, far away from the oxygen.
+    target.add_edge(node_index(0), node_index(31), ());
+    for &mode in &MODES[1..] {
+        let vf2 = steps_to_decide(&pattern, &target, mode, false);
+        let vf2pp = steps_to_decide(&pattern, &target, mode, true);
+        assert!(vf2pp * 4 < vf2, "{:?}: {} vs {}", mode, vf2pp, vf2);
+    }
+}
+
+#[test]
+fn graph_matcher_budget() {
+    let g0 = str_to_digraph(G8_1);
+    let g1 = str_to_digraph(G8_2);
+    let (g0, g1) = (&g0, &g1);
+    let mut matcher = GraphMatcher::new(
+        &g0,
+        &g1,
+        NoSemanticMatch,
+        NoSemanticMatch,
+        MatchMode::Isomorphism,
+    );
+    assert_eq!(matcher.next_within_budget(0), MatchResult::Unknown);
+    assert_eq!(matcher.next_within_budget(10), MatchResult::Unknown);
+    let mut calls = 0;
+    loop {
+        calls += 1;
+        match matcher.next_within_budget(10) {
+            MatchResult::Unknown => continue,
+            result => {
+                assert_eq!(result, MatchResult::NoMatch);
+                break;
+            }
+        }
+    }
+    assert!(calls > 1);
+    assert_eq!(matcher.next_within_budget(10), MatchResult::NoMatch);
+
+    // Resuming a budgeted search finds every mapping exactly once.
+    let g0 = str_to_digraph(COXETER_A);
+    let g1 = str_to_digraph(COXETER_B);
+    let (g0, g1) = (&g0, &g1);
+    let expected: Vec<_> = GraphMatcher::new(
+        &g0,
+        &g1,
+        NoSemanticMatch,
+        NoSemanticMatch,
+        MatchMode::Isomorphism,
+    )
+    .collect();
+    assert!(!expected.is_empty());
+    let mut matcher = GraphMatcher::new(
+        &g0,
+        &g1,
+        NoSemanticMatch,
+        NoSemanticMatch,
+        MatchMode::Isomorphism,
+    );
+    let mut found = Vec::new();
+    let mut unknown = 0;
+    loop {
+        match matcher.next_within_budget(7) {
+            MatchResult::Match(mapping) => found.push(mapping),
+            MatchResult::NoMatch => break,
+            MatchResult::Unknown => unknown += 1,
+        }
+    }
+    assert!(unknown > 0);
+    assert_eq!(found, expected);
+}
+
+#[test]
+fn graph_matcher_cancellation() {
+    let g0 = str_to_graph(PETERSEN_A);
+    let g1 = str_to_graph(PETERSEN_B);
+    let (g0, g1) = (&g0, &g1);
+    let mut matcher = GraphMatcher::new(
+        &g0,
+        &g1,
+        NoSemanticMatch,
+        NoSemanticMatch,
+        MatchMode::Isomorphism,
+    )
+    .with_vf2pp_ordering();
+    assert_eq!(matcher.next_cancellable(|| true), MatchResult::Unknown);
+    let mut calls = 0;
+    let result = matcher.next_cancellable(|| {
+        calls += 1;
+        calls > 3
+    });
+    assert_eq!((result, calls), (MatchResult::Unknown, 4));
+    match matcher.next_cancellable(|| false) {
+        MatchResult::Match(mapping) => assert_eq!(mapping.len(), 10),
+        result => panic!("{:?}", result),
+    }
+    assert!(matcher.is_match());
+}
+
+#[test]
+#[should_panic]
+fn graph_matcher_vf2pp_ordering_after_start() {
+    let g = Graph::<(), ()>::from_edges(&[(0, 1), (1, 2)]);
+    let g = &g;
+    let mut matcher = GraphMatcher::new(
+        &g,
+        &g,
+        NoSemanticMatch,
+        NoSemanticMatch,
+        MatchMode::Isomorphism,
+    );
+    assert!(matcher.is_match());
+    let _ = matcher.with_vf2pp_ordering();
+}
+
+#[test]
+fn is_isomorphic_matching_within_budget_decides() {
+    let g0 = str_to_graph(PETERSEN_A);
+    let g1 = str_to_graph(PETERSEN_B);
+    let always = |_: &(), _: &()| true;
+    assert_eq!(
+        is_isomorphic_matching_within_budget(&g0, &g1, always, always, 0),
+        MatchResult::Unknown
+    );
+    match is_isomorphic_matching_within_budget(&g0, &g1, always, always, 10_000) {
+        MatchResult::Match(mapping) => {
+            for edge in g0.edge_references() {
+                let a = node_index(mapping[edge.source().index()]);
+                let b = node_index(mapping[edge.target().index()]);
+                assert!(g1.contains_edge(a, b));
+            }
+        }
+        result => panic!("{:?}", result),
+    }
+
+    let g0 = str_to_digraph(G8_1);
+    let g1 = str_to_digraph(G8_2);
+    assert_eq!(
+        is_isomorphic_matching_within_budget(&g0, &g1, always, always, 5),
+        MatchResult::Unknown
+    );
+    assert_eq!(
+        is_isomorphic_matching_within_budget(&g0, &g1, always, always, 1_000_000),
+        MatchResult::NoMatch
+    );
+
+    // Different sizes are rejected without a search.
+    let g1 = str_to_digraph(G3_1);
+    assert_eq!(
+        is_isomorphic_matching_within_budget(&g0, &g1, always, always, 0),
+        MatchResult::NoMatch
+    );
+}
+
+#[test]
+fn is_isomorphic_matching_cancellable_uses_weights() {
+    let path = |labels: [u8; 3], weights: [u8; 2]| {
+        let mut g = UnGraph::<u8, u8>::default();
+        let n: Vec<_> = labels.iter().map(|&l| g.add_node(l)).collect();
+        g.add_edge(n[0], n[1], weights[0]);
+        g.add_edge(n[1], n[2], weights[1]);
+        g
+    };
+    let g0 = path([1, 2, 3], [10, 20]);
+    let g1 = path([3, 2, 1], [20, 10]);
+    let g2 = path([3, 2, 1], [10, 20]);
+
+    let mut calls = 0;
+    let result = is_isomorphic_matching_cancellable(&g0, &g1, u8::eq, u8::eq, || {
+        calls += 1;
+        false
+    });
+    assert_eq!(result, MatchResult::Match(vec![2, 1, 0]));
+    assert!(calls > 0);
+
+    assert_eq!(
+        is_isomorphic_matching_cancellable(&g0, &g2, u8::eq, u8::eq, || false),
+        MatchResult::NoMatch
+    );
+    assert_eq!(
+        is_isomorphic_matching_cancellable(&g0, &g2, |_, _| true, u8::eq, || false),
+        MatchResult::Match(vec![0, 1, 2])
+    );
+    assert_eq!(
+        is_isomorphic_matching_cancellable(&g0, &g1, u8::eq, u8::eq, || true),
+        MatchResult::Unknown
+    );
+}
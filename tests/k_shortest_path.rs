@@ -1,4 +1,4 @@
-use petgraph::algo::k_shortest_path;
+use petgraph::algo::{k_shortest_path, k_shortest_simple_paths};
 use petgraph::prelude::*;
 use petgraph::Graph;
 use std::collections::HashMap;
@@ -61,3 +61,36 @@ fn second_shortest_path() {
 
     assert_eq!(res, expected_res);
 }
+
+#[test]
+fn k_shortest_simple_paths_parallel_edges() {
+    let mut graph: Graph<(), u32, Directed> = Graph::new();
+    let a = graph.add_node(());
+    let b = graph.add_node(());
+    let c = graph.add_node(());
+    let ab1 = graph.add_edge(a, b, 1);
+    let ab2 = graph.add_edge(a, b, 2);
+    let bc = graph.add_edge(b, c, 1);
+    let ac = graph.add_edge(a, c, 5);
+    graph.add_edge(c, a, 1);
+
+    let paths: Vec<_> = k_shortest_simple_paths(&graph, a, c, |e| *e.weight()).collect();
+    assert_eq!(
+        paths,
+        vec![
+            (2, vec![a, b, c], vec![ab1, bc]),
+            (3, vec![a, b, c], vec![ab2, bc]),
+            (5, vec![a, c], vec![ac]),
+        ]
+    );
+
+    assert_eq!(
+        k_shortest_simple_paths(&graph, a, a, |e| *e.weight()).collect::<Vec<_>>(),
+        vec![(0, vec![a], vec![])]
+    );
+    let d = graph.add_node(());
+    assert_eq!(
+        k_shortest_simple_paths(&graph, a, d, |e| *e.weight()).count(),
+        0
+    );
+}
@@ -68,6 +68,15 @@ fn greedy_odd_path() {
     assert_one_of!(collect(m.nodes()), [set![0, 1, 2, 3], set![1, 2]]);
 }
 
+#[test]
+fn greedy_long_path() {
+    let n = 1_000_000;
+    let edges: Vec<_> = (1..n).map(|v| (v - 1, v)).collect();
+    let g: UnGraph<(), ()> = UnGraph::from_edges(&edges);
+    let m = greedy_matching(&g);
+    assert_eq!(m.len(), n as usize / 2);
+}
+
 #[test]
 fn greedy_star() {
     let g: UnGraph<(), ()> = UnGraph::from_edges(&[(0, 1), (0, 2), (0, 3)]);
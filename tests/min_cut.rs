@@ -0,0 +1,57 @@
+use petgraph::algo::{gomory_hu_tree, stoer_wagner_min_cut};
+use petgraph::prelude::*;
+
+#[test]
+fn stoer_wagner_small() {
+    let graph = UnGraph::<(), u32>::new_undirected();
+    assert_eq!(stoer_wagner_min_cut(&graph, |e| *e.weight()), None);
+
+    let mut graph = UnGraph::<(), u32>::new_undirected();
+    let a = graph.add_node(());
+    let b = graph.add_node(());
+    let c = graph.add_node(());
+    graph.add_edge(a, b, 2);
+    graph.add_edge(b, a, 3);
+    graph.add_edge(c, c, 1);
+    assert_eq!(
+        stoer_wagner_min_cut(&graph, |e| *e.weight()),
+        Some((0, vec![c]))
+    );
+}
+
+#[test]
+fn gomory_hu_float_capacities() {
+    let graph = UnGraph::<(), f64>::from_edges(&[(0, 1, 0.5), (1, 2, 1.5), (0, 2, 0.25)]);
+    let tree = gomory_hu_tree(&graph, |e| *e.weight());
+    assert_eq!(
+        tree.min_cut_value(NodeIndex::new(0), NodeIndex::new(2)),
+        Some(0.75)
+    );
+    assert_eq!(tree.edge_connectivity(), Some(0.75));
+}
+
+#[test]
+fn min_cut_of_two_triangles() {
+    // Two triangles of heavy edges, joined by a light bridge between nodes 2 and 3.
+    let graph = UnGraph::<(), u32>::from_edges(&[
+        (0, 1, 3),
+        (1, 2, 3),
+        (2, 0, 3),
+        (3, 4, 3),
+        (4, 5, 3),
+        (5, 3, 3),
+        (2, 3, 1),
+    ]);
+    let (value, mut side) = stoer_wagner_min_cut(&graph, |e| *e.weight()).unwrap();
+    assert_eq!(value, 1);
+    side.sort();
+    let n = NodeIndex::new;
+    assert!(side == [n(0), n(1), n(2)] || side == [n(3), n(4), n(5)]);
+
+    let tree = gomory_hu_tree(&graph, |e| *e.weight());
+    assert_eq!(tree.edge_connectivity(), Some(1));
+    assert_eq!(tree.min_cut_value(n(0), n(5)), Some(1));
+    // Within a triangle, node 0 can be cut off by its two edges.
+    assert_eq!(tree.min_cut_value(n(0), n(1)), Some(6));
+    assert_eq!(tree.min_cut_value(n(2), n(1)), Some(6));
+}
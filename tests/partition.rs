@@ -0,0 +1,200 @@
+use petgraph::algo::{fiduccia_mattheyses, kernighan_lin, multilevel_partition};
+use petgraph::csr::Csr;
+use petgraph::prelude::*;
+use rand::{ChaChaRng, Rng, SeedableRng};
+
+fn grid(size: usize) -> UnGraph<(), ()> {
+    let mut edges = Vec::new();
+    for i in 0..size {
+        for j in 0..size {
+            let v = (i * size + j) as u32;
+            if i + 1 < size {
+                edges.push((v, v + size as u32));
+            }
+            if j + 1 < size {
+                edges.push((v, v + 1));
+            }
+        }
+    }
+    UnGraph::from_edges(&edges)
+}
+
+fn cut(graph: &UnGraph<(), u32>, parts: &[usize]) -> u32 {
+    graph
+        .edge_references()
+        .filter(|e| parts[e.source().index()] != parts[e.target().index()])
+        .map(|e| *e.weight())
+        .sum()
+}
+
+#[test]
+fn kernighan_lin_separates_cliques() {
+    // Two heavy triangles, joined by a light edge, start out split down the middle.
+    let graph = UnGraph::<(), u32>::from_edges(&[
+        (0, 1, 5),
+        (1, 2, 5),
+        (2, 0, 5),
+        (3, 4, 5),
+        (4, 5, 5),
+        (5, 3, 5),
+        (2, 3, 1),
+    ]);
+    let initial = [0, 1, 0, 1, 0, 1];
+    let (value, parts) = kernighan_lin(&graph, Some(&initial), |e| *e.weight());
+    assert_eq!(value, 1);
+    assert_eq!(value, cut(&graph, &parts));
+    assert!(parts[..3].iter().all(|&p| p == parts[0]));
+    assert!(parts[3..].iter().all(|&p| p == 1 - parts[0]));
+}
+
+#[test]
+fn fiduccia_mattheyses_rebalances() {
+    // Everything starts in part 0, and has to move until the parts balance.
+    let graph = grid(6);
+    let (value, parts) = fiduccia_mattheyses(&graph, Some(&[0; 36]), 0., |_| 1, |_| 1);
+    assert_eq!(parts.iter().filter(|&&p| p == 0).count(), 18);
+    assert!((6..=10).contains(&value));
+}
+
+#[test]
+fn multilevel_partition_grids() {
+    for &(size, k) in &[(32, 2), (32, 4), (30, 3), (24, 8)] {
+        let graph = grid(size);
+        let n = size * size;
+        let (value, parts) = multilevel_partition(&graph, k, 0.05, |_| 1usize, |_| 1);
+        let limit = (1.05 * n as f64 / k as f64).ceil() as usize + 1;
+        for part in 0..k {
+            let count = parts.iter().filter(|&&p| p == part).count();
+            assert!(
+                count > 0 && count <= limit,
+                "part {} has {} nodes",
+                part,
+                count
+            );
+        }
+        let crossing = graph
+            .edge_references()
+            .filter(|e| parts[e.source().index()] != parts[e.target().index()])
+            .count();
+        assert_eq!(value, crossing);
+        // Cutting a grid into strips is not optimal, but is good enough.
+        assert!(
+            value <= (k - 1) * size * 3 / 2,
+            "cut {} for k = {}",
+            value,
+            k
+        );
+    }
+}
+
+#[test]
+fn multilevel_partition_planted_communities() {
+    let mut rng = ChaChaRng::from_seed([113; 32]);
+    let (k, size) = (6, 60);
+    let n = k * size;
+    let mut edges = Vec::new();
+    let mut planted = 0.;
+    for a in 0..n {
+        for b in a + 1..n {
+            let same = a / size == b / size;
+            if rng.gen::<f64>() < if same { 0.2 } else { 0.005 } {
+                let weight = rng.gen_range(0.5, 1.5);
+                edges.push((a as u32, b as u32, weight));
+                if !same {
+                    planted += weight;
+                }
+            }
+        }
+    }
+    edges.sort_by_key(|&(a, b, _)| (a, b));
+    let mut graph = Csr::<(), f64>::with_nodes(n);
+    for &(a, b, weight) in &edges {
+        graph.add_edge(a, b, weight);
+    }
+
+    let (value, parts) = multilevel_partition(&graph, k, 0.03, |e| *e.weight(), |_| 1);
+    assert!(value <= planted * 1.01, "cut {} above {}", value, planted);
+    for part in 0..k {
+        assert!(parts.iter().filter(|&&p| p == part).count() <= 62);
+    }
+}
+
+#[test]
+fn multilevel_partition_weighted_nodes() {
+    // A grid with unit edge weights, whose nodes weigh from 1 to 19.
+    let size = 20;
+    let graph = grid(size).map(|_, _| (), |_, _| 1u32);
+    let n = size * size;
+    let weights: Vec<usize> = (0..n).map(|v| v * 7 % 19 + 1).collect();
+    let total: usize = weights.iter().sum();
+    let k = 5;
+    let (value, parts) =
+        multilevel_partition(&graph, k, 0.1, |e| *e.weight(), |v| weights[v.index()]);
+    assert_eq!(value, cut(&graph, &parts));
+    for part in 0..k {
+        let weight: usize = (0..n)
+            .filter(|&v| parts[v] == part)
+            .map(|v| weights[v])
+            .sum();
+        assert!(weight <= total * 11 / 10 / k + 20);
+    }
+    let (all, _) = kernighan_lin(&graph, None, |e| *e.weight());
+    assert!(value > all);
+}
+
+#[test]
+fn multilevel_partition_large() {
+    let graph = grid(150);
+    let (value, parts) = multilevel_partition(&graph, 16, 0.03, |_| 1usize, |_| 1);
+    assert!(parts.iter().all(|&p| p < 16));
+    assert!(value < 3000);
+}
+
+#[test]
+fn partition_of_empty_graph_and_pair() {
+    let empty = UnGraph::<(), u32>::default();
+    assert_eq!(kernighan_lin(&empty, None, |e| *e.weight()), (0, vec![]));
+    assert_eq!(
+        fiduccia_mattheyses(&empty, None, 0., |e| *e.weight(), |_| 1),
+        (0, vec![])
+    );
+    assert_eq!(
+        multilevel_partition(&empty, 3, 0., |e| *e.weight(), |_| 1),
+        (0, vec![])
+    );
+
+    let pair = UnGraph::<(), u32>::from_edges(&[(0, 1, 7)]);
+    assert_eq!(
+        multilevel_partition(&pair, 1, 0., |e| *e.weight(), |_| 1),
+        (0, vec![0, 0])
+    );
+    let (value, mut parts) = multilevel_partition(&pair, 4, 0., |e| *e.weight(), |_| 1);
+    assert_eq!(value, 7);
+    parts.sort();
+    parts.dedup();
+    assert_eq!(parts.len(), 2);
+}
+
+#[test]
+fn partition_skips_removed_nodes() {
+    // Removed nodes have no part, and self loops and parallel edges are handled.
+    let mut graph = StableUnGraph::<(), u32>::default();
+    let nodes: Vec<_> = (0..6).map(|_| graph.add_node(())).collect();
+    for i in 0..6 {
+        graph.add_edge(nodes[i], nodes[(i + 1) % 6], 1);
+    }
+    graph.add_edge(nodes[0], nodes[1], 5);
+    graph.add_edge(nodes[3], nodes[3], 9);
+    graph.remove_node(nodes[4]);
+    let (value, parts) = fiduccia_mattheyses(&graph, None, 0.2, |e| *e.weight(), |_| 1);
+    assert_eq!(parts[4], std::usize::MAX);
+    assert_eq!(value, 1);
+    assert_eq!(parts[0], parts[1]);
+}
+
+#[test]
+#[should_panic]
+fn partition_into_no_parts() {
+    let graph = grid(2);
+    multilevel_partition(&graph, 0, 0., |_| 1, |_| 1);
+}
@@ -0,0 +1,89 @@
+use petgraph::algo::{
+    has_path_connecting, maximum_antichain, minimum_chain_cover, minimum_path_cover,
+};
+use petgraph::prelude::*;
+
+#[test]
+fn chains_skip_over_shared_nodes() {
+    // Two sources feed a hub that leads to two sinks. Paths can't share the hub, chains can pass
+    // through it without covering it.
+    let graph = DiGraph::<(), ()>::from_edges(&[(0, 2), (1, 2), (2, 3), (2, 4)]);
+    let n = NodeIndex::new;
+    let paths = minimum_path_cover(&graph).unwrap();
+    assert_eq!(paths.len(), 3);
+    for path in &paths {
+        for pair in path.windows(2) {
+            assert!(graph.contains_edge(pair[0], pair[1]));
+        }
+    }
+
+    let chains = minimum_chain_cover(&graph).unwrap();
+    assert_eq!(chains.len(), 2);
+    assert!(chains.iter().all(|chain| chain.len() >= 2));
+    assert!(chains.iter().any(|chain| chain.contains(&n(2))));
+
+    let mut antichain = maximum_antichain(&graph).unwrap();
+    antichain.sort();
+    assert!(antichain == [n(0), n(1)] || antichain == [n(3), n(4)]);
+}
+
+#[test]
+fn path_cover_of_grid() {
+    // A grid, ordered left to right and top to bottom, has a diagonal as its largest antichain.
+    let size = 12;
+    let mut grid = DiGraph::<(), ()>::default();
+    for _ in 0..size * size {
+        grid.add_node(());
+    }
+    let n = |i: usize, j: usize| NodeIndex::new(i * size + j);
+    for i in 0..size {
+        for j in 0..size {
+            if i + 1 < size {
+                grid.add_edge(n(i, j), n(i + 1, j), ());
+            }
+            if j + 1 < size {
+                grid.add_edge(n(i, j), n(i, j + 1), ());
+            }
+        }
+    }
+    assert_eq!(minimum_path_cover(&grid).unwrap().len(), size);
+    assert_eq!(minimum_chain_cover(&grid).unwrap().len(), size);
+    let antichain = maximum_antichain(&grid).unwrap();
+    assert_eq!(antichain.len(), size);
+    for &a in &antichain {
+        for &b in &antichain {
+            assert!(a == b || !has_path_connecting(&grid, a, b, None));
+        }
+    }
+}
+
+#[test]
+fn path_cover_skips_removed_nodes() {
+    let mut graph = StableDiGraph::<(), ()>::default();
+    let nodes: Vec<_> = (0..5).map(|_| graph.add_node(())).collect();
+    for i in 0..4 {
+        graph.add_edge(nodes[i], nodes[i + 1], ());
+    }
+    assert_eq!(minimum_path_cover(&graph).unwrap(), [&nodes[..]]);
+
+    // Both halves start at a source, so either may come first.
+    graph.remove_node(nodes[2]);
+    let mut paths = minimum_path_cover(&graph).unwrap();
+    paths.sort();
+    assert_eq!(paths, [vec![nodes[0], nodes[1]], vec![nodes[3], nodes[4]]]);
+    assert_eq!(minimum_chain_cover(&graph).unwrap().len(), 2);
+    assert_eq!(maximum_antichain(&graph).unwrap().len(), 2);
+
+    graph.add_edge(nodes[4], nodes[3], ());
+    assert!(minimum_path_cover(&graph).is_err());
+    assert!(minimum_chain_cover(&graph).is_err());
+    assert!(maximum_antichain(&graph).is_err());
+}
+
+#[test]
+fn path_cover_of_empty_graph() {
+    let empty = DiGraph::<(), ()>::new();
+    assert!(minimum_path_cover(&empty).unwrap().is_empty());
+    assert!(minimum_chain_cover(&empty).unwrap().is_empty());
+    assert!(maximum_antichain(&empty).unwrap().is_empty());
+}
@@ -0,0 +1,174 @@
+use std::collections::HashSet;
+
+use petgraph::algo::{connected_components, is_planar, planar_embedding};
+use petgraph::prelude::*;
+
+fn simple_edges(graph: &UnGraph<(), ()>) -> HashSet<(NodeIndex, NodeIndex)> {
+    graph
+        .edge_references()
+        .filter(|e| e.source() != e.target())
+        .flat_map(|e| vec![(e.source(), e.target()), (e.target(), e.source())])
+        .collect()
+}
+
+/// Check the embedding against Euler's formula, which only holds for planar
+/// embeddings.
+fn assert_planar_embedding(graph: &UnGraph<(), ()>) {
+    let embedding = planar_embedding(graph).unwrap();
+    let edges = simple_edges(graph);
+    let mut half_edges = HashSet::new();
+    for v in graph.node_indices() {
+        for &w in embedding.neighbors_cw(v) {
+            assert!(half_edges.insert((v, w)));
+        }
+    }
+    assert_eq!(half_edges, edges);
+
+    let mut non_isolated = graph.clone();
+    non_isolated.retain_nodes(|g, v| g.neighbors(v).any(|w| w != v));
+    let components = connected_components(&non_isolated);
+    let faces = embedding.faces();
+    assert_eq!(
+        faces.len() + non_isolated.node_count(),
+        edges.len() / 2 + 2 * components
+    );
+    assert_eq!(faces.iter().map(|f| f.len()).sum::<usize>(), edges.len());
+}
+
+/// Check that the subgraph is non-planar and minimal, which makes it a
+/// subdivision of K5 or K3,3.
+fn assert_kuratowski_subgraph(graph: &UnGraph<(), ()>) {
+    let kuratowski = planar_embedding(graph).unwrap_err();
+    let edges = simple_edges(graph);
+    let witness = kuratowski.edges();
+    for &(a, b) in witness {
+        assert!(edges.contains(&(a, b)));
+    }
+    let subgraph = |skip: Option<usize>| {
+        let mut g = UnGraph::<(), ()>::with_capacity(graph.node_count(), witness.len());
+        for _ in graph.node_indices() {
+            g.add_node(());
+        }
+        for (i, &(a, b)) in witness.iter().enumerate() {
+            if Some(i) != skip {
+                g.add_edge(a, b, ());
+            }
+        }
+        g
+    };
+    assert!(!is_planar(&subgraph(None)));
+    for i in 0..witness.len() {
+        assert!(is_planar(&subgraph(Some(i))));
+    }
+
+    let witness_graph = subgraph(None);
+    let degrees: Vec<_> = witness_graph
+        .node_indices()
+        .map(|v| witness_graph.neighbors(v).count())
+        .filter(|&d| d > 0)
+        .collect();
+    let branch: Vec<_> = degrees.iter().filter(|&&d| d != 2).collect();
+    assert!(
+        branch.len() == 5 && branch.iter().all(|&&d| d == 4)
+            || branch.len() == 6 && branch.iter().all(|&&d| d == 3),
+        "{:?}",
+        degrees
+    );
+}
+
+#[test]
+fn complete_graphs() {
+    for n in 0..8 {
+        let mut graph = UnGraph::<(), ()>::new_undirected();
+        let nodes: Vec<_> = (0..n).map(|_| graph.add_node(())).collect();
+        for (i, &a) in nodes.iter().enumerate() {
+            for &b in &nodes[i + 1..] {
+                graph.add_edge(a, b, ());
+            }
+        }
+        assert_eq!(is_planar(&graph), n <= 4);
+        if n <= 4 {
+            assert_planar_embedding(&graph);
+        } else {
+            assert_kuratowski_subgraph(&graph);
+        }
+    }
+}
+
+#[test]
+fn petersen_graph() {
+    let graph = UnGraph::<(), ()>::from_edges(&[
+        (0, 1),
+        (1, 2),
+        (2, 3),
+        (3, 4),
+        (4, 0),
+        (0, 5),
+        (1, 6),
+        (2, 7),
+        (3, 8),
+        (4, 9),
+        (5, 7),
+        (7, 9),
+        (9, 6),
+        (6, 8),
+        (8, 5),
+    ]);
+    assert!(!is_planar(&graph));
+    assert_kuratowski_subgraph(&graph);
+}
+
+#[test]
+fn self_loops_and_parallel_edges() {
+    let graph = UnGraph::<(), ()>::from_edges(&[(0, 0), (0, 1), (1, 0), (1, 2), (2, 0), (2, 2)]);
+    assert!(is_planar(&graph));
+    let embedding = planar_embedding(&graph).unwrap();
+    assert_eq!(embedding.neighbors_cw(0.into()).len(), 2);
+    assert_eq!(embedding.faces().len(), 2);
+}
+
+#[test]
+fn stable_graph_with_holes() {
+    let mut graph = StableUnGraph::<(), ()>::default();
+    let nodes: Vec<_> = (0..6).map(|_| graph.add_node(())).collect();
+    for (i, &a) in nodes.iter().enumerate() {
+        for &b in &nodes[i + 1..] {
+            graph.add_edge(a, b, ());
+        }
+    }
+    assert!(!is_planar(&graph));
+    graph.remove_node(nodes[1]);
+    assert!(!is_planar(&graph));
+    graph.remove_node(nodes[3]);
+    let embedding = planar_embedding(&graph).unwrap();
+    assert_eq!(embedding.neighbors_cw(nodes[0]).len(), 3);
+    assert!(embedding.neighbors_cw(nodes[1]).is_empty());
+    assert_eq!(embedding.faces().len(), 4);
+}
+
+#[test]
+fn large_grid() {
+    let size = 200;
+    let mut graph = UnGraph::<(), ()>::with_capacity(size * size, 3 * size * size);
+    for _ in 0..size * size {
+        graph.add_node(());
+    }
+    let n = |i: usize, j: usize| NodeIndex::new(i * size + j);
+    for i in 0..size {
+        for j in 0..size {
+            if i + 1 < size {
+                graph.add_edge(n(i, j), n(i + 1, j), ());
+            }
+            if j + 1 < size {
+                graph.add_edge(n(i, j), n(i, j + 1), ());
+            }
+            if i + 1 < size && j + 1 < size {
+                graph.add_edge(n(i, j), n(i + 1, j + 1), ());
+            }
+        }
+    }
+    assert_planar_embedding(&graph);
+    graph.add_edge(n(0, 0), n(size - 1, size - 1), ());
+    graph.add_edge(n(0, size - 1), n(size - 1, 0), ());
+    assert!(!is_planar(&graph));
+}
@@ -11,10 +11,10 @@ extern crate odds;
 
 mod utils;
 
-use utils::{Small, Tournament};
+use utils::{Small, Tiny, Tournament};
 
 use odds::prelude::*;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::hash::Hash;
 
 use itertools::assert_equal;
@@ -22,11 +22,28 @@ use itertools::cloned;
 use quickcheck::{Arbitrary, Gen};
 use rand::Rng;
 
+use petgraph::acyclic::Acyclic;
+use petgraph::algo::isomorphism::{GraphMatcher, MatchMode};
 use petgraph::algo::{
-    bellman_ford, condensation, dijkstra, find_negative_cycle, floyd_warshall,
-    greedy_feedback_arc_set, greedy_matching, is_cyclic_directed, is_cyclic_undirected,
-    is_isomorphic, is_isomorphic_matching, k_shortest_path, kosaraju_scc, maximum_matching,
-    min_spanning_tree, tarjan_scc, toposort, Matching,
+    algebraic_connectivity, all_shortest_paths, all_simple_paths, approximate_feedback_vertex_set,
+    approximate_graph_edit_distance, approximate_vertex_cover, bellman_ford,
+    bipartite_vertex_cover, bipartition, canonical_labeling, center, chordal_completion,
+    christofides, condensation, connected_components, diameter, dijkstra, eccentricities,
+    eccentricity, edge_betweenness, edge_connectivity, fiduccia_mattheyses, fiedler_vector,
+    find_negative_cycle, floyd_warshall, girvan_newman, gomory_hu_tree, graph_edit_distance,
+    graph_edit_distance_within_budget, greedy_dominating_set, greedy_feedback_arc_set,
+    greedy_independent_set, greedy_matching, hamiltonian_cycle, hamiltonian_cycle_bitmask,
+    hamiltonian_path, hamiltonian_path_bitmask, held_karp, is_chordal, is_cyclic_directed,
+    is_cyclic_undirected, is_isomorphic, is_isomorphic_matching, is_planar,
+    k_edge_connected_subgraphs, k_shortest_path, k_shortest_simple_paths, kernighan_lin,
+    kosaraju_scc, maximum_antichain, maximum_independent_set, maximum_matching,
+    metric_closure_steiner_tree, min_spanning_tree, minimum_chain_cover, minimum_feedback_arc_set,
+    minimum_feedback_vertex_set, minimum_path_cover, nearest_neighbor_tour, node_connectivity,
+    or_opt, periphery, planar_embedding, radius, shortest_path_counts, spectral_bisection,
+    steiner_tree, stoer_wagner_min_cut, tarjan_scc, toposort, treewidth, two_opt, two_sat,
+    weighted_center, weighted_diameter, weighted_eccentricities, weighted_eccentricity,
+    weighted_periphery, weighted_radius, weisfeiler_lehman_hash, EliminationHeuristic, Literal,
+    Matching,
 };
 use petgraph::data::FromElements;
 use petgraph::dot::{Config, Dot};
@@ -36,7 +53,8 @@ use petgraph::operator::complement;
 use petgraph::prelude::*;
 use petgraph::visit::{
     EdgeFiltered, EdgeRef, IntoEdgeReferences, IntoEdges, IntoNeighbors, IntoNodeIdentifiers,
-    IntoNodeReferences, NodeCount, NodeIndexable, Reversed, Topo, VisitMap, Visitable,
+    IntoNodeReferences, LexBfs, MaxCardinalitySearch, NodeCount, NodeIndexable, NodeRef, Reversed,
+    Topo, VisitMap, Visitable, Walker,
 };
 use petgraph::EdgeType;
 
@@ -330,6 +348,67 @@ fn isomorphism_modify() {
     quickcheck::quickcheck(prop::<Directed> as fn(_, _, _) -> bool);
 }
 
+#[test]
+fn isomorphism_vf2pp_ordering() {
+    // The pattern is either the arbitrary graph `h` or a shuffled subgraph of `g`, on the nodes in
+    // `keep` and without the edges in `drop`, so that matches are likely. It has at most five
+    // nodes, which bounds the number of monomorphisms.
+    fn prop<Ty: EdgeType>(
+        g: Tiny<Graph<u8, (), Ty>>,
+        h: Tiny<Graph<u8, (), Ty>>,
+        labels: u8,
+        subgraph: bool,
+        keep: u16,
+        drop: u32,
+    ) -> bool {
+        let labels = labels % 3 + 1;
+        let g = g.map(|_, &w| w % labels, |_, _| ());
+        let pattern = if subgraph {
+            let mut map: Vec<_> = g
+                .node_indices()
+                .filter(|v| keep & 1 << v.index() != 0)
+                .take(5)
+                .collect();
+            rand::thread_rng().shuffle(&mut map);
+            let mut pattern = Graph::<u8, (), Ty>::with_capacity(map.len(), 0);
+            for &v in &map {
+                pattern.add_node(g[v]);
+            }
+            for (i, &a) in map.iter().enumerate() {
+                for (j, &b) in map.iter().enumerate() {
+                    if g.find_edge(a, b).is_some()
+                        && (g.is_directed() || i <= j)
+                        && drop & 1 << (pattern.edge_count() % 32) == 0
+                    {
+                        pattern.add_edge(node_index(i), node_index(j), ());
+                    }
+                }
+            }
+            pattern
+        } else {
+            let mut h = h.map(|_, &w| w % labels, |_, _| ());
+            h.retain_nodes(|_, v| v.index() < 5);
+            h
+        };
+        let (g0, g1) = (&pattern, &g);
+        [
+            MatchMode::Isomorphism,
+            MatchMode::InducedSubgraph,
+            MatchMode::Monomorphism,
+        ]
+        .iter()
+        .all(|&mode| {
+            let matcher =
+                || GraphMatcher::new(&g0, &g1, PartialEq::eq, |_: &(), _: &()| true, mode);
+            let vf2: HashSet<Vec<usize>> = matcher().collect();
+            let vf2pp: Vec<Vec<usize>> = matcher().with_vf2pp_ordering().collect();
+            vf2pp.len() == vf2.len() && vf2pp.into_iter().collect::<HashSet<_>>() == vf2
+        })
+    }
+    quickcheck::quickcheck(prop::<Undirected> as fn(_, _, _, _, _, _) -> bool);
+    quickcheck::quickcheck(prop::<Directed> as fn(_, _, _, _, _, _) -> bool);
+}
+
 #[test]
 fn graph_remove_edge() {
     fn prop<Ty: EdgeType>(mut g: Graph<(), (), Ty>, a: u8, b: u8) -> bool {
@@ -1283,3 +1362,1704 @@ quickcheck! {
         true
     }
 }
+
+/// Whether the graph has a cycle, counting loops.
+fn has_cycle<N, E, Ty: EdgeType>(g: &Graph<N, E, Ty>) -> bool {
+    if Ty::is_directed() {
+        return is_cyclic_directed(g);
+    }
+    let mut trees = petgraph::unionfind::UnionFind::new(g.node_count());
+    !g.edge_references()
+        .all(|e| trees.union(e.source().index(), e.target().index()))
+}
+
+/// The least total weight of a subset of `count` elements, for which `acyclic` holds.
+fn min_acyclic_subset<F>(count: usize, weight: impl Fn(usize) -> u32, acyclic: F) -> u32
+where
+    F: Fn(&[usize]) -> bool,
+{
+    (0..1u32 << count)
+        .filter_map(|mask| {
+            let set: Vec<usize> = (0..count).filter(|&i| mask & (1 << i) != 0).collect();
+            if acyclic(&set) {
+                Some(set.iter().map(|&i| weight(i)).sum())
+            } else {
+                None
+            }
+        })
+        .min()
+        .unwrap()
+}
+
+fn without_nodes<N: Clone, E: Clone, Ty: EdgeType>(
+    g: &Graph<N, E, Ty>,
+    removed: &[usize],
+) -> Graph<N, E, Ty> {
+    let mut g = g.clone();
+    g.retain_edges(|g, e| {
+        let (a, b) = g.edge_endpoints(e).unwrap();
+        !removed.contains(&a.index()) && !removed.contains(&b.index())
+    });
+    g
+}
+
+fn min_feedback_vertex_set_weight<Ty: EdgeType>(g: &Graph<u32, (), Ty>) -> u32 {
+    min_acyclic_subset(
+        g.node_count(),
+        |v| g[node_index(v)],
+        |set| !has_cycle(&without_nodes(g, set)),
+    )
+}
+
+quickcheck! {
+    fn minimum_feedback_arc_set_is_minimum(g: Tiny<Graph<(), u32>>) -> bool {
+        let g = g.0;
+        if g.edge_count() > 12 {
+            return true;
+        }
+        let fas: Vec<usize> = minimum_feedback_arc_set(&g, |e| *e.weight())
+            .iter()
+            .map(|e| e.id().index())
+            .collect();
+        let mut h = g.clone();
+        h.retain_edges(|_, e| !fas.contains(&e.index()));
+        let weight: u32 = fas.iter().map(|&e| g[edge_index(e)]).sum();
+        let minimum = min_acyclic_subset(g.edge_count(), |e| g[edge_index(e)], |set| {
+            let mut h = g.clone();
+            h.retain_edges(|_, e| !set.contains(&e.index()));
+            !is_cyclic_directed(&h)
+        });
+        !is_cyclic_directed(&h) && weight == minimum
+    }
+
+    fn minimum_feedback_vertex_set_is_minimum(g: Tiny<Graph<u32, ()>>) -> bool {
+        let g = g.0;
+        let fvs: Vec<usize> = minimum_feedback_vertex_set(&g, |v| g[v])
+            .iter()
+            .map(|v| v.index())
+            .collect();
+        let weight: u32 = fvs.iter().map(|&v| g[node_index(v)]).sum();
+        !has_cycle(&without_nodes(&g, &fvs)) && weight == min_feedback_vertex_set_weight(&g)
+    }
+
+    fn minimum_feedback_vertex_set_undirected_is_minimum(g: Tiny<UnGraph<u32, ()>>) -> bool {
+        let g = g.0;
+        let fvs: Vec<usize> = minimum_feedback_vertex_set(&g, |v| g[v])
+            .iter()
+            .map(|v| v.index())
+            .collect();
+        let weight: u32 = fvs.iter().map(|&v| g[node_index(v)]).sum();
+        !has_cycle(&without_nodes(&g, &fvs)) && weight == min_feedback_vertex_set_weight(&g)
+    }
+
+    fn approximate_feedback_vertex_set_within_twice_minimum(g: Tiny<UnGraph<u32, ()>>) -> bool {
+        let g = g.0;
+        let fvs: Vec<usize> = approximate_feedback_vertex_set(&g, |v| g[v] as f64)
+            .iter()
+            .map(|v| v.index())
+            .collect();
+        let weight: u32 = fvs.iter().map(|&v| g[node_index(v)]).sum();
+        !has_cycle(&without_nodes(&g, &fvs)) && weight <= 2 * min_feedback_vertex_set_weight(&g)
+    }
+}
+
+/// Whether `edges` form a tree that connects the `terminals`, with only terminals as leaves.
+fn is_steiner_tree<E>(
+    g: &UnGraph<(), E>,
+    terminals: &[NodeIndex],
+    edges: &[petgraph::graph::EdgeReference<E>],
+) -> bool {
+    let mut trees = petgraph::unionfind::UnionFind::new(g.node_count());
+    let mut degree = vec![0; g.node_count()];
+    for e in edges {
+        if !trees.union(e.source().index(), e.target().index()) {
+            return false;
+        }
+        degree[e.source().index()] += 1;
+        degree[e.target().index()] += 1;
+    }
+    terminals
+        .iter()
+        .all(|t| trees.equiv(t.index(), terminals[0].index()))
+        && g.node_indices()
+            .all(|v| degree[v.index()] != 1 || terminals.contains(&v))
+}
+
+/// The weight of a minimum Steiner tree, from the minimum spanning trees of the subgraphs induced
+/// by all node sets that contain the terminals.
+fn min_steiner_tree_weight(g: &UnGraph<(), u32>, terminals: &[NodeIndex]) -> Option<u32> {
+    let n = g.node_count();
+    (0..1u32 << n)
+        .filter(|mask| terminals.iter().all(|t| mask & (1 << t.index()) != 0))
+        .filter_map(|mask| {
+            let induced = g.filter_map(
+                |v, _| {
+                    if mask & (1 << v.index()) != 0 {
+                        Some(())
+                    } else {
+                        None
+                    }
+                },
+                |_, &w| Some(w),
+            );
+            let forest = Graph::<(), u32, Undirected>::from_elements(min_spanning_tree(&induced));
+            if forest.edge_count() + 1 == induced.node_count() {
+                Some(forest.edge_weights().sum())
+            } else {
+                None
+            }
+        })
+        .min()
+}
+
+quickcheck! {
+    fn steiner_tree_within_twice_minimum(g: Tiny<UnGraph<(), u32>>, terminals: Vec<usize>) -> bool {
+        let g = g.0;
+        if g.node_count() == 0 || terminals.is_empty() {
+            return true;
+        }
+        let terminals: Vec<NodeIndex> = terminals
+            .iter()
+            .map(|&t| node_index(t % g.node_count()))
+            .collect();
+        let mut distinct = terminals.clone();
+        distinct.sort();
+        distinct.dedup();
+        let minimum = min_steiner_tree_weight(&g, &distinct);
+        let results = [
+            steiner_tree(&g, &terminals, |e| *e.weight()),
+            metric_closure_steiner_tree(&g, &terminals, |e| *e.weight()),
+        ];
+        results.iter().all(|result| match (result, minimum) {
+            (Some((weight, edges)), Some(minimum)) => {
+                is_steiner_tree(&g, &terminals, edges)
+                    && edges.iter().map(|e| *e.weight()).sum::<u32>() == *weight
+                    && *weight <= 2 * minimum
+            }
+            (None, None) => true,
+            _ => false,
+        })
+    }
+}
+
+/// Check the distance measures against the eccentricities from a shortest path search from every
+/// node, with unit edge weights or the weights of the graph.
+fn check_distance_measures<Ty: EdgeType>(g: &Graph<(), u32, Ty>, weighted: bool) -> bool {
+    let cost = |e: petgraph::graph::EdgeReference<u32>| if weighted { *e.weight() } else { 1 };
+    let mut expected = HashMap::new();
+    for v in g.node_indices() {
+        let distances = dijkstra(g, v, None, cost);
+        if distances.len() == g.node_count() {
+            expected.insert(v, *distances.values().max().unwrap());
+        }
+    }
+    let connected = g.node_count() > 0 && expected.len() == g.node_count();
+    let (found, diam, rad, cent, peri) = if weighted {
+        (
+            weighted_eccentricities(g, cost),
+            weighted_diameter(g, cost),
+            weighted_radius(g, cost),
+            weighted_center(g, cost),
+            weighted_periphery(g, cost),
+        )
+    } else {
+        let to_u32 =
+            |m: HashMap<NodeIndex, usize>| m.into_iter().map(|(v, e)| (v, e as u32)).collect();
+        (
+            eccentricities(g).map(to_u32),
+            diameter(g).map(|d| d as u32),
+            radius(g).map(|r| r as u32),
+            center(g),
+            periphery(g),
+        )
+    };
+    for v in g.node_indices() {
+        let single = if weighted {
+            weighted_eccentricity(g, v, cost)
+        } else {
+            eccentricity(g, v).map(|e| e as u32)
+        };
+        if single != expected.get(&v).cloned() {
+            return false;
+        }
+    }
+    if !connected {
+        return found.is_none()
+            && diam.is_none()
+            && rad.is_none()
+            && cent.is_empty()
+            && peri.is_empty();
+    }
+    let max = *expected.values().max().unwrap();
+    let min = *expected.values().min().unwrap();
+    let nodes_with = |e| {
+        g.node_indices()
+            .filter(|v| expected[v] == e)
+            .collect::<Vec<_>>()
+    };
+    found == Some(expected.clone())
+        && diam == Some(max)
+        && rad == Some(min)
+        && cent == nodes_with(min)
+        && peri == nodes_with(max)
+}
+
+quickcheck! {
+    fn distance_measures_directed(g: Small<Graph<(), u32>>) -> bool {
+        check_distance_measures(&g, false) && check_distance_measures(&g, true)
+    }
+
+    fn distance_measures_undirected(g: Small<UnGraph<(), u32>>) -> bool {
+        check_distance_measures(&g, false) && check_distance_measures(&g, true)
+    }
+}
+
+/// Check a bipartition or odd cycle against a search for a two-coloring.
+fn check_bipartition<Ty: EdgeType>(g: &Graph<(), (), Ty>) -> bool {
+    let bipartite = (0..1u32 << g.node_count()).any(|mask| {
+        let side = |n: NodeIndex| mask & (1 << n.index()) != 0;
+        g.edge_references()
+            .all(|e| side(e.source()) != side(e.target()))
+    });
+    match bipartition(g) {
+        Ok((left, right)) => {
+            bipartite
+                && left.len() + right.len() == g.node_count()
+                && g.edge_references()
+                    .all(|e| left.contains(&e.source()) != left.contains(&e.target()))
+        }
+        Err(cycle) => {
+            let cycle = cycle.cycle();
+            let adjacent = |a, b| g.contains_edge(a, b) || g.contains_edge(b, a);
+            !bipartite
+                && cycle.len() % 2 == 1
+                && cycle.iter().enumerate().all(|(i, &a)| {
+                    !cycle[..i].contains(&a) && adjacent(a, cycle[(i + 1) % cycle.len()])
+                })
+        }
+    }
+}
+
+fn satisfies(assignment: &[bool], clauses: &[(Literal, Literal)]) -> bool {
+    clauses
+        .iter()
+        .all(|&((a, x), (b, y))| assignment[a] == x || assignment[b] == y)
+}
+
+fn is_satisfiable(variables: usize, clauses: &[(Literal, Literal)]) -> bool {
+    (0..1u32 << variables).any(|mask| {
+        let assignment: Vec<bool> = (0..variables).map(|v| mask & (1 << v) != 0).collect();
+        satisfies(&assignment, clauses)
+    })
+}
+
+quickcheck! {
+    fn bipartition_directed(g: Tiny<Graph<(), ()>>) -> bool {
+        check_bipartition(&g.0)
+    }
+
+    fn bipartition_undirected(g: Tiny<UnGraph<(), ()>>) -> bool {
+        check_bipartition(&g.0)
+    }
+
+    fn two_sat_matches_brute_force(clauses: Small<Vec<(Literal, Literal)>>) -> bool {
+        let variables = 6;
+        let clauses: Vec<(Literal, Literal)> = clauses
+            .iter()
+            .map(|&((a, x), (b, y))| ((a % variables, x), (b % variables, y)))
+            .collect();
+        match two_sat(variables, &clauses) {
+            Ok(assignment) => assignment.len() == variables && satisfies(&assignment, &clauses),
+            Err(core) => {
+                let core_clauses: Vec<_> = core.clauses().iter().map(|&i| clauses[i]).collect();
+                !is_satisfiable(variables, &clauses)
+                    && !is_satisfiable(variables, &core_clauses)
+                    && core_clauses
+                        .iter()
+                        .any(|&((a, _), (b, _))| a == core.variable() || b == core.variable())
+            }
+        }
+    }
+}
+
+/// The cost of a tour through every node, or `None` if an edge is missing, using the cheapest
+/// parallel edge.
+fn tour_cost<Ty: EdgeType, K>(g: &Graph<(), K, Ty>, tour: &[NodeIndex]) -> Option<K>
+where
+    K: Copy + PartialOrd + std::ops::Add<Output = K> + Default,
+{
+    let mut sorted = tour.to_vec();
+    sorted.sort();
+    if sorted != g.node_indices().collect::<Vec<_>>() {
+        return None;
+    }
+    let mut total = K::default();
+    if tour.len() == 1 {
+        return Some(total);
+    }
+    for i in 0..tour.len() {
+        let (a, b) = (tour[i], tour[(i + 1) % tour.len()]);
+        let cheapest = g.edges_connecting(a, b).map(|e| *e.weight()).fold(
+            None,
+            |min: Option<K>, c| match min {
+                Some(m) if m <= c => Some(m),
+                _ => Some(c),
+            },
+        );
+        total = total + cheapest?;
+    }
+    Some(total)
+}
+
+/// The least cost of a tour, trying all the tours from the first node.
+fn min_tour_cost<Ty: EdgeType>(g: &Graph<(), u32, Ty>) -> Option<u32> {
+    let n = g.node_count();
+    let cheapest: Vec<Vec<Option<u32>>> = g
+        .node_indices()
+        .map(|a| {
+            g.node_indices()
+                .map(|b| g.edges_connecting(a, b).map(|e| *e.weight()).min())
+                .collect()
+        })
+        .collect();
+    fn extend(
+        cheapest: &[Vec<Option<u32>>],
+        tour: &mut Vec<usize>,
+        cost: u32,
+        best: &mut Option<u32>,
+    ) {
+        let last = *tour.last().unwrap();
+        if tour.len() == cheapest.len() {
+            let back = if tour.len() == 1 {
+                Some(0)
+            } else {
+                cheapest[last][0]
+            };
+            if let Some(back) = back {
+                if best.map_or(true, |best| cost + back < best) {
+                    *best = Some(cost + back);
+                }
+            }
+            return;
+        }
+        for v in 0..cheapest.len() {
+            if let Some(c) = cheapest[last][v] {
+                if !tour.contains(&v) && best.map_or(true, |best| cost + c < best) {
+                    tour.push(v);
+                    extend(cheapest, tour, cost + c, best);
+                    tour.pop();
+                }
+            }
+        }
+    }
+    if n == 0 {
+        return Some(0);
+    }
+    let mut best = None;
+    extend(&cheapest, &mut vec![0], 0, &mut best);
+    best
+}
+
+fn check_held_karp<Ty: EdgeType>(g: &Graph<(), u32, Ty>) -> bool {
+    match held_karp(g, |e| *e.weight()) {
+        Some((cost, tour)) => {
+            tour.first() == g.node_indices().next().as_ref()
+                && tour_cost(g, &tour) == Some(cost)
+                && min_tour_cost(g) == Some(cost)
+        }
+        None => min_tour_cost(g).is_none(),
+    }
+}
+
+/// Check that 2-opt and Or-opt improve the tour through the nodes in index order, if it exists.
+fn check_local_search(g: &UnGraph<(), u32>) -> bool {
+    let tour: Vec<NodeIndex> = g.node_indices().collect();
+    let before = tour_cost(g, &tour);
+    [
+        two_opt(g, &tour, |e| *e.weight()),
+        or_opt(g, &tour, |e| *e.weight()),
+    ]
+    .iter()
+    .all(|result| match (result, before) {
+        (Some((cost, found)), Some(before)) => {
+            found.first() == tour.first() && tour_cost(g, found) == Some(*cost) && *cost <= before
+        }
+        (None, None) => true,
+        _ => false,
+    })
+}
+
+quickcheck! {
+    fn held_karp_directed(g: Tiny<Graph<(), u32>>) -> bool {
+        check_held_karp(&g.0)
+    }
+
+    fn held_karp_undirected(g: Tiny<UnGraph<(), u32>>) -> bool {
+        check_held_karp(&g.0)
+    }
+
+    fn local_search_undirected(g: Small<UnGraph<(), u32>>) -> bool {
+        check_local_search(&g.0)
+    }
+
+    // Christofides is within 3/2 of the optimum on metric graphs, such as the complete graph of
+    // points in the plane with their Euclidean distances.
+    fn christofides_within_one_and_a_half_of_optimum(points: Vec<(u8, u8)>) -> bool {
+        let points = &points[..points.len().min(9)];
+        let mut g = UnGraph::<(), f64>::default();
+        for _ in points {
+            g.add_node(());
+        }
+        for (i, a) in points.iter().enumerate() {
+            for (j, b) in points.iter().enumerate().skip(i + 1) {
+                let (dx, dy) = (a.0 as f64 - b.0 as f64, a.1 as f64 - b.1 as f64);
+                g.add_edge(node_index(i), node_index(j), (dx * dx + dy * dy).sqrt());
+            }
+        }
+        let cost = |e: petgraph::graph::EdgeReference<f64>| *e.weight();
+        let (optimum, _) = held_karp(&g, cost).unwrap();
+        let (c, tour) = christofides(&g, cost).unwrap();
+        let close = |tour: &[NodeIndex], c: f64| (tour_cost(&g, tour).unwrap() - c).abs() < 1e-9;
+        if !close(&tour, c) || c > 1.5 * optimum + 1e-9 {
+            return false;
+        }
+        match g.node_indices().next() {
+            Some(start) => {
+                let (c, tour) = nearest_neighbor_tour(&g, start, cost).unwrap();
+                tour[0] == start && close(&tour, c) && c >= optimum - 1e-9
+            }
+            None => true,
+        }
+    }
+}
+
+/// Whether `order` visits every node once, with an edge from each node to the next, and back to
+/// the first node of the graph for a cycle.
+fn is_hamiltonian<Ty: EdgeType>(g: &Graph<(), (), Ty>, order: &[NodeIndex], cycle: bool) -> bool {
+    let mut sorted = order.to_vec();
+    sorted.sort();
+    sorted == g.node_indices().collect::<Vec<_>>()
+        && order.windows(2).all(|w| g.contains_edge(w[0], w[1]))
+        && (!cycle
+            || order.len() <= 1
+            || order[0] == node_index(0) && g.contains_edge(order[order.len() - 1], order[0]))
+}
+
+/// Whether there is a Hamiltonian path or cycle, trying all orders of the nodes.
+fn has_hamiltonian<Ty: EdgeType>(g: &Graph<(), (), Ty>, cycle: bool) -> bool {
+    fn extend<Ty: EdgeType>(
+        g: &Graph<(), (), Ty>,
+        order: &mut Vec<NodeIndex>,
+        cycle: bool,
+    ) -> bool {
+        let n = g.node_count();
+        if order.len() == n {
+            return !cycle || n <= 1 || g.contains_edge(order[n - 1], order[0]);
+        }
+        for v in g.node_indices() {
+            if order.contains(&v) || order.last().map_or(false, |&u| !g.contains_edge(u, v)) {
+                continue;
+            }
+            order.push(v);
+            if extend(g, order, cycle) {
+                return true;
+            }
+            order.pop();
+        }
+        false
+    }
+    extend(g, &mut Vec::new(), cycle)
+}
+
+fn check_hamiltonian<Ty: EdgeType>(g: &Graph<(), (), Ty>) -> bool {
+    [false, true].iter().all(|&cycle| {
+        let expected = has_hamiltonian(g, cycle);
+        let (backtracking, bitmask) = if cycle {
+            (hamiltonian_cycle(g), hamiltonian_cycle_bitmask(g))
+        } else {
+            (hamiltonian_path(g), hamiltonian_path_bitmask(g))
+        };
+        backtracking.is_some() == expected
+            && bitmask.is_some() == expected
+            && backtracking
+                .iter()
+                .chain(&bitmask)
+                .all(|order| is_hamiltonian(g, order, cycle))
+    })
+}
+
+quickcheck! {
+    fn hamiltonian_directed(g: Tiny<Graph<(), ()>>) -> bool {
+        let g = g.0;
+        let tour = held_karp(&g, |_| 1);
+        check_hamiltonian(&g) && hamiltonian_cycle(&g).is_some() == tour.is_some()
+    }
+
+    fn hamiltonian_undirected(g: Tiny<UnGraph<(), ()>>) -> bool {
+        check_hamiltonian(&g.0)
+    }
+}
+
+/// The nodes of a graph as a bitmask.
+fn node_mask(nodes: &[NodeIndex]) -> u32 {
+    nodes.iter().fold(0, |m, v| m | 1 << v.index())
+}
+
+fn is_independent<Ty: EdgeType>(g: &Graph<(), (), Ty>, set: u32) -> bool {
+    g.edge_references()
+        .all(|e| set & (1 << e.source().index()) == 0 || set & (1 << e.target().index()) == 0)
+}
+
+fn is_dominating<Ty: EdgeType>(g: &Graph<(), (), Ty>, set: u32) -> bool {
+    let mut dominated = set;
+    for e in g.edge_references() {
+        if set & (1 << e.source().index()) != 0 {
+            dominated |= 1 << e.target().index();
+        }
+        if !g.is_directed() && set & (1 << e.target().index()) != 0 {
+            dominated |= 1 << e.source().index();
+        }
+    }
+    dominated.count_ones() as usize == g.node_count()
+}
+
+/// The size of the smallest set of nodes with the property, trying all of them.
+fn min_node_set(n: usize, has_property: impl Fn(u32) -> bool) -> usize {
+    (0u32..1 << n)
+        .filter(|&set| has_property(set))
+        .map(|set| set.count_ones() as usize)
+        .min()
+        .unwrap()
+}
+
+fn check_covering<Ty: EdgeType>(g: &Graph<(), (), Ty>) -> bool {
+    let n = g.node_count();
+    let full = (1u32 << n) - 1;
+    let is_cover = |set: u32| is_independent(g, full & !set);
+    let min_cover = min_node_set(n, is_cover);
+
+    // The complement of a minimum vertex cover is a maximum independent set.
+    let maximum = maximum_independent_set(g);
+    let greedy = node_mask(&greedy_independent_set(g));
+    let maximal = (0..n).all(|v| greedy & (1 << v) != 0 || !is_independent(g, greedy | 1 << v));
+    let approximate = node_mask(&approximate_vertex_cover(g));
+    let dominating = greedy_dominating_set(g);
+    let min_dominating = min_node_set(n, |set| is_dominating(g, set));
+    let bipartite = match bipartite_vertex_cover(g) {
+        Ok(cover) => cover.len() == min_cover && is_cover(node_mask(&cover)),
+        Err(cycle) => {
+            let cycle = cycle.cycle();
+            cycle.len() % 2 == 1
+                && (0..cycle.len()).all(|i| {
+                    let (a, b) = (cycle[i], cycle[(i + 1) % cycle.len()]);
+                    g.contains_edge(a, b) || g.contains_edge(b, a)
+                })
+        }
+    };
+    is_independent(g, node_mask(&maximum))
+        && maximum.len() == n - min_cover
+        && is_independent(g, greedy)
+        && maximal
+        && is_cover(approximate)
+        && approximate.count_ones() as usize <= 2 * min_cover
+        && is_dominating(g, node_mask(&dominating))
+        && dominating.len() as f64 <= (1. + (n.max(1) as f64).ln()) * min_dominating as f64
+        && bipartite
+}
+
+quickcheck! {
+    fn covering_directed(g: Tiny<Graph<(), ()>>) -> bool {
+        check_covering(&g.0)
+    }
+
+    fn covering_undirected(g: Tiny<UnGraph<(), ()>>) -> bool {
+        check_covering(&g.0)
+    }
+
+    // Arbitrary graphs are rarely bipartite, so keep the edges between nodes of different parity,
+    // and thin them out so that the graph is sparse enough for edge directions to matter.
+    fn bipartite_vertex_cover_directed(g: Tiny<Graph<(), ()>>) -> bool {
+        let mut g = g.0;
+        g.retain_edges(|g, e| {
+            let (a, b) = g.edge_endpoints(e).unwrap();
+            (a.index() + b.index()) % 2 == 1 && e.index() % 3 == 0
+        });
+        check_covering(&g)
+    }
+}
+
+/// Whether `b` is reachable from `a` by a nonempty path, for all pairs.
+fn reachability(g: &Graph<(), ()>) -> Vec<Vec<bool>> {
+    let n = g.node_count();
+    let mut reachable = vec![vec![false; n]; n];
+    for e in g.edge_references() {
+        reachable[e.source().index()][e.target().index()] = true;
+    }
+    for k in 0..n {
+        for a in 0..n {
+            for b in 0..n {
+                reachable[a][b] |= reachable[a][k] && reachable[k][b];
+            }
+        }
+    }
+    reachable
+}
+
+/// Whether the lists partition the nodes, with each consecutive pair related.
+fn is_partition(
+    n: usize,
+    lists: &[Vec<NodeIndex>],
+    related: impl Fn(usize, usize) -> bool,
+) -> bool {
+    let mut nodes: Vec<usize> = lists.iter().flatten().map(|v| v.index()).collect();
+    nodes.sort();
+    nodes == (0..n).collect::<Vec<_>>()
+        && lists
+            .iter()
+            .all(|list| list.windows(2).all(|p| related(p[0].index(), p[1].index())))
+}
+
+/// The most related pairs that can be chosen with each node first in at most one pair and second
+/// in at most one pair, trying all choices.
+fn max_joins(v: usize, n: usize, related: &dyn Fn(usize, usize) -> bool, joined: u32) -> usize {
+    if v == n {
+        return 0;
+    }
+    (0..n)
+        .filter(|&w| joined & (1 << w) == 0 && related(v, w))
+        .map(|w| 1 + max_joins(v + 1, n, related, joined | 1 << w))
+        .fold(max_joins(v + 1, n, related, joined), Ord::max)
+}
+
+/// Whether no node of `set` can reach another.
+fn is_antichain(reachable: &[Vec<bool>], set: u32) -> bool {
+    let n = reachable.len();
+    let members: Vec<usize> = (0..n).filter(|&v| set & (1 << v) != 0).collect();
+    members
+        .iter()
+        .all(|&a| members.iter().all(|&b| !reachable[a][b]))
+}
+
+/// The size of the largest antichain, trying all sets of nodes.
+fn max_antichain_size(reachable: &[Vec<bool>]) -> u32 {
+    (0u32..1 << reachable.len())
+        .filter(|&set| is_antichain(reachable, set))
+        .map(u32::count_ones)
+        .max()
+        .unwrap()
+}
+
+quickcheck! {
+    fn path_cover_of_dags(g: Tiny<Graph<(), ()>>) -> bool {
+        let mut g = g.0;
+        if is_cyclic_directed(&g)
+            && (minimum_path_cover(&g).is_ok()
+                || minimum_chain_cover(&g).is_ok()
+                || maximum_antichain(&g).is_ok())
+        {
+            return false;
+        }
+        // Orient the edges from lower to higher index.
+        g.retain_edges(|g, e| {
+            let (a, b) = g.edge_endpoints(e).unwrap();
+            a < b
+        });
+        let n = g.node_count();
+        let reachable = reachability(&g);
+        let comparable = |a: usize, b: usize| reachable[a][b];
+        let adjacent = |a: usize, b: usize| g.contains_edge(NodeIndex::new(a), NodeIndex::new(b));
+        let paths = minimum_path_cover(&g).unwrap();
+        let chains = minimum_chain_cover(&g).unwrap();
+        let antichain = node_mask(&maximum_antichain(&g).unwrap());
+        let max_antichain = max_antichain_size(&reachable);
+        is_partition(n, &paths, adjacent)
+            && paths.len() == n - max_joins(0, n, &adjacent, 0)
+            && is_partition(n, &chains, comparable)
+            && chains.len() == n - max_joins(0, n, &comparable, 0)
+            && is_antichain(&reachable, antichain)
+            && antichain.count_ones() == max_antichain
+            && chains.len() == max_antichain as usize
+    }
+}
+
+fn bisection_cut(g: &UnGraph<(), u32>, parts: &[usize]) -> u32 {
+    g.edge_references()
+        .filter(|e| parts[e.source().index()] != parts[e.target().index()])
+        .map(|e| *e.weight())
+        .sum()
+}
+
+/// The smallest cut of a bisection whose parts satisfy `allowed`, trying all bisections.
+fn min_bisection_cut(g: &UnGraph<(), u32>, allowed: impl Fn(&[usize]) -> bool) -> Option<u32> {
+    let n = g.node_count();
+    (0u32..1 << n)
+        .map(|set| (0..n).map(|v| (set >> v) as usize & 1).collect::<Vec<_>>())
+        .filter(|parts| allowed(parts))
+        .map(|parts| bisection_cut(g, &parts))
+        .min()
+}
+
+quickcheck! {
+    fn kernighan_lin_keeps_sizes(g: Tiny<UnGraph<(), u32>>) -> bool {
+        let g = g.0;
+        let initial: Vec<usize> = g.node_indices().map(|v| v.index() % 2).collect();
+        let (value, parts) = kernighan_lin(&g, Some(&initial), |e| *e.weight());
+        let ones = |parts: &[usize]| parts.iter().filter(|&&p| p == 1).count();
+        let best = min_bisection_cut(&g, |parts| ones(parts) == ones(&initial)).unwrap();
+        value == bisection_cut(&g, &parts)
+            && value <= bisection_cut(&g, &initial)
+            && ones(&parts) == ones(&initial)
+            && value >= best
+    }
+
+    fn fiduccia_mattheyses_keeps_balance(g: Tiny<UnGraph<(), u32>>, imbalance: u8) -> bool {
+        let g = g.0;
+        let n = g.node_count();
+        let weight = |v: usize| v % 3 + 1;
+        let imbalance = f64::from(imbalance % 30) / 100.;
+        let half = (0..n).map(weight).sum::<usize>() as f64 / 2.;
+        let limit = ((half * (1. + imbalance)).floor() as usize).max(half.ceil() as usize);
+        let part_weight = |parts: &[usize], part| -> usize {
+            (0..n).filter(|&v| parts[v] == part).map(weight).sum()
+        };
+        let balanced = |parts: &[usize]| (0..2).all(|part| part_weight(parts, part) <= limit);
+        let (value, parts) =
+            fiduccia_mattheyses(&g, None, imbalance, |e| *e.weight(), |v| weight(v.index()));
+        // Some weights cannot be balanced at all.
+        let optimal = match min_bisection_cut(&g, balanced) {
+            Some(best) => balanced(&parts) && value >= best,
+            None => true,
+        };
+        value == bisection_cut(&g, &parts) && optimal
+    }
+}
+
+quickcheck! {
+    fn fiedler_vector_is_second_eigenvector(g: Small<UnGraph<(), u32>>) -> bool {
+        let g = g.0;
+        let n = g.node_count();
+        let weight = |e: petgraph::graph::EdgeReference<u32>| f64::from(*e.weight() + 1);
+        let (value, vector) = match fiedler_vector(&g, weight) {
+            Some(pair) => pair,
+            None => return n < 2,
+        };
+        let mut product: Vec<f64> = vector.iter().map(|x| -value * x).collect();
+        let mut degrees = vec![0.; n];
+        for e in g.edge_references().filter(|e| e.source() != e.target()) {
+            let (a, b, w) = (e.source().index(), e.target().index(), weight(e));
+            product[a] += w * (vector[a] - vector[b]);
+            product[b] += w * (vector[b] - vector[a]);
+            degrees[a] += w;
+            degrees[b] += w;
+        }
+        let largest = degrees.iter().cloned().fold(1., f64::max);
+        let smallest = degrees.iter().cloned().fold(std::f64::INFINITY, f64::min);
+        let residual = product.iter().map(|p| p * p).sum::<f64>().sqrt();
+        let (cut, parts) = spectral_bisection(&g, weight);
+        let crossing: f64 = g
+            .edge_references()
+            .filter(|e| parts[e.source().index()] != parts[e.target().index()])
+            .map(weight)
+            .sum();
+        residual < 1e-7 * largest
+            && vector.iter().sum::<f64>().abs() < 1e-8
+            && (vector.iter().map(|x| x * x).sum::<f64>() - 1.).abs() < 1e-8
+            && (value > 1e-9 * largest) == (connected_components(&g) == 1)
+            // A vector that is one at a node and centered bounds the eigenvalue.
+            && value <= smallest * n as f64 / (n - 1) as f64 + 1e-9 * largest
+            && algebraic_connectivity(&g, weight) == Some(value)
+            && parts.iter().filter(|&&p| p == 0).count() == n / 2
+            && cut == crossing
+    }
+}
+
+/// The arcs of the graph, with both directions of undirected edges.
+fn arcs<Ty: EdgeType>(g: &Graph<(), (), Ty>, e: EdgeIndex) -> Vec<(usize, usize)> {
+    let (a, b) = g.edge_endpoints(e).unwrap();
+    if g.is_directed() {
+        vec![(a.index(), b.index())]
+    } else {
+        vec![(a.index(), b.index()), (b.index(), a.index())]
+    }
+}
+
+/// Distances and numbers of shortest paths from `source`, by breadth-first search.
+fn bfs_path_counts<Ty: EdgeType>(g: &Graph<(), (), Ty>, source: usize) -> (Vec<usize>, Vec<f64>) {
+    let n = g.node_count();
+    let mut distance = vec![std::usize::MAX; n];
+    distance[source] = 0;
+    let mut order = vec![source];
+    let mut i = 0;
+    while i < order.len() {
+        let v = order[i];
+        i += 1;
+        for w in g.neighbors(NodeIndex::new(v)) {
+            if distance[w.index()] == std::usize::MAX {
+                distance[w.index()] = distance[v] + 1;
+                order.push(w.index());
+            }
+        }
+    }
+    let mut count = vec![0.; n];
+    count[source] = 1.;
+    for &v in &order {
+        for e in g.edge_indices() {
+            for (x, y) in arcs(g, e) {
+                if y == v && distance[x] != std::usize::MAX && distance[x] + 1 == distance[v] {
+                    count[v] += count[x];
+                }
+            }
+        }
+    }
+    (distance, count)
+}
+
+/// Edge betweenness from the definition: the fraction of the shortest paths between every pair
+/// that use each edge.
+fn betweenness_by_definition<Ty: EdgeType>(g: &Graph<(), (), Ty>) -> Vec<f64> {
+    let n = g.node_count();
+    let all: Vec<_> = (0..n).map(|s| bfs_path_counts(g, s)).collect();
+    let mut betweenness = vec![0.; g.edge_count()];
+    for e in g.edge_indices() {
+        for s in 0..n {
+            let (ds, cs) = &all[s];
+            for t in (0..n).filter(|&t| t != s && ds[t] != std::usize::MAX) {
+                for (x, y) in arcs(g, e) {
+                    let (dy, cy) = &all[y];
+                    let reached = ds[x] != std::usize::MAX && dy[t] != std::usize::MAX;
+                    if x != y && reached && ds[x] + 1 + dy[t] == ds[t] {
+                        betweenness[e.index()] += cs[x] * cy[t] / cs[t];
+                    }
+                }
+            }
+        }
+    }
+    if !g.is_directed() {
+        for b in &mut betweenness {
+            *b /= 2.;
+        }
+    }
+    betweenness
+}
+
+fn check_edge_betweenness<Ty: EdgeType>(g: &Graph<(), (), Ty>) -> bool {
+    edge_betweenness(g)
+        .iter()
+        .zip(&betweenness_by_definition(g))
+        .all(|(a, b)| (a - b).abs() < 1e-9)
+}
+
+quickcheck! {
+    fn edge_betweenness_directed(g: Tiny<Graph<(), ()>>) -> bool {
+        check_edge_betweenness(&g.0)
+    }
+
+    fn edge_betweenness_undirected(g: Tiny<UnGraph<(), ()>>) -> bool {
+        check_edge_betweenness(&g.0)
+    }
+
+    fn girvan_newman_partitions_refine(g: Tiny<UnGraph<(), ()>>) -> bool {
+        let g = g.0;
+        let nodes: Vec<_> = g.node_indices().collect();
+        let components = connected_components(&g);
+        let mut previous = vec![nodes.clone()];
+        let mut count = 0;
+        for partition in girvan_newman(&g) {
+            count += 1;
+            let mut covered: Vec<_> = partition.iter().flatten().cloned().collect();
+            covered.sort();
+            // Every community is sorted and lies within a community of the previous partition.
+            let refines = partition.iter().all(|community| {
+                community.windows(2).all(|w| w[0] < w[1])
+                    && previous.iter().any(|p| community.iter().all(|v| p.contains(v)))
+            });
+            if partition.len() != components + count || covered != nodes || !refines {
+                return false;
+            }
+            previous = partition;
+        }
+        count == nodes.len() - components
+    }
+
+    fn girvan_newman_dendrogram(g: Tiny<UnGraph<(), ()>>, taken: usize) -> bool {
+        let g = g.0;
+        let n = g.node_count();
+        let components = connected_components(&g);
+        let partitions: Vec<_> = girvan_newman(&g).collect();
+
+        // Taking some partitions first does not change the dendrogram.
+        let mut iter = girvan_newman(&g);
+        for _ in 0..taken % (n + 1) {
+            iter.next();
+        }
+        let dendrogram = iter.dendrogram();
+        let singletons: Vec<_> = g.node_indices().map(|v| vec![v]).collect();
+        let whole = dendrogram.cut(1);
+
+        // The clusters that are never merged again are the components.
+        let mut sizes: Vec<_> = whole.iter().map(|c| c.len()).filter(|&s| s > 1).collect();
+        sizes.sort();
+        let merges = dendrogram.merges();
+        let mut merged: Vec<_> = (0..merges.len())
+            .filter(|&i| merges.iter().all(|&(a, b)| a != n + i && b != n + i))
+            .map(|i| dendrogram.members(n + i).len())
+            .collect();
+        merged.sort();
+        dendrogram.leaves().len() == n
+            && merges.len() == n - components
+            && partitions.iter().all(|p| &dendrogram.cut(p.len()) == p)
+            && dendrogram.cut(n + 1) == singletons
+            && whole.len() == components
+            && merged == sizes
+            && (0..n).all(|v| dendrogram.members(v) == vec![dendrogram.leaves()[v]])
+    }
+}
+
+quickcheck! {
+    fn acyclic_insertions(nodes: u8, edges: Vec<(u8, u8)>) -> bool {
+        let n = usize::from(nodes % 30) + 1;
+        let mut dag = Acyclic::<DiGraph<(), ()>>::new();
+        let mut plain = DiGraph::<(), ()>::new();
+        for _ in 0..n {
+            dag.add_node(());
+            plain.add_node(());
+        }
+        for (a, b) in edges {
+            let a = node_index(usize::from(a) % n);
+            let b = node_index(usize::from(b) % n);
+            let e = plain.add_edge(a, b, ());
+            let cyclic = is_cyclic_directed(&plain);
+            match dag.try_add_edge(a, b, ()) {
+                Ok(_) if cyclic => return false,
+                Ok(_) => {}
+                Err(cycle) => {
+                    let cycle = cycle.into_cycle();
+                    let closed = (0..cycle.len())
+                        .all(|i| plain.contains_edge(cycle[i], cycle[(i + 1) % cycle.len()]));
+                    if !cyclic || !closed {
+                        return false;
+                    }
+                    plain.remove_edge(e);
+                }
+            }
+        }
+        let order = dag.topological_order();
+        let mut position = vec![0; n];
+        for (i, v) in order.iter().enumerate() {
+            position[v.index()] = i;
+        }
+        toposort(&plain, None).is_ok()
+            && order.len() == n
+            && dag.inner().edge_count() == plain.edge_count()
+            && dag
+                .inner()
+                .edge_references()
+                .all(|e| position[e.source().index()] < position[e.target().index()])
+    }
+}
+
+quickcheck! {
+    fn k_shortest_simple_paths_in_cost_order(g: Tiny<Graph<(), u32>>) -> bool {
+        let g = g.0;
+        // Dense graphs have too many simple paths to list them all.
+        if g.node_count() < 2 || g.edge_count() > 2 * g.node_count() {
+            return true;
+        }
+        let (start, goal) = (node_index(0), node_index(g.node_count() - 1));
+        let cost = |path: &[NodeIndex]| -> u32 {
+            path.windows(2)
+                .map(|w| g[g.find_edge(w[0], w[1]).unwrap()])
+                .sum()
+        };
+        let mut expected: Vec<_> = all_simple_paths::<Vec<_>, _>(&g, start, goal, 0, None)
+            .map(|path| cost(&path))
+            .collect();
+        expected.sort_unstable();
+        let paths: Vec<_> = k_shortest_simple_paths(&g, start, goal, |e| *e.weight()).collect();
+        let distinct: HashSet<_> = paths.iter().map(|p| p.1.clone()).collect();
+        paths.iter().map(|p| p.0).collect::<Vec<_>>() == expected
+            && distinct.len() == paths.len()
+            && paths.iter().all(|(c, nodes, _)| *c == cost(nodes))
+    }
+}
+
+quickcheck! {
+    fn all_shortest_paths_are_cheapest_simple_paths(g: Tiny<Graph<(), u32>>) -> bool {
+        let g = g.0;
+        // Dense graphs have too many simple paths to list them all.
+        if g.node_count() < 2 || g.edge_count() > 3 * g.node_count() {
+            return true;
+        }
+        // Edge costs must be positive.
+        let weight = |e: petgraph::graph::EdgeReference<u32>| *e.weight() + 1;
+        let cost = |path: &Vec<NodeIndex>| -> u32 {
+            path.windows(2)
+                .map(|w| g[g.find_edge(w[0], w[1]).unwrap()] + 1)
+                .sum()
+        };
+        let start = node_index(0);
+        let counts = shortest_path_counts(&g, start, weight);
+        g.node_indices().skip(1).all(|goal| {
+            let simple: Vec<Vec<_>> = all_simple_paths(&g, start, goal, 0, None).collect();
+            let min = simple.iter().map(&cost).min();
+            let expected: HashSet<_> = simple
+                .into_iter()
+                .filter(|p| Some(cost(p)) == min)
+                .collect();
+            let paths: Vec<_> = all_shortest_paths(&g, start, goal, weight).collect();
+            paths.len() == expected.len()
+                && paths.into_iter().collect::<HashSet<_>>() == expected
+                && counts.get(&goal).cloned().unwrap_or(0) == expected.len()
+        })
+    }
+}
+
+/// The nodes of `side` as a partition, with them in part 1 and the others in part 0.
+fn side_parts(n: usize, side: &[NodeIndex]) -> Vec<usize> {
+    let mut parts = vec![0; n];
+    for v in side {
+        parts[v.index()] = 1;
+    }
+    parts
+}
+
+quickcheck! {
+    fn stoer_wagner_is_minimum(g: Tiny<UnGraph<(), u32>>) -> bool {
+        let g = g.0;
+        let n = g.node_count();
+        let (value, side) = match stoer_wagner_min_cut(&g, |e| *e.weight()) {
+            Some(cut) => cut,
+            None => return n < 2,
+        };
+        let parts = side_parts(n, &side);
+        let proper = |parts: &[usize]| parts.contains(&0) && parts.contains(&1);
+        proper(&parts)
+            && bisection_cut(&g, &parts) == value
+            && min_bisection_cut(&g, proper) == Some(value)
+    }
+
+    fn gomory_hu_tree_gives_minimum_cuts(g: Tiny<UnGraph<(), u32>>) -> bool {
+        let g = g.0;
+        let n = g.node_count();
+        let tree = gomory_hu_tree(&g, |e| *e.weight());
+        let proper = |parts: &[usize]| parts.contains(&0) && parts.contains(&1);
+        if tree.edges().count() != n.saturating_sub(1)
+            || tree.edge_connectivity() != min_bisection_cut(&g, proper)
+        {
+            return false;
+        }
+        g.node_indices().all(|a| {
+            tree.min_cut_value(a, a).is_none()
+                && g.node_indices().filter(|&b| b != a).all(|b| {
+                    let expected = min_bisection_cut(&g, |p| p[a.index()] != p[b.index()]);
+                    let (value, side) = tree.min_cut(a, b).unwrap();
+                    let parts = side_parts(n, &side);
+                    tree.min_cut_value(a, b) == expected
+                        && Some(value) == expected
+                        && parts[a.index()] == 1
+                        && parts[b.index()] == 0
+                        && Some(bisection_cut(&g, &parts)) == expected
+                })
+        })
+    }
+}
+
+/// The smallest number of nodes whose removal leaves at least two nodes, not all connected, or one
+/// less than the number of nodes if there are none.
+fn min_node_separator(g: &UnGraph<(), ()>) -> usize {
+    let n = g.node_count();
+    (0u32..1 << n)
+        .map(|mask| (0..n).filter(|&v| mask & 1 << v != 0).collect::<Vec<_>>())
+        .filter(|removed| {
+            // The removed nodes stay behind as isolated nodes.
+            n - removed.len() >= 2
+                && connected_components(&without_nodes(g, removed)) - removed.len() > 1
+        })
+        .map(|removed| removed.len())
+        .min()
+        .unwrap_or_else(|| n.saturating_sub(1))
+}
+
+quickcheck! {
+    fn node_connectivity_is_minimum_separator(g: Tiny<UnGraph<(), ()>>) -> bool {
+        node_connectivity(&g.0) == min_node_separator(&g.0)
+    }
+
+    fn k_edge_connected_subgraphs_are_partition(g: Tiny<UnGraph<(), ()>>, k: u8) -> bool {
+        let g = g.0;
+        let k = usize::from(k % 4);
+        let subgraphs = k_edge_connected_subgraphs(&g, k);
+        let mut seen = HashSet::new();
+        let connected = subgraphs.iter().all(|sub| {
+            let set: HashSet<_> = sub.iter().cloned().collect();
+            let mut h = g.clone();
+            h.retain_nodes(|_, v| set.contains(&v));
+            sub.iter().all(|&v| seen.insert(v)) && (sub.len() < 2 || edge_connectivity(&h) >= k)
+        });
+        let components = k_edge_connected_subgraphs(&g, 1)
+            .iter()
+            .filter(|s| !s.is_empty())
+            .count();
+        connected && seen.len() == g.node_count() && components == connected_components(&g)
+    }
+}
+
+/// A chordal graph built from `choices`: each new node is joined to a prefix of a clique chosen
+/// among the earlier ones, and forms a new clique with it.
+fn chordal_graph(choices: &[(u8, u8)]) -> UnGraph<(), ()> {
+    let mut graph = UnGraph::new_undirected();
+    let mut cliques: Vec<Vec<NodeIndex>> = vec![vec![]];
+    for &(clique, keep) in choices.iter().take(15) {
+        let v = graph.add_node(());
+        let clique = &cliques[usize::from(clique) % cliques.len()];
+        let keep = usize::from(keep) % (clique.len() + 1);
+        let mut new_clique = vec![v];
+        for &u in &clique[..keep] {
+            graph.add_edge(u, v, ());
+            new_clique.push(u);
+        }
+        cliques.push(new_clique);
+    }
+    graph
+}
+
+fn adjacent(g: &UnGraph<(), ()>, a: NodeIndex, b: NodeIndex) -> bool {
+    g.find_edge(a, b).is_some()
+}
+
+/// Whether the neighbors of each node that come after it in `order` form a clique.
+fn is_perfect_elimination_ordering(g: &UnGraph<(), ()>, order: &[NodeIndex]) -> bool {
+    let mut sorted = order.to_vec();
+    sorted.sort();
+    sorted == g.node_indices().collect::<Vec<_>>()
+        && order.iter().enumerate().all(|(i, &v)| {
+            let later: Vec<_> = order[i + 1..]
+                .iter()
+                .cloned()
+                .filter(|&u| adjacent(g, u, v))
+                .collect();
+            later
+                .iter()
+                .all(|&a| later.iter().all(|&b| a == b || adjacent(g, a, b)))
+        })
+}
+
+fn is_chordless_cycle(g: &UnGraph<(), ()>, cycle: &[NodeIndex]) -> bool {
+    let n = cycle.len();
+    n >= 4
+        && cycle.iter().collect::<HashSet<_>>().len() == n
+        && (0..n).all(|i| {
+            (i + 1..n).all(|j| {
+                let consecutive = j == i + 1 || (i == 0 && j == n - 1);
+                adjacent(g, cycle[i], cycle[j]) == consecutive
+            })
+        })
+}
+
+/// Chordality by repeatedly removing a node whose neighbors form a clique.
+fn peels_to_empty(g: &UnGraph<(), ()>) -> bool {
+    let mut remaining: HashSet<NodeIndex> = g.node_indices().collect();
+    while !remaining.is_empty() {
+        let simplicial = remaining.iter().cloned().find(|&v| {
+            let neighbors: Vec<_> = g
+                .neighbors(v)
+                .filter(|n| *n != v && remaining.contains(n))
+                .collect();
+            neighbors
+                .iter()
+                .all(|&a| neighbors.iter().all(|&b| a == b || adjacent(g, a, b)))
+        });
+        match simplicial {
+            Some(v) => remaining.remove(&v),
+            None => return false,
+        };
+    }
+    true
+}
+
+quickcheck! {
+    fn is_chordal_gives_certificate(g: Tiny<UnGraph<(), ()>>) -> bool {
+        let g = g.0;
+        match is_chordal(&g) {
+            Ok(order) => peels_to_empty(&g) && is_perfect_elimination_ordering(&g, &order),
+            Err(cycle) => !peels_to_empty(&g) && is_chordless_cycle(&g, cycle.cycle()),
+        }
+    }
+
+    fn traversals_of_chordal_graphs(choices: Vec<(u8, u8)>) -> bool {
+        let g = chordal_graph(&choices);
+        let mut mcs: Vec<_> = MaxCardinalitySearch::new(&g).iter(&g).collect();
+        mcs.reverse();
+        let mut lex_bfs: Vec<_> = LexBfs::new(&g).iter(&g).collect();
+        lex_bfs.reverse();
+        let (order, fill) = chordal_completion(&g, EliminationHeuristic::MinFill);
+        is_perfect_elimination_ordering(&g, &mcs)
+            && is_perfect_elimination_ordering(&g, &lex_bfs)
+            && is_chordal(&g).map_or(false, |order| is_perfect_elimination_ordering(&g, &order))
+            && fill.is_empty()
+            && is_perfect_elimination_ordering(&g, &order)
+    }
+
+    fn chordal_completion_makes_chordal(g: Tiny<UnGraph<(), ()>>) -> bool {
+        let g = g.0;
+        [EliminationHeuristic::MinDegree, EliminationHeuristic::MinFill]
+            .iter()
+            .all(|&heuristic| {
+                let mut g = g.clone();
+                let (order, fill) = chordal_completion(&g, heuristic);
+                let new = fill.iter().all(|&(a, b)| {
+                    let new = a != b && !adjacent(&g, a, b);
+                    g.add_edge(a, b, ());
+                    new
+                });
+                new && peels_to_empty(&g) && is_perfect_elimination_ordering(&g, &order)
+            })
+    }
+}
+
+fn is_tree_decomposition(
+    g: &UnGraph<(), ()>,
+    width: usize,
+    decomposition: &UnGraph<Vec<NodeIndex>, ()>,
+) -> bool {
+    if g.node_count() == 0 {
+        return decomposition.node_count() == 0;
+    }
+    let is_tree = decomposition.edge_count() + 1 == decomposition.node_count()
+        && connected_components(decomposition) == 1;
+    let has_width = decomposition.node_weights().map(|b| b.len()).max() == Some(width + 1);
+    let covers_edges = g.edge_references().all(|e| {
+        decomposition
+            .node_weights()
+            .any(|b| b.contains(&e.source()) && b.contains(&e.target()))
+    });
+    // The bags containing a node are connected, and there is at least one.
+    let connected_bags = g.node_indices().all(|v| {
+        let mut with_v = decomposition.clone();
+        with_v.retain_nodes(|g, b| g[b].contains(&v));
+        with_v.node_count() > 0 && connected_components(&with_v) == 1
+    });
+    let distinct = decomposition
+        .node_weights()
+        .all(|bag| bag.iter().collect::<HashSet<_>>().len() == bag.len());
+    is_tree && has_width && covers_edges && connected_bags && distinct
+}
+
+/// The treewidth as the best elimination ordering, trying all of them.
+fn min_treewidth(g: &UnGraph<(), ()>) -> usize {
+    let n = g.node_count();
+    let mut adjacency = vec![0u32; n];
+    for e in g.edge_references() {
+        let (a, b) = (e.source().index(), e.target().index());
+        if a != b {
+            adjacency[a] |= 1 << b;
+            adjacency[b] |= 1 << a;
+        }
+    }
+    // best[s]: the smallest width of eliminating the nodes of `s` first.
+    let mut best = vec![std::usize::MAX; 1 << n];
+    best[0] = 0;
+    for s in 0..(1u32 << n) {
+        if best[s as usize] == std::usize::MAX {
+            continue;
+        }
+        for v in (0..n).filter(|&v| s & 1 << v == 0) {
+            // The neighbors of `v` after eliminating `s`: the nodes outside `s` reachable from `v`
+            // through `s`.
+            let mut reached = 1u32 << v;
+            let mut frontier = reached;
+            while frontier != 0 {
+                let next = (0..n)
+                    .filter(|&x| frontier & 1 << x != 0)
+                    .fold(0, |next, x| next | adjacency[x])
+                    & !reached;
+                reached |= next;
+                frontier = next & s;
+            }
+            let degree = (reached & !s & !(1 << v)).count_ones() as usize;
+            let t = (s | 1 << v) as usize;
+            best[t] = best[t].min(best[s as usize].max(degree));
+        }
+    }
+    best[(1 << n) - 1]
+}
+
+quickcheck! {
+    fn treewidth_is_valid_upper_bound(g: Tiny<UnGraph<(), ()>>) -> bool {
+        let g = g.0;
+        let exact = min_treewidth(&g);
+        let chordal = is_chordal(&g).is_ok();
+        [EliminationHeuristic::MinDegree, EliminationHeuristic::MinFill]
+            .iter()
+            .all(|&heuristic| {
+                let (width, decomposition) = treewidth(&g, heuristic);
+                let exact_on_chordal = !chordal
+                    || heuristic != EliminationHeuristic::MinFill
+                    || width == exact;
+                width >= exact
+                    && exact_on_chordal
+                    && is_tree_decomposition(&g, width, &decomposition)
+            })
+    }
+}
+
+/// A planar graph built from `choices`: a triangle, where each new node is put inside a face
+/// chosen among the inner ones and joined to the corners picked by the low bits of a mask.
+fn planar_graph(choices: &[(u8, u8)]) -> UnGraph<(), ()> {
+    let mut graph = UnGraph::from_edges(&[(0, 1), (1, 2), (2, 0)]);
+    let mut faces = vec![[0u32, 1, 2], [0, 1, 2]];
+    for (v, &(face, corners)) in (3..).zip(choices.iter().take(40)) {
+        let f = faces.swap_remove(usize::from(face) % faces.len());
+        graph.add_node(());
+        for (i, &c) in f.iter().enumerate() {
+            if corners & 1 << i != 0 {
+                graph.add_edge(node_index(v as usize), node_index(c as usize), ());
+            }
+        }
+        faces.push([f[0], f[1], v]);
+        faces.push([f[1], f[2], v]);
+        faces.push([f[2], f[0], v]);
+    }
+    graph
+}
+
+/// Both half edges of every edge that is not a self loop.
+fn half_edges(g: &UnGraph<(), ()>) -> HashSet<(NodeIndex, NodeIndex)> {
+    g.edge_references()
+        .filter(|e| e.source() != e.target())
+        .flat_map(|e| vec![(e.source(), e.target()), (e.target(), e.source())])
+        .collect()
+}
+
+/// Whether `planar_embedding` gives a planar embedding, checked with Euler's formula, or a minimal
+/// non-planar subgraph, which makes it a subdivision of K5 or K3,3.
+fn check_planarity(g: &UnGraph<(), ()>) -> bool {
+    let edges = half_edges(g);
+    match planar_embedding(g) {
+        Ok(embedding) => {
+            let mut embedded = HashSet::new();
+            let distinct = g.node_indices().all(|v| {
+                embedding
+                    .neighbors_cw(v)
+                    .iter()
+                    .all(|&w| embedded.insert((v, w)))
+            });
+            let mut non_isolated = g.clone();
+            non_isolated.retain_nodes(|g, v| g.neighbors(v).any(|w| w != v));
+            let components = connected_components(&non_isolated);
+            let faces = embedding.faces();
+            is_planar(g)
+                && distinct
+                && embedded == edges
+                && faces.len() + non_isolated.node_count() == edges.len() / 2 + 2 * components
+                && faces.iter().map(|f| f.len()).sum::<usize>() == edges.len()
+        }
+        Err(kuratowski) => {
+            let witness = kuratowski.edges();
+            let subgraph = |skip: Option<usize>| {
+                let mut h = UnGraph::<(), ()>::with_capacity(g.node_count(), witness.len());
+                for _ in g.node_indices() {
+                    h.add_node(());
+                }
+                for (i, &(a, b)) in witness.iter().enumerate() {
+                    if Some(i) != skip {
+                        h.add_edge(a, b, ());
+                    }
+                }
+                h
+            };
+            let h = subgraph(None);
+            let branch: Vec<_> = h
+                .node_indices()
+                .map(|v| h.neighbors(v).count())
+                .filter(|&d| d > 0 && d != 2)
+                .collect();
+            let shape = branch.len() == 5 && branch.iter().all(|&d| d == 4)
+                || branch.len() == 6 && branch.iter().all(|&d| d == 3);
+            !is_planar(g)
+                && witness.iter().all(|e| edges.contains(e))
+                && shape
+                && !is_planar(&h)
+                && (0..witness.len()).all(|i| is_planar(&subgraph(Some(i))))
+        }
+    }
+}
+
+quickcheck! {
+    fn planarity_certificate(g: Small<UnGraph<(), ()>>) -> bool {
+        check_planarity(&g)
+    }
+
+    fn planar_graphs_are_embedded(choices: Vec<(u8, u8)>) -> bool {
+        let g = planar_graph(&choices);
+        planar_embedding(&g).is_ok() && check_planarity(&g)
+    }
+}
+
+/// The node weights and edges of the graph, renumbered in canonical order, or `None` if the order
+/// is not a permutation of the nodes.
+#[allow(clippy::type_complexity)]
+fn canonical_form<Ty: EdgeType>(g: &Graph<u8, (), Ty>) -> Option<(Vec<u8>, Vec<(usize, usize)>)> {
+    let order = canonical_labeling(g, |n| *n.weight(), |_| ());
+    let mut sorted = order.clone();
+    sorted.sort();
+    if sorted != g.node_indices().collect::<Vec<_>>() {
+        return None;
+    }
+    let mut position = vec![0; order.len()];
+    for (i, n) in order.iter().enumerate() {
+        position[n.index()] = i;
+    }
+    let mut edges: Vec<_> = g
+        .edge_references()
+        .map(|e| {
+            let (a, b) = (position[e.source().index()], position[e.target().index()]);
+            if Ty::is_directed() {
+                (a, b)
+            } else {
+                (a.min(b), a.max(b))
+            }
+        })
+        .collect();
+    edges.sort();
+    Some((order.iter().map(|&n| g[n]).collect(), edges))
+}
+
+/// The graph with node weights reduced to `labels` values, so that equal labels are likely.
+fn few_labels<Ty: EdgeType>(g: &Graph<u8, (), Ty>, labels: u8) -> Graph<u8, (), Ty> {
+    let labels = labels % 3 + 1;
+    g.map(|_, &w| w % labels, |_, _| ())
+}
+
+fn wl_hash<Ty: EdgeType>(g: &Graph<u8, (), Ty>) -> u64 {
+    weisfeiler_lehman_hash(g, 3, |n| *n.weight(), |_| ())
+}
+
+fn check_canonical_shuffled<Ty: EdgeType>(g: &Graph<u8, (), Ty>, labels: u8) -> bool {
+    let g = few_labels(g, labels);
+    let mut rng = rand::thread_rng();
+    let mut map = g.node_indices().collect::<Vec<_>>();
+    rng.shuffle(&mut map);
+    let mut edges: Vec<_> = g
+        .edge_references()
+        .map(|e| (map[e.source().index()], map[e.target().index()]))
+        .collect();
+    rng.shuffle(&mut edges);
+    let mut h = Graph::<u8, (), Ty>::with_capacity(g.node_count(), g.edge_count());
+    for _ in g.node_indices() {
+        h.add_node(0);
+    }
+    for v in g.node_indices() {
+        h[map[v.index()]] = g[v];
+    }
+    for (a, b) in edges {
+        h.add_edge(a, b, ());
+    }
+    let form = canonical_form(&g);
+    form.is_some() && canonical_form(&h) == form && wl_hash(&g) == wl_hash(&h)
+}
+
+fn check_canonical_isomorphism<Ty: EdgeType>(
+    g: &Graph<u8, (), Ty>,
+    h: &Graph<u8, (), Ty>,
+    labels: u8,
+) -> bool {
+    let (g, h) = (few_labels(g, labels), few_labels(h, labels));
+    let isomorphic = is_isomorphic_matching(&g, &h, PartialEq::eq, |_, _| true);
+    let (form_g, form_h) = (canonical_form(&g), canonical_form(&h));
+    form_g.is_some()
+        && form_h.is_some()
+        && (form_g == form_h) == isomorphic
+        && (!isomorphic || wl_hash(&g) == wl_hash(&h))
+}
+
+quickcheck! {
+    fn canonical_labeling_of_shuffled_directed(g: Tiny<Graph<u8, ()>>, labels: u8) -> bool {
+        check_canonical_shuffled(&g, labels)
+    }
+
+    fn canonical_labeling_of_shuffled_undirected(g: Tiny<UnGraph<u8, ()>>, labels: u8) -> bool {
+        check_canonical_shuffled(&g, labels)
+    }
+
+    fn canonical_labeling_matches_isomorphism_directed(
+        g: Tiny<Graph<u8, ()>>,
+        h: Tiny<Graph<u8, ()>>,
+        labels: u8
+    ) -> bool {
+        check_canonical_isomorphism(&g, &h, labels)
+    }
+
+    fn canonical_labeling_matches_isomorphism_undirected(
+        g: Tiny<UnGraph<u8, ()>>,
+        h: Tiny<UnGraph<u8, ()>>,
+        labels: u8
+    ) -> bool {
+        check_canonical_isomorphism(&g, &h, labels)
+    }
+}
+
+fn edit_node_cost(a: Option<&u8>, b: Option<&u8>) -> u32 {
+    match (a, b) {
+        (Some(a), Some(b)) => 2 * (a % 3 != b % 3) as u32,
+        _ => 3,
+    }
+}
+
+fn edit_edge_cost(a: Option<&u8>, b: Option<&u8>) -> u32 {
+    match (a, b) {
+        (Some(a), Some(b)) => (i32::from(a % 3) - i32::from(b % 3)).abs() as u32,
+        _ => 2,
+    }
+}
+
+/// The cost of the edit path given by a node assignment, from the definition, or `None` if the
+/// path does not assign every node of `g0` once, in order, and every node of `g1` once.
+fn edit_path_cost<Ty: EdgeType>(
+    g0: &Graph<u8, u8, Ty>,
+    g1: &Graph<u8, u8, Ty>,
+    path: &[(Option<NodeIndex>, Option<NodeIndex>)],
+) -> Option<u32> {
+    let sources: Vec<_> = path.iter().filter_map(|p| p.0).collect();
+    let mut targets: Vec<_> = path.iter().filter_map(|p| p.1).collect();
+    targets.sort();
+    if sources != g0.node_indices().collect::<Vec<_>>()
+        || targets != g1.node_indices().collect::<Vec<_>>()
+    {
+        return None;
+    }
+    let mut image = vec![None; g0.node_count()];
+    let mut cost = 0;
+    for &(a, b) in path {
+        if let Some(a) = a {
+            image[a.index()] = b;
+        }
+        cost += edit_node_cost(a.map(|a| &g0[a]), b.map(|b| &g1[b]));
+    }
+    let mut substituted = HashSet::new();
+    for e in g0.edge_references() {
+        let f = match (image[e.source().index()], image[e.target().index()]) {
+            (Some(a), Some(b)) => g1.find_edge(a, b),
+            _ => None,
+        };
+        if let Some(f) = f {
+            substituted.insert(f);
+        }
+        cost += edit_edge_cost(Some(e.weight()), f.map(|f| &g1[f]));
+    }
+    for f in g1.edge_references() {
+        if !substituted.contains(&f.id()) {
+            cost += edit_edge_cost(None, Some(f.weight()));
+        }
+    }
+    Some(cost)
+}
+
+/// The edit distance, trying every assignment of the nodes of `g0` to distinct nodes of `g1` or to
+/// deletion.
+fn min_edit_path_cost<Ty: EdgeType>(g0: &Graph<u8, u8, Ty>, g1: &Graph<u8, u8, Ty>) -> u32 {
+    fn assign<Ty: EdgeType>(
+        g0: &Graph<u8, u8, Ty>,
+        g1: &Graph<u8, u8, Ty>,
+        path: &mut Vec<(Option<NodeIndex>, Option<NodeIndex>)>,
+    ) -> u32 {
+        if path.len() == g0.node_count() {
+            let mut complete = path.clone();
+            for b in g1.node_indices() {
+                if !path.iter().any(|p| p.1 == Some(b)) {
+                    complete.push((None, Some(b)));
+                }
+            }
+            return edit_path_cost(g0, g1, &complete).unwrap();
+        }
+        let a = node_index(path.len());
+        let targets: Vec<_> = g1
+            .node_indices()
+            .map(Some)
+            .chain(Some(None))
+            .filter(|&b| b.is_none() || !path.iter().any(|p| p.1 == b))
+            .collect();
+        targets
+            .into_iter()
+            .map(|b| {
+                path.push((Some(a), b));
+                let cost = assign(g0, g1, path);
+                path.pop();
+                cost
+            })
+            .min()
+            .unwrap()
+    }
+    assign(g0, g1, &mut Vec::new())
+}
+
+/// The graph without the nodes from index 5, which keeps the brute force search small.
+fn first_five<N: Clone, E: Clone, Ty: EdgeType>(g: &Graph<N, E, Ty>) -> Graph<N, E, Ty> {
+    let mut g = g.clone();
+    g.retain_nodes(|_, v| v.index() < 5);
+    g
+}
+
+fn check_edit_distance<Ty: EdgeType>(g0: &Graph<u8, u8, Ty>, g1: &Graph<u8, u8, Ty>) -> bool {
+    let (g0, g1) = (first_five(g0), first_five(g1));
+    let (distance, path) = graph_edit_distance(&g0, &g1, edit_node_cost, edit_edge_cost);
+    let (bounded, bounded_path, exact) = graph_edit_distance_within_budget(
+        &g0,
+        &g1,
+        edit_node_cost,
+        edit_edge_cost,
+        std::usize::MAX,
+    );
+    let (approximate, approximate_path) =
+        approximate_graph_edit_distance(&g0, &g1, edit_node_cost, edit_edge_cost);
+    edit_path_cost(&g0, &g1, &path) == Some(distance)
+        && distance == min_edit_path_cost(&g0, &g1)
+        && exact
+        && bounded == distance
+        && edit_path_cost(&g0, &g1, &bounded_path) == Some(distance)
+        && edit_path_cost(&g0, &g1, &approximate_path) == Some(approximate)
+        && approximate >= distance
+}
+
+/// Whether the edit distance with unit costs is zero exactly when `g` is isomorphic to its copy
+/// with the edge `moved` moved to `(a, b)`, which often leaves an isomorphic graph.
+fn check_edit_distance_zero<Ty: EdgeType>(g: &Graph<u8, u8, Ty>, moved: u8, a: u8, b: u8) -> bool {
+    let g0 = first_five(g).map(|_, w| w % 2, |_, w| w % 2);
+    let mut g1 = g0.clone();
+    let n = g1.node_count();
+    if g1.edge_count() > 0 {
+        let w = g1
+            .remove_edge(edge_index(usize::from(moved) % g1.edge_count()))
+            .unwrap();
+        g1.update_edge(
+            node_index(usize::from(a) % n),
+            node_index(usize::from(b) % n),
+            w,
+        );
+    }
+    let unit = |a: Option<&u8>, b: Option<&u8>| match (a, b) {
+        (Some(a), Some(b)) if a == b => 0.,
+        _ => 1.,
+    };
+    let (distance, _) = graph_edit_distance(&g0, &g1, unit, unit);
+    (distance == 0.) == is_isomorphic_matching(&g0, &g1, PartialEq::eq, PartialEq::eq)
+}
+
+quickcheck! {
+    fn edit_distance_directed(g0: Tiny<Graph<u8, u8>>, g1: Tiny<Graph<u8, u8>>) -> bool {
+        check_edit_distance(&g0, &g1)
+    }
+
+    fn edit_distance_undirected(g0: Tiny<UnGraph<u8, u8>>, g1: Tiny<UnGraph<u8, u8>>) -> bool {
+        check_edit_distance(&g0, &g1)
+    }
+
+    fn edit_distance_zero_for_isomorphic_graphs(
+        g: Tiny<Graph<u8, u8>>,
+        moved: u8,
+        a: u8,
+        b: u8
+    ) -> bool {
+        check_edit_distance_zero(&g, moved, a, b)
+    }
+}
@@ -0,0 +1,155 @@
+use std::f64::consts::PI;
+
+use petgraph::algo::{algebraic_connectivity, fiedler_vector, spectral_bisection};
+use petgraph::csr::Csr;
+use petgraph::prelude::*;
+use rand::{ChaChaRng, Rng, SeedableRng};
+
+fn grid(width: usize, height: usize) -> UnGraph<(), ()> {
+    let mut edges = Vec::new();
+    for i in 0..height {
+        for j in 0..width {
+            let v = (i * width + j) as u32;
+            if i + 1 < height {
+                edges.push((v, v + width as u32));
+            }
+            if j + 1 < width {
+                edges.push((v, v + 1));
+            }
+        }
+    }
+    UnGraph::from_edges(&edges)
+}
+
+/// Check that `vector` is a unit eigenvector of the Laplacian of `graph` for `value`, orthogonal
+/// to the constant vector.
+fn assert_eigenvector(graph: &UnGraph<(), ()>, value: f64, vector: &[f64]) {
+    let mut product: Vec<f64> = vector.iter().map(|x| -value * x).collect();
+    for e in graph.edge_references() {
+        let (a, b) = (e.source().index(), e.target().index());
+        product[a] += vector[a] - vector[b];
+        product[b] += vector[b] - vector[a];
+    }
+    let residual = product.iter().map(|p| p * p).sum::<f64>().sqrt();
+    assert!(residual < 1e-7, "residual {}", residual);
+    assert!(vector.iter().sum::<f64>().abs() < 1e-8);
+    assert!((vector.iter().map(|x| x * x).sum::<f64>() - 1.).abs() < 1e-8);
+}
+
+#[test]
+fn fiedler_of_known_spectra() {
+    let close = |a: f64, b: f64| (a - b).abs() < 1e-9;
+    for n in 2..40 {
+        let path = UnGraph::<(), ()>::from_edges((1..n as u32).map(|i| (i - 1, i)));
+        let value = algebraic_connectivity(&path, |_| 1.).unwrap();
+        assert!(close(value, 2. * (1. - (PI / n as f64).cos())));
+
+        let cycle = UnGraph::<(), ()>::from_edges((0..n as u32).map(|i| (i, (i + 1) % n as u32)));
+        let expected = if n == 2 {
+            4.
+        } else {
+            2. * (1. - (2. * PI / n as f64).cos())
+        };
+        assert!(close(
+            algebraic_connectivity(&cycle, |_| 1.).unwrap(),
+            expected
+        ));
+
+        let star = UnGraph::<(), ()>::from_edges((1..n as u32).map(|i| (0, i)));
+        let expected = if n == 2 { 2. } else { 1. };
+        assert!(close(
+            algebraic_connectivity(&star, |_| 1.).unwrap(),
+            expected
+        ));
+    }
+
+    // The hypercube of dimension 6.
+    let cube = UnGraph::<(), ()>::from_edges((0..64u32).flat_map(|v| {
+        (0..6)
+            .map(move |d| (v, v ^ (1 << d)))
+            .filter(|&(a, b)| a < b)
+    }));
+    assert!(close(algebraic_connectivity(&cube, |_| 1.).unwrap(), 2.));
+}
+
+#[test]
+fn fiedler_of_disconnected_graphs() {
+    let empty = UnGraph::<(), ()>::new_undirected();
+    assert_eq!(fiedler_vector(&empty, |_| 1.), None);
+    let single = UnGraph::<(), ()>::from_edges(&[(0, 0)]);
+    assert_eq!(algebraic_connectivity(&single, |_| 1.), None);
+    assert_eq!(spectral_bisection(&single, |_| 1.), (0., vec![0]));
+
+    let mut graph = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (3, 4), (4, 5), (5, 3)]);
+    graph.add_node(());
+    let (value, vector) = fiedler_vector(&graph, |_| 1.).unwrap();
+    assert!(value.abs() < 1e-9);
+    assert_eigenvector(&graph, value, &vector);
+
+    let isolated = UnGraph::<(), ()>::from_edges(&[(0, 0), (2, 2)]);
+    let (value, vector) = fiedler_vector(&isolated, |_| 1.).unwrap();
+    assert_eq!(value, 0.);
+    assert_eq!(vector.len(), 3);
+}
+
+#[test]
+fn fiedler_skips_removed_nodes() {
+    // Vacant indices have no value. Removing node 1 leaves the path 0, 2, 3, 4.
+    let mut stable = StableUnGraph::<(), ()>::default();
+    let nodes: Vec<_> = (0..5).map(|_| stable.add_node(())).collect();
+    for i in 0..4 {
+        stable.add_edge(nodes[i], nodes[i + 1], ());
+    }
+    stable.add_edge(nodes[0], nodes[2], ());
+    stable.remove_node(nodes[1]);
+    let (value, vector) = fiedler_vector(&stable, |_| 1.).unwrap();
+    assert!((value - 2. * (1. - (PI / 4.).cos())).abs() < 1e-9);
+    assert!(vector[1].is_nan());
+    let (cut, parts) = spectral_bisection(&stable, |_| 1.);
+    assert_eq!(cut, 1.);
+    assert_eq!(parts[1], std::usize::MAX);
+    assert_eq!(parts[0], parts[2]);
+    assert_eq!(parts[3], parts[4]);
+}
+
+#[test]
+fn spectral_bisection_of_grids_and_communities() {
+    // A long grid is cut across its short side.
+    let graph = grid(30, 8);
+    let (cut, parts) = spectral_bisection(&graph, |_| 1.);
+    assert_eq!(cut, 8.);
+    assert_eq!(parts.iter().filter(|&&p| p == 0).count(), 120);
+
+    let mut rng = ChaChaRng::from_seed([123; 32]);
+    let n = 200;
+    let mut edges = Vec::new();
+    for a in 0..n {
+        for b in a + 1..n {
+            let same = (a < n / 2) == (b < n / 2);
+            if rng.gen::<f64>() < if same { 0.1 } else { 0.01 } {
+                edges.push((a as u32, b as u32, 1.));
+            }
+        }
+    }
+    let mut csr = Csr::<(), f64>::with_nodes(n);
+    for &(a, b, w) in &edges {
+        csr.add_edge(a, b, w);
+        csr.add_edge(b, a, w);
+    }
+    let planted = edges
+        .iter()
+        .filter(|&&(a, b, _)| (a < 100) != (b < 100))
+        .count();
+    let (cut, parts) = spectral_bisection(&csr, |e| *e.weight());
+    // Both directions of each edge are counted.
+    assert_eq!(cut, 2. * planted as f64);
+    assert!(parts[..100].iter().all(|&p| p == parts[0]));
+}
+
+#[test]
+fn fiedler_of_large_grid() {
+    let graph = grid(60, 40);
+    let (value, vector) = fiedler_vector(&graph, |_| 1.).unwrap();
+    assert!((value - 2. * (1. - (PI / 60.).cos())).abs() < 1e-9);
+    assert_eigenvector(&graph, value, &vector);
+}
@@ -0,0 +1,109 @@
+use std::collections::HashSet;
+
+use petgraph::algo::{metric_closure_steiner_tree, steiner_tree};
+use petgraph::graph::EdgeReference;
+use petgraph::prelude::*;
+use petgraph::unionfind::UnionFind;
+use rand::{ChaChaRng, Rng, SeedableRng};
+
+/// Check that the edges form a tree that connects the terminals, with only
+/// terminals as leaves, and return its weight.
+fn assert_steiner_tree(
+    graph: &UnGraph<(), u32>,
+    terminals: &[NodeIndex],
+    edges: &[EdgeReference<u32>],
+) -> u32 {
+    let mut trees = UnionFind::new(graph.node_count());
+    let mut degree = vec![0; graph.node_count()];
+    for e in edges {
+        assert!(trees.union(e.source().index(), e.target().index()));
+        degree[e.source().index()] += 1;
+        degree[e.target().index()] += 1;
+    }
+    for t in terminals {
+        assert!(trees.equiv(t.index(), terminals[0].index()));
+    }
+    for v in graph.node_indices() {
+        assert!(degree[v.index()] != 1 || terminals.contains(&v));
+    }
+    let distinct: HashSet<_> = edges.iter().map(|e| e.id()).collect();
+    assert_eq!(distinct.len(), edges.len());
+    edges.iter().map(|e| *e.weight()).sum()
+}
+
+#[test]
+fn steiner_tree_detour_through_hub() {
+    // Every pair of terminals is joined directly, but the path through the
+    // hub is shorter.
+    let mut graph = UnGraph::<(), u32>::default();
+    let hub = graph.add_node(());
+    let terminals: Vec<NodeIndex> = (0..5).map(|_| graph.add_node(())).collect();
+    for (i, &a) in terminals.iter().enumerate() {
+        graph.add_edge(hub, a, 3);
+        for &b in &terminals[i + 1..] {
+            graph.add_edge(a, b, 7);
+        }
+    }
+    for result in vec![
+        steiner_tree(&graph, &terminals, |e| *e.weight()),
+        metric_closure_steiner_tree(&graph, &terminals, |e| *e.weight()),
+    ] {
+        let (weight, edges) = result.unwrap();
+        assert_eq!(assert_steiner_tree(&graph, &terminals, &edges), weight);
+        assert_eq!(weight, 15);
+    }
+}
+
+#[test]
+fn steiner_tree_of_few_terminals() {
+    let graph = UnGraph::<(), u32>::from_edges(&[(0, 1, 1), (1, 2, 1)]);
+    let none: [NodeIndex; 0] = [];
+    assert_eq!(
+        steiner_tree(&graph, &none, |e| *e.weight()),
+        Some((0, vec![]))
+    );
+    let one = [NodeIndex::new(1), NodeIndex::new(1)];
+    assert_eq!(
+        steiner_tree(&graph, &one, |e| *e.weight()),
+        Some((0, vec![]))
+    );
+    let both = [NodeIndex::new(0), NodeIndex::new(2), NodeIndex::new(0)];
+    let (weight, edges) = metric_closure_steiner_tree(&graph, &both, |e| *e.weight()).unwrap();
+    assert_eq!((weight, edges.len()), (2, 2));
+
+    // Terminals in different components are not connected.
+    let mut graph = graph;
+    graph.add_node(());
+    let apart = [NodeIndex::new(0), NodeIndex::new(3)];
+    assert_eq!(steiner_tree(&graph, &apart, |e| *e.weight()), None);
+    assert_eq!(
+        metric_closure_steiner_tree(&graph, &apart, |e| *e.weight()),
+        None
+    );
+}
+
+#[test]
+fn steiner_tree_large_grid() {
+    let size = 150;
+    let mut rng = ChaChaRng::from_seed([52; 32]);
+    let mut graph = UnGraph::<(), u32>::with_capacity(size * size, 2 * size * size);
+    for _ in 0..size * size {
+        graph.add_node(());
+    }
+    let n = |i: usize, j: usize| NodeIndex::new(i * size + j);
+    for i in 0..size {
+        for j in 0..size {
+            if i + 1 < size {
+                graph.add_edge(n(i, j), n(i + 1, j), rng.gen_range(1, 5));
+            }
+            if j + 1 < size {
+                graph.add_edge(n(i, j), n(i, j + 1), rng.gen_range(1, 5));
+            }
+        }
+    }
+    let terminals: Vec<NodeIndex> = (0..200)
+        .map(|_| n(rng.gen_range(0, size), rng.gen_range(0, size)))
+        .collect();
+    let (weight, edges) = steiner_tree(&graph, &terminals, |e| *e.weight()).unwrap();
+    assert_eq!(assert_steiner_tree(&graph, &terminals, &edges), weight);
+}
@@ -0,0 +1,99 @@
+use std::collections::HashSet;
+
+use petgraph::algo::{all_topological_sorts, topological_generations, toposort_by};
+use petgraph::prelude::*;
+
+fn assert_is_topological_order(g: &DiGraph<(), ()>, order: &[NodeIndex]) {
+    assert_eq!(order.len(), g.node_count());
+    for edge in g.edge_references() {
+        let source = order.iter().position(|&n| n == edge.source()).unwrap();
+        let target = order.iter().position(|&n| n == edge.target()).unwrap();
+        assert!(
+            source < target,
+            "{:?} is ordered after its successor",
+            edge.source()
+        );
+    }
+}
+
+#[test]
+fn generations_diamond() {
+    let g = DiGraph::<(), ()>::from_edges(&[(0, 1), (0, 2), (1, 3), (2, 3), (4, 3)]);
+    let generations = topological_generations(&g).unwrap();
+    assert_eq!(
+        generations,
+        vec![vec![n(0), n(4)], vec![n(1), n(2)], vec![n(3)]]
+    );
+}
+
+#[test]
+fn generations_use_longest_path() {
+    // 0 -> 2 directly and through 1, so 2 must wait for 1.
+    let g = DiGraph::<(), ()>::from_edges(&[(0, 2), (0, 1), (1, 2)]);
+    let generations = topological_generations(&g).unwrap();
+    assert_eq!(generations, vec![vec![n(0)], vec![n(1)], vec![n(2)]]);
+}
+
+#[test]
+fn generations_cycle() {
+    let g = DiGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 1), (2, 3)]);
+    let cycle = topological_generations(&g).unwrap_err();
+    assert!([n(1), n(2)].contains(&cycle.node_id()));
+
+    let mut g = DiGraph::<(), ()>::new();
+    let a = g.add_node(());
+    g.add_edge(a, a, ());
+    assert_eq!(topological_generations(&g).unwrap_err().node_id(), a);
+}
+
+#[test]
+fn toposort_by_lexicographically_smallest() {
+    let g = DiGraph::<(), ()>::from_edges(&[(3, 0), (2, 1), (4, 1), (4, 0)]);
+    let order = toposort_by(&g, |a, b| a.cmp(b)).unwrap();
+    assert_eq!(order, vec![n(2), n(3), n(4), n(0), n(1)]);
+
+    let order = toposort_by(&g, |a, b| b.cmp(a)).unwrap();
+    assert_eq!(order, vec![n(4), n(3), n(2), n(1), n(0)]);
+}
+
+#[test]
+fn toposort_by_cycle() {
+    let g = DiGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 0), (3, 0)]);
+    let cycle = toposort_by(&g, |a, b| a.cmp(b)).unwrap_err();
+    assert!([n(0), n(1), n(2)].contains(&cycle.node_id()));
+}
+
+#[test]
+fn all_orders() {
+    let g = DiGraph::<(), ()>::from_edges(&[(0, 1), (0, 2), (1, 3), (2, 3), (4, 4)]);
+    // the self loop makes every order impossible
+    assert_eq!(all_topological_sorts(&g).count(), 0);
+
+    let g = DiGraph::<(), ()>::from_edges(&[(0, 1), (0, 2), (1, 3), (2, 3)]);
+    let orders: Vec<_> = all_topological_sorts(&g).collect();
+    assert_eq!(
+        orders,
+        vec![vec![n(0), n(1), n(2), n(3)], vec![n(0), n(2), n(1), n(3)],]
+    );
+}
+
+#[test]
+fn all_orders_count_and_validity() {
+    let mut g = DiGraph::<(), ()>::from_edges(&[(0, 1), (2, 3)]);
+    g.add_node(());
+    let orders: Vec<_> = all_topological_sorts(&g).collect();
+    // 5! / (2 * 2) interleavings of two chains and a free node
+    assert_eq!(orders.len(), 30);
+    let distinct: HashSet<_> = orders.iter().cloned().collect();
+    assert_eq!(distinct.len(), orders.len());
+    for order in &orders {
+        assert_is_topological_order(&g, order);
+    }
+
+    let g = DiGraph::<(), ()>::new();
+    assert_eq!(all_topological_sorts(&g).collect::<Vec<_>>(), vec![vec![]]);
+}
+
+fn n(ix: usize) -> NodeIndex {
+    NodeIndex::new(ix)
+}
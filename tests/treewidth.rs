@@ -0,0 +1,103 @@
+use std::collections::HashSet;
+
+use petgraph::algo::{connected_components, treewidth, EliminationHeuristic};
+use petgraph::prelude::*;
+
+const HEURISTICS: [EliminationHeuristic; 2] = [
+    EliminationHeuristic::MinDegree,
+    EliminationHeuristic::MinFill,
+];
+
+fn assert_tree_decomposition(
+    graph: &UnGraph<(), ()>,
+    width: usize,
+    decomposition: &UnGraph<Vec<NodeIndex>, ()>,
+) {
+    if graph.node_count() == 0 {
+        assert_eq!(decomposition.node_count(), 0);
+        return;
+    }
+    // The bags form a tree.
+    assert_eq!(decomposition.edge_count() + 1, decomposition.node_count());
+    assert_eq!(connected_components(decomposition), 1);
+    assert_eq!(
+        decomposition.node_weights().map(|b| b.len()).max(),
+        Some(width + 1)
+    );
+    // Every edge is in a bag.
+    for e in graph.edge_references() {
+        assert!(decomposition
+            .node_weights()
+            .any(|b| b.contains(&e.source()) && b.contains(&e.target())));
+    }
+    // The bags containing a node are connected, and there is at least one.
+    for v in graph.node_indices() {
+        let mut with_v = decomposition.clone();
+        with_v.retain_nodes(|g, b| g[b].contains(&v));
+        assert!(with_v.node_count() > 0);
+        assert_eq!(connected_components(&with_v), 1);
+    }
+    for bag in decomposition.node_weights() {
+        assert_eq!(bag.iter().collect::<HashSet<_>>().len(), bag.len());
+    }
+}
+
+#[test]
+fn treewidth_of_known_graphs() {
+    let path = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 3)]);
+    let star = UnGraph::<(), ()>::from_edges(&[(0, 1), (0, 2), (0, 3), (0, 4)]);
+    let cycle = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 3), (3, 4), (4, 5), (5, 0)]);
+    let mut complete = UnGraph::<(), ()>::new_undirected();
+    let nodes: Vec<_> = (0..5).map(|_| complete.add_node(())).collect();
+    for (i, &a) in nodes.iter().enumerate() {
+        for &b in &nodes[i + 1..] {
+            complete.add_edge(a, b, ());
+        }
+    }
+    let mut isolated = UnGraph::<(), ()>::new_undirected();
+    for _ in 0..3 {
+        isolated.add_node(());
+    }
+    for &heuristic in &HEURISTICS {
+        for &(ref graph, expected) in &[
+            (&path, 1),
+            (&star, 1),
+            (&cycle, 2),
+            (&complete, 4),
+            (&isolated, 0),
+        ] {
+            let (width, decomposition) = treewidth(graph, heuristic);
+            assert_eq!(width, expected);
+            assert_tree_decomposition(graph, width, &decomposition);
+        }
+    }
+    let (width, decomposition) = treewidth(&complete, EliminationHeuristic::MinFill);
+    assert_eq!((width, decomposition.node_count()), (4, 1));
+}
+
+#[test]
+fn treewidth_empty_graph() {
+    let graph = UnGraph::<(), ()>::new_undirected();
+    for &heuristic in &HEURISTICS {
+        let (width, decomposition) = treewidth(&graph, heuristic);
+        assert_eq!(width, 0);
+        assert_eq!(decomposition.node_count(), 0);
+    }
+}
+
+#[test]
+fn treewidth_of_grid() {
+    let mut graph = UnGraph::<(), ()>::new_undirected();
+    let n: Vec<_> = (0..16).map(|_| graph.add_node(())).collect();
+    for i in 0..4 {
+        for j in 0..3 {
+            graph.add_edge(n[4 * i + j], n[4 * i + j + 1], ());
+            graph.add_edge(n[4 * j + i], n[4 * (j + 1) + i], ());
+        }
+    }
+    for &heuristic in &HEURISTICS {
+        let (width, decomposition) = treewidth(&graph, heuristic);
+        assert!(width >= 4);
+        assert_tree_decomposition(&graph, width, &decomposition);
+    }
+}
@@ -0,0 +1,123 @@
+use petgraph::algo::{christofides, held_karp, nearest_neighbor_tour, or_opt, two_opt};
+use petgraph::graph::EdgeReference;
+use petgraph::matrix_graph::MatrixGraph;
+use petgraph::prelude::*;
+
+#[test]
+fn held_karp_follows_cheap_directions() {
+    // Going around the directed square one way is cheap, the other way expensive.
+    let mut edges = Vec::new();
+    for i in 0..4 {
+        edges.push((i, (i + 1) % 4, 1));
+        edges.push(((i + 1) % 4, i, 5));
+    }
+    let graph = DiGraph::<(), u32>::from_edges(&edges);
+    let tour: Vec<NodeIndex> = (0..4).map(NodeIndex::new).collect();
+    assert_eq!(held_karp(&graph, |e| *e.weight()), Some((4, tour.clone())));
+    assert_eq!(
+        nearest_neighbor_tour(&graph, tour[2], |e| *e.weight())
+            .unwrap()
+            .0,
+        4
+    );
+}
+
+#[test]
+fn local_search_uncrosses_tour() {
+    // The corners of a unit square, visited along the diagonals.
+    let diagonal = 2f64.sqrt();
+    let graph = UnGraph::<(), f64>::from_edges(&[
+        (0, 1, 1.),
+        (1, 2, 1.),
+        (2, 3, 1.),
+        (3, 0, 1.),
+        (0, 2, diagonal),
+        (1, 3, diagonal),
+    ]);
+    let crossing: Vec<NodeIndex> = [0, 2, 1, 3].iter().map(|&i| NodeIndex::new(i)).collect();
+    let cost = |e: EdgeReference<f64>| *e.weight();
+    for result in vec![
+        two_opt(&graph, &crossing, cost),
+        or_opt(&graph, &crossing, cost),
+    ] {
+        let (c, tour) = result.unwrap();
+        assert_eq!(c, 4.);
+        assert_eq!(tour[0], crossing[0]);
+    }
+    assert_eq!(christofides(&graph, cost).unwrap().0, 4.);
+}
+
+#[test]
+fn tsp_on_matrix_graph() {
+    // Distances between the corners and the middle of the sides of a 2 × 2 square.
+    let points: [(i32, i32); 8] = [
+        (0, 0),
+        (1, 0),
+        (2, 0),
+        (2, 1),
+        (2, 2),
+        (1, 2),
+        (0, 2),
+        (0, 1),
+    ];
+    let mut graph = MatrixGraph::<(), u32, Undirected>::with_capacity(points.len());
+    let nodes: Vec<_> = points.iter().map(|_| graph.add_node(())).collect();
+    for a in 0..points.len() {
+        for b in a + 1..points.len() {
+            let (dx, dy) = (points[a].0 - points[b].0, points[a].1 - points[b].1);
+            // Manhattan distances satisfy the triangle inequality.
+            let distance = dx.abs() + dy.abs();
+            graph.add_edge(nodes[a], nodes[b], distance as u32);
+        }
+    }
+    let cost = |e: (_, _, &u32)| *e.2;
+    assert_eq!(held_karp(&graph, cost).unwrap().0, 8);
+    let (c, tour) = christofides(&graph, cost).unwrap();
+    assert!(c <= 12);
+    assert_eq!(tour.len(), 8);
+    let (c, _) = nearest_neighbor_tour(&graph, nodes[0], cost).unwrap();
+    assert_eq!(c, 8);
+    let crossing: Vec<_> = [0, 4, 1, 5, 2, 6, 3, 7].iter().map(|&i| nodes[i]).collect();
+    let (c, tour) = two_opt(&graph, &crossing, cost).unwrap();
+    assert!((8..22).contains(&c));
+    assert_eq!(tour[0], nodes[0]);
+}
+
+#[test]
+fn tsp_of_graphs_with_few_nodes() {
+    let empty = UnGraph::<(), u32>::default();
+    assert_eq!(held_karp(&empty, |e| *e.weight()), Some((0, vec![])));
+    assert_eq!(christofides(&empty, |e| *e.weight()), Some((0, vec![])));
+
+    let single = UnGraph::<(), u32>::from_edges(&[(0, 0, 3)]);
+    let tour = vec![NodeIndex::new(0)];
+    assert_eq!(held_karp(&single, |e| *e.weight()), Some((0, tour.clone())));
+    assert_eq!(
+        christofides(&single, |e| *e.weight()),
+        Some((0, tour.clone()))
+    );
+    assert_eq!(
+        two_opt(&single, &tour, |e| *e.weight()),
+        Some((0, tour.clone()))
+    );
+
+    // Two nodes make a tour out of one edge, there and back.
+    let pair = UnGraph::<(), u32>::from_edges(&[(0, 1, 3)]);
+    assert_eq!(held_karp(&pair, |e| *e.weight()).unwrap().0, 6);
+    assert_eq!(christofides(&pair, |e| *e.weight()).unwrap().0, 6);
+
+    let path = UnGraph::<(), u32>::from_edges(&[(0, 1, 1), (1, 2, 1)]);
+    assert_eq!(held_karp(&path, |e| *e.weight()), None);
+    assert_eq!(christofides(&path, |e| *e.weight()), None);
+    assert_eq!(
+        nearest_neighbor_tour(&path, 1.into(), |e| *e.weight()),
+        None
+    );
+}
+
+#[test]
+#[should_panic]
+fn two_opt_rejects_incomplete_tour() {
+    let graph = UnGraph::<(), u32>::from_edges(&[(0, 1, 1), (1, 2, 1), (2, 0, 1)]);
+    two_opt(&graph, &[0.into(), 1.into()], |e| *e.weight());
+}
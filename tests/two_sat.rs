@@ -0,0 +1,38 @@
+use petgraph::algo::{two_sat, Literal};
+
+fn satisfies(assignment: &[bool], clauses: &[(Literal, Literal)]) -> bool {
+    clauses
+        .iter()
+        .all(|&((a, x), (b, y))| assignment[a] == x || assignment[b] == y)
+}
+
+#[test]
+fn two_sat_chain_of_implications() {
+    // x0 → x1 → ... → x(n-1) → ¬x0, so x0 is false, and forcing x0 makes the
+    // whole chain the core.
+    let n = 1000;
+    let mut clauses: Vec<(Literal, Literal)> =
+        (0..n - 1).map(|v| ((v, false), (v + 1, true))).collect();
+    clauses.push(((n - 1, false), (0, false)));
+    let assignment = two_sat(n, &clauses).unwrap();
+    assert!(!assignment[0]);
+    assert!(satisfies(&assignment, &clauses));
+
+    clauses.push(((0, true), (0, true)));
+    let core = two_sat(n, &clauses).unwrap_err();
+    assert_eq!(core.clauses().len(), n + 1);
+}
+
+#[test]
+fn two_sat_of_empty_and_unit_formulas() {
+    assert_eq!(two_sat(0, &[]), Ok(vec![]));
+    assert!(!two_sat(2, &[((1, false), (1, false))]).unwrap()[1]);
+    let core = two_sat(1, &[((0, true), (0, true)), ((0, false), (0, false))]).unwrap_err();
+    assert_eq!((core.variable(), core.clauses()), (0, &[0, 1][..]));
+}
+
+#[test]
+#[should_panic]
+fn two_sat_variable_out_of_bounds() {
+    let _ = two_sat(2, &[((0, true), (2, false))]);
+}
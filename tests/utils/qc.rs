@@ -27,6 +27,30 @@ where
     }
 }
 
+#[derive(Copy, Clone, Debug)]
+/// quickcheck Arbitrary adaptor - fewer than 10 nodes, small enough to check against brute force
+pub struct Tiny<T>(pub T);
+
+impl<T> Deref for Tiny<T> {
+    type Target = T;
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> Arbitrary for Tiny<T>
+where
+    T: Arbitrary,
+{
+    fn arbitrary<G: Gen>(g: &mut G) -> Self {
+        Tiny(T::arbitrary(&mut StdGen::new(g, 10)))
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        Box::new((**self).shrink().map(Tiny))
+    }
+}
+
 #[cfg(feature = "stable_graph")]
 /// A directed graph where each pair of nodes has exactly one edge between them, and no loops.
 #[derive(Clone, Debug)]